@@ -0,0 +1,15 @@
+//! Matrix decompositions.
+//!
+//! The decompositions are written purely in terms of indexing so that they can be used with any
+//! scalar type (e.g. dual numbers). They provide the default implementations of the
+//! corresponding [`Matrix`](crate::Matrix) methods, which the implementations for types from
+//! numerical computing crates override where those crates provide the decomposition.
+
+// Module declarations.
+pub(crate) mod cholesky;
+pub(crate) mod eigen;
+pub(crate) mod lu;
+pub(crate) mod qr;
+pub(crate) mod schur;
+pub(crate) mod svd;
+pub(crate) mod symmetric_eigen;
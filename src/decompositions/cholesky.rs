@@ -0,0 +1,227 @@
+use crate::error::LinalgError;
+use crate::matrix::matrix_trait::Matrix;
+use crate::scalar::Scalar;
+use crate::vector::vector_trait::{Vector, check_length};
+use std::marker::PhantomData;
+
+/// Cholesky decomposition of a symmetric positive definite matrix (`A = L * Lᵀ`).
+///
+/// * `L` is a lower triangular matrix with strictly positive diagonal elements.
+///
+/// # Note
+///
+/// This decomposition is created via [`Matrix::cholesky`]. Only the lower triangular part of the
+/// factored matrix is read (i.e. the matrix is assumed to be symmetric without this being
+/// checked).
+///
+/// # Example
+///
+/// ```
+/// use linalg_traits::{LinalgError, Mat, Matrix};
+///
+/// // Factor a symmetric positive definite matrix.
+/// let a = Mat::from_row_slice(2, 2, &[4.0, 2.0, 2.0, 5.0]);
+/// let chol = a.cholesky().unwrap();
+/// assert_eq!(chol.l(), Mat::from_row_slice(2, 2, &[2.0, 0.0, 1.0, 2.0]));
+/// assert_eq!(chol.solve(&vec![6.0, 7.0]).unwrap(), vec![1.0, 1.0]);
+///
+/// // Attempt to factor a matrix that is not positive definite.
+/// let b = Mat::from_row_slice(2, 2, &[1.0, 2.0, 2.0, 1.0]);
+/// assert_eq!(b.cholesky(), Err(LinalgError::NotPositiveDefinite { pivot: 1 }));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cholesky<S: Scalar, M: Matrix<S>> {
+    /// Lower triangular factor `L` (with its strictly upper triangular part set to zero).
+    l: M,
+
+    /// Scalar type of the factored matrix.
+    _scalar: PhantomData<S>,
+}
+
+impl<S: Scalar, M: Matrix<S>> Cholesky<S, M> {
+    /// Cholesky decomposition.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - The symmetric positive definite matrix to factor. Only its lower triangular
+    ///   part is read.
+    ///
+    /// # Returns
+    ///
+    /// Cholesky decomposition of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// * [`LinalgError::ShapeMismatch`] if the matrix is not square.
+    /// * [`LinalgError::NotPositiveDefinite`] if the matrix is not positive definite, naming the
+    ///   first pivot that was not strictly positive.
+    pub(crate) fn new(matrix: &M) -> Result<Self, LinalgError> {
+        matrix.check_square()?;
+        let n = matrix.shape().0;
+        let mut l = M::new_with_shape(n, n);
+
+        for col in 0..n {
+            // Diagonal element.
+            let mut pivot = matrix[(col, col)];
+            for k in 0..col {
+                pivot -= l[(col, k)] * l[(col, k)];
+            }
+            if pivot <= S::zero() || pivot.is_nan() {
+                return Err(LinalgError::NotPositiveDefinite { pivot: col });
+            }
+            let diag = pivot.sqrt();
+            l[(col, col)] = diag;
+
+            // Elements below the diagonal.
+            for row in (col + 1)..n {
+                let mut sum = matrix[(row, col)];
+                for k in 0..col {
+                    sum -= l[(row, k)] * l[(col, k)];
+                }
+                l[(row, col)] = sum / diag;
+            }
+        }
+
+        Ok(Cholesky {
+            l,
+            _scalar: PhantomData,
+        })
+    }
+
+    /// Create a Cholesky decomposition from its lower triangular factor.
+    ///
+    /// # Arguments
+    ///
+    /// * `l` - Lower triangular factor `L` (with its strictly upper triangular part set to zero).
+    ///
+    /// # Returns
+    ///
+    /// Cholesky decomposition.
+    #[cfg(any(feature = "faer", feature = "nalgebra"))]
+    pub(crate) fn from_l(l: M) -> Self {
+        Cholesky {
+            l,
+            _scalar: PhantomData,
+        }
+    }
+
+    /// Get the lower triangular factor `L`.
+    ///
+    /// # Returns
+    ///
+    /// Lower triangular factor `L`.
+    pub fn l(&self) -> M {
+        self.l.clone()
+    }
+
+    /// Solve the linear system `A * x = b`.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side vector.
+    ///
+    /// # Returns
+    ///
+    /// Solution `x` of the linear system.
+    ///
+    /// # Errors
+    ///
+    /// * [`LinalgError::LengthMismatch`] if the length of `b` does not match the number of rows of
+    ///   the factored matrix.
+    pub fn solve(&self, b: &M::VectorM) -> Result<M::VectorM, LinalgError> {
+        let mut x = b.clone();
+        self.solve_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solve the linear system `A * x = b` in place (i.e. overwriting `b` with `x`).
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side vector, which is overwritten with the solution `x`.
+    ///
+    /// # Errors
+    ///
+    /// * [`LinalgError::LengthMismatch`] if the length of `b` does not match the number of rows of
+    ///   the factored matrix (in which case `b` is left unchanged).
+    pub fn solve_in_place(&self, b: &mut M::VectorM) -> Result<(), LinalgError> {
+        check_length(self.l.shape().0, b.len())?;
+        self.substitute(b);
+        Ok(())
+    }
+
+    /// Overwrite `b` with `A⁻¹ * b` via forward and back substitution.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side vector, which must have the same length as the number of rows
+    ///   of the factored matrix.
+    fn substitute(&self, b: &mut M::VectorM) {
+        let n = self.l.shape().0;
+
+        // Forward substitution (b <- L⁻¹ * b).
+        for row in 0..n {
+            for col in 0..row {
+                let update = self.l[(row, col)] * b[col];
+                b[row] -= update;
+            }
+            b[row] /= self.l[(row, row)];
+        }
+
+        // Back substitution (b <- L⁻ᵀ * b).
+        for row in (0..n).rev() {
+            for col in (row + 1)..n {
+                let update = self.l[(col, row)] * b[col];
+                b[row] -= update;
+            }
+            b[row] /= self.l[(row, row)];
+        }
+    }
+
+    /// Natural logarithm of the determinant of the factored matrix.
+    ///
+    /// # Returns
+    ///
+    /// Natural logarithm of the determinant of the factored matrix.
+    ///
+    /// # Note
+    ///
+    /// This is computed directly from the diagonal of `L` (`ln|A| = 2 Σᵢ ln Lᵢᵢ`), so it does not
+    /// overflow or underflow for large matrices the way computing the determinant itself can.
+    pub fn log_det(&self) -> S {
+        let n = self.l.shape().0;
+        let two = S::one() + S::one();
+        two * (0..n).fold(S::zero(), |sum, k| sum + self.l[(k, k)].ln())
+    }
+
+    /// Determinant of the factored matrix.
+    ///
+    /// # Returns
+    ///
+    /// Determinant of the factored matrix.
+    pub fn determinant(&self) -> S {
+        let n = self.l.shape().0;
+        (0..n).fold(S::one(), |det, k| det * self.l[(k, k)] * self.l[(k, k)])
+    }
+
+    /// Inverse of the factored matrix.
+    ///
+    /// # Returns
+    ///
+    /// Inverse of the factored matrix.
+    pub fn inverse(&self) -> M {
+        let n = self.l.shape().0;
+        let mut inverse = M::new_with_shape(n, n);
+        let mut col_vec = self.l.new_vector_m();
+        for col in 0..n {
+            for row in 0..n {
+                col_vec[row] = if row == col { S::one() } else { S::zero() };
+            }
+            self.substitute(&mut col_vec);
+            for row in 0..n {
+                inverse[(row, col)] = col_vec[row];
+            }
+        }
+        inverse
+    }
+}
@@ -0,0 +1,324 @@
+use crate::matrix::mat::Mat;
+use crate::matrix::matrix_trait::Matrix;
+use crate::scalar::Scalar;
+use crate::vector::vector_trait::Vector;
+
+/// Eigendecomposition of a general (not necessarily symmetric) real square matrix.
+///
+/// Since the eigenvalues and eigenvectors of a real matrix may be complex, their real and
+/// imaginary parts are stored separately.
+///
+/// * Complex eigenvalues come in complex conjugate pairs, which are stored consecutively with the
+///   eigenvalue with the positive imaginary part first.
+/// * The eigenvectors are stored in a paired-column format. If the `k`th eigenvalue is real, the
+///   `k`th column of the matrix of eigenvectors is its (real) eigenvector. If the `k`th and
+///   `(k + 1)`th eigenvalues are a complex conjugate pair, then the eigenvector of the `k`th
+///   eigenvalue is `v[:, k] + i * v[:, k + 1]`, and the eigenvector of the `(k + 1)`th eigenvalue
+///   is its complex conjugate, `v[:, k] - i * v[:, k + 1]`.
+/// * Each eigenvector is normalized to have a (complex) Euclidean norm of 1.
+///
+/// # Note
+///
+/// This decomposition is created via [`Matrix::eigen`] or [`crate::Schur::eigen`].
+///
+/// # Example
+///
+/// ```
+/// use linalg_traits::{Mat, Matrix};
+///
+/// // Rotation by 90 degrees (eigenvalues ±i).
+/// let a = Mat::from_row_slice(2, 2, &[0.0, -1.0, 1.0, 0.0]);
+/// let eigen = a.eigen().unwrap();
+/// assert_eq!(eigen.eigenvalues_real().as_slice(), [0.0, 0.0]);
+/// assert_eq!(eigen.eigenvalues_imag().as_slice(), [1.0, -1.0]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Eigen<S: Scalar, M: Matrix<S>> {
+    /// Real parts of the eigenvalues.
+    eigenvalues_real: M::VectorM,
+
+    /// Imaginary parts of the eigenvalues.
+    eigenvalues_imag: M::VectorM,
+
+    /// Eigenvectors (stored in the paired-column format).
+    eigenvectors: M,
+}
+
+impl<S: Scalar, M: Matrix<S>> Eigen<S, M> {
+    /// Create an eigendecomposition from its eigenvalues and (possibly unnormalized) eigenvectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `eigenvalues_real` - Real parts of the eigenvalues.
+    /// * `eigenvalues_imag` - Imaginary parts of the eigenvalues (complex conjugate pairs must be
+    ///   stored consecutively, with the eigenvalue with the positive imaginary part first).
+    /// * `eigenvectors` - `N x N` matrix of eigenvectors stored in the paired-column format.
+    ///
+    /// # Returns
+    ///
+    /// Eigendecomposition, with each eigenvector normalized to have a Euclidean norm of 1.
+    pub(crate) fn from_parts<T: Matrix<S>>(
+        eigenvalues_real: &[S],
+        eigenvalues_imag: &[S],
+        eigenvectors: &T,
+    ) -> Self {
+        let n = eigenvalues_real.len();
+        let mut vectors = M::new_with_shape(n, n);
+        let mut col = 0;
+        while col < n {
+            let width = if eigenvalues_imag[col] == S::zero() {
+                1
+            } else {
+                2
+            };
+            let norm = (0..n * width).fold(S::zero(), |norm, idx| {
+                norm.hypot(eigenvectors[(idx % n, col + idx / n)])
+            });
+            let norm = if norm == S::zero() { S::one() } else { norm };
+            for k in col..(col + width) {
+                for row in 0..n {
+                    vectors[(row, k)] = eigenvectors[(row, k)] / norm;
+                }
+            }
+            col += width;
+        }
+        Eigen {
+            eigenvalues_real: M::VectorM::from_slice(eigenvalues_real),
+            eigenvalues_imag: M::VectorM::from_slice(eigenvalues_imag),
+            eigenvectors: vectors,
+        }
+    }
+
+    /// Get the real parts of the eigenvalues.
+    ///
+    /// # Returns
+    ///
+    /// Real parts of the eigenvalues.
+    pub fn eigenvalues_real(&self) -> &M::VectorM {
+        &self.eigenvalues_real
+    }
+
+    /// Get the imaginary parts of the eigenvalues.
+    ///
+    /// # Returns
+    ///
+    /// Imaginary parts of the eigenvalues.
+    pub fn eigenvalues_imag(&self) -> &M::VectorM {
+        &self.eigenvalues_imag
+    }
+
+    /// Get the eigenvectors.
+    ///
+    /// # Returns
+    ///
+    /// Matrix of eigenvectors stored in the paired-column format (see [`Eigen`]).
+    pub fn eigenvectors(&self) -> &M {
+        &self.eigenvectors
+    }
+
+    /// Get the eigenvector corresponding to a single eigenvalue.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - Index of the eigenvalue.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the real part and the imaginary part of the eigenvector corresponding
+    /// to the `k`th eigenvalue.
+    ///
+    /// # Panics
+    ///
+    /// * If `k` is out of bounds.
+    pub fn eigenvector(&self, k: usize) -> (M::VectorM, M::VectorM) {
+        let n = self.eigenvalues_real.len();
+        assert!(
+            k < n,
+            "Index of the eigenvalue ({k}) is out of bounds for a matrix of size {n}."
+        );
+        let imag = self.eigenvalues_imag[k];
+        let mut real_part = M::VectorM::new_with_length(n);
+        let mut imag_part = M::VectorM::new_with_length(n);
+        for row in 0..n {
+            if imag == S::zero() {
+                real_part[row] = self.eigenvectors[(row, k)];
+            } else if imag > S::zero() {
+                real_part[row] = self.eigenvectors[(row, k)];
+                imag_part[row] = self.eigenvectors[(row, k + 1)];
+            } else {
+                real_part[row] = self.eigenvectors[(row, k - 1)];
+                imag_part[row] = -self.eigenvectors[(row, k)];
+            }
+        }
+        (real_part, imag_part)
+    }
+}
+
+/// Complex number used internally when computing complex eigenvectors.
+#[derive(Clone, Copy)]
+struct Complex<S: Scalar> {
+    /// Real part.
+    re: S,
+
+    /// Imaginary part.
+    im: S,
+}
+
+impl<S: Scalar> Complex<S> {
+    /// Create a complex number.
+    fn new(re: S, im: S) -> Self {
+        Complex { re, im }
+    }
+
+    /// Sum of two complex numbers.
+    fn add(self, other: Self) -> Self {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+
+    /// Difference of two complex numbers.
+    fn sub(self, other: Self) -> Self {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+
+    /// Product of two complex numbers.
+    fn mul(self, other: Self) -> Self {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+
+    /// Quotient of two complex numbers (using Smith's algorithm to avoid overflow).
+    fn div(self, other: Self) -> Self {
+        if other.re.abs() >= other.im.abs() {
+            let ratio = other.im / other.re;
+            let denom = other.re + ratio * other.im;
+            Complex::new(
+                (self.re + ratio * self.im) / denom,
+                (self.im - ratio * self.re) / denom,
+            )
+        } else {
+            let ratio = other.re / other.im;
+            let denom = other.im + ratio * other.re;
+            Complex::new(
+                (ratio * self.re + self.im) / denom,
+                (ratio * self.im - self.re) / denom,
+            )
+        }
+    }
+
+    /// Absolute value (modulus) of the complex number.
+    fn abs(self) -> S {
+        self.re.hypot(self.im)
+    }
+}
+
+/// Eigenvectors of an upper quasi-triangular matrix computed using back substitution.
+///
+/// # Arguments
+///
+/// * `t` - Upper quasi-triangular `N x N` matrix whose first subdiagonal is only nonzero within
+///   `2 x 2` diagonal blocks (i.e. a real Schur form).
+/// * `eigenvalues_real` - Real parts of the eigenvalues, in the order of the diagonal blocks.
+/// * `eigenvalues_imag` - Imaginary parts of the eigenvalues, in the order of the diagonal
+///   blocks (with the eigenvalue with the positive imaginary part first within each complex
+///   conjugate pair).
+///
+/// # Returns
+///
+/// Upper quasi-triangular matrix of (unnormalized) eigenvectors stored in the paired-column
+/// format.
+///
+/// # Note
+///
+/// Near-zero pivots are perturbed to `ε * ‖T‖` so that (nearly) defective matrices still produce
+/// finite eigenvectors.
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn quasi_triangular_eigenvectors<S: Scalar, M: Matrix<S>>(
+    t: &M,
+    eigenvalues_real: &[S],
+    eigenvalues_imag: &[S],
+) -> Mat<S> {
+    let n = t.shape().0;
+    let norm = (0..n * n).fold(S::zero(), |norm, idx| norm.hypot(t[(idx / n, idx % n)]));
+    let small = if norm == S::zero() {
+        S::epsilon()
+    } else {
+        S::epsilon() * norm
+    };
+    let zero = Complex::new(S::zero(), S::zero());
+    let entry = |row: usize, col: usize| Complex::new(t[(row, col)], S::zero());
+    let guard = |pivot: Complex<S>| {
+        if pivot.abs() < small {
+            Complex::new(small, S::zero())
+        } else {
+            pivot
+        }
+    };
+
+    let mut v = Mat::<S>::new_with_shape(n, n);
+    let mut k = 0;
+    while k < n {
+        let lambda = Complex::new(eigenvalues_real[k], eigenvalues_imag[k]);
+        let complex = eigenvalues_imag[k] != S::zero();
+        let width = if complex { 2 } else { 1 };
+
+        // Eigenvector x of T for λ, with x[j] = 0 for all j past the current diagonal block.
+        let mut x = vec![zero; k + width];
+        if complex {
+            // Null vector of the 2 x 2 block minus λI (picking the better conditioned row).
+            let (a, b, c, d) = (
+                entry(k, k),
+                entry(k, k + 1),
+                entry(k + 1, k),
+                entry(k + 1, k + 1),
+            );
+            if b.abs() >= c.abs() {
+                x[k] = b;
+                x[k + 1] = lambda.sub(a);
+            } else {
+                x[k] = lambda.sub(d);
+                x[k + 1] = c;
+            }
+        } else {
+            x[k] = Complex::new(S::one(), S::zero());
+        }
+
+        // Back substitution through the diagonal blocks above the current one.
+        let mut i = k;
+        while i > 0 {
+            let block_start = if i >= 2 && t[(i - 1, i - 2)] != S::zero() {
+                i - 2
+            } else {
+                i - 1
+            };
+            let rhs = |row: usize, x: &[Complex<S>]| {
+                ((i)..(k + width)).fold(zero, |sum, col| sum.add(entry(row, col).mul(x[col])))
+            };
+            if block_start == i - 1 {
+                let pivot = guard(entry(i - 1, i - 1).sub(lambda));
+                x[i - 1] = zero.sub(rhs(i - 1, &x)).div(pivot);
+            } else {
+                // Solve the 2 x 2 system (B - λI) * [x₁; x₂] = -[r₁; r₂] using Cramer's rule.
+                let (r1, r2) = (rhs(i - 2, &x), rhs(i - 1, &x));
+                let a = entry(i - 2, i - 2).sub(lambda);
+                let b = entry(i - 2, i - 1);
+                let c = entry(i - 1, i - 2);
+                let d = entry(i - 1, i - 1).sub(lambda);
+                let det = guard(a.mul(d).sub(b.mul(c)));
+                x[i - 2] = b.mul(r2).sub(d.mul(r1)).div(det);
+                x[i - 1] = c.mul(r1).sub(a.mul(r2)).div(det);
+            }
+            i = block_start;
+        }
+
+        for (row, value) in x.iter().enumerate() {
+            v[(row, k)] = value.re;
+            if complex {
+                v[(row, k + 1)] = value.im;
+            }
+        }
+        k += width;
+    }
+    v
+}
@@ -0,0 +1,463 @@
+use crate::error::LinalgError;
+use crate::matrix::matrix_trait::Matrix;
+use crate::scalar::Scalar;
+use crate::vector::vector_trait::{Vector, check_length};
+
+/// Maximum number of iterations of the Hager/Higham 1-norm estimator.
+const MAX_NORM_ESTIMATE_ITERATIONS: usize = 5;
+
+/// LU decomposition with partial (row) pivoting of a square matrix (`P * A = L * U`).
+///
+/// * `P` is a permutation matrix.
+/// * `L` is a unit lower triangular matrix (i.e. a lower triangular matrix with ones along its
+///   diagonal).
+/// * `U` is an upper triangular matrix.
+///
+/// # Note
+///
+/// This decomposition is created via [`Matrix::lu`]. Once created, it can be reused to solve
+/// linear systems for any number of right-hand sides.
+///
+/// # Example
+///
+/// ```
+/// use linalg_traits::{Mat, Matrix};
+///
+/// // Factor the matrix once.
+/// let a = Mat::from_row_slice(2, 2, &[4.0, 3.0, 6.0, 3.0]);
+/// let lu = a.lu();
+///
+/// // Solve the linear system for two different right-hand sides.
+/// assert_eq!(lu.solve(&vec![10.0, 12.0]).unwrap(), vec![1.0, 2.0]);
+/// assert_eq!(lu.solve(&vec![7.0, 9.0]).unwrap(), vec![1.0, 1.0]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lu<S: Scalar, M: Matrix<S>> {
+    /// `L` (strictly lower triangular part, with its unit diagonal implied) and `U` (upper
+    /// triangular part) packed into a single matrix.
+    factors: M,
+
+    /// Row swaps applied during the factorization (row `k` was swapped with row `swaps[k]` at the
+    /// `k`th step).
+    swaps: Vec<usize>,
+
+    /// Determinant of the permutation matrix `P` (either `1` or `-1`).
+    perm_sign: S,
+}
+
+impl<S: Scalar, M: Matrix<S>> Lu<S, M> {
+    /// LU decomposition with partial (row) pivoting.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - The square matrix to factor.
+    ///
+    /// # Returns
+    ///
+    /// LU decomposition of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// * If the matrix is not square.
+    pub(crate) fn new(matrix: &M) -> Self {
+        matrix.assert_square();
+        let n = matrix.shape().0;
+        let mut factors = matrix.clone();
+        let mut swaps = Vec::with_capacity(n);
+        let mut perm_sign = S::one();
+
+        for k in 0..n {
+            // Pick the element with the largest magnitude on or below the diagonal as the pivot.
+            let mut pivot = k;
+            for row in (k + 1)..n {
+                if factors[(row, k)].abs() > factors[(pivot, k)].abs() {
+                    pivot = row;
+                }
+            }
+            swaps.push(pivot);
+            if pivot != k {
+                for col in 0..n {
+                    let temp = factors[(k, col)];
+                    factors[(k, col)] = factors[(pivot, col)];
+                    factors[(pivot, col)] = temp;
+                }
+                perm_sign = -perm_sign;
+            }
+
+            // Eliminate the elements below the pivot. If the pivot is zero, the entire column is
+            // already zero on and below the diagonal (i.e. the matrix is singular), so there is
+            // nothing to eliminate.
+            let pivot_value = factors[(k, k)];
+            if pivot_value != S::zero() {
+                for row in (k + 1)..n {
+                    let factor = factors[(row, k)] / pivot_value;
+                    factors[(row, k)] = factor;
+                    for col in (k + 1)..n {
+                        let update = factor * factors[(k, col)];
+                        factors[(row, col)] -= update;
+                    }
+                }
+            }
+        }
+
+        Lu {
+            factors,
+            swaps,
+            perm_sign,
+        }
+    }
+
+    /// Create an LU decomposition from `L` and `U` packed into a single matrix along with the row
+    /// permutation.
+    ///
+    /// # Arguments
+    ///
+    /// * `factors` - `L` (strictly lower triangular part, with its unit diagonal implied) and `U`
+    ///   (upper triangular part) packed into a single matrix.
+    /// * `perm` - Row permutation, where row `i` of `P * A` is row `perm[i]` of `A`.
+    ///
+    /// # Returns
+    ///
+    /// LU decomposition.
+    #[cfg(any(feature = "faer", feature = "nalgebra"))]
+    pub(crate) fn from_parts(factors: M, perm: &[usize]) -> Self {
+        // Convert the permutation into the equivalent sequence of row swaps. `rows[i]` is the
+        // (original) row currently at position `i`, and `positions[r]` is the current position of
+        // (original) row `r`.
+        let n = perm.len();
+        let mut rows: Vec<usize> = (0..n).collect();
+        let mut positions: Vec<usize> = (0..n).collect();
+        let mut swaps = Vec::with_capacity(n);
+        let mut perm_sign = S::one();
+        for (k, &row) in perm.iter().enumerate() {
+            let current = positions[row];
+            swaps.push(current);
+            if current != k {
+                rows.swap(k, current);
+                positions[rows[k]] = k;
+                positions[rows[current]] = current;
+                perm_sign = -perm_sign;
+            }
+        }
+        Lu {
+            factors,
+            swaps,
+            perm_sign,
+        }
+    }
+
+    /// Get the unit lower triangular matrix `L`.
+    ///
+    /// # Returns
+    ///
+    /// Unit lower triangular matrix `L`.
+    pub fn l(&self) -> M {
+        let n = self.swaps.len();
+        let mut l = M::new_with_shape(n, n);
+        for row in 0..n {
+            for col in 0..row {
+                l[(row, col)] = self.factors[(row, col)];
+            }
+            l[(row, row)] = S::one();
+        }
+        l
+    }
+
+    /// Get the upper triangular matrix `U`.
+    ///
+    /// # Returns
+    ///
+    /// Upper triangular matrix `U`.
+    pub fn u(&self) -> M {
+        let n = self.swaps.len();
+        let mut u = M::new_with_shape(n, n);
+        for row in 0..n {
+            for col in row..n {
+                u[(row, col)] = self.factors[(row, col)];
+            }
+        }
+        u
+    }
+
+    /// Get the row permutation represented by the permutation matrix `P`.
+    ///
+    /// # Returns
+    ///
+    /// Row permutation, where row `i` of `P * A` is row `perm[i]` of `A`.
+    pub fn p(&self) -> Vec<usize> {
+        let mut perm: Vec<usize> = (0..self.swaps.len()).collect();
+        for (k, &swap) in self.swaps.iter().enumerate() {
+            perm.swap(k, swap);
+        }
+        perm
+    }
+
+    /// Determine whether or not the factored matrix is invertible.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the factored matrix is invertible, `false` if it is singular.
+    pub fn is_invertible(&self) -> bool {
+        (0..self.swaps.len()).all(|k| self.factors[(k, k)] != S::zero())
+    }
+
+    /// Solve the linear system `A * x = b`.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side vector.
+    ///
+    /// # Returns
+    ///
+    /// Solution `x` of the linear system.
+    ///
+    /// # Errors
+    ///
+    /// * [`LinalgError::LengthMismatch`] if the length of `b` does not match the number of rows of
+    ///   the factored matrix.
+    /// * [`LinalgError::SingularMatrix`] if the factored matrix is singular.
+    pub fn solve(&self, b: &M::VectorM) -> Result<M::VectorM, LinalgError> {
+        let mut x = b.clone();
+        self.solve_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solve the linear system `A * x = b` in place (i.e. overwriting `b` with `x`).
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side vector, which is overwritten with the solution `x`.
+    ///
+    /// # Errors
+    ///
+    /// * [`LinalgError::LengthMismatch`] if the length of `b` does not match the number of rows of
+    ///   the factored matrix.
+    /// * [`LinalgError::SingularMatrix`] if the factored matrix is singular (in which case `b` is
+    ///   left unchanged).
+    pub fn solve_in_place(&self, b: &mut M::VectorM) -> Result<(), LinalgError> {
+        let n = self.swaps.len();
+        check_length(n, b.len())?;
+        if !self.is_invertible() {
+            return Err(LinalgError::SingularMatrix);
+        }
+
+        // Apply the row permutation (b <- P * b).
+        for (k, &swap) in self.swaps.iter().enumerate() {
+            if swap != k {
+                let temp = b[k];
+                b[k] = b[swap];
+                b[swap] = temp;
+            }
+        }
+
+        // Forward substitution (b <- L⁻¹ * b).
+        for row in 0..n {
+            for col in 0..row {
+                let update = self.factors[(row, col)] * b[col];
+                b[row] -= update;
+            }
+        }
+
+        // Back substitution (b <- U⁻¹ * b).
+        for row in (0..n).rev() {
+            for col in (row + 1)..n {
+                let update = self.factors[(row, col)] * b[col];
+                b[row] -= update;
+            }
+            b[row] /= self.factors[(row, row)];
+        }
+
+        Ok(())
+    }
+
+    /// Solve the linear system `Aᵀ * x = b`.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side vector.
+    ///
+    /// # Returns
+    ///
+    /// Solution `x` of the linear system.
+    ///
+    /// # Errors
+    ///
+    /// * [`LinalgError::LengthMismatch`] if the length of `b` does not match the number of rows of
+    ///   the factored matrix.
+    /// * [`LinalgError::SingularMatrix`] if the factored matrix is singular.
+    pub fn solve_transpose(&self, b: &M::VectorM) -> Result<M::VectorM, LinalgError> {
+        let mut x = b.clone();
+        self.solve_transpose_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solve the linear system `Aᵀ * x = b` in place (i.e. overwriting `b` with `x`).
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side vector, which is overwritten with the solution `x`.
+    ///
+    /// # Errors
+    ///
+    /// * [`LinalgError::LengthMismatch`] if the length of `b` does not match the number of rows of
+    ///   the factored matrix.
+    /// * [`LinalgError::SingularMatrix`] if the factored matrix is singular (in which case `b` is
+    ///   left unchanged).
+    pub fn solve_transpose_in_place(&self, b: &mut M::VectorM) -> Result<(), LinalgError> {
+        let n = self.swaps.len();
+        check_length(n, b.len())?;
+        if !self.is_invertible() {
+            return Err(LinalgError::SingularMatrix);
+        }
+
+        // Since Aᵀ = Uᵀ * Lᵀ * P, we have x = Pᵀ * L⁻ᵀ * U⁻ᵀ * b.
+
+        // Forward substitution (b <- U⁻ᵀ * b).
+        for row in 0..n {
+            for col in 0..row {
+                let update = self.factors[(col, row)] * b[col];
+                b[row] -= update;
+            }
+            b[row] /= self.factors[(row, row)];
+        }
+
+        // Back substitution (b <- L⁻ᵀ * b).
+        for row in (0..n).rev() {
+            for col in (row + 1)..n {
+                let update = self.factors[(col, row)] * b[col];
+                b[row] -= update;
+            }
+        }
+
+        // Undo the row permutation (b <- Pᵀ * b).
+        for (k, &swap) in self.swaps.iter().enumerate().rev() {
+            if swap != k {
+                let temp = b[k];
+                b[k] = b[swap];
+                b[swap] = temp;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Estimate of the 1-norm of the inverse of the factored matrix.
+    ///
+    /// # Returns
+    ///
+    /// Estimate of `‖A⁻¹‖₁` (a lower bound that is almost always within a factor of 3 of the
+    /// exact value), or infinity if the factored matrix is singular.
+    ///
+    /// # Note
+    ///
+    /// This uses Hager's method with Higham's refinements (as in LAPACK's `xLACON`), which only
+    /// requires solving a few linear systems with `A` and `Aᵀ` (i.e. the inverse is never
+    /// formed). Multiplying the result by `‖A‖₁` gives an estimate of the 1-norm condition number
+    /// of `A`.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn inverse_norm_1_estimate(&self) -> S {
+        let n = self.swaps.len();
+        if n == 0 {
+            return S::zero();
+        }
+        if !self.is_invertible() {
+            return S::infinity();
+        }
+
+        // Since the factored matrix is invertible, none of the solves below can fail.
+        let n_scalar = S::new(n as f64);
+        let mut x = self.factors.new_vector_m();
+        for i in 0..n {
+            x[i] = n_scalar.recip();
+        }
+
+        // Hager's method (gradient ascent of ‖A⁻¹ * x‖₁ over the unit 1-norm ball).
+        let mut estimate = S::zero();
+        let mut last_index = None;
+        for _ in 0..MAX_NORM_ESTIMATE_ITERATIONS {
+            let previous = x.clone();
+            let _ = self.solve_in_place(&mut x);
+            let new_estimate = x.norm_l1();
+            if last_index.is_some() && new_estimate <= estimate {
+                break;
+            }
+            estimate = new_estimate;
+
+            // x <- A⁻ᵀ * sign(A⁻¹ * x).
+            for i in 0..n {
+                x[i] = if x[i] < S::zero() {
+                    -S::one()
+                } else {
+                    S::one()
+                };
+            }
+            let _ = self.solve_transpose_in_place(&mut x);
+            let index = (0..n).fold(
+                0,
+                |best, i| if x[i].abs() > x[best].abs() { i } else { best },
+            );
+            let z_dot_previous = (0..n).fold(S::zero(), |sum, i| sum + x[i] * previous[i]);
+            if Some(index) == last_index || x[index].abs() <= z_dot_previous {
+                break;
+            }
+
+            // Continue from the standard basis vector at the maximizing index.
+            for i in 0..n {
+                x[i] = if i == index { S::one() } else { S::zero() };
+            }
+            last_index = Some(index);
+        }
+
+        // Higham's alternating-sign vector, which guards against underestimation.
+        let denominator = if n > 1 {
+            S::new((n - 1) as f64)
+        } else {
+            S::one()
+        };
+        for i in 0..n {
+            let magnitude = S::one() + S::new(i as f64) / denominator;
+            x[i] = if i % 2 == 0 { magnitude } else { -magnitude };
+        }
+        let _ = self.solve_in_place(&mut x);
+        let alternative = (S::one() + S::one()) * x.norm_l1() / (S::new(3.0) * n_scalar);
+        if alternative > estimate {
+            alternative
+        } else {
+            estimate
+        }
+    }
+
+    /// Determinant of the factored matrix.
+    ///
+    /// # Returns
+    ///
+    /// Determinant of the factored matrix.
+    pub fn determinant(&self) -> S {
+        (0..self.swaps.len()).fold(self.perm_sign, |det, k| det * self.factors[(k, k)])
+    }
+
+    /// Inverse of the factored matrix.
+    ///
+    /// # Returns
+    ///
+    /// Inverse of the factored matrix.
+    ///
+    /// # Errors
+    ///
+    /// * [`LinalgError::SingularMatrix`] if the factored matrix is singular.
+    pub fn inverse(&self) -> Result<M, LinalgError> {
+        let n = self.swaps.len();
+        let mut inverse = M::new_with_shape(n, n);
+        let mut col_vec = self.factors.new_vector_m();
+        for col in 0..n {
+            for row in 0..n {
+                col_vec[row] = if row == col { S::one() } else { S::zero() };
+            }
+            self.solve_in_place(&mut col_vec)?;
+            for row in 0..n {
+                inverse[(row, col)] = col_vec[row];
+            }
+        }
+        Ok(inverse)
+    }
+}
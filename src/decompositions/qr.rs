@@ -0,0 +1,386 @@
+use crate::error::LinalgError;
+use crate::matrix::matrix_trait::Matrix;
+use crate::scalar::Scalar;
+use crate::vector::vector_trait::{Vector, check_length};
+
+/// QR decomposition of a (possibly rectangular) matrix computed using Householder reflections,
+/// optionally with column pivoting (`A * P = Q * R`).
+///
+/// * `Q` is an orthogonal matrix.
+/// * `R` is an upper trapezoidal matrix.
+/// * `P` is a permutation matrix (the identity matrix when column pivoting is not used).
+///
+/// For an `M x N` matrix `A`,
+///
+/// * the **full** decomposition has an `M x M` matrix `Q` and an `M x N` matrix `R`, while
+/// * the **thin** decomposition (only defined for `M >= N`) has an `M x N` matrix `Q` (with
+///   orthonormal columns) and an `N x N` matrix `R`.
+///
+/// # Note
+///
+/// This decomposition is created via [`Matrix::qr`] or [`Matrix::col_piv_qr`]. Internally, `Q` is
+/// stored as a sequence of Householder reflections, so it is only formed explicitly when requested
+/// via [`Qr::q`] or [`Qr::q_thin`].
+///
+/// # Example
+///
+/// ```
+/// use linalg_traits::{Mat, Matrix};
+///
+/// // Fit a line (y = c0 + c1 * t) through three points.
+/// let a = Mat::from_row_slice(3, 2, &[1.0, 0.0, 1.0, 1.0, 1.0, 2.0]);
+/// let y = vec![1.0, 3.0, 5.0];
+/// let c = a.qr().least_squares(&y).unwrap();
+/// assert!((c[0] - 1.0).abs() < 1e-14);
+/// assert!((c[1] - 2.0).abs() < 1e-14);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Qr<S: Scalar, M: Matrix<S>> {
+    /// `R` (upper trapezoidal part) and the Householder vectors (strictly lower trapezoidal part,
+    /// with their unit first elements implied) packed into a single matrix.
+    factors: M,
+
+    /// Scaling factors of the Householder reflections (the `k`th reflection is
+    /// `Hₖ = I - τₖ vₖ vₖᵀ`).
+    tau: Vec<S>,
+
+    /// Column permutation, where column `j` of `A * P` is column `perm[j]` of `A`.
+    perm: Vec<usize>,
+}
+
+impl<S: Scalar, M: Matrix<S>> Qr<S, M> {
+    /// QR decomposition using Householder reflections.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - The matrix to factor.
+    /// * `col_pivoting` - `true` to use column pivoting (making the decomposition rank-revealing),
+    ///   `false` otherwise.
+    ///
+    /// # Returns
+    ///
+    /// QR decomposition of the matrix.
+    pub(crate) fn new(matrix: &M, col_pivoting: bool) -> Self {
+        let (rows, cols) = matrix.shape();
+        let mut factors = matrix.clone();
+        let mut tau = Vec::with_capacity(rows.min(cols));
+        let mut perm: Vec<usize> = (0..cols).collect();
+
+        for k in 0..rows.min(cols) {
+            // Move the remaining column with the largest norm into the pivot position.
+            if col_pivoting {
+                let col_norm_squared = |col: usize| {
+                    (k..rows).fold(S::zero(), |sum, row| sum + factors[(row, col)].powi(2))
+                };
+                let mut pivot = k;
+                let mut pivot_norm_squared = col_norm_squared(k);
+                for col in (k + 1)..cols {
+                    let norm_squared = col_norm_squared(col);
+                    if norm_squared > pivot_norm_squared {
+                        pivot = col;
+                        pivot_norm_squared = norm_squared;
+                    }
+                }
+                if pivot != k {
+                    for row in 0..rows {
+                        let temp = factors[(row, k)];
+                        factors[(row, k)] = factors[(row, pivot)];
+                        factors[(row, pivot)] = temp;
+                    }
+                    perm.swap(k, pivot);
+                }
+            }
+
+            // Householder reflection mapping the kth column (on and below the diagonal) onto a
+            // multiple of the first unit vector. The sign of beta is chosen opposite to that of
+            // alpha to avoid cancellation.
+            let alpha = factors[(k, k)];
+            let tail_norm =
+                ((k + 1)..rows).fold(S::zero(), |norm, row| norm.hypot(factors[(row, k)]));
+            if tail_norm == S::zero() {
+                tau.push(S::zero());
+                continue;
+            }
+            let beta = -alpha.signum() * alpha.hypot(tail_norm);
+            tau.push((beta - alpha) / beta);
+            let scale = alpha - beta;
+            for row in (k + 1)..rows {
+                factors[(row, k)] /= scale;
+            }
+            factors[(k, k)] = beta;
+
+            // Apply the reflection to the remaining columns.
+            for col in (k + 1)..cols {
+                let mut w = factors[(k, col)];
+                for row in (k + 1)..rows {
+                    w += factors[(row, k)] * factors[(row, col)];
+                }
+                w *= tau[k];
+                factors[(k, col)] -= w;
+                for row in (k + 1)..rows {
+                    let update = factors[(row, k)] * w;
+                    factors[(row, col)] -= update;
+                }
+            }
+        }
+
+        Qr { factors, tau, perm }
+    }
+
+    /// Create a QR decomposition from `R` and the Householder reflections packed into a single
+    /// matrix along with the column permutation.
+    ///
+    /// # Arguments
+    ///
+    /// * `factors` - `R` (upper trapezoidal part) and the Householder vectors (strictly lower
+    ///   trapezoidal part, with their unit first elements implied) packed into a single matrix.
+    /// * `tau` - Scaling factors of the Householder reflections.
+    /// * `perm` - Column permutation, where column `j` of `A * P` is column `perm[j]` of `A`.
+    ///
+    /// # Returns
+    ///
+    /// QR decomposition.
+    #[cfg(any(feature = "nalgebra", feature = "faer"))]
+    pub(crate) fn from_parts(factors: M, tau: Vec<S>, perm: Vec<usize>) -> Self {
+        Qr { factors, tau, perm }
+    }
+
+    /// Apply the `k`th Householder reflection to a column of a matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - Index of the Householder reflection.
+    /// * `target` - Matrix with the same number of rows as the factored matrix.
+    /// * `col` - Index of the column of `target` to reflect.
+    fn reflect_column<T: Matrix<S>>(&self, k: usize, target: &mut T, col: usize) {
+        let rows = self.factors.shape().0;
+        let mut w = target[(k, col)];
+        for row in (k + 1)..rows {
+            w += self.factors[(row, k)] * target[(row, col)];
+        }
+        w *= self.tau[k];
+        target[(k, col)] -= w;
+        for row in (k + 1)..rows {
+            target[(row, col)] -= self.factors[(row, k)] * w;
+        }
+    }
+
+    /// Apply the `k`th Householder reflection to a vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - Index of the Householder reflection.
+    /// * `v` - Vector with the same length as the number of rows of the factored matrix.
+    fn reflect_vector(&self, k: usize, v: &mut M::VectorM) {
+        let rows = self.factors.shape().0;
+        let mut w = v[k];
+        for row in (k + 1)..rows {
+            w += self.factors[(row, k)] * v[row];
+        }
+        w *= self.tau[k];
+        v[k] -= w;
+        for row in (k + 1)..rows {
+            v[row] -= self.factors[(row, k)] * w;
+        }
+    }
+
+    /// Assert that the factored matrix has at least as many rows as columns.
+    ///
+    /// # Panics
+    ///
+    /// * If the factored matrix has fewer rows than columns.
+    fn assert_thin(&self) {
+        let (rows, cols) = self.factors.shape();
+        assert!(
+            rows >= cols,
+            "Thin QR decomposition requires at least as many rows as columns ({rows}x{cols})."
+        );
+    }
+
+    /// Get the full orthogonal factor `Q`.
+    ///
+    /// # Returns
+    ///
+    /// Full orthogonal factor `Q` (an `M x M` matrix).
+    pub fn q(&self) -> M::MatrixMxM {
+        let rows = self.factors.shape().0;
+        let mut q = M::MatrixMxM::new_with_shape(rows, rows);
+        for col in 0..rows {
+            q[(col, col)] = S::one();
+            for k in (0..self.tau.len()).rev() {
+                self.reflect_column(k, &mut q, col);
+            }
+        }
+        q
+    }
+
+    /// Get the thin orthogonal factor `Q`.
+    ///
+    /// # Returns
+    ///
+    /// Thin orthogonal factor `Q` (an `M x N` matrix with orthonormal columns).
+    ///
+    /// # Panics
+    ///
+    /// * If the factored matrix has fewer rows than columns.
+    pub fn q_thin(&self) -> M {
+        self.assert_thin();
+        let (rows, cols) = self.factors.shape();
+        let mut q = M::new_with_shape(rows, cols);
+        for col in 0..cols {
+            q[(col, col)] = S::one();
+            for k in (0..self.tau.len()).rev() {
+                self.reflect_column(k, &mut q, col);
+            }
+        }
+        q
+    }
+
+    /// Get the full upper trapezoidal factor `R`.
+    ///
+    /// # Returns
+    ///
+    /// Full upper trapezoidal factor `R` (an `M x N` matrix).
+    pub fn r(&self) -> M {
+        let (rows, cols) = self.factors.shape();
+        let mut r = M::new_with_shape(rows, cols);
+        for row in 0..rows.min(cols) {
+            for col in row..cols {
+                r[(row, col)] = self.factors[(row, col)];
+            }
+        }
+        r
+    }
+
+    /// Get the thin upper triangular factor `R`.
+    ///
+    /// # Returns
+    ///
+    /// Thin upper triangular factor `R` (an `N x N` matrix).
+    ///
+    /// # Panics
+    ///
+    /// * If the factored matrix has fewer rows than columns.
+    pub fn r_thin(&self) -> M::MatrixNxN {
+        self.assert_thin();
+        let cols = self.factors.shape().1;
+        let mut r = M::MatrixNxN::new_with_shape(cols, cols);
+        for row in 0..cols {
+            for col in row..cols {
+                r[(row, col)] = self.factors[(row, col)];
+            }
+        }
+        r
+    }
+
+    /// Get the column permutation represented by the permutation matrix `P`.
+    ///
+    /// # Returns
+    ///
+    /// Column permutation, where column `j` of `A * P` is column `perm[j]` of `A`. This is the
+    /// identity permutation if column pivoting was not used.
+    pub fn p(&self) -> Vec<usize> {
+        self.perm.clone()
+    }
+
+    /// Numerical rank of the factored matrix.
+    ///
+    /// # Returns
+    ///
+    /// Number of diagonal elements of `R` whose magnitude exceeds `ε * max(M, N) * max |Rᵢᵢ|`,
+    /// where `ε` is the machine epsilon.
+    ///
+    /// # Note
+    ///
+    /// This is only a reliable estimate of the rank when column pivoting is used (see
+    /// [`Matrix::col_piv_qr`]).
+    pub fn rank(&self) -> usize {
+        let tolerance = self.rank_tolerance();
+        (0..self.tau.len())
+            .filter(|&k| self.factors[(k, k)].abs() > tolerance)
+            .count()
+    }
+
+    /// Tolerance below which diagonal elements of `R` are treated as zero.
+    ///
+    /// # Returns
+    ///
+    /// Tolerance below which diagonal elements of `R` are treated as zero.
+    #[allow(clippy::cast_precision_loss)]
+    fn rank_tolerance(&self) -> S {
+        let (rows, cols) = self.factors.shape();
+        let max_diag =
+            (0..self.tau.len()).fold(S::zero(), |max, k| max.max(self.factors[(k, k)].abs()));
+        S::epsilon() * S::new(rows.max(cols) as f64) * max_diag
+    }
+
+    /// Multiply a vector by `Qᵀ` in place (i.e. overwriting `b` with `Qᵀ * b`).
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - Length-`M` vector, which is overwritten with `Qᵀ * b`.
+    ///
+    /// # Errors
+    ///
+    /// * [`LinalgError::LengthMismatch`] if the length of `b` does not match the number of rows of
+    ///   the factored matrix (in which case `b` is left unchanged).
+    pub fn q_transpose_mul_in_place(&self, b: &mut M::VectorM) -> Result<(), LinalgError> {
+        check_length(self.factors.shape().0, b.len())?;
+        for k in 0..self.tau.len() {
+            self.reflect_vector(k, b);
+        }
+        Ok(())
+    }
+
+    /// Solve the linear least squares problem `min ||A * x - b||₂`.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The right-hand side vector (length `M`).
+    ///
+    /// # Returns
+    ///
+    /// Least squares solution `x` (length `N`).
+    ///
+    /// # Errors
+    ///
+    /// * [`LinalgError::LengthMismatch`] if the length of `b` does not match the number of rows of
+    ///   the factored matrix.
+    ///
+    /// # Note
+    ///
+    /// * If the factored matrix is rank deficient (see [`Qr::rank`]), this returns the basic
+    ///   solution, where the elements of `x` corresponding to the trailing `N - rank` columns of
+    ///   `A * P` are set to zero. This requires column pivoting (see [`Matrix::col_piv_qr`]) to be
+    ///   meaningful.
+    /// * For underdetermined systems (`M < N`), this is also the basic solution rather than the
+    ///   minimum norm solution.
+    pub fn least_squares(&self, b: &M::VectorM) -> Result<M::VectorN, LinalgError> {
+        // y = Qᵀ * b.
+        let mut y = b.clone();
+        self.q_transpose_mul_in_place(&mut y)?;
+
+        // Leading rank x rank block of R. Diagonal elements are only treated as nonzero up until
+        // the first negligible one.
+        let tolerance = self.rank_tolerance();
+        let rank = (0..self.tau.len())
+            .take_while(|&k| self.factors[(k, k)].abs() > tolerance)
+            .count();
+
+        // Back substitution (z <- R⁻¹ * y, using the leading rank x rank block of R).
+        for row in (0..rank).rev() {
+            for col in (row + 1)..rank {
+                let update = self.factors[(row, col)] * y[col];
+                y[row] -= update;
+            }
+            y[row] /= self.factors[(row, row)];
+        }
+
+        // Undo the column permutation (x = P * z).
+        let mut x = self.factors.new_vector_n();
+        for k in 0..rank {
+            x[self.perm[k]] = y[k];
+        }
+        Ok(x)
+    }
+}
@@ -0,0 +1,542 @@
+use crate::decompositions::eigen::{self, Eigen};
+use crate::error::LinalgError;
+use crate::matrix::mat::Mat;
+use crate::matrix::matrix_trait::Matrix;
+use crate::scalar::Scalar;
+use crate::vector::vector_trait::Vector;
+
+/// Maximum number of Francis double-shift QR iterations allowed per eigenvalue.
+const MAX_FRANCIS_ITERATIONS_PER_EIGENVALUE: usize = 30;
+
+/// Real Schur decomposition of a square matrix (`A = Z * T * Zᵀ`).
+///
+/// * `Z` is an orthogonal matrix (the Schur vectors).
+/// * `T` is an upper quasi-triangular matrix (the real Schur form), i.e. an upper triangular
+///   matrix except for `2 x 2` blocks on its diagonal, each of which corresponds to a pair of
+///   complex conjugate eigenvalues.
+///
+/// # Note
+///
+/// This decomposition is created via [`Matrix::schur`].
+///
+/// # Example
+///
+/// ```
+/// use linalg_traits::{Mat, Matrix};
+///
+/// // Rotation by 90 degrees (eigenvalues ±i).
+/// let a = Mat::from_row_slice(2, 2, &[0.0, -1.0, 1.0, 0.0]);
+/// let schur = a.schur().unwrap();
+/// let (re, im) = schur.eigenvalues();
+/// assert_eq!(re.as_slice(), [0.0, 0.0]);
+/// assert_eq!(im.as_slice(), [1.0, -1.0]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Schur<S: Scalar, M: Matrix<S>> {
+    /// Orthogonal matrix of Schur vectors.
+    z: M,
+
+    /// Upper quasi-triangular matrix (real Schur form).
+    t: M,
+
+    /// Type of the scalars.
+    _scalar: std::marker::PhantomData<S>,
+}
+
+impl<S: Scalar, M: Matrix<S>> Schur<S, M> {
+    /// Compute the real Schur decomposition of a square matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - The `N x N` matrix to decompose.
+    ///
+    /// # Returns
+    ///
+    /// Real Schur decomposition of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// * [`LinalgError::ShapeMismatch`] if the matrix is not square.
+    /// * [`LinalgError::NonFinite`] if any element of the matrix is NaN or infinite.
+    /// * [`LinalgError::NoConvergence`] if the QR algorithm fails to converge.
+    pub(crate) fn new(matrix: &M) -> Result<Self, LinalgError> {
+        let (t, z) = real_schur(matrix, true)?;
+        Ok(Self::from_parts(&z, &t))
+    }
+
+    /// Create a real Schur decomposition from its factors.
+    ///
+    /// # Arguments
+    ///
+    /// * `z` - Orthogonal `N x N` matrix of Schur vectors.
+    /// * `t` - Upper quasi-triangular `N x N` matrix (real Schur form) whose elements below the
+    ///   first subdiagonal are zero, and whose first subdiagonal is only nonzero within `2 x 2`
+    ///   diagonal blocks.
+    ///
+    /// # Returns
+    ///
+    /// Real Schur decomposition.
+    pub(crate) fn from_parts<A: Matrix<S>, B: Matrix<S>>(z: &A, t: &B) -> Self {
+        let n = t.shape().0;
+        let mut z_out = M::new_with_shape(n, n);
+        let mut t_out = M::new_with_shape(n, n);
+        for row in 0..n {
+            for col in 0..n {
+                z_out[(row, col)] = z[(row, col)];
+                t_out[(row, col)] = t[(row, col)];
+            }
+        }
+        Schur {
+            z: z_out,
+            t: t_out,
+            _scalar: std::marker::PhantomData,
+        }
+    }
+
+    /// Get the Schur vectors.
+    ///
+    /// # Returns
+    ///
+    /// Orthogonal matrix `Z` whose columns are the Schur vectors.
+    pub fn z(&self) -> &M {
+        &self.z
+    }
+
+    /// Get the real Schur form.
+    ///
+    /// # Returns
+    ///
+    /// Upper quasi-triangular matrix `T`.
+    pub fn t(&self) -> &M {
+        &self.t
+    }
+
+    /// Get the eigenvalues of the decomposed matrix.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the real parts and the imaginary parts of the eigenvalues, in the order
+    /// of the diagonal blocks of `T`. Complex conjugate eigenvalues appear consecutively, with the
+    /// eigenvalue with the positive imaginary part first.
+    pub fn eigenvalues(&self) -> (M::VectorM, M::VectorM) {
+        let (re, im) = block_eigenvalues(&self.t);
+        (M::VectorM::from_slice(&re), M::VectorM::from_slice(&im))
+    }
+
+    /// Compute the eigendecomposition of the decomposed matrix from this real Schur
+    /// decomposition.
+    ///
+    /// # Returns
+    ///
+    /// Eigendecomposition of the decomposed matrix, with the eigenvalues in the same order as
+    /// returned by [`Schur::eigenvalues`].
+    pub fn eigen(&self) -> Eigen<S, M> {
+        let n = self.t.shape().0;
+        let (re, im) = block_eigenvalues(&self.t);
+        let x = eigen::quasi_triangular_eigenvectors(&self.t, &re, &im);
+
+        // V = Z * X, where X is upper quasi-triangular.
+        let mut v = Mat::<S>::new_with_shape(n, n);
+        for col in 0..n {
+            let last = (col + 1).min(n - 1);
+            for row in 0..n {
+                v[(row, col)] =
+                    (0..=last).fold(S::zero(), |sum, k| sum + self.z[(row, k)] * x[(k, col)]);
+            }
+        }
+        Eigen::from_parts(&re, &im, &v)
+    }
+}
+
+/// Eigenvalues of an upper quasi-triangular matrix.
+///
+/// # Arguments
+///
+/// * `t` - Upper quasi-triangular `N x N` matrix whose first subdiagonal is only nonzero within
+///   `2 x 2` diagonal blocks.
+///
+/// # Returns
+///
+/// A tuple containing the real parts and the imaginary parts of the eigenvalues, in the order of
+/// the diagonal blocks. Complex conjugate eigenvalues appear consecutively, with the eigenvalue
+/// with the positive imaginary part first.
+#[allow(clippy::many_single_char_names)]
+fn block_eigenvalues<S: Scalar, M: Matrix<S>>(t: &M) -> (Vec<S>, Vec<S>) {
+    let n = t.shape().0;
+    let two = S::one() + S::one();
+    let mut re = vec![S::zero(); n];
+    let mut im = vec![S::zero(); n];
+    let mut k = 0;
+    while k < n {
+        if k + 1 < n && t[(k + 1, k)] != S::zero() {
+            let (a, b, c, d) = (t[(k, k)], t[(k, k + 1)], t[(k + 1, k)], t[(k + 1, k + 1)]);
+            let mean = (a + d) / two;
+            let half_diff = (a - d) / two;
+            let discriminant = half_diff * half_diff + b * c;
+            if discriminant < S::zero() {
+                let imag = (-discriminant).sqrt();
+                (re[k], im[k]) = (mean, imag);
+                (re[k + 1], im[k + 1]) = (mean, -imag);
+            } else {
+                let root = discriminant.sqrt();
+                re[k] = mean + root;
+                re[k + 1] = mean - root;
+            }
+            k += 2;
+        } else {
+            re[k] = t[(k, k)];
+            k += 1;
+        }
+    }
+    (re, im)
+}
+
+/// Eigenvalues of a square matrix computed from its real Schur form (without accumulating the
+/// Schur vectors).
+///
+/// # Arguments
+///
+/// * `matrix` - The `N x N` matrix.
+///
+/// # Returns
+///
+/// A tuple containing the real parts and the imaginary parts of the eigenvalues.
+///
+/// # Errors
+///
+/// * [`LinalgError::ShapeMismatch`] if the matrix is not square.
+/// * [`LinalgError::NonFinite`] if any element of the matrix is NaN or infinite.
+/// * [`LinalgError::NoConvergence`] if the QR algorithm fails to converge.
+pub(crate) fn eigenvalues<S: Scalar, M: Matrix<S>>(
+    matrix: &M,
+) -> Result<(M::VectorM, M::VectorM), LinalgError> {
+    let (t, _) = real_schur(matrix, false)?;
+    let (re, im) = block_eigenvalues(&t);
+    Ok((M::VectorM::from_slice(&re), M::VectorM::from_slice(&im)))
+}
+
+/// Real Schur form of a square matrix computed using Hessenberg reduction followed by the
+/// Francis double-shift QR algorithm.
+///
+/// # Arguments
+///
+/// * `matrix` - The `N x N` matrix to decompose.
+/// * `vectors` - `true` to accumulate the Schur vectors, `false` to only compute the real Schur
+///   form (in which case the returned matrix of Schur vectors is empty).
+///
+/// # Returns
+///
+/// A tuple containing the upper quasi-triangular matrix `T` (with all elements below its first
+/// subdiagonal set to zero, and with its first subdiagonal only nonzero within `2 x 2` blocks
+/// corresponding to complex conjugate eigenvalues) and the orthogonal matrix `Z`.
+///
+/// # Errors
+///
+/// * [`LinalgError::ShapeMismatch`] if the matrix is not square.
+/// * [`LinalgError::NonFinite`] if any element of the matrix is NaN or infinite.
+/// * [`LinalgError::NoConvergence`] if the QR algorithm fails to converge.
+fn real_schur<S: Scalar, M: Matrix<S>>(
+    matrix: &M,
+    vectors: bool,
+) -> Result<(Mat<S>, Mat<S>), LinalgError> {
+    matrix.check_square()?;
+
+    // The QR algorithm never converges if the matrix contains NaNs or infinities.
+    matrix.check_finite()?;
+    let n = matrix.shape().0;
+    let mut t = Mat::<S>::new_with_shape(n, n);
+    for row in 0..n {
+        for col in 0..n {
+            t[(row, col)] = matrix[(row, col)];
+        }
+    }
+    let mut z = Mat::<S>::new_with_shape(if vectors { n } else { 0 }, n);
+    for k in 0..z.shape().0 {
+        z[(k, k)] = S::one();
+    }
+    hessenberg(&mut t, &mut z);
+    francis_qr(&mut t, &mut z)?;
+    Ok((t, z))
+}
+
+/// Reduce a square matrix to upper Hessenberg form using Householder reflections.
+///
+/// # Arguments
+///
+/// * `h` - The `N x N` matrix to reduce (overwritten with its upper Hessenberg form).
+/// * `z` - Matrix of accumulated transformations (post-multiplied by the reflections), or an
+///   empty matrix if the transformations should not be accumulated.
+#[allow(clippy::many_single_char_names)]
+fn hessenberg<S: Scalar>(h: &mut Mat<S>, z: &mut Mat<S>) {
+    let n = h.shape().0;
+    for k in 0..n.saturating_sub(2) {
+        let x: Vec<S> = ((k + 1)..n).map(|row| h[(row, k)]).collect();
+        let Some((u, beta, alpha)) = householder(&x) else {
+            continue;
+        };
+        reflect_rows(h, &u, beta, k + 1, k..n);
+        reflect_columns(h, &u, beta, k + 1, 0..n);
+        reflect_columns(z, &u, beta, k + 1, 0..z.shape().0);
+        h[(k + 1, k)] = alpha;
+        for row in (k + 2)..n {
+            h[(row, k)] = S::zero();
+        }
+    }
+}
+
+/// Reduce an upper Hessenberg matrix to real Schur form using the Francis double-shift QR
+/// algorithm.
+///
+/// # Arguments
+///
+/// * `t` - The `N x N` upper Hessenberg matrix (overwritten with its real Schur form).
+/// * `z` - Matrix of accumulated transformations (post-multiplied by the orthogonal
+///   transformations), or an empty matrix if the transformations should not be accumulated.
+///
+/// # Errors
+///
+/// * [`LinalgError::NoConvergence`] if the algorithm fails to converge.
+fn francis_qr<S: Scalar>(t: &mut Mat<S>, z: &mut Mat<S>) -> Result<(), LinalgError> {
+    let n = t.shape().0;
+    if n == 0 {
+        return Ok(());
+    }
+
+    // Frobenius norm of the matrix, used to decide negligibility when the neighbouring diagonal
+    // elements are zero.
+    let norm = (0..n * n).fold(S::zero(), |norm, idx| norm.hypot(t[(idx / n, idx % n)]));
+    let negligible = |t: &Mat<S>, k: usize| {
+        let scale = t[(k, k)].abs() + t[(k - 1, k - 1)].abs();
+        let scale = if scale == S::zero() { norm } else { scale };
+        t[(k, k - 1)].abs() <= S::epsilon() * scale
+    };
+
+    let max_iterations = MAX_FRANCIS_ITERATIONS_PER_EIGENVALUE * n;
+    let mut iterations = 0;
+    let mut iterations_since_deflation = 0;
+    let mut end = n - 1;
+    while end > 0 {
+        // Deflate a converged 1 x 1 block at the bottom of the active submatrix.
+        if negligible(t, end) {
+            t[(end, end - 1)] = S::zero();
+            end -= 1;
+            iterations_since_deflation = 0;
+            continue;
+        }
+
+        // Find the start of the unreduced Hessenberg submatrix ending at row `end`.
+        let mut start = end - 1;
+        while start > 0 && !negligible(t, start) {
+            start -= 1;
+        }
+        if start > 0 {
+            t[(start, start - 1)] = S::zero();
+        }
+
+        // Deflate a converged 2 x 2 block, splitting it if it has real eigenvalues.
+        if end - start == 1 {
+            split_block(t, z, start);
+            if end < 2 {
+                break;
+            }
+            end -= 2;
+            iterations_since_deflation = 0;
+            continue;
+        }
+
+        if iterations == max_iterations {
+            return Err(LinalgError::NoConvergence);
+        }
+        iterations += 1;
+        iterations_since_deflation += 1;
+        francis_step(t, z, start, end, iterations_since_deflation % 10 == 0);
+    }
+    Ok(())
+}
+
+/// Perform a single Francis double-shift QR step on an unreduced Hessenberg submatrix.
+///
+/// # Arguments
+///
+/// * `t` - The `N x N` upper Hessenberg matrix.
+/// * `z` - Matrix of accumulated transformations (or an empty matrix).
+/// * `start` - Index of the first row/column of the submatrix.
+/// * `end` - Index of the last row/column of the submatrix (at least `start + 2`).
+/// * `exceptional` - `true` to use an exceptional shift (to break cycles in the iteration).
+#[allow(clippy::many_single_char_names)]
+fn francis_step<S: Scalar>(
+    t: &mut Mat<S>,
+    z: &mut Mat<S>,
+    start: usize,
+    end: usize,
+    exceptional: bool,
+) {
+    let n = t.shape().0;
+    let z_rows = z.shape().0;
+
+    // Sum and product of the two shifts (the eigenvalues of the trailing 2 x 2 block).
+    let (sum, product) = if exceptional {
+        let w = t[(end, end - 1)].abs() + t[(end - 1, end - 2)].abs();
+        (S::new(1.5) * w, w * w)
+    } else {
+        (
+            t[(end - 1, end - 1)] + t[(end, end)],
+            t[(end - 1, end - 1)] * t[(end, end)] - t[(end - 1, end)] * t[(end, end - 1)],
+        )
+    };
+
+    // First column of (T - s₁I)(T - s₂I).
+    let mut x = t[(start, start)] * t[(start, start)]
+        + t[(start, start + 1)] * t[(start + 1, start)]
+        - sum * t[(start, start)]
+        + product;
+    let mut y = t[(start + 1, start)] * (t[(start, start)] + t[(start + 1, start + 1)] - sum);
+    let mut w = t[(start + 1, start)] * t[(start + 2, start + 1)];
+
+    // Chase the bulge down the submatrix.
+    for k in start..(end - 1) {
+        if let Some((u, beta, alpha)) = householder(&[x, y, w]) {
+            let first_col = if k > start { k - 1 } else { start };
+            reflect_rows(t, &u, beta, k, first_col..n);
+            reflect_columns(t, &u, beta, k, 0..(k + 4).min(end + 1));
+            reflect_columns(z, &u, beta, k, 0..z_rows);
+            if k > start {
+                t[(k, k - 1)] = alpha;
+                t[(k + 1, k - 1)] = S::zero();
+                t[(k + 2, k - 1)] = S::zero();
+            }
+        }
+        x = t[(k + 1, k)];
+        y = t[(k + 2, k)];
+        if k + 3 <= end {
+            w = t[(k + 3, k)];
+        }
+    }
+    if let Some((u, beta, alpha)) = householder(&[x, y]) {
+        reflect_rows(t, &u, beta, end - 1, (end - 2)..n);
+        reflect_columns(t, &u, beta, end - 1, 0..(end + 1));
+        reflect_columns(z, &u, beta, end - 1, 0..z_rows);
+        t[(end - 1, end - 2)] = alpha;
+        t[(end, end - 2)] = S::zero();
+    }
+}
+
+/// Split a converged `2 x 2` diagonal block into two `1 x 1` blocks if its eigenvalues are real.
+///
+/// # Arguments
+///
+/// * `t` - The `N x N` upper quasi-triangular matrix.
+/// * `z` - Matrix of accumulated transformations (or an empty matrix).
+/// * `k` - Index of the first row/column of the block.
+#[allow(clippy::many_single_char_names)]
+fn split_block<S: Scalar>(t: &mut Mat<S>, z: &mut Mat<S>, k: usize) {
+    let n = t.shape().0;
+    let two = S::one() + S::one();
+    let (a, b, c, d) = (t[(k, k)], t[(k, k + 1)], t[(k + 1, k)], t[(k + 1, k + 1)]);
+    let half_diff = (a - d) / two;
+    let discriminant = half_diff * half_diff + b * c;
+    if discriminant < S::zero() {
+        return;
+    }
+
+    // Eigenvector (λ - d, c) of the block, where λ is the eigenvalue farthest from d (which avoids
+    // cancellation when forming λ - d).
+    let root = discriminant.sqrt();
+    let lambda_minus_d = half_diff + if half_diff < S::zero() { -root } else { root };
+    let radius = lambda_minus_d.hypot(c);
+    let (cos, sin) = (lambda_minus_d / radius, c / radius);
+
+    // T <- Gᵀ * T * G and Z <- Z * G, where G = [cos -sin; sin cos].
+    for col in k..n {
+        let (first, second) = (t[(k, col)], t[(k + 1, col)]);
+        t[(k, col)] = cos * first + sin * second;
+        t[(k + 1, col)] = cos * second - sin * first;
+    }
+    for row in 0..(k + 2) {
+        let (first, second) = (t[(row, k)], t[(row, k + 1)]);
+        t[(row, k)] = cos * first + sin * second;
+        t[(row, k + 1)] = cos * second - sin * first;
+    }
+    for row in 0..z.shape().0 {
+        let (first, second) = (z[(row, k)], z[(row, k + 1)]);
+        z[(row, k)] = cos * first + sin * second;
+        z[(row, k + 1)] = cos * second - sin * first;
+    }
+    t[(k + 1, k)] = S::zero();
+}
+
+/// Householder reflection that maps a vector onto a multiple of the first standard basis vector.
+///
+/// # Arguments
+///
+/// * `x` - The vector to reflect.
+///
+/// # Returns
+///
+/// A tuple `(u, β, α)` such that `(I - β * u * uᵀ) * x = α * e₁`, or `None` if `x` is already a
+/// multiple of `e₁` (in which case no reflection is needed).
+fn householder<S: Scalar>(x: &[S]) -> Option<(Vec<S>, S, S)> {
+    if x[1..].iter().all(|&value| value == S::zero()) {
+        return None;
+    }
+    let norm = x.iter().fold(S::zero(), |norm, &value| norm.hypot(value));
+    let alpha = if x[0] > S::zero() { -norm } else { norm };
+    let mut u = x.to_vec();
+    u[0] -= alpha;
+    let u_norm_squared = u.iter().fold(S::zero(), |sum, &value| sum + value * value);
+    Some((u, (S::one() + S::one()) / u_norm_squared, alpha))
+}
+
+/// Apply a Householder reflection `I - β * u * uᵀ` from the left to a block of rows.
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix to update.
+/// * `u` - Householder vector.
+/// * `beta` - Householder coefficient.
+/// * `first_row` - Index of the first row affected by the reflection.
+/// * `cols` - Range of columns to update.
+fn reflect_rows<S: Scalar>(
+    matrix: &mut Mat<S>,
+    u: &[S],
+    beta: S,
+    first_row: usize,
+    cols: std::ops::Range<usize>,
+) {
+    for col in cols {
+        let dot = u.iter().enumerate().fold(S::zero(), |sum, (i, &ui)| {
+            sum + ui * matrix[(first_row + i, col)]
+        });
+        let scale = beta * dot;
+        for (i, &ui) in u.iter().enumerate() {
+            matrix[(first_row + i, col)] -= scale * ui;
+        }
+    }
+}
+
+/// Apply a Householder reflection `I - β * u * uᵀ` from the right to a block of columns.
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix to update.
+/// * `u` - Householder vector.
+/// * `beta` - Householder coefficient.
+/// * `first_col` - Index of the first column affected by the reflection.
+/// * `rows` - Range of rows to update.
+fn reflect_columns<S: Scalar>(
+    matrix: &mut Mat<S>,
+    u: &[S],
+    beta: S,
+    first_col: usize,
+    rows: std::ops::Range<usize>,
+) {
+    for row in rows {
+        let dot = u.iter().enumerate().fold(S::zero(), |sum, (i, &ui)| {
+            sum + matrix[(row, first_col + i)] * ui
+        });
+        let scale = beta * dot;
+        for (i, &ui) in u.iter().enumerate() {
+            matrix[(row, first_col + i)] -= scale * ui;
+        }
+    }
+}
@@ -0,0 +1,533 @@
+use crate::error::LinalgError;
+use crate::matrix::mat::Mat;
+use crate::matrix::matrix_trait::Matrix;
+use crate::scalar::Scalar;
+use crate::vector::vector_trait::Vector;
+use std::cmp::Ordering;
+
+/// Maximum number of sweeps performed by the one-sided Jacobi SVD algorithm.
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// Left singular vectors `U`, singular values `σ`, and right singular vectors `V` (each stored as
+/// the columns of a matrix).
+pub(crate) type SvdParts<S, M> = (M, Vec<S>, M);
+
+/// Singular value decomposition of a (possibly rectangular) matrix (`A = U * Σ * Vᵀ`).
+///
+/// * `U` is a matrix with orthonormal columns (the left singular vectors).
+/// * `Σ` is a diagonal matrix whose diagonal elements (the singular values, `σ`) are non-negative
+///   and sorted in non-increasing order.
+/// * `Vᵀ` is a matrix with orthonormal rows (the right singular vectors).
+///
+/// For an `M x N` matrix `A` with `K = min(M, N)` singular values,
+///
+/// * the **full** decomposition (see [`Matrix::svd`]) has an `M x M` matrix `U` and an `N x N`
+///   matrix `Vᵀ`, while
+/// * the **thin** decomposition (see [`Matrix::svd_thin`], only defined for `M >= N`) has an
+///   `M x N` matrix `U` and an `N x N` matrix `Vᵀ`.
+///
+/// In either case, only the first `K` columns of `U` and the first `K` rows of `Vᵀ` correspond to
+/// singular values.
+///
+/// # Type Parameters
+///
+/// * `S` - Scalar type.
+/// * `U` - Matrix type of `U`.
+/// * `VT` - Matrix type of `Vᵀ`.
+///
+/// # Example
+///
+/// ```
+/// use linalg_traits::{Mat, Matrix};
+///
+/// let a = Mat::from_row_slice(2, 2, &[3.0, 0.0, 0.0, -4.0]);
+/// let svd = a.svd().unwrap();
+/// assert_eq!(svd.sigma(), &vec![4.0, 3.0]);
+/// assert_eq!(svd.norm_2(), 4.0);
+/// assert_eq!(svd.rank(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Svd<S: Scalar, U: Matrix<S>, VT: Matrix<S>> {
+    /// Left singular vectors.
+    u: U,
+
+    /// Singular values (sorted in non-increasing order).
+    sigma: <VT::VectorN as Vector<S>>::DVectorT<S>,
+
+    /// Right singular vectors (transposed).
+    v_t: VT,
+}
+
+impl<S: Scalar, U: Matrix<S>, VT: Matrix<S>> Svd<S, U, VT> {
+    /// Create a singular value decomposition from `U`, `σ`, and `V`.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - `M x K` matrix whose columns are the left singular vectors.
+    /// * `sigma` - The `K` singular values (sorted in non-increasing order).
+    /// * `v` - `N x K` matrix whose columns are the right singular vectors.
+    /// * `u_cols` - Number of columns of `U` (if larger than `K`, `U` is completed to have
+    ///   orthonormal columns).
+    /// * `v_t_rows` - Number of rows of `Vᵀ` (if larger than `K`, `Vᵀ` is completed to have
+    ///   orthonormal rows).
+    ///
+    /// # Returns
+    ///
+    /// Singular value decomposition.
+    pub(crate) fn from_parts<A: Matrix<S>, B: Matrix<S>>(
+        u: &A,
+        sigma: &[S],
+        v: &B,
+        u_cols: usize,
+        v_t_rows: usize,
+    ) -> Self {
+        let u = complete_orthonormal_columns(u, u_cols);
+        let v = complete_orthonormal_columns(v, v_t_rows);
+        let (rows, cols) = (u.shape().0, v.shape().0);
+        let mut u_out = U::new_with_shape(rows, u_cols);
+        for row in 0..rows {
+            for col in 0..u_cols {
+                u_out[(row, col)] = u[(row, col)];
+            }
+        }
+        let mut v_t_out = VT::new_with_shape(v_t_rows, cols);
+        for row in 0..v_t_rows {
+            for col in 0..cols {
+                v_t_out[(row, col)] = v[(col, row)];
+            }
+        }
+        Svd {
+            u: u_out,
+            sigma: <VT::VectorN as Vector<S>>::DVectorT::<S>::from_slice(sigma),
+            v_t: v_t_out,
+        }
+    }
+
+    /// Get the left singular vectors `U`.
+    ///
+    /// # Returns
+    ///
+    /// Left singular vectors `U`.
+    pub fn u(&self) -> &U {
+        &self.u
+    }
+
+    /// Get the singular values `σ`.
+    ///
+    /// # Returns
+    ///
+    /// The `min(M, N)` singular values `σ`, sorted in non-increasing order.
+    pub fn sigma(&self) -> &<VT::VectorN as Vector<S>>::DVectorT<S> {
+        &self.sigma
+    }
+
+    /// Get the (transposed) right singular vectors `Vᵀ`.
+    ///
+    /// # Returns
+    ///
+    /// Transposed right singular vectors `Vᵀ`.
+    pub fn v_t(&self) -> &VT {
+        &self.v_t
+    }
+
+    /// Numerical rank of the decomposed matrix.
+    ///
+    /// # Returns
+    ///
+    /// Number of singular values exceeding `ε * max(M, N) * σ₁`, where `ε` is the machine epsilon
+    /// and `σ₁` is the largest singular value.
+    pub fn rank(&self) -> usize {
+        let (rows, cols) = (self.u.shape().0, self.v_t.shape().1);
+        rank(self.sigma.as_slice().as_ref(), rows, cols)
+    }
+
+    /// 2-norm (i.e. spectral norm) of the decomposed matrix.
+    ///
+    /// # Returns
+    ///
+    /// 2-norm of the decomposed matrix (its largest singular value).
+    pub fn norm_2(&self) -> S {
+        if self.sigma.is_empty() {
+            S::zero()
+        } else {
+            self.sigma[0]
+        }
+    }
+
+    /// Condition number (with respect to the 2-norm) of the decomposed matrix.
+    ///
+    /// # Returns
+    ///
+    /// Condition number of the decomposed matrix (the ratio of its largest to smallest singular
+    /// values). This is infinite if the smallest singular value is zero.
+    pub fn cond(&self) -> S {
+        let len = self.sigma.len();
+        if len == 0 {
+            return S::zero();
+        }
+        let sigma_min = self.sigma[len - 1];
+        if sigma_min == S::zero() {
+            S::infinity()
+        } else {
+            self.sigma[0] / sigma_min
+        }
+    }
+
+    /// Pseudo-inverse of the decomposed matrix (`A⁺ = V * Σ⁺ * Uᵀ`).
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - Matrix type of the pseudo-inverse.
+    ///
+    /// # Returns
+    ///
+    /// `N x M` pseudo-inverse of the decomposed matrix, where singular values that do not exceed
+    /// the tolerance used by [`Svd::rank`] are treated as zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use linalg_traits::{Mat, Matrix};
+    ///
+    /// // The pseudo-inverse can be computed from both the full and the thin decompositions.
+    /// let a = Mat::from_row_slice(3, 2, &[1.0, 0.0, 0.0, 2.0, 0.0, 0.0]);
+    /// let expected = Mat::from_row_slice(2, 3, &[1.0, 0.0, 0.0, 0.0, 0.5, 0.0]);
+    /// for svd in [a.svd().unwrap(), a.svd_thin().unwrap()] {
+    ///     let a_pinv: Mat<f64> = svd.pinv();
+    ///     assert_eq!(a_pinv.shape(), (2, 3));
+    ///     assert!(a_pinv.sub(&expected).norm_max() < 1e-15);
+    /// }
+    /// ```
+    pub fn pinv<T: Matrix<S>>(&self) -> T {
+        let (rows, cols) = (self.u.shape().0, self.v_t.shape().1);
+        let mut pinv = T::new_with_shape(cols, rows);
+        for k in 0..self.rank() {
+            let sigma_inv = self.sigma[k].recip();
+            for row in 0..cols {
+                let scaled = self.v_t[(k, row)] * sigma_inv;
+                for col in 0..rows {
+                    pinv[(row, col)] += scaled * self.u[(col, k)];
+                }
+            }
+        }
+        pinv
+    }
+}
+
+/// Numerical rank of a matrix given its singular values.
+///
+/// # Arguments
+///
+/// * `sigma` - Singular values of the matrix (sorted in non-increasing order).
+/// * `rows` - Number of rows of the matrix.
+/// * `cols` - Number of columns of the matrix.
+///
+/// # Returns
+///
+/// Number of singular values exceeding `ε * max(M, N) * σ₁`.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn rank<S: Scalar>(sigma: &[S], rows: usize, cols: usize) -> usize {
+    let Some(&sigma_max) = sigma.first() else {
+        return 0;
+    };
+    let tolerance = S::epsilon() * S::new(rows.max(cols) as f64) * sigma_max;
+    sigma.iter().filter(|&&s| s > tolerance).count()
+}
+
+/// Singular value decomposition computed using the one-sided Jacobi (Hestenes) algorithm.
+///
+/// # Arguments
+///
+/// * `matrix` - The `M x N` matrix to decompose.
+/// * `vectors` - `true` to compute the singular vectors, `false` to only compute the singular
+///   values (in which case the returned matrices are empty).
+///
+/// # Returns
+///
+/// A tuple containing
+///
+/// * the `M x K` matrix `U` whose columns are the left singular vectors,
+/// * the `K` singular values `σ` (sorted in non-increasing order), and
+/// * the `N x K` matrix `V` whose columns are the right singular vectors,
+///
+/// where `K = min(M, N)`.
+///
+/// # Errors
+///
+/// * [`LinalgError::NonFinite`] if any element of the matrix is NaN or infinite.
+/// * [`LinalgError::NoConvergence`] if the columns are not mutually orthogonal after the maximum
+///   number of sweeps.
+///
+/// # Note
+///
+/// * The one-sided Jacobi algorithm orthogonalizes the columns of the matrix using plane
+///   rotations, which also makes it very accurate for small singular values.
+/// * For wide matrices (`M < N`), the transpose is decomposed instead.
+pub(crate) fn jacobi_svd<S: Scalar, M: Matrix<S>>(
+    matrix: &M,
+    vectors: bool,
+) -> Result<SvdParts<S, Mat<S>>, LinalgError> {
+    matrix.check_finite()?;
+
+    // Work with a tall matrix (rows >= cols).
+    let (rows, cols) = matrix.shape();
+    let wide = rows < cols;
+    let (rows, cols) = if wide { (cols, rows) } else { (rows, cols) };
+    let mut work = Mat::<S>::new_with_shape(rows, cols);
+    for row in 0..rows {
+        for col in 0..cols {
+            work[(row, col)] = if wide {
+                matrix[(col, row)]
+            } else {
+                matrix[(row, col)]
+            };
+        }
+    }
+    let mut v = Mat::<S>::new_with_shape(if vectors { cols } else { 0 }, cols);
+    for k in 0..v.shape().0 {
+        v[(k, k)] = S::one();
+    }
+
+    // Frobenius norm of the matrix (invariant under the rotations), used to detect negligible
+    // columns.
+    let norm = (0..rows * cols).fold(S::zero(), |norm, idx| {
+        norm.hypot(work[(idx / cols, idx % cols)])
+    });
+    let negligible = S::epsilon() * norm;
+
+    // Apply plane rotations to pairs of columns until all columns are mutually orthogonal.
+    let two = S::one() + S::one();
+    let mut converged = false;
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let mut rotated = false;
+        for p in 0..cols {
+            for q in (p + 1)..cols {
+                let (mut alpha, mut beta, mut gamma) = (S::zero(), S::zero(), S::zero());
+                for row in 0..rows {
+                    alpha += work[(row, p)] * work[(row, p)];
+                    beta += work[(row, q)] * work[(row, q)];
+                    gamma += work[(row, p)] * work[(row, q)];
+                }
+
+                // Columns that are negligible compared to the matrix are numerically zero (their
+                // inner products with other columns are pure rounding error), so they are already
+                // orthogonal to every other column.
+                let (norm_p, norm_q) = (alpha.sqrt(), beta.sqrt());
+                if gamma == S::zero()
+                    || norm_p <= negligible
+                    || norm_q <= negligible
+                    || gamma.abs() <= S::epsilon() * norm_p * norm_q
+                {
+                    continue;
+                }
+                rotated = true;
+
+                // Rotation angle that zeros the (p, q) element of the Gram matrix.
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = zeta.signum() / (zeta.abs() + (S::one() + zeta * zeta).sqrt());
+                let c = (S::one() + t * t).sqrt().recip();
+                let s = c * t;
+                rotate_columns(&mut work, p, q, c, s);
+                if vectors {
+                    rotate_columns(&mut v, p, q, c, s);
+                }
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(LinalgError::NoConvergence);
+    }
+
+    // The singular values are the norms of the (now orthogonal) columns.
+    let norms: Vec<S> = (0..cols)
+        .map(|col| (0..rows).fold(S::zero(), |norm, row| norm.hypot(work[(row, col)])))
+        .collect();
+    let mut order: Vec<usize> = (0..cols).collect();
+    order.sort_by(|&i, &j| norms[j].partial_cmp(&norms[i]).unwrap_or(Ordering::Equal));
+    let sigma: Vec<S> = order.iter().map(|&k| norms[k]).collect();
+    if !vectors {
+        return Ok((Mat::new_with_shape(0, 0), sigma, Mat::new_with_shape(0, 0)));
+    }
+
+    // The left singular vectors are the normalized columns. Columns corresponding to negligible
+    // singular values are dominated by rounding errors (so they are not necessarily orthogonal to
+    // the other columns after normalization), so they are instead completed afterwards.
+    let nonzero = rank(&sigma, rows, cols);
+    let mut u = Mat::<S>::new_with_shape(rows, nonzero);
+    let mut v_sorted = Mat::<S>::new_with_shape(cols, cols);
+    for (new, &old) in order.iter().enumerate() {
+        if new < nonzero {
+            for row in 0..rows {
+                u[(row, new)] = work[(row, old)] / norms[old];
+            }
+        }
+        for row in 0..cols {
+            v_sorted[(row, new)] = v[(row, old)];
+        }
+    }
+    let u = complete_orthonormal_columns(&u, cols);
+
+    if wide {
+        Ok((v_sorted, sigma, u))
+    } else {
+        Ok((u, sigma, v_sorted))
+    }
+}
+
+/// Apply a plane rotation to a pair of columns of a matrix.
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix.
+/// * `first` - Index of the first column.
+/// * `second` - Index of the second column.
+/// * `cos` - Cosine of the rotation angle.
+/// * `sin` - Sine of the rotation angle.
+fn rotate_columns<S: Scalar>(matrix: &mut Mat<S>, first: usize, second: usize, cos: S, sin: S) {
+    for row in 0..matrix.shape().0 {
+        let (x, y) = (matrix[(row, first)], matrix[(row, second)]);
+        matrix[(row, first)] = cos * x - sin * y;
+        matrix[(row, second)] = sin * x + cos * y;
+    }
+}
+
+/// Extend a matrix with orthonormal columns to a matrix with more orthonormal columns.
+///
+/// # Arguments
+///
+/// * `q` - `M x K` matrix with orthonormal columns.
+/// * `cols` - Number of columns of the extended matrix (`K <= cols <= M`).
+///
+/// # Returns
+///
+/// `M x cols` matrix whose first `K` columns are those of `q`, and whose remaining columns are
+/// orthonormal and orthogonal to those of `q`.
+///
+/// # Note
+///
+/// The additional columns are found by orthogonalizing the standard basis vectors against the
+/// existing columns (using modified Gram-Schmidt with reorthogonalization), picking the candidate
+/// with the largest remaining norm.
+pub(crate) fn complete_orthonormal_columns<S: Scalar, M: Matrix<S>>(q: &M, cols: usize) -> Mat<S> {
+    let (rows, existing) = q.shape();
+    let mut completed = Mat::<S>::new_with_shape(rows, cols);
+    for row in 0..rows {
+        for col in 0..existing.min(cols) {
+            completed[(row, col)] = q[(row, col)];
+        }
+    }
+
+    let half = (S::one() + S::one()).recip();
+    let mut candidate = vec![S::zero(); rows];
+    let mut best = vec![S::zero(); rows];
+    for col in existing..cols {
+        let mut best_norm = S::zero();
+        for basis in 0..rows {
+            // Orthogonalize the standard basis vector against the columns found so far (twice,
+            // for numerical stability).
+            candidate.fill(S::zero());
+            candidate[basis] = S::one();
+            for _ in 0..2 {
+                for prev in 0..col {
+                    let dot = (0..rows).fold(S::zero(), |dot, row| {
+                        dot + completed[(row, prev)] * candidate[row]
+                    });
+                    for (row, value) in candidate.iter_mut().enumerate() {
+                        *value -= dot * completed[(row, prev)];
+                    }
+                }
+            }
+            let norm = candidate
+                .iter()
+                .fold(S::zero(), |norm, &value| norm.hypot(value));
+            if norm > best_norm {
+                best_norm = norm;
+                best.copy_from_slice(&candidate);
+            }
+
+            // Any candidate retaining at least half of its norm is well-conditioned enough.
+            if best_norm > half {
+                break;
+            }
+        }
+        for (row, &value) in best.iter().enumerate() {
+            completed[(row, col)] = value / best_norm;
+        }
+    }
+    completed
+}
+
+/// Full singular value decomposition computed using the one-sided Jacobi algorithm.
+///
+/// # Arguments
+///
+/// * `matrix` - The `M x N` matrix to decompose.
+///
+/// # Returns
+///
+/// Full singular value decomposition of the matrix.
+///
+/// # Errors
+///
+/// * [`LinalgError::NonFinite`] if any element of the matrix is NaN or infinite.
+/// * [`LinalgError::NoConvergence`] if the Jacobi algorithm fails to converge.
+pub(crate) fn svd_full<S: Scalar, M: Matrix<S>>(
+    matrix: &M,
+) -> Result<Svd<S, M::MatrixMxM, M::MatrixNxN>, LinalgError> {
+    let (rows, cols) = matrix.shape();
+    let (u, sigma, v) = jacobi_svd(matrix, true)?;
+    Ok(Svd::from_parts(&u, &sigma, &v, rows, cols))
+}
+
+/// Thin singular value decomposition computed using the one-sided Jacobi algorithm.
+///
+/// # Arguments
+///
+/// * `matrix` - The `M x N` matrix to decompose (where `M >= N`).
+///
+/// # Returns
+///
+/// Thin singular value decomposition of the matrix.
+///
+/// # Errors
+///
+/// * [`LinalgError::ShapeMismatch`] if the matrix has fewer rows than columns.
+/// * [`LinalgError::NonFinite`] if any element of the matrix is NaN or infinite.
+/// * [`LinalgError::NoConvergence`] if the Jacobi algorithm fails to converge.
+pub(crate) fn svd_thin<S: Scalar, M: Matrix<S>>(
+    matrix: &M,
+) -> Result<Svd<S, M, M::MatrixNxN>, LinalgError> {
+    let cols = check_thin(matrix)?;
+    let (u, sigma, v) = jacobi_svd(matrix, true)?;
+    Ok(Svd::from_parts(&u, &sigma, &v, cols, cols))
+}
+
+/// Check that a matrix has at least as many rows as columns (as required by the thin SVD).
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix.
+///
+/// # Returns
+///
+/// Number of columns of the matrix.
+///
+/// # Errors
+///
+/// * [`LinalgError::ShapeMismatch`] if the matrix has fewer rows than columns (with the square
+///   `N x N` shape as the expected shape).
+pub(crate) fn check_thin<S: Scalar, M: Matrix<S>>(matrix: &M) -> Result<usize, LinalgError> {
+    let (rows, cols) = matrix.shape();
+    if rows >= cols {
+        Ok(cols)
+    } else {
+        Err(LinalgError::ShapeMismatch {
+            expected: (cols, cols),
+            actual: (rows, cols),
+        })
+    }
+}
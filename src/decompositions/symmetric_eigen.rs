@@ -0,0 +1,349 @@
+use crate::error::LinalgError;
+use crate::matrix::mat::Mat;
+use crate::matrix::matrix_trait::Matrix;
+use crate::scalar::Scalar;
+use crate::vector::vector_trait::Vector;
+use std::cmp::Ordering;
+
+/// Maximum number of sweeps performed by the cyclic Jacobi eigenvalue algorithm.
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// Eigendecomposition of a real symmetric matrix (`A = V * Λ * Vᵀ`).
+///
+/// * `Λ` is a diagonal matrix whose diagonal elements are the (real) eigenvalues, sorted in
+///   non-decreasing order.
+/// * `V` is an orthogonal matrix whose columns are the corresponding eigenvectors.
+///
+/// # Note
+///
+/// This decomposition is created via [`Matrix::symmetric_eigen`]. Only the lower triangular part
+/// of the decomposed matrix is read (i.e. the matrix is assumed to be symmetric without this being
+/// checked).
+///
+/// # Example
+///
+/// ```
+/// use linalg_traits::{Mat, Matrix};
+///
+/// let a = Mat::from_row_slice(2, 2, &[2.0, 1.0, 1.0, 2.0]);
+/// let eigen = a.symmetric_eigen().unwrap();
+/// assert!((eigen.eigenvalues()[0] - 1.0).abs() < 1e-15);
+/// assert!((eigen.eigenvalues()[1] - 3.0).abs() < 1e-15);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SymmetricEigen<S: Scalar, M: Matrix<S>> {
+    /// Eigenvalues (sorted in non-decreasing order).
+    eigenvalues: M::VectorM,
+
+    /// Eigenvectors (stored as the columns of the matrix).
+    eigenvectors: M,
+}
+
+impl<S: Scalar, M: Matrix<S>> SymmetricEigen<S, M> {
+    /// Create a symmetric eigendecomposition from (possibly unsorted) eigenvalues and eigenvectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `eigenvalues` - Eigenvalues.
+    /// * `eigenvectors` - `N x N` matrix whose columns are the corresponding eigenvectors.
+    ///
+    /// # Returns
+    ///
+    /// Symmetric eigendecomposition, with the eigenvalues sorted in non-decreasing order.
+    pub(crate) fn from_parts<T: Matrix<S>>(eigenvalues: &[S], eigenvectors: &T) -> Self {
+        let n = eigenvalues.len();
+        let order = sorted_order(eigenvalues);
+        let mut values = M::VectorM::new_with_length(n);
+        let mut vectors = M::new_with_shape(n, n);
+        for (new, &old) in order.iter().enumerate() {
+            values[new] = eigenvalues[old];
+            for row in 0..n {
+                vectors[(row, new)] = eigenvectors[(row, old)];
+            }
+        }
+        SymmetricEigen {
+            eigenvalues: values,
+            eigenvectors: vectors,
+        }
+    }
+
+    /// Get the eigenvalues.
+    ///
+    /// # Returns
+    ///
+    /// Eigenvalues, sorted in non-decreasing order.
+    pub fn eigenvalues(&self) -> &M::VectorM {
+        &self.eigenvalues
+    }
+
+    /// Get the eigenvectors.
+    ///
+    /// # Returns
+    ///
+    /// Orthogonal matrix whose columns are the eigenvectors (the `i`th column corresponds to the
+    /// `i`th eigenvalue).
+    pub fn eigenvectors(&self) -> &M {
+        &self.eigenvectors
+    }
+
+    /// Get the eigenpairs with the `k` largest eigenvalues.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - Number of eigenpairs.
+    ///
+    /// # Returns
+    ///
+    /// The `k` eigenpairs (eigenvalue and eigenvector) with the largest eigenvalues, sorted in
+    /// non-increasing order of eigenvalue.
+    ///
+    /// # Panics
+    ///
+    /// * If `k` exceeds the size of the decomposed matrix.
+    pub fn largest(&self, k: usize) -> Vec<(S, M::VectorM)> {
+        self.extreme(k, true)
+    }
+
+    /// Get the eigenpairs with the `k` smallest eigenvalues.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - Number of eigenpairs.
+    ///
+    /// # Returns
+    ///
+    /// The `k` eigenpairs (eigenvalue and eigenvector) with the smallest eigenvalues, sorted in
+    /// non-decreasing order of eigenvalue.
+    ///
+    /// # Panics
+    ///
+    /// * If `k` exceeds the size of the decomposed matrix.
+    pub fn smallest(&self, k: usize) -> Vec<(S, M::VectorM)> {
+        self.extreme(k, false)
+    }
+
+    /// Get the eigenpairs with either the largest or the smallest eigenvalues.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - Number of eigenpairs.
+    /// * `largest` - `true` for the `k` largest eigenvalues, `false` for the `k` smallest.
+    ///
+    /// # Returns
+    ///
+    /// The `k` eigenpairs (eigenvalue and eigenvector), ordered from the most extreme eigenvalue
+    /// inwards.
+    ///
+    /// # Panics
+    ///
+    /// * If `k` exceeds the size of the decomposed matrix.
+    fn extreme(&self, k: usize, largest: bool) -> Vec<(S, M::VectorM)> {
+        let n = self.eigenvalues.len();
+        assert!(
+            k <= n,
+            "Number of requested eigenpairs ({k}) exceeds the size of the matrix ({n})."
+        );
+        (0..k)
+            .map(|i| {
+                let col = if largest { n - 1 - i } else { i };
+                let mut eigenvector = M::VectorM::new_with_length(n);
+                for row in 0..n {
+                    eigenvector[row] = self.eigenvectors[(row, col)];
+                }
+                (self.eigenvalues[col], eigenvector)
+            })
+            .collect()
+    }
+}
+
+/// Check that a number of requested eigenpairs does not exceed the size of a matrix.
+///
+/// # Arguments
+///
+/// * `k` - Number of requested eigenpairs.
+/// * `n` - Number of rows (and columns) of the matrix.
+///
+/// # Errors
+///
+/// * [`LinalgError::IndexOutOfBounds`] if `k` exceeds `n`, reporting the index `k - 1` of the last
+///   requested eigenpair.
+pub(crate) fn check_eigenpair_count(k: usize, n: usize) -> Result<(), LinalgError> {
+    if k <= n {
+        Ok(())
+    } else {
+        Err(LinalgError::IndexOutOfBounds {
+            index: k - 1,
+            len: n,
+        })
+    }
+}
+
+/// Symmetric eigendecomposition (including eigenvectors) computed using [`jacobi_eigen`].
+///
+/// # Arguments
+///
+/// * `matrix` - The `N x N` symmetric matrix to decompose (only its lower triangular part is
+///   read).
+///
+/// # Returns
+///
+/// Eigendecomposition of the matrix, with the eigenvalues sorted in non-decreasing order.
+///
+/// # Errors
+///
+/// * [`LinalgError::ShapeMismatch`] if the matrix is not square.
+/// * [`LinalgError::NonFinite`] if any element of the lower triangular part of the matrix is NaN
+///   or infinite.
+/// * [`LinalgError::NoConvergence`] if the Jacobi algorithm fails to converge.
+pub(crate) fn symmetric_eigen<S: Scalar, M: Matrix<S>>(
+    matrix: &M,
+) -> Result<SymmetricEigen<S, M>, LinalgError> {
+    let (eigenvalues, eigenvectors) = jacobi_eigen(matrix, true)?;
+    Ok(SymmetricEigen::from_parts(&eigenvalues, &eigenvectors))
+}
+
+/// Check that the lower triangular part of a matrix only contains finite values.
+///
+/// # Arguments
+///
+/// * `matrix` - The square matrix to check.
+///
+/// # Errors
+///
+/// * [`LinalgError::NonFinite`] if any element of the lower triangular part of the matrix is NaN
+///   or infinite.
+///
+/// # Note
+///
+/// Iterative eigenvalue algorithms never converge if the matrix contains NaNs, so this is checked
+/// up front.
+pub(crate) fn check_lower_finite<S: Scalar, M: Matrix<S>>(matrix: &M) -> Result<(), LinalgError> {
+    let n = matrix.shape().0;
+    if (0..n).all(|row| (0..=row).all(|col| matrix[(row, col)].is_finite())) {
+        Ok(())
+    } else {
+        Err(LinalgError::NonFinite)
+    }
+}
+
+/// Indices that sort a slice of eigenvalues in non-decreasing order.
+///
+/// # Arguments
+///
+/// * `eigenvalues` - Eigenvalues.
+///
+/// # Returns
+///
+/// Indices that sort the eigenvalues in non-decreasing order.
+pub(crate) fn sorted_order<S: Scalar>(eigenvalues: &[S]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..eigenvalues.len()).collect();
+    order.sort_by(|&i, &j| {
+        eigenvalues[i]
+            .partial_cmp(&eigenvalues[j])
+            .unwrap_or(Ordering::Equal)
+    });
+    order
+}
+
+/// Eigendecomposition of a real symmetric matrix computed using the cyclic Jacobi eigenvalue
+/// algorithm.
+///
+/// # Arguments
+///
+/// * `matrix` - The `N x N` symmetric matrix to decompose (only its lower triangular part is
+///   read).
+/// * `vectors` - `true` to compute the eigenvectors, `false` to only compute the eigenvalues (in
+///   which case the returned matrix is empty).
+///
+/// # Returns
+///
+/// A tuple containing the (unsorted) eigenvalues and the matrix whose columns are the
+/// corresponding eigenvectors.
+///
+/// # Errors
+///
+/// * [`LinalgError::ShapeMismatch`] if the matrix is not square.
+/// * [`LinalgError::NonFinite`] if any element of the lower triangular part of the matrix is NaN
+///   or infinite.
+/// * [`LinalgError::NoConvergence`] if the off-diagonal elements are still not negligible after
+///   the maximum number of sweeps.
+///
+/// # Note
+///
+/// The Jacobi eigenvalue algorithm annihilates the off-diagonal elements using plane rotations,
+/// which also makes it very accurate for small eigenvalues.
+pub(crate) fn jacobi_eigen<S: Scalar, M: Matrix<S>>(
+    matrix: &M,
+    vectors: bool,
+) -> Result<(Vec<S>, Mat<S>), LinalgError> {
+    matrix.check_square()?;
+    check_lower_finite(matrix)?;
+    let n = matrix.shape().0;
+    let mut work = Mat::<S>::new_with_shape(n, n);
+    for row in 0..n {
+        for col in 0..=row {
+            work[(row, col)] = matrix[(row, col)];
+            work[(col, row)] = matrix[(row, col)];
+        }
+    }
+    let mut v = Mat::<S>::new_with_shape(if vectors { n } else { 0 }, n);
+    for k in 0..v.shape().0 {
+        v[(k, k)] = S::one();
+    }
+
+    // Frobenius norm of the matrix (invariant under the rotations), used to decide convergence.
+    let norm = (0..n * n).fold(S::zero(), |norm, idx| norm.hypot(work[(idx / n, idx % n)]));
+    let two = S::one() + S::one();
+    let mut sweeps = 0;
+    loop {
+        let off_diag = (0..n).fold(S::zero(), |off_diag, row| {
+            (0..row).fold(off_diag, |off_diag, col| off_diag.hypot(work[(row, col)]))
+        });
+        if off_diag <= S::epsilon() * norm {
+            break;
+        }
+        if sweeps == MAX_JACOBI_SWEEPS {
+            return Err(LinalgError::NoConvergence);
+        }
+        sweeps += 1;
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let apq = work[(p, q)];
+                if apq == S::zero() {
+                    continue;
+                }
+
+                // Rotation angle that zeros the (p, q) element.
+                let theta = (work[(q, q)] - work[(p, p)]) / (two * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + S::one()).sqrt());
+                let cos = (t * t + S::one()).sqrt().recip();
+                let sin = t * cos;
+
+                // A <- Jᵀ * A * J.
+                for k in 0..n {
+                    let (akp, akq) = (work[(k, p)], work[(k, q)]);
+                    work[(k, p)] = cos * akp - sin * akq;
+                    work[(k, q)] = sin * akp + cos * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (work[(p, k)], work[(q, k)]);
+                    work[(p, k)] = cos * apk - sin * aqk;
+                    work[(q, k)] = sin * apk + cos * aqk;
+                }
+                work[(p, q)] = S::zero();
+                work[(q, p)] = S::zero();
+
+                // V <- V * J.
+                if vectors {
+                    for k in 0..n {
+                        let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                        v[(k, p)] = cos * vkp - sin * vkq;
+                        v[(k, q)] = sin * vkp + cos * vkq;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(((0..n).map(|k| work[(k, k)]).collect(), v))
+}
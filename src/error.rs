@@ -0,0 +1,78 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors that can occur when performing linear algebra operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LinalgError {
+    /// The matrix is singular (i.e. it is not invertible).
+    SingularMatrix,
+
+    /// The matrix is not positive definite.
+    NotPositiveDefinite {
+        /// Index of the diagonal element at which the Cholesky factorization failed (i.e. the
+        /// first pivot that was not strictly positive).
+        pivot: usize,
+    },
+
+    /// An iterative algorithm (e.g. the QR algorithm used to compute eigenvalues) failed to
+    /// converge within its maximum number of iterations.
+    NoConvergence,
+
+    /// The shape of a matrix does not match the shape required by an operation.
+    ShapeMismatch {
+        /// Required shape (number of rows, number of columns).
+        expected: (usize, usize),
+
+        /// Actual shape (number of rows, number of columns).
+        actual: (usize, usize),
+    },
+
+    /// The length of a vector or slice does not match the length required by an operation.
+    LengthMismatch {
+        /// Required length.
+        expected: usize,
+
+        /// Actual length.
+        actual: usize,
+    },
+
+    /// An index is out of bounds.
+    IndexOutOfBounds {
+        /// The index.
+        index: usize,
+
+        /// Number of elements (or rows/columns) being indexed into.
+        len: usize,
+    },
+
+    /// A value is not finite (i.e. it is NaN or infinite).
+    NonFinite,
+}
+
+impl fmt::Display for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinalgError::SingularMatrix => write!(f, "Matrix is singular."),
+            LinalgError::NotPositiveDefinite { pivot } => write!(
+                f,
+                "Matrix is not positive definite (non-positive pivot at index {pivot})."
+            ),
+            LinalgError::NoConvergence => write!(f, "Iterative algorithm failed to converge."),
+            LinalgError::ShapeMismatch { expected, actual } => write!(
+                f,
+                "Shape mismatch (expected {}x{}, got {}x{}).",
+                expected.0, expected.1, actual.0, actual.1
+            ),
+            LinalgError::LengthMismatch { expected, actual } => {
+                write!(f, "Length mismatch (expected {expected}, got {actual}).")
+            }
+            LinalgError::IndexOutOfBounds { index, len } => {
+                write!(f, "Index ({index}) is out of bounds for length {len}.")
+            }
+            LinalgError::NonFinite => write!(f, "Value is not finite."),
+        }
+    }
+}
+
+impl Error for LinalgError {}
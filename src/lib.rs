@@ -43,8 +43,8 @@
 //! | Trait | Implementations on Foreign Types | Implementations on Local Types |
 //! | ----- | -------------------------------- | ------------------------------ |
 //! | [`Scalar`] | [`f64`] and all other types that satisfy its trait bounds. | N/A |
-//! | [`Vector`] | [`Vec<S>`] <BR> [`nalgebra::DVector<S>`] <BR> [`nalgebra::SVector<S, N>`] <BR> [`ndarray::Array1<T>`] <BR> [`faer::Mat<U>`] <BR><BR> Note:<BR>   • `S: Scalar` <BR>   • `T: Scalar + ndarray::ScalarOperand + ndarray::LinalgScalar` <BR>   • `U: Scalar + faer_traits::RealField` <BR> • `N: usize` | N/A |
-//! | [`Matrix`] | [`nalgebra::DMatrix<S>`] <BR> [`nalgebra::SMatrix<S, M, N>`] <BR> [`ndarray::Array2<T>`] <BR> [`faer::Mat<U>`] <BR><BR> Note:<BR>   • `S: Scalar` <BR>   • `T: Scalar + ndarray::ScalarOperand + ndarray::LinalgScalar` <BR>   • `U: Scalar + faer_traits::RealField` <BR>   • `M: usize` <BR>   • `N: usize` | [`Mat<S>`] <BR><BR> Note:<BR>   • `S: Scalar` |
+//! | [`Vector`] | [`Vec<S>`] <BR> `[S; N]` <BR> [`nalgebra::DVector<S>`] <BR> [`nalgebra::SVector<S, N>`] <BR> [`ndarray::Array1<T>`] <BR> [`faer::Mat<U>`] <BR><BR> Note:<BR>   • `S: Scalar` <BR>   • `T: Scalar + ndarray::ScalarOperand + ndarray::LinalgScalar` <BR>   • `U: Scalar + faer_traits::RealField` <BR> • `N: usize` | N/A |
+//! | [`Matrix`] | [`nalgebra::DMatrix<S>`] <BR> [`nalgebra::SMatrix<S, M, N>`] <BR> [`ndarray::Array2<T>`] <BR> [`faer::Mat<U>`] <BR><BR> Note:<BR>   • `S: Scalar` <BR>   • `T: Scalar + ndarray::ScalarOperand + ndarray::LinalgScalar` <BR>   • `U: Scalar + faer_traits::RealField` <BR>   • `M: usize` <BR>   • `N: usize` | [`Mat<S>`] <BR> [`SMat<S, M, N>`] <BR><BR> Note:<BR>   • `S: Scalar` <BR>   • `M: usize` <BR>   • `N: usize` |
 //!
 //! See the [Using with `nalgebra`, `ndarray`, and `faer`](#using-with-nalgebra-ndarray-and-faer)
 //! section further down on this page for information on using the `linalg-traits` crate with types
@@ -126,12 +126,26 @@
 #![allow(clippy::float_cmp, clippy::unreadable_literal)]
 
 // Module declarations.
+pub(crate) mod decompositions;
+pub(crate) mod error;
 pub(crate) mod matrix;
+#[cfg(feature = "nalgebra")]
+pub(crate) mod nalgebra_native;
 pub(crate) mod scalar;
 pub(crate) mod vector;
 
 // Re-exports.
+pub use crate::decompositions::cholesky::Cholesky;
+pub use crate::decompositions::eigen::Eigen;
+pub use crate::decompositions::lu::Lu;
+pub use crate::decompositions::qr::Qr;
+pub use crate::decompositions::schur::Schur;
+pub use crate::decompositions::svd::Svd;
+pub use crate::decompositions::symmetric_eigen::SymmetricEigen;
+pub use crate::error::LinalgError;
 pub use crate::matrix::mat::Mat;
 pub use crate::matrix::matrix_trait::Matrix;
+pub use crate::matrix::smat::SMat;
 pub use crate::scalar::{Scalar, ScalarBase};
+pub use crate::vector::nan_policy::NanPolicy;
 pub use crate::vector::vector_trait::Vector;
@@ -10,3 +10,4 @@ pub(crate) mod nalgebra_dmatrix;
 pub(crate) mod nalgebra_smatrix;
 #[cfg(feature = "ndarray")]
 pub(crate) mod ndarray_array2;
+pub(crate) mod smat;
@@ -1,7 +1,19 @@
-use crate::{Matrix, Scalar};
-use faer::{Col, Mat, Scale};
+use crate::decompositions::{svd, symmetric_eigen};
+use crate::matrix::matrix_trait::{
+    assert_block_in_bounds, assert_column_index, assert_column_length, assert_output_shape,
+    default_set_block,
+};
+use crate::vector::vector_trait::nan_max;
+use crate::{Cholesky, Eigen, LinalgError, Lu, Matrix, Qr, Scalar, Svd, SymmetricEigen};
+use faer::diag::Diag;
+use faer::dyn_stack::{MemBuffer, MemStack};
+use faer::linalg::cholesky::llt::factor::LltError;
+use faer::linalg::evd::{self, ComputeEigenvectors};
+use faer::linalg::matmul::matmul;
+use faer::{Accum, Col, Mat, MatRef, Scale, Side, Spec, get_global_parallelism, unzip, zip};
 use faer_traits::RealField;
 use std::borrow::Cow;
+use std::cmp::Ordering;
 
 impl<S> Matrix<S> for Mat<S>
 where
@@ -11,6 +23,20 @@ where
 
     type VectorN = Col<S>;
 
+    type MatrixNxP<const P: usize> = Mat<S>;
+
+    type MatrixMxP<const P: usize> = Mat<S>;
+
+    type Transpose = Mat<S>;
+
+    type MatrixMxM = Mat<S>;
+
+    type MatrixNxN = Mat<S>;
+
+    type MatrixT<T: Scalar> = Mat<T>;
+
+    type DMatrixMxN = Mat<S>;
+
     fn is_statically_sized() -> bool {
         false
     }
@@ -19,6 +45,10 @@ where
         true
     }
 
+    fn static_shape() -> Option<(usize, usize)> {
+        None
+    }
+
     fn is_row_major() -> bool {
         false
     }
@@ -60,6 +90,9 @@ where
     }
 
     fn as_slice(&self) -> Cow<'_, [S]> {
+        if let Some(slice) = self.try_as_contiguous_slice() {
+            return Cow::Borrowed(slice);
+        }
         let mut slice_vec = Vec::<S>::with_capacity(self.nrows() * self.ncols());
         for i in 0..self.ncols() {
             slice_vec.extend_from_slice(self.col_as_slice(i));
@@ -67,6 +100,39 @@ where
         Cow::from(slice_vec)
     }
 
+    fn try_as_contiguous_slice(&self) -> Option<&[S]> {
+        let (nrows, ncols) = (self.nrows(), self.ncols());
+        if nrows == 0 || ncols == 0 {
+            Some(&[])
+        } else if ncols == 1 {
+            Some(self.col_as_slice(0))
+        } else if self.row_stride() == 1 && usize::try_from(self.col_stride()) == Ok(nrows) {
+            // SAFETY: Each column is contiguous and each column starts immediately after the
+            // previous one, so the matrix's elements occupy exactly `nrows * ncols` consecutive
+            // elements starting at `as_ptr()`, all of which are borrowed through `self`.
+            Some(unsafe { std::slice::from_raw_parts(self.as_ptr(), nrows * ncols) })
+        } else {
+            None
+        }
+    }
+
+    fn try_as_contiguous_mut_slice(&mut self) -> Option<&mut [S]> {
+        let (nrows, ncols) = (self.nrows(), self.ncols());
+        if nrows == 0 || ncols == 0 {
+            Some(&mut [])
+        } else if ncols == 1 {
+            Some(self.col_as_slice_mut(0))
+        } else if self.row_stride() == 1 && usize::try_from(self.col_stride()) == Ok(nrows) {
+            // SAFETY: Each column is contiguous and each column starts immediately after the
+            // previous one, so the matrix's elements occupy exactly `nrows * ncols` consecutive
+            // elements starting at `as_ptr_mut()`, all of which are mutably borrowed through
+            // `self`.
+            Some(unsafe { std::slice::from_raw_parts_mut(self.as_ptr_mut(), nrows * ncols) })
+        } else {
+            None
+        }
+    }
+
     fn get(&self, index: (usize, usize)) -> Option<&S> {
         let (row, col) = index;
         if row < self.nrows() && col < self.ncols() {
@@ -107,4 +173,421 @@ where
     fn div_assign(&mut self, scalar: S) {
         *self /= Scale(scalar);
     }
+
+    fn mul_vector(&self, vector: &Col<S>) -> Col<S> {
+        self * vector
+    }
+
+    fn mul_vector_to(&self, vector: &Col<S>, out: &mut Col<S>) {
+        matmul(
+            out.as_mat_mut(),
+            Accum::Replace,
+            self,
+            vector.as_mat(),
+            S::one(),
+            get_global_parallelism(),
+        );
+    }
+
+    fn vector_mul(&self, vector: &Col<S>) -> Col<S> {
+        self.transpose() * vector
+    }
+
+    fn vector_mul_to(&self, vector: &Col<S>, out: &mut Col<S>) {
+        matmul(
+            out.as_mat_mut(),
+            Accum::Replace,
+            self.transpose(),
+            vector.as_mat(),
+            S::one(),
+            get_global_parallelism(),
+        );
+    }
+
+    fn matmul<const P: usize>(&self, other: &Mat<S>) -> Mat<S> {
+        self * other
+    }
+
+    fn matmul_to<const P: usize>(&self, other: &Mat<S>, out: &mut Mat<S>) {
+        matmul(
+            out,
+            Accum::Replace,
+            self,
+            other,
+            S::one(),
+            get_global_parallelism(),
+        );
+    }
+
+    fn gemm(
+        &mut self,
+        alpha: S,
+        a: &Self,
+        transpose_a: bool,
+        b: &Self,
+        transpose_b: bool,
+        beta: S,
+    ) {
+        let a = if transpose_a {
+            a.transpose()
+        } else {
+            a.as_ref()
+        };
+        let b = if transpose_b {
+            b.transpose()
+        } else {
+            b.as_ref()
+        };
+
+        // faer can only overwrite or accumulate into the output matrix, so we scale it by beta
+        // ourselves before accumulating.
+        let accum = if beta == S::zero() {
+            Accum::Replace
+        } else {
+            *self *= Scale(beta);
+            Accum::Add
+        };
+        matmul(self, accum, a, b, alpha, get_global_parallelism());
+    }
+
+    fn transpose(&self) -> Mat<S> {
+        Mat::transpose(self).to_owned()
+    }
+
+    fn column(&self, col: usize) -> Col<S> {
+        assert_column_index(self, col);
+        let column = self.col_as_slice(col);
+        Col::from_fn(column.len(), |row| column[row])
+    }
+
+    fn set_column(&mut self, col: usize, values: &Col<S>) {
+        assert_column_index(self, col);
+        assert_column_length(self, values.nrows());
+        for (a, b) in self.col_as_slice_mut(col).iter_mut().zip(values.iter()) {
+            *a = *b;
+        }
+    }
+
+    fn submatrix(&self, row0: usize, col0: usize, nrows: usize, ncols: usize) -> Mat<S> {
+        assert_block_in_bounds(self, row0, col0, nrows, ncols);
+        self.as_ref().submatrix(row0, col0, nrows, ncols).to_owned()
+    }
+
+    fn set_block<B: Matrix<S>>(&mut self, row0: usize, col0: usize, block: &B) {
+        let (nrows, ncols) = block.shape();
+        assert_block_in_bounds(self, row0, col0, nrows, ncols);
+        match block.try_as_contiguous_slice() {
+            Some(slice) => {
+                let block = if B::is_row_major() {
+                    MatRef::from_row_major_slice(slice, nrows, ncols)
+                } else {
+                    MatRef::from_column_major_slice(slice, nrows, ncols)
+                };
+                self.as_mut()
+                    .submatrix_mut(row0, col0, nrows, ncols)
+                    .copy_from(block);
+            }
+            None => default_set_block(self, row0, col0, block),
+        }
+    }
+
+    fn identity(n: usize) -> Self {
+        Mat::identity(n, n)
+    }
+
+    fn from_element(rows: usize, cols: usize, value: S) -> Self {
+        Mat::full(rows, cols, value)
+    }
+
+    fn from_fn<F: FnMut(usize, usize) -> S>(rows: usize, cols: usize, f: F) -> Self {
+        Mat::from_fn(rows, cols, f)
+    }
+
+    fn diagonal(&self) -> Col<S> {
+        self.assert_square();
+        self.as_ref().diagonal().column_vector().to_owned()
+    }
+
+    fn norm_frobenius(&self) -> S {
+        self.as_ref().norm_l2()
+    }
+
+    // faer's maxima ignore NaNs, so the absolute sums are reduced with `nan_max` instead and
+    // NaNs are checked for separately in the max-abs norm.
+
+    fn norm_1(&self) -> S {
+        self.col_iter()
+            .map(|col| col.norm_l1())
+            .fold(S::zero(), nan_max)
+    }
+
+    fn norm_inf(&self) -> S {
+        self.row_iter()
+            .map(|row| row.norm_l1())
+            .fold(S::zero(), nan_max)
+    }
+
+    fn norm_max(&self) -> S {
+        if self.has_nan() {
+            S::nan()
+        } else {
+            self.as_ref().norm_max()
+        }
+    }
+
+    fn lu(&self) -> Lu<S, Mat<S>> {
+        self.assert_square();
+        let lu = self.partial_piv_lu();
+        let l = lu.L();
+        let u = lu.U();
+        let packed = Mat::from_fn(self.nrows(), self.ncols(), |row, col| {
+            if row > col {
+                l[(row, col)]
+            } else {
+                u[(row, col)]
+            }
+        });
+        Lu::from_parts(packed, lu.P().arrays().0)
+    }
+
+    fn cholesky(&self) -> Result<Cholesky<S, Mat<S>>, LinalgError> {
+        self.check_square()?;
+        match self.llt(Side::Lower) {
+            Ok(llt) => Ok(Cholesky::from_l(llt.L().to_owned())),
+            Err(LltError::NonPositivePivot { index }) => {
+                Err(LinalgError::NotPositiveDefinite { pivot: index })
+            }
+        }
+    }
+
+    fn qr(&self) -> Qr<S, Mat<S>> {
+        let qr = self.qr();
+        let perm = (0..self.ncols()).collect();
+        qr_from_faer(qr.Q_basis(), qr.Q_coeff(), qr.R(), perm)
+    }
+
+    fn col_piv_qr(&self) -> Qr<S, Mat<S>> {
+        let qr = self.col_piv_qr();
+        let perm = qr.P().arrays().0.to_vec();
+        qr_from_faer(qr.Q_basis(), qr.Q_coeff(), qr.R(), perm)
+    }
+
+    fn svd(&self) -> Result<Svd<S, Mat<S>, Mat<S>>, LinalgError> {
+        self.check_finite()?;
+        let (rows, cols) = self.shape();
+        match self.svd() {
+            Ok(svd) => {
+                let sigma: Vec<S> = svd.S().column_vector().iter().copied().collect();
+                Ok(Svd::from_parts(
+                    &svd.U().to_owned(),
+                    &sigma,
+                    &svd.V().to_owned(),
+                    rows,
+                    cols,
+                ))
+            }
+            Err(_) => svd::svd_full(self),
+        }
+    }
+
+    fn svd_thin(&self) -> Result<Svd<S, Mat<S>, Mat<S>>, LinalgError> {
+        let cols = svd::check_thin(self)?;
+        self.check_finite()?;
+        match self.thin_svd() {
+            Ok(svd) => {
+                let sigma: Vec<S> = svd.S().column_vector().iter().copied().collect();
+                Ok(Svd::from_parts(
+                    &svd.U().to_owned(),
+                    &sigma,
+                    &svd.V().to_owned(),
+                    cols,
+                    cols,
+                ))
+            }
+            Err(_) => svd::svd_thin(self),
+        }
+    }
+
+    fn singular_values(&self) -> Result<Col<S>, LinalgError> {
+        self.check_finite()?;
+        let sigma = match self.singular_values() {
+            Ok(sigma) => sigma,
+            Err(_) => svd::jacobi_svd(self, false)?.1,
+        };
+        Ok(Col::from_fn(sigma.len(), |i| sigma[i]))
+    }
+
+    fn symmetric_eigen(&self) -> Result<SymmetricEigen<S, Mat<S>>, LinalgError> {
+        self.check_square()?;
+        symmetric_eigen::check_lower_finite(self)?;
+        // faer cannot compute the eigendecomposition of an empty matrix.
+        if self.nrows() == 0 {
+            return symmetric_eigen::symmetric_eigen(self);
+        }
+        if let Ok(eigen) = self.self_adjoint_eigen(Side::Lower) {
+            let eigenvalues: Vec<S> = eigen.S().column_vector().iter().copied().collect();
+            Ok(SymmetricEigen::from_parts(
+                &eigenvalues,
+                &eigen.U().to_owned(),
+            ))
+        } else {
+            symmetric_eigen::symmetric_eigen(self)
+        }
+    }
+
+    fn symmetric_eigenvalues(&self) -> Result<Col<S>, LinalgError> {
+        self.check_square()?;
+        symmetric_eigen::check_lower_finite(self)?;
+        // faer cannot compute the eigenvalues of an empty matrix.
+        if self.nrows() == 0 {
+            return Ok(Col::zeros(0));
+        }
+        let mut eigenvalues = match self.self_adjoint_eigenvalues(Side::Lower) {
+            Ok(eigenvalues) => eigenvalues,
+            Err(_) => symmetric_eigen::jacobi_eigen(self, false)?.0,
+        };
+        eigenvalues.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        Ok(Col::from_fn(eigenvalues.len(), |i| eigenvalues[i]))
+    }
+
+    fn eigen(&self) -> Result<Eigen<S, Mat<S>>, LinalgError> {
+        self.check_square()?;
+        self.check_finite()?;
+        let n = self.nrows();
+        let par = get_global_parallelism();
+        let mut eigenvalues_real = Diag::<S>::zeros(n);
+        let mut eigenvalues_imag = Diag::<S>::zeros(n);
+        let mut eigenvectors = Mat::<S>::zeros(n, n);
+        evd::evd_real(
+            self.as_ref(),
+            eigenvalues_real.as_mut(),
+            eigenvalues_imag.as_mut(),
+            None,
+            Some(eigenvectors.as_mut()),
+            par,
+            MemStack::new(&mut MemBuffer::new(evd::evd_scratch::<S>(
+                n,
+                ComputeEigenvectors::No,
+                ComputeEigenvectors::Yes,
+                par,
+                Spec::default(),
+            ))),
+            Spec::default(),
+        )
+        .map_err(|_| LinalgError::NoConvergence)?;
+        let eigenvalues_real: Vec<S> = eigenvalues_real.column_vector().iter().copied().collect();
+        let eigenvalues_imag: Vec<S> = eigenvalues_imag.column_vector().iter().copied().collect();
+        Ok(Eigen::from_parts(
+            &eigenvalues_real,
+            &eigenvalues_imag,
+            &eigenvectors,
+        ))
+    }
+
+    fn eigenvalues(&self) -> Result<(Col<S>, Col<S>), LinalgError> {
+        self.check_square()?;
+        self.check_finite()?;
+        let eigenvalues = self.eigenvalues().map_err(|_| LinalgError::NoConvergence)?;
+        Ok((
+            Col::from_fn(eigenvalues.len(), |i| eigenvalues[i].re),
+            Col::from_fn(eigenvalues.len(), |i| eigenvalues[i].im),
+        ))
+    }
+
+    fn transpose_in_place(&mut self) {
+        self.assert_square();
+        for row in 0..self.nrows() {
+            for col in (row + 1)..self.ncols() {
+                let temp = self[(row, col)];
+                self[(row, col)] = self[(col, row)];
+                self[(col, row)] = temp;
+            }
+        }
+    }
+
+    fn map<F: FnMut(S) -> S>(&self, mut f: F) -> Self {
+        zip!(self).map(|unzip!(a)| f(*a))
+    }
+
+    fn map_in_place<F: FnMut(S) -> S>(&mut self, mut f: F) {
+        zip!(self).for_each(|unzip!(a)| *a = f(*a));
+    }
+
+    fn zip_map<F: FnMut(S, S) -> S>(&self, other: &Self, mut f: F) -> Self {
+        self.assert_same_shape(other);
+        zip!(self, other).map(|unzip!(a, b)| f(*a, *b))
+    }
+
+    fn zip_apply<F: FnMut(&mut S, S)>(&mut self, other: &Self, mut f: F) {
+        self.assert_same_shape(other);
+        zip!(self, other).for_each(|unzip!(a, b)| f(a, *b));
+    }
+
+    fn fold<T, F: FnMut(T, S) -> T>(&self, init: T, mut f: F) -> T {
+        (0..self.ncols()).fold(init, |acc, col| {
+            self.col_as_slice(col).iter().fold(acc, |acc, a| f(acc, *a))
+        })
+    }
+
+    fn map_scalar<T: Scalar, F: FnMut(S) -> T>(&self, mut f: F) -> Mat<T> {
+        zip!(self).map(|unzip!(a)| f(*a))
+    }
+
+    fn add_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_shape(other);
+        assert_output_shape(self, out);
+        zip!(out, self, other).for_each(|unzip!(o, a, b)| *o = *a + *b);
+    }
+
+    fn sub_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_shape(other);
+        assert_output_shape(self, out);
+        zip!(out, self, other).for_each(|unzip!(o, a, b)| *o = *a - *b);
+    }
+}
+
+/// Convert a QR decomposition computed by faer into a [`Qr`].
+///
+/// # Arguments
+///
+/// * `basis` - Householder basis (unit lower trapezoidal matrix whose columns are the Householder
+///   vectors).
+/// * `coeff` - Householder coefficients of the blocked reflections.
+/// * `r` - Upper trapezoidal factor `R`.
+/// * `perm` - Column permutation, where column `j` of `A * P` is column `perm[j]` of `A`.
+///
+/// # Returns
+///
+/// QR decomposition.
+///
+/// # Note
+///
+/// faer groups the Householder reflections into blocks, storing an upper triangular factor `T` for
+/// each block (with the reflections of a block combining to `I - V T⁻¹ Vᵀ`). The diagonal elements
+/// of `T` are the reciprocals of the scaling factors of the individual reflections.
+fn qr_from_faer<S: Scalar + RealField>(
+    basis: MatRef<'_, S>,
+    coeff: MatRef<'_, S>,
+    r: MatRef<'_, S>,
+    perm: Vec<usize>,
+) -> Qr<S, Mat<S>> {
+    let (rows, cols) = (basis.nrows(), r.ncols());
+    let size = rows.min(cols);
+    let block_size = coeff.nrows();
+    let factors = Mat::from_fn(rows, cols, |row, col| {
+        if row <= col {
+            if row < size { r[(row, col)] } else { S::zero() }
+        } else if col < size {
+            basis[(row, col)]
+        } else {
+            S::zero()
+        }
+    });
+    let tau = (0..size)
+        .map(|k| {
+            let t = coeff[(k % block_size, k)];
+            if t == S::zero() { S::zero() } else { t.recip() }
+        })
+        .collect();
+    Qr::from_parts(factors, tau, perm)
 }
@@ -1,10 +1,16 @@
 use crate::Vector;
-use crate::matrix::matrix_trait::Matrix;
+use crate::matrix::matrix_trait::{
+    Matrix, assert_block_in_bounds, assert_output_shape, assert_row_index, assert_row_length,
+    default_set_block,
+};
 use crate::scalar::Scalar;
 use std::borrow::Cow;
 use std::iter::Iterator;
 use std::ops::{Index, IndexMut};
 
+/// Block size (in number of elements along each dimension) used by cache-blocked algorithms.
+const BLOCK_SIZE: usize = 64;
+
 /// Extremely basic matrix type, written as `Mat<S>`, short for "matrix".
 ///
 /// # Implementation Details
@@ -54,6 +60,100 @@ where
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut S> {
         self.data.iter_mut()
     }
+
+    /// Cache-blocked general matrix-matrix multiplication
+    /// (`self = alpha * op(a) * op(b) + beta * self`).
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - The scalar to multiply the product `op(a) * op(b)` by.
+    /// * `a` - The left operand.
+    /// * `transpose_a` - `true` if `op(a)` is the transpose of `a`, `false` if `op(a)` is `a`.
+    /// * `b` - The right operand.
+    /// * `transpose_b` - `true` if `op(b)` is the transpose of `b`, `false` if `op(b)` is `b`.
+    /// * `beta` - The scalar to multiply this matrix by before the product is accumulated into it.
+    ///
+    /// # Panics
+    ///
+    /// * If the number of columns of `op(a)` does not match the number of rows of `op(b)`.
+    /// * If the shape of this matrix does not match the shape of `op(a) * op(b)`.
+    fn gemm_blocked(
+        &mut self,
+        alpha: S,
+        a: &Mat<S>,
+        transpose_a: bool,
+        b: &Mat<S>,
+        transpose_b: bool,
+        beta: S,
+    ) {
+        // Shapes of op(a) and op(b).
+        let (rows, inner) = if transpose_a {
+            (a.cols, a.rows)
+        } else {
+            (a.rows, a.cols)
+        };
+        let (inner_b, cols) = if transpose_b {
+            (b.cols, b.rows)
+        } else {
+            (b.rows, b.cols)
+        };
+        assert_eq!(
+            inner, inner_b,
+            "Matrices have incompatible shapes for multiplication ({rows}x{inner} and \
+            {inner_b}x{cols}).",
+        );
+        assert_eq!(
+            (self.rows, self.cols),
+            (rows, cols),
+            "Shape of the output matrix ({}x{}) does not match the shape of the product \
+            ({rows}x{cols}).",
+            self.rows,
+            self.cols,
+        );
+
+        // Elements of op(a) and op(b).
+        let a_at = |row: usize, col: usize| {
+            if transpose_a {
+                a.data[col * a.cols + row]
+            } else {
+                a.data[row * a.cols + col]
+            }
+        };
+        let b_at = |row: usize, col: usize| {
+            if transpose_b {
+                b.data[col * b.cols + row]
+            } else {
+                b.data[row * b.cols + col]
+            }
+        };
+
+        // Scale this matrix by beta (overwriting it without reading it if beta is zero).
+        if beta == S::zero() {
+            self.data.fill(S::zero());
+        } else if beta != S::one() {
+            self.data.mul_assign(beta);
+        }
+
+        // Accumulate the product block-by-block, traversing the rows of this matrix contiguously.
+        for row_start in (0..rows).step_by(BLOCK_SIZE) {
+            let row_end = (row_start + BLOCK_SIZE).min(rows);
+            for inner_start in (0..inner).step_by(BLOCK_SIZE) {
+                let inner_end = (inner_start + BLOCK_SIZE).min(inner);
+                for col_start in (0..cols).step_by(BLOCK_SIZE) {
+                    let col_end = (col_start + BLOCK_SIZE).min(cols);
+                    for row in row_start..row_end {
+                        let out_row = &mut self.data[row * cols + col_start..row * cols + col_end];
+                        for idx in inner_start..inner_end {
+                            let scale = alpha * a_at(row, idx);
+                            for (col, out) in (col_start..col_end).zip(out_row.iter_mut()) {
+                                *out += scale * b_at(idx, col);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl<S> IntoIterator for Mat<S>
@@ -90,6 +190,20 @@ where
 
     type VectorN = Vec<S>;
 
+    type MatrixNxP<const P: usize> = Mat<S>;
+
+    type MatrixMxP<const P: usize> = Mat<S>;
+
+    type Transpose = Mat<S>;
+
+    type MatrixMxM = Mat<S>;
+
+    type MatrixNxN = Mat<S>;
+
+    type MatrixT<T: Scalar> = Mat<T>;
+
+    type DMatrixMxN = Mat<S>;
+
     fn is_statically_sized() -> bool {
         false
     }
@@ -98,6 +212,10 @@ where
         true
     }
 
+    fn static_shape() -> Option<(usize, usize)> {
+        None
+    }
+
     fn is_row_major() -> bool {
         true
     }
@@ -156,6 +274,14 @@ where
         Cow::from(self.data.as_slice())
     }
 
+    fn try_as_contiguous_slice(&self) -> Option<&[S]> {
+        Some(&self.data)
+    }
+
+    fn try_as_contiguous_mut_slice(&mut self) -> Option<&mut [S]> {
+        Some(&mut self.data)
+    }
+
     fn get(&self, index: (usize, usize)) -> Option<&S> {
         let (row, col) = index;
         let (rows, cols) = self.shape();
@@ -217,6 +343,253 @@ where
     fn div_assign(&mut self, scalar: S) {
         self.data.div_assign(scalar);
     }
+
+    fn mul_vector(&self, vector: &Vec<S>) -> Vec<S> {
+        let mut out = self.new_vector_m();
+        self.mul_vector_to(vector, &mut out);
+        out
+    }
+
+    fn mul_vector_to(&self, vector: &Vec<S>, out: &mut Vec<S>) {
+        assert_eq!(
+            vector.len(),
+            self.cols,
+            "Length of the vector ({}) does not match the number of columns of the matrix ({}).",
+            vector.len(),
+            self.cols
+        );
+        assert_eq!(
+            out.len(),
+            self.rows,
+            "Length of the output vector ({}) does not match the number of rows of the matrix \
+            ({}).",
+            out.len(),
+            self.rows
+        );
+        for (row, out_row) in out.iter_mut().enumerate() {
+            let data_row = &self.data[row * self.cols..(row + 1) * self.cols];
+            *out_row = data_row
+                .iter()
+                .zip(vector.iter())
+                .fold(S::zero(), |acc, (a, b)| acc + *a * *b);
+        }
+    }
+
+    fn vector_mul(&self, vector: &Vec<S>) -> Vec<S> {
+        let mut out = self.new_vector_n();
+        self.vector_mul_to(vector, &mut out);
+        out
+    }
+
+    fn vector_mul_to(&self, vector: &Vec<S>, out: &mut Vec<S>) {
+        assert_eq!(
+            vector.len(),
+            self.rows,
+            "Length of the vector ({}) does not match the number of rows of the matrix ({}).",
+            vector.len(),
+            self.rows
+        );
+        assert_eq!(
+            out.len(),
+            self.cols,
+            "Length of the output vector ({}) does not match the number of columns of the matrix \
+            ({}).",
+            out.len(),
+            self.cols
+        );
+
+        // Accumulate row-by-row so that the matrix data is traversed contiguously.
+        out.fill(S::zero());
+        for (row, &scale) in vector.iter().enumerate() {
+            let data_row = &self.data[row * self.cols..(row + 1) * self.cols];
+            for (out_col, &a) in out.iter_mut().zip(data_row.iter()) {
+                *out_col += scale * a;
+            }
+        }
+    }
+
+    fn matmul<const P: usize>(&self, other: &Mat<S>) -> Mat<S> {
+        let mut out = Mat::new_with_shape(self.rows, other.cols);
+        self.matmul_to::<P>(other, &mut out);
+        out
+    }
+
+    fn matmul_to<const P: usize>(&self, other: &Mat<S>, out: &mut Mat<S>) {
+        out.gemm_blocked(S::one(), self, false, other, false, S::zero());
+    }
+
+    fn gemm(
+        &mut self,
+        alpha: S,
+        a: &Self,
+        transpose_a: bool,
+        b: &Self,
+        transpose_b: bool,
+        beta: S,
+    ) {
+        self.gemm_blocked(alpha, a, transpose_a, b, transpose_b, beta);
+    }
+
+    fn transpose(&self) -> Mat<S> {
+        // Copy block-by-block so that both the reads and the writes stay within cache.
+        let mut data = vec![S::zero(); self.data.len()];
+        for row_start in (0..self.rows).step_by(BLOCK_SIZE) {
+            let row_end = (row_start + BLOCK_SIZE).min(self.rows);
+            for col_start in (0..self.cols).step_by(BLOCK_SIZE) {
+                let col_end = (col_start + BLOCK_SIZE).min(self.cols);
+                for row in row_start..row_end {
+                    for col in col_start..col_end {
+                        data[col * self.rows + row] = self.data[row * self.cols + col];
+                    }
+                }
+            }
+        }
+        Mat {
+            data,
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    fn transpose_in_place(&mut self) {
+        self.assert_square();
+        let n = self.rows;
+
+        // Swap block-by-block over the upper triangle so that both swapped elements stay within
+        // cache.
+        for row_start in (0..n).step_by(BLOCK_SIZE) {
+            let row_end = (row_start + BLOCK_SIZE).min(n);
+            for col_start in (row_start..n).step_by(BLOCK_SIZE) {
+                let col_end = (col_start + BLOCK_SIZE).min(n);
+                for row in row_start..row_end {
+                    for col in col_start.max(row + 1)..col_end {
+                        self.data.swap(row * n + col, col * n + row);
+                    }
+                }
+            }
+        }
+    }
+
+    fn map<F: FnMut(S) -> S>(&self, f: F) -> Self {
+        Mat {
+            data: self.data.iter().copied().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    fn map_in_place<F: FnMut(S) -> S>(&mut self, mut f: F) {
+        for a in &mut self.data {
+            *a = f(*a);
+        }
+    }
+
+    fn zip_map<F: FnMut(S, S) -> S>(&self, other: &Self, mut f: F) -> Self {
+        self.assert_same_shape(other);
+        Mat {
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(a, b)| f(*a, *b))
+                .collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    fn zip_apply<F: FnMut(&mut S, S)>(&mut self, other: &Self, mut f: F) {
+        self.assert_same_shape(other);
+        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
+            f(a, *b);
+        }
+    }
+
+    fn fold<T, F: FnMut(T, S) -> T>(&self, init: T, f: F) -> T {
+        self.data.iter().copied().fold(init, f)
+    }
+
+    fn map_scalar<T: Scalar, F: FnMut(S) -> T>(&self, f: F) -> Mat<T> {
+        Mat {
+            data: self.data.iter().copied().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    fn row(&self, row: usize) -> Vec<S> {
+        assert_row_index(self, row);
+        self.data[row * self.cols..(row + 1) * self.cols].to_vec()
+    }
+
+    fn set_row(&mut self, row: usize, values: &Vec<S>) {
+        assert_row_index(self, row);
+        assert_row_length(self, values.len());
+        self.data[row * self.cols..(row + 1) * self.cols].copy_from_slice(values);
+    }
+
+    fn submatrix(&self, row0: usize, col0: usize, nrows: usize, ncols: usize) -> Mat<S> {
+        assert_block_in_bounds(self, row0, col0, nrows, ncols);
+        let mut data = Vec::with_capacity(nrows * ncols);
+        for row in row0..(row0 + nrows) {
+            let start = row * self.cols + col0;
+            data.extend_from_slice(&self.data[start..start + ncols]);
+        }
+        Mat {
+            data,
+            rows: nrows,
+            cols: ncols,
+        }
+    }
+
+    fn set_block<B: Matrix<S>>(&mut self, row0: usize, col0: usize, block: &B) {
+        let (nrows, ncols) = block.shape();
+        match block.try_as_contiguous_slice() {
+            Some(slice) if B::is_row_major() => {
+                assert_block_in_bounds(self, row0, col0, nrows, ncols);
+                for row in 0..nrows {
+                    let start = (row0 + row) * self.cols + col0;
+                    self.data[start..start + ncols]
+                        .copy_from_slice(&slice[row * ncols..(row + 1) * ncols]);
+                }
+            }
+            _ => default_set_block(self, row0, col0, block),
+        }
+    }
+
+    fn from_element(rows: usize, cols: usize, value: S) -> Self {
+        Mat {
+            data: vec![value; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    fn from_fn<F: FnMut(usize, usize) -> S>(rows: usize, cols: usize, mut f: F) -> Self {
+        Mat {
+            data: (0..rows * cols)
+                .map(|idx| f(idx / cols, idx % cols))
+                .collect(),
+            rows,
+            cols,
+        }
+    }
+
+    fn add_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_shape(other);
+        assert_output_shape(self, out);
+        for ((o, a), b) in out.data.iter_mut().zip(&self.data).zip(&other.data) {
+            *o = *a + *b;
+        }
+    }
+
+    fn sub_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_shape(other);
+        assert_output_shape(self, out);
+        for ((o, a), b) in out.data.iter_mut().zip(&self.data).zip(&other.data) {
+            *o = *a - *b;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,16 @@
+use crate::decompositions::cholesky::Cholesky;
+use crate::decompositions::eigen::Eigen;
+use crate::decompositions::lu::Lu;
+use crate::decompositions::qr::Qr;
+use crate::decompositions::schur::{self, Schur};
+use crate::decompositions::svd::{self, Svd};
+use crate::decompositions::symmetric_eigen::{self, SymmetricEigen};
+use crate::error::LinalgError;
 use crate::scalar::Scalar;
-use crate::vector::vector_trait::Vector;
+use crate::vector::nan_policy::NanPolicy;
+use crate::vector::vector_trait::{
+    Vector, check_index, check_length, nan_max, nan_min, scaled_l2_norm,
+};
 use std::borrow::Cow;
 use std::fmt::Debug;
 use std::ops::{Index, IndexMut};
@@ -44,6 +55,15 @@ use std::ops::{Index, IndexMut};
 /// [`Scalar`] trait for `CustomType`. If we want to be able to pass an
 /// [`ndarray::Array2<CustomType>`] into `my_function` from the example above, then we must also
 /// implement the [`ndarray::ScalarOperand`] and [`ndarray::LinalgScalar`] traits for `CustomType`.
+///
+/// # Decompositions
+///
+/// The default implementations of the decompositions ([`Matrix::lu`], [`Matrix::cholesky`],
+/// [`Matrix::qr`], [`Matrix::col_piv_qr`], [`Matrix::svd`], [`Matrix::symmetric_eigen`],
+/// [`Matrix::schur`], and the methods built on them) are written purely in terms of indexing, so
+/// they can be used with any scalar type (e.g. dual numbers). The implementations for types from
+/// numerical computing crates override them to use the decompositions provided by those crates
+/// where possible.
 pub trait Matrix<S: Scalar>:
     Index<(usize, usize), Output = S>       // Indexing via square brackets.
     + IndexMut<(usize, usize), Output = S>  // Index-assignment via square brackets.
@@ -67,6 +87,87 @@ pub trait Matrix<S: Scalar>:
     /// vector type with length `N` (mathematically representing a row vector).
     type VectorM: Vector<S>;
 
+    /// `N x P` matrix type implementing the [`crate::Matrix`] trait that is compatible with this
+    /// matrix type. An instance of this matrix type with shape `(M, N)` can be multiplied from the
+    /// right by an instance of this `N x P` matrix type, resulting in an instance of
+    /// [`crate::Matrix::MatrixMxP`] with shape `(M, P)`.
+    /// 
+    /// # Note
+    /// 
+    /// * We say that the instance of the type implementing the [`Matrix`] trait has shape
+    ///   `(M, N)`.
+    /// * Therefore, we already know one of the dimensions (`N`) of this `N`-by-`P` matrix.
+    /// * For statically-sized matrices, to know the other dimension (`P`) at compile time, we need
+    ///   to provide `P` as a const generic.
+    /// * For dynamically-sized matrices, the const generic `P` is not used.
+    type MatrixNxP<const P: usize>: Matrix<S>;
+
+    /// `M x P` matrix type implementing the [`crate::Matrix`] trait that is compatible with this
+    /// matrix type. This is the type resulting from multiplying an instance of this matrix type
+    /// with shape `(M, N)` from the right by an instance of [`crate::Matrix::MatrixNxP`] with shape
+    /// `(N, P)`.
+    /// 
+    /// # Note
+    /// 
+    /// * For statically-sized matrices, to know the number of columns (`P`) at compile time, we
+    ///   need to provide `P` as a const generic.
+    /// * For dynamically-sized matrices, the const generic `P` is not used.
+    type MatrixMxP<const P: usize>: Matrix<S>;
+
+    /// `N x M` matrix type implementing the [`crate::Matrix`] trait that can store the transpose
+    /// of an instance of this matrix type with shape `(M, N)`.
+    /// 
+    /// # Note
+    /// 
+    /// * For dynamically-sized matrices, this is typically the same type as this matrix type.
+    /// * For statically-sized matrices, this is typically a different type, since the transpose of
+    ///   an `M x N` matrix is an `N x M` matrix.
+    /// * The transpose of this type must be this matrix type, and its compatible vector types are
+    ///   swapped relative to the vector types compatible with this matrix type.
+    type Transpose: Matrix<
+        S,
+        Transpose = Self,
+        VectorM = Self::VectorN,
+        VectorN = Self::VectorM,
+    >;
+
+    /// `M x M` (square) matrix type implementing the [`crate::Matrix`] trait that is compatible
+    /// with this matrix type. This is the type of the full orthogonal factor `Q` in the QR
+    /// decomposition of an instance of this matrix type with shape `(M, N)`.
+    /// 
+    /// # Note
+    /// 
+    /// For dynamically-sized matrices, this is typically the same type as this matrix type.
+    type MatrixMxM: Matrix<S, VectorM = Self::VectorM, VectorN = Self::VectorM>;
+
+    /// `N x N` (square) matrix type implementing the [`crate::Matrix`] trait that is compatible
+    /// with this matrix type. This is the type of the triangular factor `R` in the thin QR
+    /// decomposition of an instance of this matrix type with shape `(M, N)`.
+    /// 
+    /// # Note
+    /// 
+    /// For dynamically-sized matrices, this is typically the same type as this matrix type.
+    type MatrixNxN: Matrix<S, VectorM = Self::VectorN, VectorN = Self::VectorN>;
+
+    /// Matrix type that is of the same "outer" matrix type (i.e. the `Matrix` part of `Matrix<S>`
+    /// where `S: Scalar`) and has the same shape, but where the element type can be any other type
+    /// that implements the [`crate::Scalar`] trait.
+    /// 
+    /// # Note
+    /// 
+    /// For statically-sized matrices, this type must have the same (static) shape as this matrix
+    /// type.
+    type MatrixT<T: Scalar>: Matrix<T>;
+
+    /// Dynamically-sized matrix type of the same "outer" matrix type (i.e. the `Matrix` part of
+    /// `Matrix<S>` where `S: Scalar`), whose shape is determined at runtime. This is the type of
+    /// the submatrices extracted from an instance of this matrix type.
+    /// 
+    /// # Note
+    /// 
+    /// For dynamically-sized matrices, this is typically the same type as this matrix type.
+    type DMatrixMxN: Matrix<S>;
+
     // -------------------------------
     // Default method implementations.
     // -------------------------------
@@ -180,83 +281,2588 @@ pub trait Matrix<S: Scalar>:
         );
     }
 
-    /// Return a slice view of the matrix's elements in row-major order.
-    ///
+    /// Assert that this matrix is square.
+    /// 
+    /// # Panics
+    /// 
+    /// * If the number of rows of this matrix is not equal to its number of columns.
+    fn assert_square(&self) {
+        let (rows, cols) = self.shape();
+        assert_eq!(rows, cols, "Matrix is not square ({rows}x{cols}).");
+    }
+
+    /// Create a matrix with the specified shape, with each element set to 0, without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rows` - Number of rows.
+    /// * `cols` - Number of columns.
+    /// 
     /// # Returns
-    ///
-    /// A slice of the matrix's elements in row-major order.
+    /// 
+    /// Matrix with the specified shape, with each element set to 0.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `(rows, cols)` does not match the shape of the matrix
+    ///   (for statically-sized matrices only).
+    fn try_new_with_shape(rows: usize, cols: usize) -> Result<Self, LinalgError> {
+        check_static_shape::<S, Self>(rows, cols)?;
+        Ok(Self::new_with_shape(rows, cols))
+    }
+
+    /// Create a matrix from a slice of scalars arranged in row-major order without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rows` - Number of rows.
+    /// * `cols` - Number of columns.
+    /// * `slice` - The slice of scalar values to initialize the matrix.
+    /// 
+    /// # Returns
+    /// 
+    /// A matrix containing the elements from the slice.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if the length of the slice is not `rows * cols`.
+    /// * [`LinalgError::ShapeMismatch`] if `(rows, cols)` does not match the shape of the matrix
+    ///   (for statically-sized matrices only).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use linalg_traits::{LinalgError, Mat, Matrix};
+    /// 
+    /// let a = Mat::<f64>::try_from_row_slice(2, 2, &[1.0, 2.0, 3.0]);
+    /// assert_eq!(a, Err(LinalgError::LengthMismatch { expected: 4, actual: 3 }));
+    /// ```
+    fn try_from_row_slice(rows: usize, cols: usize, slice: &[S]) -> Result<Self, LinalgError> {
+        check_slice_length(rows, cols, slice)?;
+        check_static_shape::<S, Self>(rows, cols)?;
+        Ok(Self::from_row_slice(rows, cols, slice))
+    }
+
+    /// Create a matrix from a slice of scalars arranged in column-major order without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rows` - Number of rows.
+    /// * `cols` - Number of columns.
+    /// * `slice` - The slice of scalar values to initialize the matrix.
+    /// 
+    /// # Returns
+    /// 
+    /// A matrix containing the elements from the slice.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if the length of the slice is not `rows * cols`.
+    /// * [`LinalgError::ShapeMismatch`] if `(rows, cols)` does not match the shape of the matrix
+    ///   (for statically-sized matrices only).
+    fn try_from_col_slice(rows: usize, cols: usize, slice: &[S]) -> Result<Self, LinalgError> {
+        check_slice_length(rows, cols, slice)?;
+        check_static_shape::<S, Self>(rows, cols)?;
+        Ok(Self::from_col_slice(rows, cols, slice))
+    }
+
+    /// Matrix addition (elementwise) without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix to add to this matrix.
+    /// 
+    /// # Returns
+    /// 
+    /// Sum of this matrix with the other matrix (i.e. `self + other`).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `self` and `other` do not have the same shape.
+    fn try_add(&self, other: &Self) -> Result<Self, LinalgError> {
+        check_shape(self.shape(), other.shape())?;
+        Ok(self.add(other))
+    }
+
+    /// Matrix subtraction (elementwise) without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix to subtract from this matrix.
+    /// 
+    /// # Returns
+    /// 
+    /// The difference of this matrix with the other matrix (i.e. `self - other`).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `self` and `other` do not have the same shape.
+    fn try_sub(&self, other: &Self) -> Result<Self, LinalgError> {
+        check_shape(self.shape(), other.shape())?;
+        Ok(self.sub(other))
+    }
+
+    /// Matrix-vector multiplication without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `vector` - The length-`N` vector to multiply this `M x N` matrix by from the right.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`M` vector resulting from the product of this matrix with the vector (i.e.
+    /// `self * vector`).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if the length of the vector does not match the number of
+    ///   columns of this matrix.
+    fn try_mul_vector(&self, vector: &Self::VectorN) -> Result<Self::VectorM, LinalgError> {
+        check_length(self.shape().1, vector.len())?;
+        Ok(self.mul_vector(vector))
+    }
+
+    /// Matrix-matrix multiplication without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The `N x P` matrix to multiply this `M x N` matrix by from the right.
+    /// 
+    /// # Returns
+    /// 
+    /// `M x P` matrix resulting from the product of this matrix with the other matrix (i.e.
+    /// `self * other`).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if the number of rows of the other matrix does not match
+    ///   the number of columns of this matrix.
     /// 
     /// # Note
     /// 
-    /// The slice is returned as a `Cow<[S]>` instead of a `&[S]`. This is primarily because the
-    /// underlying data will be _either_ in row-major order _or_ in column-major order. If it is in
-    /// column-major order, then we will need to re-order it to be in row-major order. This requires
-    /// building a temporary variable within this method which will be dropped when the method scope
-    /// ends. In such cases, this method will clone the data when returning it in a `Cow<[S]>`.
+    /// See [`Matrix::matmul`] for the meaning of the const generic parameter `P`.
+    fn try_matmul<const P: usize>(
+        &self,
+        other: &Self::MatrixNxP<P>,
+    ) -> Result<Self::MatrixMxP<P>, LinalgError> {
+        let (other_rows, other_cols) = other.shape();
+        check_shape((self.shape().1, other_cols), (other_rows, other_cols))?;
+        Ok(self.matmul(other))
+    }
+
+    /// Get a row of this matrix without panicking.
     /// 
-    /// In most cases, if the data is in row-major order, then this function will not perform any
-    /// cloning. However, in some cases, even if the underlying data is in row-major order, its data
-    /// may not be contiguous in memory. This would also require cloning the data from a temporary
-    /// variable. See [`Matrix::as_slice`] for more information.
-    fn as_row_slice(&self) -> Cow<'_, [S]>  {
-        if Self::is_row_major() {
-            self.as_slice()
-        } else {
-            let (rows, cols) = self.shape();
-            let mut vec = Vec::<S>::with_capacity(rows * cols);
-            for row in 0..rows {
-                for col in 0..cols {
-                    vec.push(self[(row, col)]);
-                }
+    /// # Arguments
+    /// 
+    /// * `row` - Index of the row.
+    /// 
+    /// # Returns
+    /// 
+    /// Copy of the row as a length-`N` vector.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::IndexOutOfBounds`] if `row` is out of bounds.
+    fn try_row(&self, row: usize) -> Result<Self::VectorN, LinalgError> {
+        check_index(row, self.shape().0)?;
+        Ok(self.row(row))
+    }
+
+    /// Get a column of this matrix without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `col` - Index of the column.
+    /// 
+    /// # Returns
+    /// 
+    /// Copy of the column as a length-`M` vector.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::IndexOutOfBounds`] if `col` is out of bounds.
+    fn try_column(&self, col: usize) -> Result<Self::VectorM, LinalgError> {
+        check_index(col, self.shape().1)?;
+        Ok(self.column(col))
+    }
+
+    /// In-place matrix addition (elementwise) without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix to add to this matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `self` and `other` do not have the same shape (in which
+    ///   case this matrix is left unchanged).
+    fn try_add_assign(&mut self, other: &Self) -> Result<(), LinalgError> {
+        check_shape(self.shape(), other.shape())?;
+        self.add_assign(other);
+        Ok(())
+    }
+
+    /// In-place matrix subtraction (elementwise) without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix to subtract from this matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `self` and `other` do not have the same shape (in which
+    ///   case this matrix is left unchanged).
+    fn try_sub_assign(&mut self, other: &Self) -> Result<(), LinalgError> {
+        check_shape(self.shape(), other.shape())?;
+        self.sub_assign(other);
+        Ok(())
+    }
+
+    /// Matrix-vector multiplication, storing the result in a preallocated vector
+    /// (`out = self * vector`), without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `vector` - The length-`N` vector to multiply this `M x N` matrix by from the right.
+    /// * `out` - The length-`M` vector to store the result in.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if the length of the vector does not match the number of
+    ///   columns of this matrix, or if the length of the output vector does not match the number
+    ///   of rows of this matrix (in which case `out` is left unchanged).
+    fn try_mul_vector_to(
+        &self,
+        vector: &Self::VectorN,
+        out: &mut Self::VectorM,
+    ) -> Result<(), LinalgError> {
+        let (rows, cols) = self.shape();
+        check_length(cols, vector.len())?;
+        check_length(rows, out.len())?;
+        self.mul_vector_to(vector, out);
+        Ok(())
+    }
+
+    /// Vector-matrix multiplication without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `vector` - The length-`M` vector to multiply this `M x N` matrix by from the left.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`N` vector resulting from the product of the vector with this matrix (i.e.
+    /// `vector * self`).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if the length of the vector does not match the number of
+    ///   rows of this matrix.
+    fn try_vector_mul(&self, vector: &Self::VectorM) -> Result<Self::VectorN, LinalgError> {
+        check_length(self.shape().0, vector.len())?;
+        Ok(self.vector_mul(vector))
+    }
+
+    /// Matrix-matrix multiplication, storing the result in a preallocated matrix
+    /// (`out = self * other`), without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The `N x P` matrix to multiply this `M x N` matrix by from the right.
+    /// * `out` - The `M x P` matrix to store the result in.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if the number of rows of the other matrix does not match
+    ///   the number of columns of this matrix, or if the shape of the output matrix is not
+    ///   `(M, P)` (in which case `out` is left unchanged).
+    /// 
+    /// # Note
+    /// 
+    /// See [`Matrix::matmul`] for the meaning of the const generic parameter `P`.
+    fn try_matmul_to<const P: usize>(
+        &self,
+        other: &Self::MatrixNxP<P>,
+        out: &mut Self::MatrixMxP<P>,
+    ) -> Result<(), LinalgError> {
+        let (rows, cols) = self.shape();
+        let (other_rows, other_cols) = other.shape();
+        check_shape((cols, other_cols), (other_rows, other_cols))?;
+        check_shape((rows, other_cols), out.shape())?;
+        self.matmul_to(other, out);
+        Ok(())
+    }
+
+    /// Overwrite a row of this matrix without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `row` - Index of the row.
+    /// * `values` - Length-`N` vector containing the new elements of the row.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::IndexOutOfBounds`] if `row` is out of bounds.
+    /// * [`LinalgError::LengthMismatch`] if the length of `values` is not equal to the number of
+    ///   columns of this matrix.
+    fn try_set_row(&mut self, row: usize, values: &Self::VectorN) -> Result<(), LinalgError> {
+        let (rows, cols) = self.shape();
+        check_index(row, rows)?;
+        check_length(cols, values.len())?;
+        self.set_row(row, values);
+        Ok(())
+    }
+
+    /// Overwrite a column of this matrix without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `col` - Index of the column.
+    /// * `values` - Length-`M` vector containing the new elements of the column.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::IndexOutOfBounds`] if `col` is out of bounds.
+    /// * [`LinalgError::LengthMismatch`] if the length of `values` is not equal to the number of
+    ///   rows of this matrix.
+    fn try_set_column(&mut self, col: usize, values: &Self::VectorM) -> Result<(), LinalgError> {
+        let (rows, cols) = self.shape();
+        check_index(col, cols)?;
+        check_length(rows, values.len())?;
+        self.set_column(col, values);
+        Ok(())
+    }
+
+    /// Extract a submatrix (block) of this matrix without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `row0` - Row index of the top-left element of the block.
+    /// * `col0` - Column index of the top-left element of the block.
+    /// * `nrows` - Number of rows of the block.
+    /// * `ncols` - Number of columns of the block.
+    /// 
+    /// # Returns
+    /// 
+    /// `nrows x ncols` dynamically-sized matrix containing the block of this matrix whose top-left
+    /// element is at `(row0, col0)`.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::IndexOutOfBounds`] if the block does not fit within this matrix,
+    ///   reporting the last row (or column) index spanned by the block.
+    fn try_submatrix(
+        &self,
+        row0: usize,
+        col0: usize,
+        nrows: usize,
+        ncols: usize,
+    ) -> Result<Self::DMatrixMxN, LinalgError> {
+        check_block_in_bounds(self, row0, col0, nrows, ncols)?;
+        Ok(self.submatrix(row0, col0, nrows, ncols))
+    }
+
+    /// Overwrite a block of this matrix with another matrix without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `row0` - Row index at which the top-left element of the block is placed.
+    /// * `col0` - Column index at which the top-left element of the block is placed.
+    /// * `block` - The block (of any matrix type with the same scalar type).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::IndexOutOfBounds`] if the block does not fit within this matrix,
+    ///   reporting the last row (or column) index spanned by the block (in which case this matrix
+    ///   is left unchanged).
+    fn try_set_block<B: Matrix<S>>(
+        &mut self,
+        row0: usize,
+        col0: usize,
+        block: &B,
+    ) -> Result<(), LinalgError> {
+        let (nrows, ncols) = block.shape();
+        check_block_in_bounds(self, row0, col0, nrows, ncols)?;
+        self.set_block(row0, col0, block);
+        Ok(())
+    }
+
+    /// Stack matrices horizontally (i.e. side by side) without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `blocks` - The matrices to stack, from left to right.
+    /// 
+    /// # Returns
+    /// 
+    /// Matrix `[B₁, B₂, ..., Bₖ]` (a `0 x 0` matrix if there are no blocks).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if the blocks do not all have the same number of rows
+    ///   (reporting the first block that does not), or if the shape of the result
+    ///   does not match the shape of this matrix type (for statically-sized matrices only).
+    fn try_hstack<B: Matrix<S>>(blocks: &[&B]) -> Result<Self, LinalgError> {
+        let rows = blocks.first().map_or(0, |block| block.shape().0);
+        for block in blocks {
+            let (block_rows, block_cols) = block.shape();
+            check_shape((rows, block_cols), (block_rows, block_cols))?;
+        }
+        let cols = blocks.iter().map(|block| block.shape().1).sum();
+        check_static_shape::<S, Self>(rows, cols)?;
+        Ok(Self::hstack(blocks))
+    }
+
+    /// Stack matrices vertically (i.e. one on top of the other) without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `blocks` - The matrices to stack, from top to bottom.
+    /// 
+    /// # Returns
+    /// 
+    /// Matrix `[B₁; B₂; ...; Bₖ]` (a `0 x 0` matrix if there are no blocks).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if the blocks do not all have the same number of columns
+    ///   (reporting the first block that does not), or if the shape of the result
+    ///   does not match the shape of this matrix type (for statically-sized matrices only).
+    fn try_vstack<B: Matrix<S>>(blocks: &[&B]) -> Result<Self, LinalgError> {
+        let cols = blocks.first().map_or(0, |block| block.shape().1);
+        for block in blocks {
+            let (block_rows, block_cols) = block.shape();
+            check_shape((block_rows, cols), (block_rows, block_cols))?;
+        }
+        let rows = blocks.iter().map(|block| block.shape().0).sum();
+        check_static_shape::<S, Self>(rows, cols)?;
+        Ok(Self::vstack(blocks))
+    }
+
+    /// Create an identity matrix without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `n` - Number of rows and columns.
+    /// 
+    /// # Returns
+    /// 
+    /// `n x n` identity matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `(n, n)` does not match the shape of the matrix (for
+    ///   statically-sized matrices only).
+    fn try_identity(n: usize) -> Result<Self, LinalgError> {
+        check_static_shape::<S, Self>(n, n)?;
+        Ok(Self::identity(n))
+    }
+
+    /// Create a matrix by evaluating a function at each position without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rows` - Number of rows.
+    /// * `cols` - Number of columns.
+    /// * `f` - Function returning the element at the specified row and column indices.
+    /// 
+    /// # Returns
+    /// 
+    /// `rows x cols` matrix whose `(i, j)`th element is `f(i, j)`.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `(rows, cols)` does not match the shape of the matrix
+    ///   (for statically-sized matrices only).
+    fn try_from_fn<F: FnMut(usize, usize) -> S>(
+        rows: usize,
+        cols: usize,
+        f: F,
+    ) -> Result<Self, LinalgError> {
+        check_static_shape::<S, Self>(rows, cols)?;
+        Ok(Self::from_fn(rows, cols, f))
+    }
+
+    /// Create a matrix from its rows without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rows` - Rows of the matrix (each a length-`N` vector).
+    /// 
+    /// # Returns
+    /// 
+    /// Matrix whose `i`th row is `rows[i]` (a `0 x 0` matrix if there are no rows).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if the rows do not all have the same length as the first
+    ///   row.
+    /// * [`LinalgError::ShapeMismatch`] if the shape of the result does not match the shape of
+    ///   this matrix type (for statically-sized matrices only).
+    fn try_from_rows(rows: &[Self::VectorN]) -> Result<Self, LinalgError> {
+        let cols = rows.first().map_or(0, Vector::len);
+        for row in rows {
+            check_length(cols, row.len())?;
+        }
+        check_static_shape::<S, Self>(rows.len(), cols)?;
+        Ok(Self::from_rows(rows))
+    }
+
+    /// Create a matrix from its columns without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `columns` - Columns of the matrix (each a length-`M` vector).
+    /// 
+    /// # Returns
+    /// 
+    /// Matrix whose `j`th column is `columns[j]` (a `0 x 0` matrix if there are no columns).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if the columns do not all have the same length as the
+    ///   first column.
+    /// * [`LinalgError::ShapeMismatch`] if the shape of the result does not match the shape of
+    ///   this matrix type (for statically-sized matrices only).
+    fn try_from_columns(columns: &[Self::VectorM]) -> Result<Self, LinalgError> {
+        let rows = columns.first().map_or(0, Vector::len);
+        for column in columns {
+            check_length(rows, column.len())?;
+        }
+        check_static_shape::<S, Self>(rows, columns.len())?;
+        Ok(Self::from_columns(columns))
+    }
+
+    /// Elementwise (Hadamard) product of two matrices without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix to multiply this matrix by (elementwise).
+    /// 
+    /// # Returns
+    /// 
+    /// Elementwise product of this matrix with the other matrix (i.e. `self ⊙ other`).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `self` and `other` do not have the same shape.
+    fn try_hadamard(&self, other: &Self) -> Result<Self, LinalgError> {
+        check_shape(self.shape(), other.shape())?;
+        Ok(self.hadamard(other))
+    }
+
+    /// Combine this matrix with another matrix elementwise without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix.
+    /// * `f` - Function to apply to each pair of elements.
+    /// 
+    /// # Returns
+    /// 
+    /// Matrix whose `(i, j)`th element is `f(aᵢⱼ, bᵢⱼ)`, where `a` is this matrix and `b` is the
+    /// other matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `self` and `other` do not have the same shape.
+    fn try_zip_map<F: FnMut(S, S) -> S>(&self, other: &Self, f: F) -> Result<Self, LinalgError> {
+        check_shape(self.shape(), other.shape())?;
+        Ok(self.zip_map(other, f))
+    }
+
+    /// Update this matrix elementwise using another matrix without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix.
+    /// * `f` - Function called as `f(&mut aᵢⱼ, bᵢⱼ)`, where `a` is this matrix and `b` is the other
+    ///   matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `self` and `other` do not have the same shape (in which
+    ///   case this matrix is left unchanged).
+    fn try_zip_apply<F: FnMut(&mut S, S)>(&mut self, other: &Self, f: F) -> Result<(), LinalgError> {
+        check_shape(self.shape(), other.shape())?;
+        self.zip_apply(other, f);
+        Ok(())
+    }
+
+    /// Elementwise (Hadamard) quotient of two matrices without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix to divide this matrix by (elementwise).
+    /// 
+    /// # Returns
+    /// 
+    /// Elementwise quotient of this matrix with the other matrix (i.e. `self ⊘ other`).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `self` and `other` do not have the same shape.
+    fn try_hadamard_div(&self, other: &Self) -> Result<Self, LinalgError> {
+        check_shape(self.shape(), other.shape())?;
+        Ok(self.hadamard_div(other))
+    }
+
+    /// Matrix addition (elementwise), storing the result in a preallocated matrix
+    /// (`out = self + other`), without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix to add to this matrix.
+    /// * `out` - The matrix to store the result in.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `other` or `out` does not have the same shape as this
+    ///   matrix (in which case `out` is left unchanged).
+    fn try_add_to(&self, other: &Self, out: &mut Self) -> Result<(), LinalgError> {
+        check_shape(self.shape(), other.shape())?;
+        check_shape(self.shape(), out.shape())?;
+        self.add_to(other, out);
+        Ok(())
+    }
+
+    /// Matrix subtraction (elementwise), storing the result in a preallocated matrix
+    /// (`out = self - other`), without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix to subtract from this matrix.
+    /// * `out` - The matrix to store the result in.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `other` or `out` does not have the same shape as this
+    ///   matrix (in which case `out` is left unchanged).
+    fn try_sub_to(&self, other: &Self, out: &mut Self) -> Result<(), LinalgError> {
+        check_shape(self.shape(), other.shape())?;
+        check_shape(self.shape(), out.shape())?;
+        self.sub_to(other, out);
+        Ok(())
+    }
+
+    /// Matrix-scalar multiplication, storing the result in a preallocated matrix
+    /// (`out = self * scalar`), without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `scalar` - The scalar to multiply each element of this matrix by.
+    /// * `out` - The matrix to store the result in.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `out` does not have the same shape as this matrix (in
+    ///   which case `out` is left unchanged).
+    fn try_mul_to(&self, scalar: S, out: &mut Self) -> Result<(), LinalgError> {
+        check_shape(self.shape(), out.shape())?;
+        self.mul_to(scalar, out);
+        Ok(())
+    }
+
+    /// Matrix-scalar division, storing the result in a preallocated matrix
+    /// (`out = self / scalar`), without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `scalar` - The scalar to divide each element of this matrix by.
+    /// * `out` - The matrix to store the result in.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `out` does not have the same shape as this matrix (in
+    ///   which case `out` is left unchanged).
+    fn try_div_to(&self, scalar: S, out: &mut Self) -> Result<(), LinalgError> {
+        check_shape(self.shape(), out.shape())?;
+        self.div_to(scalar, out);
+        Ok(())
+    }
+
+    /// Vector-matrix multiplication, storing the result in a preallocated vector
+    /// (`out = vector * self`), without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `vector` - The length-`M` vector to multiply this `M x N` matrix by from the left.
+    /// * `out` - The length-`N` vector to store the result in.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if the length of the vector does not match the number of
+    ///   rows of this matrix, or if the length of the output vector does not match the number of
+    ///   columns of this matrix (in which case `out` is left unchanged).
+    fn try_vector_mul_to(
+        &self,
+        vector: &Self::VectorM,
+        out: &mut Self::VectorN,
+    ) -> Result<(), LinalgError> {
+        let (rows, cols) = self.shape();
+        check_length(rows, vector.len())?;
+        check_length(cols, out.len())?;
+        self.vector_mul_to(vector, out);
+        Ok(())
+    }
+
+    /// General matrix-matrix multiplication (`self = alpha * op(a) * op(b) + beta * self`)
+    /// without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `alpha` - The scalar to multiply the product `op(a) * op(b)` by.
+    /// * `a` - The left operand.
+    /// * `transpose_a` - `true` if `op(a)` is the transpose of `a`, `false` if `op(a)` is `a`.
+    /// * `b` - The right operand.
+    /// * `transpose_b` - `true` if `op(b)` is the transpose of `b`, `false` if `op(b)` is `b`.
+    /// * `beta` - The scalar to multiply this matrix by before the product is accumulated into it.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if the number of columns of `op(a)` does not match the
+    ///   number of rows of `op(b)`, or if the shape of this matrix does not match the shape of
+    ///   `op(a) * op(b)` (in which case this matrix is left unchanged).
+    fn try_gemm(
+        &mut self,
+        alpha: S,
+        a: &Self,
+        transpose_a: bool,
+        b: &Self,
+        transpose_b: bool,
+        beta: S,
+    ) -> Result<(), LinalgError> {
+        let op_shape = |(rows, cols): (usize, usize), transpose: bool| {
+            if transpose { (cols, rows) } else { (rows, cols) }
+        };
+        let (a_rows, a_cols) = op_shape(a.shape(), transpose_a);
+        let (b_rows, b_cols) = op_shape(b.shape(), transpose_b);
+        check_shape((a_cols, b_cols), (b_rows, b_cols))?;
+        check_shape((a_rows, b_cols), self.shape())?;
+        self.gemm(alpha, a, transpose_a, b, transpose_b, beta);
+        Ok(())
+    }
+
+    /// Create a block diagonal matrix without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `blocks` - The diagonal blocks, from top-left to bottom-right (they do not need to be
+    ///   square).
+    /// 
+    /// # Returns
+    /// 
+    /// Block diagonal matrix `diag(B₁, B₂, ..., Bₖ)`, with every element outside of the diagonal
+    /// blocks set to 0.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if the shape of the result does not match the shape of
+    ///   this matrix type (for statically-sized matrices only).
+    fn try_block_diag<B: Matrix<S>>(blocks: &[&B]) -> Result<Self, LinalgError> {
+        let rows = blocks.iter().map(|block| block.shape().0).sum();
+        let cols = blocks.iter().map(|block| block.shape().1).sum();
+        check_static_shape::<S, Self>(rows, cols)?;
+        Ok(Self::block_diag(blocks))
+    }
+
+    /// Create a diagonal matrix without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `diagonal` - Length-`N` vector containing the diagonal elements.
+    /// 
+    /// # Returns
+    /// 
+    /// `N x N` matrix with the specified diagonal elements, and every other element set to 0.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if the length of `diagonal` does not match the shape of
+    ///   this matrix type (for statically-sized matrices only).
+    fn try_from_diagonal(diagonal: &Self::VectorM) -> Result<Self, LinalgError> {
+        let n = diagonal.len();
+        check_static_shape::<S, Self>(n, n)?;
+        Ok(Self::from_diagonal(diagonal))
+    }
+
+    /// Get the diagonal of this matrix without panicking.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`N` vector containing the diagonal elements of this matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix is not square.
+    fn try_diagonal(&self) -> Result<Self::VectorM, LinalgError> {
+        self.check_square()?;
+        Ok(self.diagonal())
+    }
+
+    /// Trace of this matrix (the sum of its diagonal elements) without panicking.
+    /// 
+    /// # Returns
+    /// 
+    /// Trace of this matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix is not square.
+    fn try_trace(&self) -> Result<S, LinalgError> {
+        self.check_square()?;
+        Ok(self.trace())
+    }
+
+    /// Convert this matrix into another matrix type without panicking.
+    /// 
+    /// # Returns
+    /// 
+    /// Matrix of type `N` with the same shape and elements as this matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if the shape of this matrix does not match the shape of
+    ///   `N` (for statically-sized `N` only).
+    fn try_convert<N: Matrix<S>>(&self) -> Result<N, LinalgError> {
+        let (rows, cols) = self.shape();
+        check_static_shape::<S, N>(rows, cols)?;
+        Ok(self.convert())
+    }
+
+    /// Copy the elements of another matrix (of any matrix type) into this matrix without
+    /// panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The matrix to copy the elements from.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if `other` does not have the same shape as this matrix (in
+    ///   which case this matrix is left unchanged).
+    fn try_copy_from<N: Matrix<S>>(&mut self, other: &N) -> Result<(), LinalgError> {
+        check_shape(self.shape(), other.shape())?;
+        self.copy_from(other);
+        Ok(())
+    }
+
+    /// LU decomposition with partial (row) pivoting (`P * A = L * U`) without panicking.
+    /// 
+    /// # Returns
+    /// 
+    /// LU decomposition of this matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix is not square.
+    fn try_lu(&self) -> Result<Lu<S, Self>, LinalgError> {
+        self.check_square()?;
+        Ok(self.lu())
+    }
+
+    /// Determinant of this matrix without panicking.
+    /// 
+    /// # Returns
+    /// 
+    /// Determinant of this matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix is not square.
+    fn try_determinant(&self) -> Result<S, LinalgError> {
+        self.check_square()?;
+        Ok(self.determinant())
+    }
+
+    /// Estimate of the 1-norm condition number of this matrix (`‖A‖₁ * ‖A⁻¹‖₁`) without panicking.
+    /// 
+    /// # Returns
+    /// 
+    /// Estimate of the 1-norm condition number of this matrix (see [`Matrix::cond_1_estimate`]).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix is not square.
+    fn try_cond_1_estimate(&self) -> Result<S, LinalgError> {
+        self.check_square()?;
+        Ok(self.cond_1_estimate())
+    }
+
+    /// Check that this matrix is square.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if the number of rows of this matrix is not equal to its
+    ///   number of columns.
+    fn check_square(&self) -> Result<(), LinalgError> {
+        let (rows, cols) = self.shape();
+        check_shape((rows, rows), (rows, cols))
+    }
+
+    /// Check that every element of this matrix is finite.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::NonFinite`] if any element of this matrix is NaN or infinite.
+    fn check_finite(&self) -> Result<(), LinalgError> {
+        if self.fold(true, |finite, x| finite && x.is_finite()) {
+            Ok(())
+        } else {
+            Err(LinalgError::NonFinite)
+        }
+    }
+
+    /// Return a slice view of the matrix's elements in row-major order.
+    ///
+    /// # Returns
+    ///
+    /// A slice of the matrix's elements in row-major order.
+    /// 
+    /// # Note
+    /// 
+    /// The slice is returned as a `Cow<[S]>` instead of a `&[S]`. This is primarily because the
+    /// underlying data will be _either_ in row-major order _or_ in column-major order. If it is in
+    /// column-major order, then we will need to re-order it to be in row-major order. This requires
+    /// building a temporary variable within this method which will be dropped when the method scope
+    /// ends. In such cases, this method will clone the data when returning it in a `Cow<[S]>`.
+    /// 
+    /// In most cases, if the data is in row-major order, then this function will not perform any
+    /// cloning. However, in some cases, even if the underlying data is in row-major order, its data
+    /// may not be contiguous in memory. This would also require cloning the data from a temporary
+    /// variable. See [`Matrix::as_slice`] for more information.
+    fn as_row_slice(&self) -> Cow<'_, [S]>  {
+        if Self::is_row_major() {
+            self.as_slice()
+        } else {
+            let (rows, cols) = self.shape();
+            let mut vec = Vec::<S>::with_capacity(rows * cols);
+            for row in 0..rows {
+                for col in 0..cols {
+                    vec.push(self[(row, col)]);
+                }
+            }
+            Cow::from(vec)
+        }
+    }
+
+    /// Return a slice view of the matrix's elements in column-major order.
+    ///
+    /// # Returns
+    ///
+    /// A slice of the matrix's elements in column-major order.
+    /// 
+    /// # Note
+    /// 
+    /// The slice is returned as a `Cow<[S]>` instead of a `&[S]`. This is primarily because the
+    /// underlying data will be _either_ in row-major order _or_ in column-major order. If it is in
+    /// row-major order, then we will need to re-order it to be in column-major order. This requires
+    /// building a temporary variable within this method which will be dropped when the method scope
+    /// ends. In such cases, this method will clone the data when returning it in a `Cow<[S]>`.
+    /// 
+    /// In most cases, if the data is in column-major order, then this function will not perform any
+    /// cloning. However, in some cases, even if the underlying data is in column-major order, its
+    /// data may not be contiguous in memory. This would also require cloning the data from a
+    /// temporary variable. See [`Matrix::as_slice`] for more information.
+    fn as_col_slice(&self) -> Cow<'_, [S]> {
+        if Self::is_column_major() {
+            self.as_slice()
+        } else {
+            let (rows, cols) = self.shape();
+            let mut vec = Vec::<S>::with_capacity(rows * cols);
+            for col in 0..cols {
+                for row in 0..rows {
+                    vec.push(self[(row, col)]);
+                }
+            }
+            Cow::from(vec)
+        }
+    }
+
+    /// Call a function with a mutable slice of the matrix's elements.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Function to call with a mutable slice of the matrix's elements.
+    /// 
+    /// # Returns
+    /// 
+    /// The value returned by `f`.
+    /// 
+    /// # Warning
+    /// 
+    /// The order of the elements depends on whether the matrix is row-major or column-major. This
+    /// can be programmatically determined via the [`Matrix::is_row_major`] and
+    /// [`Matrix::is_column_major`] methods.
+    /// 
+    /// # Note
+    /// 
+    /// If the elements are stored contiguously (see [`Matrix::try_as_contiguous_mut_slice`]), `f`
+    /// writes to them directly. Otherwise, `f` is given a temporary copy of the elements, which is
+    /// written back to this matrix once `f` returns.
+    fn with_mut_slice<R, F: FnOnce(&mut [S]) -> R>(&mut self, f: F) -> R {
+        if let Some(slice) = self.try_as_contiguous_mut_slice() {
+            return f(slice);
+        }
+        let (rows, cols) = self.shape();
+        let mut values = self.as_slice().into_owned();
+        let result = f(&mut values);
+        *self = if Self::is_row_major() {
+            Self::from_row_slice(rows, cols, &values)
+        } else {
+            Self::from_col_slice(rows, cols, &values)
+        };
+        result
+    }
+
+    /// Convert this matrix into another matrix type.
+    /// 
+    /// # Returns
+    /// 
+    /// Matrix of type `N` with the same shape and elements as this matrix.
+    /// 
+    /// # Panics
+    /// 
+    /// * If the shape of this matrix does not match the shape of `N` (for statically-sized `N`
+    ///   only).
+    /// 
+    /// # Note
+    /// 
+    /// The elements are copied directly from a slice of this matrix's elements in its own storage
+    /// order (see [`Matrix::is_row_major`]), so no intermediate copy is made when this matrix is
+    /// stored contiguously; any reordering is done while building the new matrix.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # #[cfg(feature = "nalgebra")]
+    /// # {
+    /// use linalg_traits::{Mat, Matrix};
+    /// use nalgebra::DMatrix;
+    /// 
+    /// let a: Mat<f64> = Mat::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+    /// let b: DMatrix<f64> = a.convert();
+    /// assert_eq!(b, DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]));
+    /// # }
+    /// ```
+    fn convert<N: Matrix<S>>(&self) -> N {
+        let (rows, cols) = self.shape();
+        if Self::is_row_major() {
+            N::from_row_slice(rows, cols, &self.as_slice())
+        } else {
+            N::from_col_slice(rows, cols, &self.as_slice())
+        }
+    }
+
+    /// Copy the elements of another matrix (of any matrix type) into this matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The matrix to copy the elements from.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `other` does not have the same shape as this matrix.
+    fn copy_from<N: Matrix<S>>(&mut self, other: &N) {
+        let (rows, cols) = self.shape();
+        let (other_rows, other_cols) = other.shape();
+        assert!(
+            (other_rows, other_cols) == (rows, cols),
+            "Shape of the other matrix ({other_rows}x{other_cols}) does not match the shape of \
+            this matrix ({rows}x{cols})."
+        );
+        let values = if Self::is_row_major() {
+            other.as_row_slice()
+        } else {
+            other.as_col_slice()
+        };
+        if let Some(slice) = self.try_as_contiguous_mut_slice() {
+            slice.copy_from_slice(&values);
+        } else if Self::is_row_major() {
+            for (k, &value) in values.iter().enumerate() {
+                self[(k / cols, k % cols)] = value;
+            }
+        } else {
+            for (k, &value) in values.iter().enumerate() {
+                self[(k % rows, k / rows)] = value;
+            }
+        }
+    }
+
+    /// Get a row of this matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `row` - Index of the row.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`N` vector containing the `row`th row of this matrix.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `row` is out of bounds.
+    fn row(&self, row: usize) -> Self::VectorN {
+        assert_row_index(self, row);
+        let cols = self.shape().1;
+        let mut out = Self::VectorN::new_with_length(cols);
+        for col in 0..cols {
+            out[col] = self[(row, col)];
+        }
+        out
+    }
+
+    /// Get a column of this matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `col` - Index of the column.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`M` vector containing the `col`th column of this matrix.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `col` is out of bounds.
+    fn column(&self, col: usize) -> Self::VectorM {
+        assert_column_index(self, col);
+        let rows = self.shape().0;
+        let mut out = Self::VectorM::new_with_length(rows);
+        for row in 0..rows {
+            out[row] = self[(row, col)];
+        }
+        out
+    }
+
+    /// Overwrite a row of this matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `row` - Index of the row.
+    /// * `values` - Length-`N` vector containing the new elements of the row.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `row` is out of bounds.
+    /// * If the length of `values` is not equal to the number of columns of this matrix.
+    fn set_row(&mut self, row: usize, values: &Self::VectorN) {
+        assert_row_index(self, row);
+        assert_row_length(self, values.len());
+        for col in 0..values.len() {
+            self[(row, col)] = values[col];
+        }
+    }
+
+    /// Overwrite a column of this matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `col` - Index of the column.
+    /// * `values` - Length-`M` vector containing the new elements of the column.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `col` is out of bounds.
+    /// * If the length of `values` is not equal to the number of rows of this matrix.
+    fn set_column(&mut self, col: usize, values: &Self::VectorM) {
+        assert_column_index(self, col);
+        assert_column_length(self, values.len());
+        for row in 0..values.len() {
+            self[(row, col)] = values[row];
+        }
+    }
+
+    /// Iterate over the rows of this matrix.
+    /// 
+    /// # Returns
+    /// 
+    /// Iterator yielding each row of this matrix (in order) as a length-`N` vector.
+    fn rows(&self) -> impl Iterator<Item = Self::VectorN> {
+        (0..self.shape().0).map(|row| self.row(row))
+    }
+
+    /// Iterate over the columns of this matrix.
+    /// 
+    /// # Returns
+    /// 
+    /// Iterator yielding each column of this matrix (in order) as a length-`M` vector.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use linalg_traits::{Mat, Matrix};
+    /// 
+    /// let a = Mat::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+    /// let column_sums: Vec<f64> = a.columns().map(|col| col.iter().sum()).collect();
+    /// assert_eq!(column_sums, vec![4.0, 6.0]);
+    /// ```
+    fn columns(&self) -> impl Iterator<Item = Self::VectorM> {
+        (0..self.shape().1).map(|col| self.column(col))
+    }
+
+    /// Extract a submatrix (block) of this matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `row0` - Row index of the top-left element of the block.
+    /// * `col0` - Column index of the top-left element of the block.
+    /// * `nrows` - Number of rows of the block.
+    /// * `ncols` - Number of columns of the block.
+    /// 
+    /// # Returns
+    /// 
+    /// `nrows x ncols` dynamically-sized matrix containing the block of this matrix whose top-left
+    /// element is at `(row0, col0)`.
+    /// 
+    /// # Panics
+    /// 
+    /// * If the block does not fit within this matrix.
+    fn submatrix(&self, row0: usize, col0: usize, nrows: usize, ncols: usize) -> Self::DMatrixMxN {
+        assert_block_in_bounds(self, row0, col0, nrows, ncols);
+        let mut block = Self::DMatrixMxN::new_with_shape(nrows, ncols);
+        for row in 0..nrows {
+            for col in 0..ncols {
+                block[(row, col)] = self[(row0 + row, col0 + col)];
+            }
+        }
+        block
+    }
+
+    /// Overwrite a block of this matrix with another matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `row0` - Row index at which the top-left element of the block is placed.
+    /// * `col0` - Column index at which the top-left element of the block is placed.
+    /// * `block` - The block (of any matrix type with the same scalar type).
+    /// 
+    /// # Panics
+    /// 
+    /// * If the block does not fit within this matrix.
+    fn set_block<B: Matrix<S>>(&mut self, row0: usize, col0: usize, block: &B) {
+        default_set_block(self, row0, col0, block);
+    }
+
+    /// Stack matrices horizontally (i.e. side by side).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `blocks` - The matrices to stack, from left to right.
+    /// 
+    /// # Returns
+    /// 
+    /// Matrix `[B₁, B₂, ..., Bₖ]` (a `0 x 0` matrix if there are no blocks).
+    /// 
+    /// # Panics
+    /// 
+    /// * If the blocks do not all have the same number of rows.
+    /// * If the shape of the result does not match the shape of this matrix type (for
+    ///   statically-sized matrices only).
+    /// 
+    /// # Note
+    /// 
+    /// All of the blocks must be of the same type `B`, so statically-sized blocks of different
+    /// shapes cannot be mixed. To combine them, first convert each block to a common
+    /// dynamically-sized type (e.g. `Self::DMatrixMxN` using [`Matrix::convert`]).
+    fn hstack<B: Matrix<S>>(blocks: &[&B]) -> Self {
+        let rows = blocks.first().map_or(0, |block| block.shape().0);
+        assert!(
+            blocks.iter().all(|block| block.shape().0 == rows),
+            "Blocks must all have the same number of rows to be stacked horizontally."
+        );
+        let cols = blocks.iter().map(|block| block.shape().1).sum();
+        let mut out = Self::new_with_shape(rows, cols);
+        let mut col0 = 0;
+        for block in blocks {
+            out.set_block(0, col0, *block);
+            col0 += block.shape().1;
+        }
+        out
+    }
+
+    /// Stack matrices vertically (i.e. one on top of the other).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `blocks` - The matrices to stack, from top to bottom.
+    /// 
+    /// # Returns
+    /// 
+    /// Matrix `[B₁; B₂; ...; Bₖ]` (a `0 x 0` matrix if there are no blocks).
+    /// 
+    /// # Panics
+    /// 
+    /// * If the blocks do not all have the same number of columns.
+    /// * If the shape of the result does not match the shape of this matrix type (for
+    ///   statically-sized matrices only).
+    /// 
+    /// # Note
+    /// 
+    /// All of the blocks must be of the same type `B`, so statically-sized blocks of different
+    /// shapes cannot be mixed. To combine them, first convert each block to a common
+    /// dynamically-sized type (e.g. `Self::DMatrixMxN` using [`Matrix::convert`]).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use linalg_traits::{Mat, Matrix};
+    /// 
+    /// // Augmented matrix [[A, B], [0, 0]].
+    /// let a = Mat::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+    /// let b = Mat::from_row_slice(2, 1, &[5.0, 6.0]);
+    /// let top = Mat::hstack(&[&a, &b]);
+    /// let augmented = Mat::vstack(&[&top, &Mat::new_with_shape(1, 3)]);
+    /// assert_eq!(
+    ///     augmented,
+    ///     Mat::from_row_slice(3, 3, &[1.0, 2.0, 5.0, 3.0, 4.0, 6.0, 0.0, 0.0, 0.0])
+    /// );
+    /// ```
+    fn vstack<B: Matrix<S>>(blocks: &[&B]) -> Self {
+        let cols = blocks.first().map_or(0, |block| block.shape().1);
+        assert!(
+            blocks.iter().all(|block| block.shape().1 == cols),
+            "Blocks must all have the same number of columns to be stacked vertically."
+        );
+        let rows = blocks.iter().map(|block| block.shape().0).sum();
+        let mut out = Self::new_with_shape(rows, cols);
+        let mut row0 = 0;
+        for block in blocks {
+            out.set_block(row0, 0, *block);
+            row0 += block.shape().0;
+        }
+        out
+    }
+
+    /// Create a block diagonal matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `blocks` - The diagonal blocks, from top-left to bottom-right (they do not need to be
+    ///   square).
+    /// 
+    /// # Returns
+    /// 
+    /// Block diagonal matrix `diag(B₁, B₂, ..., Bₖ)`, with every element outside of the diagonal
+    /// blocks set to 0.
+    /// 
+    /// # Panics
+    /// 
+    /// * If the shape of the result does not match the shape of this matrix type (for
+    ///   statically-sized matrices only).
+    /// 
+    /// # Note
+    /// 
+    /// All of the blocks must be of the same type `B`, so statically-sized blocks of different
+    /// shapes cannot be mixed. To combine them, first convert each block to a common
+    /// dynamically-sized type (e.g. `Self::DMatrixMxN` using [`Matrix::convert`]).
+    fn block_diag<B: Matrix<S>>(blocks: &[&B]) -> Self {
+        let rows = blocks.iter().map(|block| block.shape().0).sum();
+        let cols = blocks.iter().map(|block| block.shape().1).sum();
+        let mut out = Self::new_with_shape(rows, cols);
+        let (mut row0, mut col0) = (0, 0);
+        for block in blocks {
+            out.set_block(row0, col0, *block);
+            row0 += block.shape().0;
+            col0 += block.shape().1;
+        }
+        out
+    }
+
+    /// Create an identity matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `n` - Number of rows and columns.
+    /// 
+    /// # Returns
+    /// 
+    /// `n x n` identity matrix.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `n` does not match the shape of this matrix type (for statically-sized matrices only).
+    #[must_use]
+    fn identity(n: usize) -> Self {
+        let mut out = Self::new_with_shape(n, n);
+        for i in 0..n {
+            out[(i, i)] = S::one();
+        }
+        out
+    }
+
+    /// Create a matrix with every element set to the same value.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rows` - Number of rows.
+    /// * `cols` - Number of columns.
+    /// * `value` - Value of every element.
+    /// 
+    /// # Returns
+    /// 
+    /// `rows x cols` matrix with every element set to `value`.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `rows` or `cols` does not match the shape of this matrix type (for statically-sized
+    ///   matrices only).
+    fn from_element(rows: usize, cols: usize, value: S) -> Self {
+        Self::from_fn(rows, cols, |_, _| value)
+    }
+
+    /// Create a matrix by evaluating a function at each position.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rows` - Number of rows.
+    /// * `cols` - Number of columns.
+    /// * `f` - Function returning the element at the specified row and column indices.
+    /// 
+    /// # Returns
+    /// 
+    /// `rows x cols` matrix whose `(i, j)`th element is `f(i, j)`.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `rows` or `cols` does not match the shape of this matrix type (for statically-sized
+    ///   matrices only).
+    /// 
+    /// # Note
+    /// 
+    /// The order in which `f` is called is not specified (it is typically the storage order of the
+    /// matrix).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use linalg_traits::{Mat, Matrix};
+    /// 
+    /// // 3x3 Hilbert matrix.
+    /// let h = Mat::<f64>::from_fn(3, 3, |i, j| 1.0 / (i + j + 1) as f64);
+    /// assert_eq!(h[(1, 2)], 0.25);
+    /// ```
+    fn from_fn<F: FnMut(usize, usize) -> S>(rows: usize, cols: usize, mut f: F) -> Self {
+        let mut out = Self::new_with_shape(rows, cols);
+        if Self::is_row_major() {
+            for row in 0..rows {
+                for col in 0..cols {
+                    out[(row, col)] = f(row, col);
+                }
+            }
+        } else {
+            for col in 0..cols {
+                for row in 0..rows {
+                    out[(row, col)] = f(row, col);
+                }
+            }
+        }
+        out
+    }
+
+    /// Create a diagonal matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `diagonal` - Length-`N` vector containing the diagonal elements.
+    /// 
+    /// # Returns
+    /// 
+    /// `N x N` matrix with the specified diagonal elements, and every other element set to 0.
+    /// 
+    /// # Panics
+    /// 
+    /// * If the length of `diagonal` does not match the shape of this matrix type (for
+    ///   statically-sized matrices only).
+    fn from_diagonal(diagonal: &Self::VectorM) -> Self {
+        let n = diagonal.len();
+        let mut out = Self::new_with_shape(n, n);
+        for i in 0..n {
+            out[(i, i)] = diagonal[i];
+        }
+        out
+    }
+
+    /// Create a matrix from its rows.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rows` - Rows of the matrix (each a length-`N` vector).
+    /// 
+    /// # Returns
+    /// 
+    /// Matrix whose `i`th row is `rows[i]` (a `0 x 0` matrix if there are no rows).
+    /// 
+    /// # Panics
+    /// 
+    /// * If the rows do not all have the same length.
+    /// * If the shape of the result does not match the shape of this matrix type (for
+    ///   statically-sized matrices only).
+    fn from_rows(rows: &[Self::VectorN]) -> Self {
+        let cols = rows.first().map_or(0, Vector::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "Rows must all have the same length."
+        );
+        Self::from_fn(rows.len(), cols, |row, col| rows[row][col])
+    }
+
+    /// Create a matrix from its columns.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `columns` - Columns of the matrix (each a length-`M` vector).
+    /// 
+    /// # Returns
+    /// 
+    /// Matrix whose `j`th column is `columns[j]` (a `0 x 0` matrix if there are no columns).
+    /// 
+    /// # Panics
+    /// 
+    /// * If the columns do not all have the same length.
+    /// * If the shape of the result does not match the shape of this matrix type (for
+    ///   statically-sized matrices only).
+    fn from_columns(columns: &[Self::VectorM]) -> Self {
+        let rows = columns.first().map_or(0, Vector::len);
+        assert!(
+            columns.iter().all(|column| column.len() == rows),
+            "Columns must all have the same length."
+        );
+        Self::from_fn(rows, columns.len(), |row, col| columns[col][row])
+    }
+
+    /// Get the diagonal of this matrix.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`N` vector containing the diagonal elements of this matrix.
+    /// 
+    /// # Panics
+    /// 
+    /// * If this matrix is not square.
+    fn diagonal(&self) -> Self::VectorM {
+        self.assert_square();
+        let n = self.shape().0;
+        let mut out = Self::VectorM::new_with_length(n);
+        for i in 0..n {
+            out[i] = self[(i, i)];
+        }
+        out
+    }
+
+    /// Trace of this matrix (sum of its diagonal elements).
+    /// 
+    /// # Returns
+    /// 
+    /// Trace of this matrix (0 for an empty matrix).
+    /// 
+    /// # Panics
+    /// 
+    /// * If this matrix is not square.
+    fn trace(&self) -> S {
+        self.assert_square();
+        (0..self.shape().0).fold(S::zero(), |sum, i| sum + self[(i, i)])
+    }
+
+    /// Frobenius norm of this matrix (square root of the sum of the squares of its elements).
+    /// 
+    /// # Returns
+    /// 
+    /// Frobenius norm of this matrix.
+    /// 
+    /// # Note
+    /// 
+    /// The default implementation accumulates the sum of squares relative to the largest
+    /// magnitude seen so far, so it does not overflow or underflow unless the norm itself does.
+    fn norm_frobenius(&self) -> S {
+        let (rows, cols) = self.shape();
+        scaled_l2_norm((0..rows * cols).map(|idx| self[(idx / cols, idx % cols)]))
+    }
+
+    /// 1-norm of this matrix (maximum absolute column sum).
+    /// 
+    /// # Returns
+    /// 
+    /// 1-norm of this matrix (0 for an empty matrix).
+    fn norm_1(&self) -> S {
+        let (rows, cols) = self.shape();
+        (0..cols)
+            .map(|col| (0..rows).fold(S::zero(), |sum, row| sum + self[(row, col)].abs()))
+            .fold(S::zero(), nan_max)
+    }
+
+    /// Infinity norm of this matrix (maximum absolute row sum).
+    /// 
+    /// # Returns
+    /// 
+    /// Infinity norm of this matrix (0 for an empty matrix).
+    fn norm_inf(&self) -> S {
+        let (rows, cols) = self.shape();
+        (0..rows)
+            .map(|row| (0..cols).fold(S::zero(), |sum, col| sum + self[(row, col)].abs()))
+            .fold(S::zero(), nan_max)
+    }
+
+    /// Max-abs norm of this matrix (maximum absolute value of its elements).
+    /// 
+    /// # Returns
+    /// 
+    /// Maximum absolute value of the elements of this matrix (0 for an empty matrix).
+    /// 
+    /// # Note
+    /// 
+    /// Unlike the other matrix norms, this norm is not submultiplicative.
+    fn norm_max(&self) -> S {
+        let (rows, cols) = self.shape();
+        (0..rows * cols)
+            .map(|idx| self[(idx / cols, idx % cols)].abs())
+            .fold(S::zero(), nan_max)
+    }
+
+    /// Sum of each row of this matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`M` vector whose `i`th element is the sum of the `i`th row of this matrix.
+    fn row_sums(&self, nan_policy: NanPolicy) -> Self::VectorM {
+        reduce_rows(self, S::zero(), |sum, x| if nan_policy.skips(x) { sum } else { sum + x })
+    }
+
+    /// Sum of each column of this matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`N` vector whose `j`th element is the sum of the `j`th column of this matrix.
+    fn column_sums(&self, nan_policy: NanPolicy) -> Self::VectorN {
+        reduce_columns(self, S::zero(), |sum, x| if nan_policy.skips(x) { sum } else { sum + x })
+    }
+
+    /// Arithmetic mean of each row of this matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`M` vector whose `i`th element is the mean of the `i`th row of this matrix (NaN for
+    /// a row with no elements to average).
+    #[allow(clippy::cast_precision_loss)]
+    fn row_means(&self, nan_policy: NanPolicy) -> Self::VectorM {
+        let sums = self.row_sums(nan_policy);
+        match nan_policy {
+            NanPolicy::Propagate => sums.div(S::new(self.shape().1 as f64)),
+            NanPolicy::Ignore => sums.hadamard_div(&reduce_rows(self, S::zero(), count_non_nan)),
+        }
+    }
+
+    /// Arithmetic mean of each column of this matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`N` vector whose `j`th element is the mean of the `j`th column of this matrix (NaN
+    /// for a column with no elements to average).
+    #[allow(clippy::cast_precision_loss)]
+    fn column_means(&self, nan_policy: NanPolicy) -> Self::VectorN {
+        let sums = self.column_sums(nan_policy);
+        match nan_policy {
+            NanPolicy::Propagate => sums.div(S::new(self.shape().0 as f64)),
+            NanPolicy::Ignore => {
+                sums.hadamard_div(&reduce_columns(self, S::zero(), count_non_nan))
+            }
+        }
+    }
+
+    /// Minimum element of each row of this matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`M` vector whose `i`th element is the minimum element of the `i`th row of this
+    /// matrix (positive infinity for a row with no elements to compare).
+    fn row_mins(&self, nan_policy: NanPolicy) -> Self::VectorM {
+        match nan_policy {
+            NanPolicy::Propagate => reduce_rows(self, S::infinity(), nan_min),
+            NanPolicy::Ignore => reduce_rows(self, S::infinity(), S::min),
+        }
+    }
+
+    /// Minimum element of each column of this matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`N` vector whose `j`th element is the minimum element of the `j`th column of this
+    /// matrix (positive infinity for a column with no elements to compare).
+    fn column_mins(&self, nan_policy: NanPolicy) -> Self::VectorN {
+        match nan_policy {
+            NanPolicy::Propagate => reduce_columns(self, S::infinity(), nan_min),
+            NanPolicy::Ignore => reduce_columns(self, S::infinity(), S::min),
+        }
+    }
+
+    /// Maximum element of each row of this matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`M` vector whose `i`th element is the maximum element of the `i`th row of this
+    /// matrix (negative infinity for a row with no elements to compare).
+    fn row_maxes(&self, nan_policy: NanPolicy) -> Self::VectorM {
+        match nan_policy {
+            NanPolicy::Propagate => reduce_rows(self, S::neg_infinity(), nan_max),
+            NanPolicy::Ignore => reduce_rows(self, S::neg_infinity(), S::max),
+        }
+    }
+
+    /// Maximum element of each column of this matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`N` vector whose `j`th element is the maximum element of the `j`th column of this
+    /// matrix (negative infinity for a column with no elements to compare).
+    fn column_maxes(&self, nan_policy: NanPolicy) -> Self::VectorN {
+        match nan_policy {
+            NanPolicy::Propagate => reduce_columns(self, S::neg_infinity(), nan_max),
+            NanPolicy::Ignore => reduce_columns(self, S::neg_infinity(), S::max),
+        }
+    }
+
+    /// Euclidean norm of each row of this matrix.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`M` vector whose `i`th element is the Euclidean norm of the `i`th row of this
+    /// matrix.
+    /// 
+    /// # Note
+    /// 
+    /// The norms are accumulated using [`num_traits::Float::hypot`], so they do not overflow or
+    /// underflow unless the norms themselves do.
+    fn row_norms(&self) -> Self::VectorM {
+        reduce_rows(self, S::zero(), S::hypot)
+    }
+
+    /// Euclidean norm of each column of this matrix.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`N` vector whose `j`th element is the Euclidean norm of the `j`th column of this
+    /// matrix.
+    /// 
+    /// # Note
+    /// 
+    /// The norms are accumulated using [`num_traits::Float::hypot`], so they do not overflow or
+    /// underflow unless the norms themselves do.
+    fn column_norms(&self) -> Self::VectorN {
+        reduce_columns(self, S::zero(), S::hypot)
+    }
+
+    /// LU decomposition with partial (row) pivoting (`P * A = L * U`).
+    /// 
+    /// # Returns
+    /// 
+    /// LU decomposition of this matrix, which can be reused to solve linear systems for any number
+    /// of right-hand sides, and to compute the determinant and inverse of this matrix.
+    /// 
+    /// # Panics
+    /// 
+    /// * If this matrix is not square.
+    fn lu(&self) -> Lu<S, Self> {
+        Lu::new(self)
+    }
+
+    /// Solve the linear system `self * x = b`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `b` - The right-hand side vector.
+    /// 
+    /// # Returns
+    /// 
+    /// Solution `x` of the linear system.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix is not square.
+    /// * [`LinalgError::LengthMismatch`] if the length of `b` does not match the number of rows of
+    ///   this matrix.
+    /// * [`LinalgError::SingularMatrix`] if this matrix is singular.
+    /// 
+    /// # Note
+    /// 
+    /// This method factors the matrix every time it is called. To solve multiple linear systems
+    /// with the same matrix, factor it once using [`Matrix::lu`] and reuse the factorization.
+    fn solve(&self, b: &Self::VectorM) -> Result<Self::VectorM, LinalgError> {
+        self.check_square()?;
+        self.lu().solve(b)
+    }
+
+    /// Determinant of this matrix.
+    /// 
+    /// # Returns
+    /// 
+    /// Determinant of this matrix.
+    /// 
+    /// # Panics
+    /// 
+    /// * If this matrix is not square.
+    fn determinant(&self) -> S {
+        self.lu().determinant()
+    }
+
+    /// Inverse of this matrix.
+    /// 
+    /// # Returns
+    /// 
+    /// Inverse of this matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix is not square.
+    /// * [`LinalgError::SingularMatrix`] if this matrix is singular.
+    fn inverse(&self) -> Result<Self, LinalgError> {
+        self.check_square()?;
+        self.lu().inverse()
+    }
+
+    /// Estimate of the 1-norm condition number of this matrix (`‖A‖₁ * ‖A⁻¹‖₁`).
+    /// 
+    /// # Returns
+    /// 
+    /// Estimate of the 1-norm condition number of this matrix (a lower bound that is almost
+    /// always within a factor of 3 of the exact value), or infinity if this matrix is singular.
+    /// 
+    /// # Panics
+    /// 
+    /// * If this matrix is not square.
+    /// 
+    /// # Note
+    /// 
+    /// This is much cheaper than computing the exact condition number since `‖A⁻¹‖₁` is estimated
+    /// from the LU decomposition of this matrix (see [`Lu::inverse_norm_1_estimate`]) without
+    /// forming the inverse. If the LU decomposition is already available, use
+    /// `self.norm_1() * lu.inverse_norm_1_estimate()` instead to avoid refactoring this matrix.
+    fn cond_1_estimate(&self) -> S {
+        self.norm_1() * self.lu().inverse_norm_1_estimate()
+    }
+
+    /// Cholesky decomposition (`A = L * Lᵀ`).
+    /// 
+    /// # Returns
+    /// 
+    /// Cholesky decomposition of this matrix, which can be reused to solve linear systems for any
+    /// number of right-hand sides, and to compute the (log) determinant and inverse of this matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix is not square.
+    /// * [`LinalgError::NotPositiveDefinite`] if this matrix is not positive definite, naming the
+    ///   first pivot that was not strictly positive.
+    /// 
+    /// # Note
+    /// 
+    /// Only the lower triangular part of this matrix is read (i.e. this matrix is assumed to be
+    /// symmetric without this being checked).
+    fn cholesky(&self) -> Result<Cholesky<S, Self>, LinalgError> {
+        Cholesky::new(self)
+    }
+
+    /// QR decomposition using Householder reflections (`A = Q * R`).
+    /// 
+    /// # Returns
+    /// 
+    /// QR decomposition of this matrix, from which both the full and thin factors can be
+    /// retrieved.
+    fn qr(&self) -> Qr<S, Self> {
+        Qr::new(self, false)
+    }
+
+    /// Rank-revealing QR decomposition with column pivoting (`A * P = Q * R`).
+    /// 
+    /// # Returns
+    /// 
+    /// Column-pivoted QR decomposition of this matrix.
+    /// 
+    /// # Note
+    /// 
+    /// The default implementation pivots on the remaining column with the largest norm, so the
+    /// magnitudes of the diagonal elements of `R` are non-increasing. Overrides may pivot
+    /// differently (e.g. nalgebra pivots on the column containing the largest remaining element).
+    fn col_piv_qr(&self) -> Qr<S, Self> {
+        Qr::new(self, true)
+    }
+
+    /// Solve the linear least squares problem `min ||self * x - b||₂`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `b` - The right-hand side vector (length `M`).
+    /// 
+    /// # Returns
+    /// 
+    /// Least squares solution `x` (length `N`).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if the length of `b` does not match the number of rows of
+    ///   this matrix.
+    /// 
+    /// # Note
+    /// 
+    /// This method uses the column-pivoted QR decomposition (see [`Matrix::col_piv_qr`]), so it
+    /// also handles rank-deficient matrices (returning the basic solution, see
+    /// [`Qr::least_squares`]). To solve multiple least squares problems with the same matrix,
+    /// factor it once and reuse the factorization.
+    fn least_squares(&self, b: &Self::VectorM) -> Result<Self::VectorN, LinalgError> {
+        self.col_piv_qr().least_squares(b)
+    }
+
+    /// Full singular value decomposition (`A = U * Σ * Vᵀ`).
+    /// 
+    /// # Returns
+    /// 
+    /// Full singular value decomposition of this matrix, where `U` is an `M x M` matrix and `Vᵀ` is
+    /// an `N x N` matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::NonFinite`] if any element of this matrix is NaN or infinite.
+    /// * [`LinalgError::NoConvergence`] if the singular value decomposition fails to converge.
+    /// 
+    /// # Note
+    /// 
+    /// The default implementation uses the one-sided Jacobi algorithm.
+    fn svd(&self) -> Result<Svd<S, Self::MatrixMxM, Self::MatrixNxN>, LinalgError> {
+        svd::svd_full(self)
+    }
+
+    /// Thin singular value decomposition (`A = U * Σ * Vᵀ`).
+    /// 
+    /// # Returns
+    /// 
+    /// Thin singular value decomposition of this matrix, where `U` is an `M x N` matrix and `Vᵀ`
+    /// is an `N x N` matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix has fewer rows than columns (with the
+    ///   square `N x N` shape as the expected shape).
+    /// * [`LinalgError::NonFinite`] if any element of this matrix is NaN or infinite.
+    /// * [`LinalgError::NoConvergence`] if the singular value decomposition fails to converge.
+    fn svd_thin(&self) -> Result<Svd<S, Self, Self::MatrixNxN>, LinalgError> {
+        svd::svd_thin(self)
+    }
+
+    /// Singular values of this matrix.
+    /// 
+    /// # Returns
+    /// 
+    /// The `min(M, N)` singular values of this matrix, sorted in non-increasing order.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::NonFinite`] if any element of this matrix is NaN or infinite.
+    /// * [`LinalgError::NoConvergence`] if the singular value decomposition fails to converge.
+    /// 
+    /// # Note
+    /// 
+    /// This avoids computing the singular vectors.
+    fn singular_values(&self) -> Result<<Self::VectorN as Vector<S>>::DVectorT<S>, LinalgError> {
+        let (_, sigma, _) = svd::jacobi_svd(self, false)?;
+        Ok(<Self::VectorN as Vector<S>>::DVectorT::<S>::from_slice(&sigma))
+    }
+
+    /// Numerical rank of this matrix.
+    /// 
+    /// # Returns
+    /// 
+    /// Number of singular values of this matrix exceeding `ε * max(M, N) * σ₁`, where `ε` is the
+    /// machine epsilon and `σ₁` is the largest singular value.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::NonFinite`] if any element of this matrix is NaN or infinite.
+    /// * [`LinalgError::NoConvergence`] if the singular value decomposition fails to converge.
+    fn rank(&self) -> Result<usize, LinalgError> {
+        let (rows, cols) = self.shape();
+        Ok(svd::rank(&self.singular_values()?.as_slice(), rows, cols))
+    }
+
+    /// 2-norm (i.e. spectral norm) of this matrix.
+    /// 
+    /// # Returns
+    /// 
+    /// 2-norm of this matrix (its largest singular value).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::NonFinite`] if any element of this matrix is NaN or infinite.
+    /// * [`LinalgError::NoConvergence`] if the singular value decomposition fails to converge.
+    fn norm_2(&self) -> Result<S, LinalgError> {
+        let sigma = self.singular_values()?;
+        Ok(if sigma.is_empty() { S::zero() } else { sigma[0] })
+    }
+
+    /// Condition number of this matrix with respect to the 2-norm.
+    /// 
+    /// # Returns
+    /// 
+    /// Condition number of this matrix (the ratio of its largest to smallest singular values).
+    /// This is infinite if the smallest singular value is zero.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::NonFinite`] if any element of this matrix is NaN or infinite.
+    /// * [`LinalgError::NoConvergence`] if the singular value decomposition fails to converge.
+    fn cond(&self) -> Result<S, LinalgError> {
+        let sigma = self.singular_values()?;
+        let len = sigma.len();
+        Ok(if len == 0 {
+            S::zero()
+        } else if sigma[len - 1] == S::zero() {
+            S::infinity()
+        } else {
+            sigma[0] / sigma[len - 1]
+        })
+    }
+
+    /// Moore-Penrose pseudo-inverse of this matrix.
+    /// 
+    /// # Returns
+    /// 
+    /// `N x M` pseudo-inverse of this matrix, where singular values not exceeding the tolerance
+    /// used by [`Matrix::rank`] are treated as zero.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::NonFinite`] if any element of this matrix is NaN or infinite.
+    /// * [`LinalgError::NoConvergence`] if the singular value decomposition fails to converge.
+    /// 
+    /// # Note
+    /// 
+    /// This is computed from the thin singular value decomposition of this matrix (or of its
+    /// transpose if this matrix has fewer rows than columns). If a singular value decomposition of
+    /// this matrix is already available, use [`Svd::pinv`] instead.
+    fn pinv(&self) -> Result<Self::Transpose, LinalgError> {
+        let (rows, cols) = self.shape();
+        if rows >= cols {
+            Ok(self.svd_thin()?.pinv())
+        } else {
+            Ok(self.transpose().pinv()?.transpose())
+        }
+    }
+
+    /// Eigendecomposition of this matrix, assuming it is symmetric (`A = V * Λ * Vᵀ`).
+    /// 
+    /// # Returns
+    /// 
+    /// Eigendecomposition of this matrix, with the eigenvalues sorted in non-decreasing order.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix is not square.
+    /// * [`LinalgError::NonFinite`] if any element of the lower triangular part of this matrix is
+    ///   NaN or infinite.
+    /// * [`LinalgError::NoConvergence`] if the eigenvalue algorithm fails to converge.
+    /// 
+    /// # Note
+    /// 
+    /// Only the lower triangular part of this matrix is read (i.e. this matrix is assumed to be
+    /// symmetric without this being checked). The default implementation uses the cyclic Jacobi
+    /// eigenvalue algorithm.
+    fn symmetric_eigen(&self) -> Result<SymmetricEigen<S, Self>, LinalgError> {
+        symmetric_eigen::symmetric_eigen(self)
+    }
+
+    /// Eigenvalues of this matrix, assuming it is symmetric.
+    /// 
+    /// # Returns
+    /// 
+    /// Eigenvalues of this matrix, sorted in non-decreasing order.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix is not square.
+    /// * [`LinalgError::NonFinite`] if any element of the lower triangular part of this matrix is
+    ///   NaN or infinite.
+    /// * [`LinalgError::NoConvergence`] if the eigenvalue algorithm fails to converge.
+    /// 
+    /// # Note
+    /// 
+    /// This avoids computing the eigenvectors. Only the lower triangular part of this matrix is
+    /// read (i.e. this matrix is assumed to be symmetric without this being checked).
+    fn symmetric_eigenvalues(&self) -> Result<Self::VectorM, LinalgError> {
+        let (eigenvalues, _) = symmetric_eigen::jacobi_eigen(self, false)?;
+        let sorted: Vec<S> = symmetric_eigen::sorted_order(&eigenvalues)
+            .iter()
+            .map(|&k| eigenvalues[k])
+            .collect();
+        Ok(Self::VectorM::from_slice(&sorted))
+    }
+
+    /// Eigenpairs of this matrix with the `k` largest eigenvalues, assuming it is symmetric.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `k` - Number of eigenpairs.
+    /// 
+    /// # Returns
+    /// 
+    /// The `k` eigenpairs (eigenvalue and eigenvector) with the largest eigenvalues, sorted in
+    /// non-increasing order of eigenvalue.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix is not square.
+    /// * [`LinalgError::NonFinite`] if any element of the lower triangular part of this matrix is
+    ///   NaN or infinite.
+    /// * [`LinalgError::IndexOutOfBounds`] if `k` exceeds the size of this matrix (reporting the
+    ///   index `k - 1` of the last requested eigenpair).
+    /// * [`LinalgError::NoConvergence`] if the eigenvalue algorithm fails to converge.
+    fn symmetric_eigen_largest(&self, k: usize) -> Result<Vec<(S, Self::VectorM)>, LinalgError> {
+        self.check_square()?;
+        symmetric_eigen::check_eigenpair_count(k, self.shape().0)?;
+        Ok(self.symmetric_eigen()?.largest(k))
+    }
+
+    /// Eigenpairs of this matrix with the `k` smallest eigenvalues, assuming it is symmetric.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `k` - Number of eigenpairs.
+    /// 
+    /// # Returns
+    /// 
+    /// The `k` eigenpairs (eigenvalue and eigenvector) with the smallest eigenvalues, sorted in
+    /// non-decreasing order of eigenvalue.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix is not square.
+    /// * [`LinalgError::NonFinite`] if any element of the lower triangular part of this matrix is
+    ///   NaN or infinite.
+    /// * [`LinalgError::IndexOutOfBounds`] if `k` exceeds the size of this matrix (reporting the
+    ///   index `k - 1` of the last requested eigenpair).
+    /// * [`LinalgError::NoConvergence`] if the eigenvalue algorithm fails to converge.
+    fn symmetric_eigen_smallest(&self, k: usize) -> Result<Vec<(S, Self::VectorM)>, LinalgError> {
+        self.check_square()?;
+        symmetric_eigen::check_eigenpair_count(k, self.shape().0)?;
+        Ok(self.symmetric_eigen()?.smallest(k))
+    }
+
+    /// Real Schur decomposition of this matrix (`A = Z * T * Zᵀ`).
+    /// 
+    /// # Returns
+    /// 
+    /// Real Schur decomposition of this matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix is not square.
+    /// * [`LinalgError::NonFinite`] if any element of this matrix is NaN or infinite.
+    /// * [`LinalgError::NoConvergence`] if the QR algorithm fails to converge.
+    /// 
+    /// # Note
+    /// 
+    /// The default implementation reduces this matrix to upper Hessenberg form and then applies
+    /// the Francis double-shift QR algorithm.
+    fn schur(&self) -> Result<Schur<S, Self>, LinalgError> {
+        Schur::new(self)
+    }
+
+    /// Eigendecomposition of this matrix (which need not be symmetric).
+    /// 
+    /// # Returns
+    /// 
+    /// Eigendecomposition of this matrix, with the real and imaginary parts of the eigenvalues and
+    /// eigenvectors stored separately (see [`Eigen`]).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix is not square.
+    /// * [`LinalgError::NonFinite`] if any element of this matrix is NaN or infinite.
+    /// * [`LinalgError::NoConvergence`] if the QR algorithm fails to converge.
+    /// 
+    /// # Note
+    /// 
+    /// The default implementation computes the eigenvectors from the real Schur decomposition
+    /// returned by [`Matrix::schur`]. For symmetric matrices, [`Matrix::symmetric_eigen`] is more
+    /// efficient and is guaranteed to return real eigenvalues and orthogonal eigenvectors.
+    fn eigen(&self) -> Result<Eigen<S, Self>, LinalgError> {
+        Ok(self.schur()?.eigen())
+    }
+
+    /// Eigenvalues of this matrix (which need not be symmetric).
+    /// 
+    /// # Returns
+    /// 
+    /// A tuple containing the real parts and the imaginary parts of the eigenvalues. Complex
+    /// conjugate eigenvalues appear consecutively, with the eigenvalue with the positive imaginary
+    /// part first.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::ShapeMismatch`] if this matrix is not square.
+    /// * [`LinalgError::NonFinite`] if any element of this matrix is NaN or infinite.
+    /// * [`LinalgError::NoConvergence`] if the QR algorithm fails to converge.
+    /// 
+    /// # Note
+    /// 
+    /// This avoids computing the eigenvectors (and the Schur vectors). The order of the
+    /// eigenvalues is otherwise unspecified and may differ between implementations.
+    fn eigenvalues(&self) -> Result<(Self::VectorM, Self::VectorM), LinalgError> {
+        schur::eigenvalues(self)
+    }
+
+    /// Elementwise (Hadamard) product of two matrices.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix to multiply this matrix by (elementwise).
+    /// 
+    /// # Returns
+    /// 
+    /// Elementwise product of this matrix with the other matrix (i.e. `self ⊙ other`).
+    /// 
+    /// # Panics
+    /// 
+    /// * If the two matrices do not have the same shape.
+    #[must_use]
+    fn hadamard(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a * b)
+    }
+
+    /// Elementwise (Hadamard) quotient of two matrices.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix to divide this matrix by (elementwise).
+    /// 
+    /// # Returns
+    /// 
+    /// Elementwise quotient of this matrix with the other matrix (i.e. `self ⊘ other`).
+    /// 
+    /// # Panics
+    /// 
+    /// * If the two matrices do not have the same shape.
+    #[must_use]
+    fn hadamard_div(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a / b)
+    }
+
+    /// Matrix addition (elementwise), storing the result in a preallocated matrix
+    /// (`out = self + other`).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix to add to this matrix.
+    /// * `out` - The matrix to store the result in.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `self` and `other` do not have the same shape.
+    /// * If `out` does not have the same shape as this matrix.
+    fn add_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_shape(other);
+        assert_output_shape(self, out);
+        out.zip_apply(self, |o, a| *o = a);
+        out.zip_apply(other, |o, b| *o += b);
+    }
+
+    /// Matrix subtraction (elementwise), storing the result in a preallocated matrix
+    /// (`out = self - other`).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix to subtract from this matrix.
+    /// * `out` - The matrix to store the result in.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `self` and `other` do not have the same shape.
+    /// * If `out` does not have the same shape as this matrix.
+    fn sub_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_shape(other);
+        assert_output_shape(self, out);
+        out.zip_apply(self, |o, a| *o = a);
+        out.zip_apply(other, |o, b| *o -= b);
+    }
+
+    /// Matrix-scalar multiplication, storing the result in a preallocated matrix
+    /// (`out = self * scalar`).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `scalar` - The scalar to multiply each element of this matrix by.
+    /// * `out` - The matrix to store the result in.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `out` does not have the same shape as this matrix.
+    fn mul_to(&self, scalar: S, out: &mut Self) {
+        assert_output_shape(self, out);
+        out.zip_apply(self, |o, a| *o = a * scalar);
+    }
+
+    /// Matrix-scalar division, storing the result in a preallocated matrix
+    /// (`out = self / scalar`).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `scalar` - The scalar to divide each element of this matrix by.
+    /// * `out` - The matrix to store the result in.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `out` does not have the same shape as this matrix.
+    fn div_to(&self, scalar: S, out: &mut Self) {
+        assert_output_shape(self, out);
+        out.zip_apply(self, |o, a| *o = a / scalar);
+    }
+
+    /// Return the element at the specified index if it exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The row and column indices of the element to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// The element at the specified index, or `None` if `index` is out of bounds.
+    fn get(&self, index: (usize, usize)) -> Option<&S>;
+
+    /// Get the fixed shape of the matrix type.
+    /// 
+    /// # Returns
+    /// 
+    /// The shape (number of rows, number of columns) of every matrix of this type if the matrix
+    /// is statically-sized, `None` if the matrix is dynamically-sized.
+    /// 
+    /// # Note
+    /// 
+    /// The default implementation returns `None`. Statically-sized matrices should override it so
+    /// that the fallible constructors (e.g. [`Matrix::try_new_with_shape`]) can report a shape
+    /// mismatch instead of panicking.
+    #[must_use]
+    fn static_shape() -> Option<(usize, usize)> {
+        None
+    }
+
+    /// Return a slice of the matrix's elements without copying, if they are stored contiguously.
+    /// 
+    /// # Returns
+    /// 
+    /// A slice of the matrix's elements, or `None` if the elements are not stored contiguously in
+    /// memory.
+    /// 
+    /// # Warning
+    /// 
+    /// The order of the elements depends on whether the matrix is row-major or column-major. This
+    /// can be programmatically determined via the [`Matrix::is_row_major`] and
+    /// [`Matrix::is_column_major`] methods.
+    /// 
+    /// # Note
+    /// 
+    /// Unlike [`Matrix::as_slice`], this method never allocates.
+    fn try_as_contiguous_slice(&self) -> Option<&[S]> {
+        None
+    }
+
+    /// Return a mutable slice of the matrix's elements, if they are stored contiguously.
+    /// 
+    /// # Returns
+    /// 
+    /// A mutable slice of the matrix's elements, or `None` if the elements are not stored
+    /// contiguously in memory.
+    /// 
+    /// # Warning
+    /// 
+    /// The order of the elements depends on whether the matrix is row-major or column-major. This
+    /// can be programmatically determined via the [`Matrix::is_row_major`] and
+    /// [`Matrix::is_column_major`] methods.
+    /// 
+    /// # Note
+    /// 
+    /// To write to the elements of any matrix through a slice, use [`Matrix::with_mut_slice`].
+    fn try_as_contiguous_mut_slice(&mut self) -> Option<&mut [S]> {
+        None
+    }
+
+    /// Apply a function to each element of this matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Function to apply to each element.
+    /// 
+    /// # Returns
+    /// 
+    /// Matrix whose elements are `f(aᵢⱼ)`.
+    /// 
+    /// # Note
+    /// 
+    /// The order in which the elements are visited is not specified.
+    #[must_use]
+    fn map<F: FnMut(S) -> S>(&self, f: F) -> Self {
+        let mut out = self.clone();
+        out.map_in_place(f);
+        out
+    }
+
+    /// Apply a function to each element of this matrix in place.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Function to apply to each element (`aᵢⱼ = f(aᵢⱼ)`).
+    /// 
+    /// # Note
+    /// 
+    /// The order in which the elements are visited is not specified.
+    fn map_in_place<F: FnMut(S) -> S>(&mut self, mut f: F) {
+        let (rows, cols) = self.shape();
+        for row in 0..rows {
+            for col in 0..cols {
+                self[(row, col)] = f(self[(row, col)]);
+            }
+        }
+    }
+
+    /// Apply a function to each pair of corresponding elements of two matrices.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix.
+    /// * `f` - Function to apply to each pair of elements.
+    /// 
+    /// # Returns
+    /// 
+    /// Matrix whose elements are `f(aᵢⱼ, bᵢⱼ)`, where `a` is this matrix and `b` is the other
+    /// matrix.
+    /// 
+    /// # Panics
+    /// 
+    /// * If the two matrices do not have the same shape.
+    /// 
+    /// # Note
+    /// 
+    /// The order in which the elements are visited is not specified.
+    #[must_use]
+    fn zip_map<F: FnMut(S, S) -> S>(&self, other: &Self, mut f: F) -> Self {
+        let mut out = self.clone();
+        out.zip_apply(other, |a, b| *a = f(*a, b));
+        out
+    }
+
+    /// Apply a function to each pair of corresponding elements of two matrices, updating this
+    /// matrix in place.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other matrix.
+    /// * `f` - Function called as `f(&mut aᵢⱼ, bᵢⱼ)`, where `a` is this matrix and `b` is the
+    ///   other matrix.
+    /// 
+    /// # Panics
+    /// 
+    /// * If the two matrices do not have the same shape.
+    /// 
+    /// # Note
+    /// 
+    /// The order in which the elements are visited is not specified.
+    fn zip_apply<F: FnMut(&mut S, S)>(&mut self, other: &Self, mut f: F) {
+        self.assert_same_shape(other);
+        let (rows, cols) = self.shape();
+        for row in 0..rows {
+            for col in 0..cols {
+                f(&mut self[(row, col)], other[(row, col)]);
             }
-            Cow::from(vec)
         }
     }
 
-    /// Return a slice view of the matrix's elements in column-major order.
-    ///
+    /// Fold the elements of this matrix into a single value.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `init` - Initial value of the accumulator.
+    /// * `f` - Function combining the accumulator with each element.
+    /// 
     /// # Returns
-    ///
-    /// A slice of the matrix's elements in column-major order.
+    /// 
+    /// Final value of the accumulator.
     /// 
     /// # Note
     /// 
-    /// The slice is returned as a `Cow<[S]>` instead of a `&[S]`. This is primarily because the
-    /// underlying data will be _either_ in row-major order _or_ in column-major order. If it is in
-    /// row-major order, then we will need to re-order it to be in column-major order. This requires
-    /// building a temporary variable within this method which will be dropped when the method scope
-    /// ends. In such cases, this method will clone the data when returning it in a `Cow<[S]>`.
+    /// The order in which the elements are visited is not specified (it is typically the storage
+    /// order of the matrix).
+    fn fold<T, F: FnMut(T, S) -> T>(&self, init: T, mut f: F) -> T {
+        let (rows, cols) = self.shape();
+        (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| (row, col)))
+            .fold(init, |acc, index| f(acc, self[index]))
+    }
+
+    /// Apply a function to each element of this matrix, producing a matrix with a different
+    /// scalar type.
     /// 
-    /// In most cases, if the data is in column-major order, then this function will not perform any
-    /// cloning. However, in some cases, even if the underlying data is in column-major order, its
-    /// data may not be contiguous in memory. This would also require cloning the data from a
-    /// temporary variable. See [`Matrix::as_slice`] for more information.
-    fn as_col_slice(&self) -> Cow<'_, [S]> {
-        if Self::is_column_major() {
-            self.as_slice()
-        } else {
-            let (rows, cols) = self.shape();
-            let mut vec = Vec::<S>::with_capacity(rows * cols);
+    /// # Arguments
+    /// 
+    /// * `f` - Function mapping each element to the new scalar type.
+    /// 
+    /// # Returns
+    /// 
+    /// Matrix of type [`Matrix::MatrixT`] with the same shape as this matrix, whose elements are
+    /// `f(aᵢⱼ)`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use linalg_traits::{Mat, Matrix, Scalar};
+    /// 
+    /// // Lift a matrix of `f64`s into a matrix of any other scalar type.
+    /// fn lift<S: Scalar, M: Matrix<f64>>(a: &M) -> M::MatrixT<S> {
+    ///     a.map_scalar(S::new)
+    /// }
+    /// 
+    /// let a: Mat<f64> = Mat::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+    /// let b: Mat<f64> = lift(&a);
+    /// assert_eq!(b, a);
+    /// ```
+    fn map_scalar<T: Scalar, F: FnMut(S) -> T>(&self, mut f: F) -> Self::MatrixT<T> {
+        let (rows, cols) = self.shape();
+        let mut out = Self::MatrixT::<T>::new_with_shape(rows, cols);
+        for row in 0..rows {
             for col in 0..cols {
-                for row in 0..rows {
-                    vec.push(self[(row, col)]);
-                }
+                out[(row, col)] = f(self[(row, col)]);
             }
-            Cow::from(vec)
         }
+        out
     }
 
-    /// Return the element at the specified index if it exists.
-    ///
-    /// # Arguments
-    ///
-    /// * `index` - The row and column indices of the element to retrieve.
-    ///
-    /// # Returns
-    ///
-    /// The element at the specified index, or `None` if `index` is out of bounds.
-    fn get(&self, index: (usize, usize)) -> Option<&S>;
-
     // -----------------------------
     // Required method declarations.
     // -----------------------------
@@ -471,4 +3077,503 @@ pub trait Matrix<S: Scalar>:
     /// * `scalar` - The scalar to divide each element of this matrix by.
     fn div_assign(&mut self, scalar: S);
 
+    /// Matrix-vector multiplication.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `vector` - The length-`N` vector to multiply this `M x N` matrix by from the right.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`M` vector resulting from the product of this matrix with the vector (i.e.
+    /// `self * vector`).
+    /// 
+    /// # Panics
+    /// 
+    /// * If the length of the vector does not match the number of columns of this matrix.
+    #[must_use]
+    fn mul_vector(&self, vector: &Self::VectorN) -> Self::VectorM;
+
+    /// Matrix-vector multiplication, storing the result in a preallocated vector
+    /// (`out = self * vector`).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `vector` - The length-`N` vector to multiply this `M x N` matrix by from the right.
+    /// * `out` - The length-`M` vector to store the result in.
+    /// 
+    /// # Panics
+    /// 
+    /// * If the length of the vector does not match the number of columns of this matrix.
+    /// * If the length of the output vector does not match the number of rows of this matrix.
+    fn mul_vector_to(&self, vector: &Self::VectorN, out: &mut Self::VectorM);
+
+    /// Vector-matrix multiplication.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `vector` - The length-`M` vector to multiply this `M x N` matrix by from the left.
+    /// 
+    /// # Returns
+    /// 
+    /// Length-`N` vector resulting from the product of the vector with this matrix (i.e.
+    /// `vector * self`).
+    /// 
+    /// # Panics
+    /// 
+    /// * If the length of the vector does not match the number of rows of this matrix.
+    /// 
+    /// # Note
+    /// 
+    /// Mathematically, the input vector and the resulting vector both represent row vectors.
+    #[must_use]
+    fn vector_mul(&self, vector: &Self::VectorM) -> Self::VectorN;
+
+    /// Vector-matrix multiplication, storing the result in a preallocated vector
+    /// (`out = vector * self`).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `vector` - The length-`M` vector to multiply this `M x N` matrix by from the left.
+    /// * `out` - The length-`N` vector to store the result in.
+    /// 
+    /// # Panics
+    /// 
+    /// * If the length of the vector does not match the number of rows of this matrix.
+    /// * If the length of the output vector does not match the number of columns of this matrix.
+    fn vector_mul_to(&self, vector: &Self::VectorM, out: &mut Self::VectorN);
+
+    /// Matrix-matrix multiplication.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The `N x P` matrix to multiply this `M x N` matrix by from the right.
+    /// 
+    /// # Returns
+    /// 
+    /// `M x P` matrix resulting from the product of this matrix with the other matrix (i.e.
+    /// `self * other`).
+    /// 
+    /// # Panics
+    /// 
+    /// * If the number of rows of the other matrix does not match the number of columns of this
+    ///   matrix.
+    /// 
+    /// # Note
+    /// 
+    /// * Statically-sized matrices will determine the number of columns (`P`) of the resulting
+    ///   matrix through the const generic parameter `P`.
+    /// * For dynamically-sized matrices, the const generic parameter `P` can be specified as `0`
+    ///   since it is not used anyways.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # #[cfg(feature = "nalgebra")]
+    /// # {
+    /// use linalg_traits::Matrix;
+    /// use nalgebra::{SMatrix, DMatrix};
+    /// 
+    /// // Multiply a statically-sized 2x3 matrix by a statically-sized 3x4 matrix.
+    /// let a: SMatrix<f64, 2, 3> = SMatrix::new_with_shape(2, 3);
+    /// let b: SMatrix<f64, 3, 4> = SMatrix::new_with_shape(3, 4);
+    /// let c: SMatrix<f64, 2, 4> = a.matmul::<4>(&b);
+    /// assert_eq!(c.shape(), (2, 4));
+    /// 
+    /// // Multiply a dynamically-sized 2x3 matrix by a dynamically-sized 3x4 matrix.
+    /// let a: DMatrix<f64> = DMatrix::new_with_shape(2, 3);
+    /// let b: DMatrix<f64> = DMatrix::new_with_shape(3, 4);
+    /// let c: DMatrix<f64> = a.matmul::<0>(&b);
+    /// assert_eq!(c.shape(), (2, 4));
+    /// # }
+    /// ```
+    #[must_use]
+    fn matmul<const P: usize>(&self, other: &Self::MatrixNxP<P>) -> Self::MatrixMxP<P>;
+
+    /// Matrix-matrix multiplication, storing the result in a preallocated matrix
+    /// (`out = self * other`).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The `N x P` matrix to multiply this `M x N` matrix by from the right.
+    /// * `out` - The `M x P` matrix to store the result in.
+    /// 
+    /// # Panics
+    /// 
+    /// * If the number of rows of the other matrix does not match the number of columns of this
+    ///   matrix.
+    /// * If the shape of the output matrix is not `(M, P)`.
+    fn matmul_to<const P: usize>(&self, other: &Self::MatrixNxP<P>, out: &mut Self::MatrixMxP<P>);
+
+    /// General matrix-matrix multiplication (`self = alpha * op(a) * op(b) + beta * self`), where
+    /// `op(x)` is either `x` or its transpose.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `alpha` - The scalar to multiply the product `op(a) * op(b)` by.
+    /// * `a` - The left operand.
+    /// * `transpose_a` - `true` if `op(a)` is the transpose of `a`, `false` if `op(a)` is `a`.
+    /// * `b` - The right operand.
+    /// * `transpose_b` - `true` if `op(b)` is the transpose of `b`, `false` if `op(b)` is `b`.
+    /// * `beta` - The scalar to multiply this matrix by before the product is accumulated into it.
+    /// 
+    /// # Panics
+    /// 
+    /// * If the number of columns of `op(a)` does not match the number of rows of `op(b)`.
+    /// * If the shape of this matrix does not match the shape of `op(a) * op(b)`.
+    /// 
+    /// # Note
+    /// 
+    /// * If `beta` is zero, this matrix is overwritten without being read (i.e. `NaN`s or
+    ///   infinities stored in this matrix do not propagate into the result).
+    /// * Since `a`, `b`, and this matrix all share the same type, this method is only useful for
+    ///   dynamically-sized matrices and square statically-sized matrices. Use
+    ///   [`Matrix::matmul`] or [`Matrix::matmul_to`] to multiply statically-sized matrices of
+    ///   different shapes.
+    fn gemm(&mut self, alpha: S, a: &Self, transpose_a: bool, b: &Self, transpose_b: bool, beta: S);
+
+    /// Matrix transpose.
+    /// 
+    /// # Returns
+    /// 
+    /// Transpose of this matrix.
+    #[must_use]
+    fn transpose(&self) -> Self::Transpose;
+
+    /// In-place matrix transpose.
+    /// 
+    /// # Panics
+    /// 
+    /// * If this matrix is not square.
+    fn transpose_in_place(&mut self);
+
+}
+
+/// Reduce each row of a matrix to a single value, traversing the matrix in its storage order.
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix.
+/// * `init` - Initial value of the accumulator of each row.
+/// * `f` - Function combining the accumulator of a row with each element of that row.
+///
+/// # Returns
+///
+/// Length-`M` vector whose `i`th element is the final value of the accumulator of the `i`th row.
+pub(crate) fn reduce_rows<S: Scalar, M: Matrix<S>>(
+    matrix: &M,
+    init: S,
+    mut f: impl FnMut(S, S) -> S,
+) -> M::VectorM {
+    let (rows, cols) = matrix.shape();
+    let mut out = M::VectorM::new_with_length(rows);
+    if M::is_row_major() {
+        for row in 0..rows {
+            out[row] = (0..cols).fold(init, |acc, col| f(acc, matrix[(row, col)]));
+        }
+    } else {
+        out.map_in_place(|_| init);
+        for col in 0..cols {
+            for row in 0..rows {
+                out[row] = f(out[row], matrix[(row, col)]);
+            }
+        }
+    }
+    out
+}
+
+/// Reduce each column of a matrix to a single value, traversing the matrix in its storage order.
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix.
+/// * `init` - Initial value of the accumulator of each column.
+/// * `f` - Function combining the accumulator of a column with each element of that column.
+///
+/// # Returns
+///
+/// Length-`N` vector whose `j`th element is the final value of the accumulator of the `j`th
+/// column.
+pub(crate) fn reduce_columns<S: Scalar, M: Matrix<S>>(
+    matrix: &M,
+    init: S,
+    mut f: impl FnMut(S, S) -> S,
+) -> M::VectorN {
+    let (rows, cols) = matrix.shape();
+    let mut out = M::VectorN::new_with_length(cols);
+    if M::is_row_major() {
+        out.map_in_place(|_| init);
+        for row in 0..rows {
+            for col in 0..cols {
+                out[col] = f(out[col], matrix[(row, col)]);
+            }
+        }
+    } else {
+        for col in 0..cols {
+            out[col] = (0..rows).fold(init, |acc, row| f(acc, matrix[(row, col)]));
+        }
+    }
+    out
+}
+
+/// Accumulator counting the elements that are not NaN.
+///
+/// # Arguments
+///
+/// * `count` - Number of non-NaN elements seen so far.
+/// * `x` - The next element.
+///
+/// # Returns
+///
+/// Updated number of non-NaN elements.
+fn count_non_nan<S: Scalar>(count: S, x: S) -> S {
+    if x.is_nan() { count } else { count + S::one() }
+}
+
+/// Assert that a row index is in bounds for a matrix.
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix.
+/// * `row` - Index of the row.
+///
+/// # Panics
+///
+/// * If `row` is out of bounds.
+pub(crate) fn assert_row_index<S: Scalar, M: Matrix<S>>(matrix: &M, row: usize) {
+    let rows = matrix.shape().0;
+    assert!(
+        row < rows,
+        "Row index ({row}) is out of bounds for a matrix with {rows} rows."
+    );
+}
+
+/// Assert that a column index is in bounds for a matrix.
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix.
+/// * `col` - Index of the column.
+///
+/// # Panics
+///
+/// * If `col` is out of bounds.
+pub(crate) fn assert_column_index<S: Scalar, M: Matrix<S>>(matrix: &M, col: usize) {
+    let cols = matrix.shape().1;
+    assert!(
+        col < cols,
+        "Column index ({col}) is out of bounds for a matrix with {cols} columns."
+    );
+}
+
+/// Assert that the length of a row vector matches the number of columns of a matrix.
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix.
+/// * `len` - Length of the row vector.
+///
+/// # Panics
+///
+/// * If `len` is not equal to the number of columns of the matrix.
+pub(crate) fn assert_row_length<S: Scalar, M: Matrix<S>>(matrix: &M, len: usize) {
+    let cols = matrix.shape().1;
+    assert_eq!(
+        len, cols,
+        "Length of the row ({len}) does not match the number of columns of this matrix ({cols})."
+    );
+}
+
+/// Assert that the length of a column vector matches the number of rows of a matrix.
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix.
+/// * `len` - Length of the column vector.
+///
+/// # Panics
+///
+/// * If `len` is not equal to the number of rows of the matrix.
+pub(crate) fn assert_column_length<S: Scalar, M: Matrix<S>>(matrix: &M, len: usize) {
+    let rows = matrix.shape().0;
+    assert_eq!(
+        len, rows,
+        "Length of the column ({len}) does not match the number of rows of this matrix ({rows})."
+    );
+}
+
+/// Assert that a block fits within a matrix.
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix.
+/// * `row0` - Row index of the top-left element of the block.
+/// * `col0` - Column index of the top-left element of the block.
+/// * `nrows` - Number of rows of the block.
+/// * `ncols` - Number of columns of the block.
+///
+/// # Panics
+///
+/// * If the block does not fit within the matrix.
+pub(crate) fn assert_block_in_bounds<S: Scalar, M: Matrix<S>>(
+    matrix: &M,
+    row0: usize,
+    col0: usize,
+    nrows: usize,
+    ncols: usize,
+) {
+    let (matrix_rows, matrix_cols) = matrix.shape();
+    let rows_fit = row0
+        .checked_add(nrows)
+        .is_some_and(|end| end <= matrix_rows);
+    let cols_fit = col0
+        .checked_add(ncols)
+        .is_some_and(|end| end <= matrix_cols);
+    assert!(
+        rows_fit && cols_fit,
+        "Block of size {nrows}x{ncols} at ({row0}, {col0}) does not fit in a matrix of size \
+         {matrix_rows}x{matrix_cols}."
+    );
+}
+
+/// Check that a block fits within a matrix.
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix.
+/// * `row0` - Row index of the top-left element of the block.
+/// * `col0` - Column index of the top-left element of the block.
+/// * `nrows` - Number of rows of the block.
+/// * `ncols` - Number of columns of the block.
+///
+/// # Errors
+///
+/// * [`LinalgError::IndexOutOfBounds`] if the block does not fit within the matrix, reporting the
+///   last row (or column) index spanned by the block.
+fn check_block_in_bounds<S: Scalar, M: Matrix<S>>(
+    matrix: &M,
+    row0: usize,
+    col0: usize,
+    nrows: usize,
+    ncols: usize,
+) -> Result<(), LinalgError> {
+    let (matrix_rows, matrix_cols) = matrix.shape();
+    for (start, len, matrix_len) in [(row0, nrows, matrix_rows), (col0, ncols, matrix_cols)] {
+        if start.checked_add(len).is_none_or(|end| end > matrix_len) {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: start.saturating_add(len.saturating_sub(1)),
+                len: matrix_len,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Default implementation of [`Matrix::set_block`].
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix to write the block into.
+/// * `row0` - Row index at which the top-left element of the block is placed.
+/// * `col0` - Column index at which the top-left element of the block is placed.
+/// * `block` - The block.
+///
+/// # Panics
+///
+/// * If the block does not fit within the matrix.
+pub(crate) fn default_set_block<S: Scalar, M: Matrix<S>, B: Matrix<S>>(
+    matrix: &mut M,
+    row0: usize,
+    col0: usize,
+    block: &B,
+) {
+    let (nrows, ncols) = block.shape();
+    assert_block_in_bounds(matrix, row0, col0, nrows, ncols);
+    if M::is_row_major() {
+        for row in 0..nrows {
+            for col in 0..ncols {
+                matrix[(row0 + row, col0 + col)] = block[(row, col)];
+            }
+        }
+    } else {
+        for col in 0..ncols {
+            for row in 0..nrows {
+                matrix[(row0 + row, col0 + col)] = block[(row, col)];
+            }
+        }
+    }
+}
+
+/// Assert that an output matrix has the same shape as a matrix.
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix.
+/// * `out` - The output matrix.
+///
+/// # Panics
+///
+/// * If `out` does not have the same shape as `matrix`.
+pub(crate) fn assert_output_shape<S: Scalar, M: Matrix<S>>(matrix: &M, out: &M) {
+    let (rows, cols) = matrix.shape();
+    let (out_rows, out_cols) = out.shape();
+    assert!(
+        (out_rows, out_cols) == (rows, cols),
+        "Shape of the output matrix ({out_rows}x{out_cols}) does not match the shape of this \
+        matrix ({rows}x{cols})."
+    );
+}
+
+/// Check that two shapes are equal.
+///
+/// # Arguments
+///
+/// * `expected` - The required shape.
+/// * `actual` - The actual shape.
+///
+/// # Errors
+///
+/// * [`LinalgError::ShapeMismatch`] if `actual` is not equal to `expected`.
+pub(crate) fn check_shape(
+    expected: (usize, usize),
+    actual: (usize, usize),
+) -> Result<(), LinalgError> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(LinalgError::ShapeMismatch { expected, actual })
+    }
+}
+
+/// Check that a shape is compatible with a matrix type.
+///
+/// # Arguments
+///
+/// * `rows` - Number of rows.
+/// * `cols` - Number of columns.
+///
+/// # Errors
+///
+/// * [`LinalgError::ShapeMismatch`] if `M` is statically-sized and `(rows, cols)` does not match
+///   its shape.
+fn check_static_shape<S: Scalar, M: Matrix<S>>(
+    rows: usize,
+    cols: usize,
+) -> Result<(), LinalgError> {
+    M::static_shape().map_or(Ok(()), |expected| check_shape(expected, (rows, cols)))
+}
+
+/// Check that a slice has exactly one element for every element of a matrix.
+///
+/// # Arguments
+///
+/// * `rows` - Number of rows of the matrix.
+/// * `cols` - Number of columns of the matrix.
+/// * `slice` - The slice.
+///
+/// # Errors
+///
+/// * [`LinalgError::LengthMismatch`] if the length of the slice is not `rows * cols`.
+fn check_slice_length<S: Scalar>(rows: usize, cols: usize, slice: &[S]) -> Result<(), LinalgError> {
+    check_length(rows.saturating_mul(cols), slice.len())
 }
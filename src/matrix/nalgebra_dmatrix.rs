@@ -1,6 +1,50 @@
-use crate::{Matrix, Scalar};
+use crate::decompositions::svd::{self, SvdParts};
+use crate::decompositions::{schur, symmetric_eigen};
+use crate::matrix::matrix_trait::{
+    assert_block_in_bounds, assert_column_index, assert_column_length, assert_output_shape,
+    assert_row_index, assert_row_length, default_set_block, reduce_columns, reduce_rows,
+};
+use crate::nalgebra_native;
+use crate::scalar::as_f64;
+use crate::vector::vector_trait::{nan_max, scaled_l2_norm};
+use crate::{Cholesky, LinalgError, Lu, Matrix, NanPolicy, Qr, Scalar, Schur, Svd, SymmetricEigen};
 use nalgebra::{DMatrix, DVector};
 use std::borrow::Cow;
+use std::cmp::Ordering;
+
+/// Thin singular value decomposition of a [`DMatrix`] computed using nalgebra if its scalar type
+/// is `f64`.
+///
+/// # Arguments
+///
+/// * `matrix` - The `M x N` matrix to decompose.
+///
+/// # Returns
+///
+/// A tuple containing the `M x K` matrix `U`, the `K` singular values `σ` (sorted in
+/// non-increasing order), and the `N x K` matrix `V` (where `K = min(M, N)`) if the scalar type is
+/// `f64` and the matrix is not empty, or `None` otherwise.
+///
+/// # Errors
+///
+/// * [`LinalgError::NonFinite`] if any element of the matrix is NaN or infinite (nalgebra's SVD
+///   iterates until convergence, which never happens if the matrix contains NaNs).
+fn svd_f64<S: Scalar>(matrix: &DMatrix<S>) -> Result<Option<SvdParts<S, DMatrix<S>>>, LinalgError> {
+    matrix.check_finite()?;
+    // nalgebra cannot compute the SVD of an empty matrix.
+    if matrix.is_empty() {
+        return Ok(None);
+    }
+    let Some(matrix) = as_f64::<DMatrix<f64>, _>(matrix) else {
+        return Ok(None);
+    };
+    let svd = nalgebra::SVD::new(matrix.clone(), true, true);
+    let sigma = svd.singular_values.iter().map(|&s| S::new(s)).collect();
+    Ok(svd
+        .u
+        .zip(svd.v_t)
+        .map(|(u, v_t)| (u.map(S::new), sigma, v_t.transpose().map(S::new))))
+}
 
 impl<S> Matrix<S> for DMatrix<S>
 where
@@ -10,6 +54,20 @@ where
 
     type VectorN = DVector<S>;
 
+    type MatrixNxP<const P: usize> = DMatrix<S>;
+
+    type MatrixMxP<const P: usize> = DMatrix<S>;
+
+    type Transpose = DMatrix<S>;
+
+    type MatrixMxM = DMatrix<S>;
+
+    type MatrixNxN = DMatrix<S>;
+
+    type MatrixT<T: Scalar> = DMatrix<T>;
+
+    type DMatrixMxN = DMatrix<S>;
+
     fn is_statically_sized() -> bool {
         false
     }
@@ -18,6 +76,10 @@ where
         true
     }
 
+    fn static_shape() -> Option<(usize, usize)> {
+        None
+    }
+
     fn is_row_major() -> bool {
         false
     }
@@ -46,6 +108,14 @@ where
         Cow::from(Self::as_slice(self))
     }
 
+    fn try_as_contiguous_slice(&self) -> Option<&[S]> {
+        Some(Self::as_slice(self))
+    }
+
+    fn try_as_contiguous_mut_slice(&mut self) -> Option<&mut [S]> {
+        Some(Self::as_mut_slice(self))
+    }
+
     fn get(&self, index: (usize, usize)) -> Option<&S> {
         DMatrix::get(self, index)
     }
@@ -81,4 +151,387 @@ where
     fn div_assign(&mut self, scalar: S) {
         *self /= scalar;
     }
+
+    fn mul_vector(&self, vector: &DVector<S>) -> DVector<S> {
+        self * vector
+    }
+
+    fn mul_vector_to(&self, vector: &DVector<S>, out: &mut DVector<S>) {
+        out.gemv(S::one(), self, vector, S::zero());
+    }
+
+    fn vector_mul(&self, vector: &DVector<S>) -> DVector<S> {
+        self.tr_mul(vector)
+    }
+
+    fn vector_mul_to(&self, vector: &DVector<S>, out: &mut DVector<S>) {
+        out.gemv_tr(S::one(), self, vector, S::zero());
+    }
+
+    fn matmul<const P: usize>(&self, other: &DMatrix<S>) -> DMatrix<S> {
+        self * other
+    }
+
+    fn matmul_to<const P: usize>(&self, other: &DMatrix<S>, out: &mut DMatrix<S>) {
+        out.gemm(S::one(), self, other, S::zero());
+    }
+
+    fn gemm(
+        &mut self,
+        alpha: S,
+        a: &Self,
+        transpose_a: bool,
+        b: &Self,
+        transpose_b: bool,
+        beta: S,
+    ) {
+        match (transpose_a, transpose_b) {
+            (false, false) => DMatrix::gemm(self, alpha, a, b, beta),
+            (true, false) => self.gemm_tr(alpha, a, b, beta),
+            (false, true) => DMatrix::gemm(self, alpha, a, &b.transpose(), beta),
+            (true, true) => self.gemm_tr(alpha, a, &b.transpose(), beta),
+        }
+    }
+
+    fn transpose(&self) -> DMatrix<S> {
+        DMatrix::transpose(self)
+    }
+
+    fn row(&self, row: usize) -> DVector<S> {
+        assert_row_index(self, row);
+        self.row(row).transpose()
+    }
+
+    fn column(&self, col: usize) -> DVector<S> {
+        assert_column_index(self, col);
+        self.column(col).into_owned()
+    }
+
+    fn set_row(&mut self, row: usize, values: &DVector<S>) {
+        assert_row_index(self, row);
+        assert_row_length(self, values.len());
+        self.set_row(row, &values.transpose());
+    }
+
+    fn set_column(&mut self, col: usize, values: &DVector<S>) {
+        assert_column_index(self, col);
+        assert_column_length(self, values.len());
+        self.set_column(col, values);
+    }
+
+    fn submatrix(&self, row0: usize, col0: usize, nrows: usize, ncols: usize) -> DMatrix<S> {
+        assert_block_in_bounds(self, row0, col0, nrows, ncols);
+        self.view((row0, col0), (nrows, ncols)).clone_owned()
+    }
+
+    fn set_block<B: Matrix<S>>(&mut self, row0: usize, col0: usize, block: &B) {
+        let (nrows, ncols) = block.shape();
+        assert_block_in_bounds(self, row0, col0, nrows, ncols);
+        match block.try_as_contiguous_slice() {
+            Some(slice) => nalgebra_native::copy_block(
+                self,
+                (row0, col0),
+                (nrows, ncols),
+                slice,
+                B::is_row_major(),
+            ),
+            None => default_set_block(self, row0, col0, block),
+        }
+    }
+
+    fn identity(n: usize) -> Self {
+        DMatrix::identity(n, n)
+    }
+
+    fn from_element(rows: usize, cols: usize, value: S) -> Self {
+        DMatrix::from_element(rows, cols, value)
+    }
+
+    fn from_fn<F: FnMut(usize, usize) -> S>(rows: usize, cols: usize, f: F) -> Self {
+        DMatrix::from_fn(rows, cols, f)
+    }
+
+    fn from_diagonal(diagonal: &DVector<S>) -> Self {
+        DMatrix::from_diagonal(diagonal)
+    }
+
+    fn from_columns(columns: &[DVector<S>]) -> Self {
+        // nalgebra cannot determine the number of rows if there are no columns.
+        if columns.is_empty() {
+            return DMatrix::zeros(0, 0);
+        }
+        assert!(
+            columns
+                .iter()
+                .all(|column| column.len() == columns[0].len()),
+            "Columns must all have the same length."
+        );
+        DMatrix::from_columns(columns)
+    }
+
+    fn diagonal(&self) -> DVector<S> {
+        self.assert_square();
+        self.diagonal()
+    }
+
+    fn trace(&self) -> S {
+        self.assert_square();
+        self.trace()
+    }
+
+    fn norm_frobenius(&self) -> S {
+        // nalgebra does not rescale when computing the norm, so fall back to the scaled algorithm
+        // if the sum of squares overflowed or underflowed.
+        if let Some(matrix) = as_f64::<DMatrix<f64>, _>(self) {
+            let norm = matrix.norm();
+            if norm.is_finite() && norm >= f64::MIN_POSITIVE.sqrt() {
+                return S::new(norm);
+            }
+        }
+        scaled_l2_norm(self.iter().copied())
+    }
+
+    fn norm_1(&self) -> S {
+        self.column_iter()
+            .map(|col| col.fold(S::zero(), |sum, x| sum + x.abs()))
+            .fold(S::zero(), nan_max)
+    }
+
+    fn norm_inf(&self) -> S {
+        self.row_iter()
+            .map(|row| row.fold(S::zero(), |sum, x| sum + x.abs()))
+            .fold(S::zero(), nan_max)
+    }
+
+    fn norm_max(&self) -> S {
+        // nalgebra's `amax` ignores NaNs (other than in the first element), so NaNs are checked
+        // for separately.
+        match as_f64::<DMatrix<f64>, _>(self) {
+            Some(matrix) if !matrix.iter().any(|x| x.is_nan()) => S::new(matrix.amax()),
+            _ => self.fold(S::zero(), |max, x| nan_max(max, x.abs())),
+        }
+    }
+
+    fn row_sums(&self, nan_policy: NanPolicy) -> DVector<S> {
+        match nan_policy {
+            NanPolicy::Propagate => self.column_sum(),
+            NanPolicy::Ignore => {
+                reduce_rows(
+                    self,
+                    S::zero(),
+                    |sum, x| if x.is_nan() { sum } else { sum + x },
+                )
+            }
+        }
+    }
+
+    fn column_sums(&self, nan_policy: NanPolicy) -> DVector<S> {
+        match nan_policy {
+            NanPolicy::Propagate => self.row_sum_tr(),
+            NanPolicy::Ignore => {
+                reduce_columns(
+                    self,
+                    S::zero(),
+                    |sum, x| if x.is_nan() { sum } else { sum + x },
+                )
+            }
+        }
+    }
+
+    fn lu(&self) -> Lu<S, DMatrix<S>> {
+        self.assert_square();
+        let Some(matrix) = as_f64::<DMatrix<f64>, _>(self) else {
+            return Lu::new(self);
+        };
+        let (packed, perm) = nalgebra_native::lu(matrix);
+        Lu::from_parts(packed, &perm)
+    }
+
+    fn cholesky(&self) -> Result<Cholesky<S, DMatrix<S>>, LinalgError> {
+        self.check_square()?;
+        let Some(matrix) = as_f64::<DMatrix<f64>, _>(self) else {
+            return Cholesky::new(self);
+        };
+        match nalgebra::Cholesky::new(matrix.clone()) {
+            Some(chol) => {
+                let l = chol.unpack();
+                Ok(Cholesky::from_l(l.map(S::new)))
+            }
+            // nalgebra does not report where the factorization failed, so rerun the default
+            // implementation to find the failing pivot.
+            None => Cholesky::new(self),
+        }
+    }
+
+    fn qr(&self) -> Qr<S, DMatrix<S>> {
+        let Some(matrix) = as_f64::<DMatrix<f64>, _>(self) else {
+            return Qr::new(self, false);
+        };
+        let (factors, tau, perm) = nalgebra_native::qr(matrix, false);
+        Qr::from_parts(factors, tau, perm)
+    }
+
+    fn col_piv_qr(&self) -> Qr<S, DMatrix<S>> {
+        let Some(matrix) = as_f64::<DMatrix<f64>, _>(self) else {
+            return Qr::new(self, true);
+        };
+        let (factors, tau, perm) = nalgebra_native::qr(matrix, true);
+        Qr::from_parts(factors, tau, perm)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn least_squares(&self, b: &DVector<S>) -> Result<DVector<S>, LinalgError> {
+        // nalgebra's column-pivoted QR decomposition pivots on the largest element rather than
+        // the largest column norm, so it is not reliably rank-revealing. Instead, nalgebra's
+        // (unpivoted) QR decomposition is used for overdetermined systems with full column rank,
+        // falling back to the default (column-pivoted) implementation otherwise.
+        let (rows, cols) = self.shape();
+        if let (Some(matrix), Some(b)) = (
+            as_f64::<DMatrix<f64>, _>(self),
+            as_f64::<DVector<f64>, _>(b),
+        ) && rows >= cols
+            && b.len() == rows
+        {
+            let qr = nalgebra::QR::new(matrix.clone());
+            let r = qr.r();
+            let max_diag = r.diagonal().amax();
+            let tolerance = f64::EPSILON * rows as f64 * max_diag;
+            if r.diagonal().iter().all(|diag| diag.abs() > tolerance) {
+                let mut y = b.clone();
+                qr.q_tr_mul(&mut y);
+                if let Some(x) = r.solve_upper_triangular(&y.rows(0, cols)) {
+                    return Ok(x.map(S::new));
+                }
+            }
+        }
+        Qr::new(self, true).least_squares(b)
+    }
+
+    fn svd(&self) -> Result<Svd<S, DMatrix<S>, DMatrix<S>>, LinalgError> {
+        let (rows, cols) = self.shape();
+        match svd_f64(self)? {
+            Some((u, sigma, v)) => Ok(Svd::from_parts(&u, &sigma, &v, rows, cols)),
+            None => svd::svd_full(self),
+        }
+    }
+
+    fn svd_thin(&self) -> Result<Svd<S, DMatrix<S>, DMatrix<S>>, LinalgError> {
+        let cols = svd::check_thin(self)?;
+        match svd_f64(self)? {
+            Some((u, sigma, v)) => Ok(Svd::from_parts(&u, &sigma, &v, cols, cols)),
+            None => svd::svd_thin(self),
+        }
+    }
+
+    fn singular_values(&self) -> Result<DVector<S>, LinalgError> {
+        // nalgebra's SVD iterates until convergence, which never happens if the matrix contains
+        // NaNs.
+        self.check_finite()?;
+        match as_f64::<DMatrix<f64>, _>(self) {
+            // nalgebra cannot compute the SVD of an empty matrix.
+            Some(matrix) if !matrix.is_empty() => Ok(matrix.singular_values().map(S::new)),
+            _ => Ok(DVector::from_vec(svd::jacobi_svd(self, false)?.1)),
+        }
+    }
+
+    fn symmetric_eigen(&self) -> Result<SymmetricEigen<S, DMatrix<S>>, LinalgError> {
+        self.check_square()?;
+        // nalgebra cannot compute the eigendecomposition of an empty matrix.
+        if self.is_empty() {
+            return symmetric_eigen::symmetric_eigen(self);
+        }
+        let Some(matrix) = as_f64::<DMatrix<f64>, _>(self) else {
+            return symmetric_eigen::symmetric_eigen(self);
+        };
+
+        // nalgebra's eigenvalue algorithm iterates until convergence, which never happens if the
+        // matrix contains NaNs.
+        symmetric_eigen::check_lower_finite(self)?;
+        let eigen = nalgebra::SymmetricEigen::new(matrix.clone());
+        let eigenvalues: Vec<S> = eigen.eigenvalues.iter().map(|&e| S::new(e)).collect();
+        Ok(SymmetricEigen::from_parts(
+            &eigenvalues,
+            &eigen.eigenvectors.map(S::new),
+        ))
+    }
+
+    fn symmetric_eigenvalues(&self) -> Result<DVector<S>, LinalgError> {
+        self.check_square()?;
+        symmetric_eigen::check_lower_finite(self)?;
+        let mut eigenvalues: Vec<S> = match as_f64::<DMatrix<f64>, _>(self) {
+            // nalgebra cannot compute the eigenvalues of an empty matrix.
+            Some(matrix) if !matrix.is_empty() => matrix
+                .symmetric_eigenvalues()
+                .iter()
+                .map(|&e| S::new(e))
+                .collect(),
+            _ => symmetric_eigen::jacobi_eigen(self, false)?.0,
+        };
+        eigenvalues.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        Ok(DVector::from_vec(eigenvalues))
+    }
+
+    fn schur(&self) -> Result<Schur<S, DMatrix<S>>, LinalgError> {
+        self.check_square()?;
+        self.check_finite()?;
+        let Some(matrix) = as_f64::<DMatrix<f64>, _>(self) else {
+            return Schur::new(self);
+        };
+        let n = matrix.nrows();
+        let max_iterations = 30 * n.max(1);
+        let (z, t) = nalgebra::Schur::try_new(matrix.clone(), f64::EPSILON, max_iterations)
+            .ok_or(LinalgError::NoConvergence)?
+            .unpack();
+        Ok(Schur::from_parts(&z.map(S::new), &t.map(S::new)))
+    }
+
+    fn eigenvalues(&self) -> Result<(DVector<S>, DVector<S>), LinalgError> {
+        if as_f64::<DMatrix<f64>, _>(self).is_none() {
+            return schur::eigenvalues(self);
+        }
+        Ok(self.schur()?.eigenvalues())
+    }
+
+    fn transpose_in_place(&mut self) {
+        self.assert_square();
+        self.transpose_mut();
+    }
+
+    fn map<F: FnMut(S) -> S>(&self, f: F) -> Self {
+        self.map(f)
+    }
+
+    fn map_in_place<F: FnMut(S) -> S>(&mut self, mut f: F) {
+        self.apply(|a| *a = f(*a));
+    }
+
+    fn zip_map<F: FnMut(S, S) -> S>(&self, other: &Self, f: F) -> Self {
+        self.assert_same_shape(other);
+        self.zip_map(other, f)
+    }
+
+    fn zip_apply<F: FnMut(&mut S, S)>(&mut self, other: &Self, f: F) {
+        self.assert_same_shape(other);
+        self.zip_apply(other, f);
+    }
+
+    fn fold<T, F: FnMut(T, S) -> T>(&self, init: T, f: F) -> T {
+        self.fold(init, f)
+    }
+
+    fn map_scalar<T: Scalar, F: FnMut(S) -> T>(&self, f: F) -> DMatrix<T> {
+        self.map(f)
+    }
+
+    fn add_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_shape(other);
+        assert_output_shape(self, out);
+        self.add_to(other, out);
+    }
+
+    fn sub_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_shape(other);
+        assert_output_shape(self, out);
+        self.sub_to(other, out);
+    }
 }
@@ -1,5 +1,11 @@
-use crate::{Matrix, Scalar};
-use nalgebra::{SMatrix, SVector};
+use crate::matrix::matrix_trait::{
+    assert_block_in_bounds, assert_column_index, assert_column_length, assert_output_shape,
+    assert_row_index, assert_row_length, default_set_block, reduce_columns, reduce_rows,
+};
+use crate::nalgebra_native;
+use crate::scalar::as_f64;
+use crate::{Lu, Matrix, NanPolicy, Qr, Scalar};
+use nalgebra::{DMatrix, SMatrix, SVector};
 use std::borrow::Cow;
 
 impl<S, const M: usize, const N: usize> Matrix<S> for SMatrix<S, M, N>
@@ -10,6 +16,20 @@ where
 
     type VectorN = SVector<S, N>;
 
+    type MatrixNxP<const P: usize> = SMatrix<S, N, P>;
+
+    type MatrixMxP<const P: usize> = SMatrix<S, M, P>;
+
+    type Transpose = SMatrix<S, N, M>;
+
+    type MatrixMxM = SMatrix<S, M, M>;
+
+    type MatrixNxN = SMatrix<S, N, N>;
+
+    type MatrixT<T: Scalar> = SMatrix<T, M, N>;
+
+    type DMatrixMxN = DMatrix<S>;
+
     fn is_statically_sized() -> bool {
         true
     }
@@ -18,6 +38,10 @@ where
         false
     }
 
+    fn static_shape() -> Option<(usize, usize)> {
+        Some((M, N))
+    }
+
     fn is_row_major() -> bool {
         false
     }
@@ -52,6 +76,14 @@ where
         Cow::from(Self::as_slice(self))
     }
 
+    fn try_as_contiguous_slice(&self) -> Option<&[S]> {
+        Some(Self::as_slice(self))
+    }
+
+    fn try_as_contiguous_mut_slice(&mut self) -> Option<&mut [S]> {
+        Some(Self::as_mut_slice(self))
+    }
+
     fn get(&self, index: (usize, usize)) -> Option<&S> {
         SMatrix::get(self, index)
     }
@@ -87,4 +119,220 @@ where
     fn div_assign(&mut self, scalar: S) {
         *self /= scalar;
     }
+
+    fn mul_vector(&self, vector: &SVector<S, N>) -> SVector<S, M> {
+        self * vector
+    }
+
+    fn mul_vector_to(&self, vector: &SVector<S, N>, out: &mut SVector<S, M>) {
+        out.gemv(S::one(), self, vector, S::zero());
+    }
+
+    fn vector_mul(&self, vector: &SVector<S, M>) -> SVector<S, N> {
+        self.tr_mul(vector)
+    }
+
+    fn vector_mul_to(&self, vector: &SVector<S, M>, out: &mut SVector<S, N>) {
+        out.gemv_tr(S::one(), self, vector, S::zero());
+    }
+
+    fn matmul<const P: usize>(&self, other: &SMatrix<S, N, P>) -> SMatrix<S, M, P> {
+        self * other
+    }
+
+    fn matmul_to<const P: usize>(&self, other: &SMatrix<S, N, P>, out: &mut SMatrix<S, M, P>) {
+        out.gemm(S::one(), self, other, S::zero());
+    }
+
+    fn gemm(
+        &mut self,
+        alpha: S,
+        a: &Self,
+        transpose_a: bool,
+        b: &Self,
+        transpose_b: bool,
+        beta: S,
+    ) {
+        // The shapes of op(a) and op(b) cannot be checked at compile time (all three matrices have
+        // shape (M, N), but op(a) and op(b) may be transposed), so we work with dynamically-sized
+        // views and let nalgebra check the shapes at runtime.
+        let mut c = self.view_mut((0, 0), (M, N));
+        let a = a.view((0, 0), (M, N));
+        let b = b.view((0, 0), (M, N));
+        match (transpose_a, transpose_b) {
+            (false, false) => c.gemm(alpha, &a, &b, beta),
+            (true, false) => c.gemm_tr(alpha, &a, &b, beta),
+            (false, true) => c.gemm(alpha, &a, &b.transpose(), beta),
+            (true, true) => c.gemm_tr(alpha, &a, &b.transpose(), beta),
+        }
+    }
+
+    fn lu(&self) -> Lu<S, SMatrix<S, M, N>> {
+        self.assert_square();
+        let Some(matrix) = as_f64::<SMatrix<f64, M, N>, _>(self) else {
+            return Lu::new(self);
+        };
+
+        // nalgebra's decompositions of statically-sized matrices require type-level dimensions, so
+        // we factor a dynamically-sized copy instead.
+        let (packed, perm) =
+            nalgebra_native::lu(&DMatrix::from_column_slice(M, N, matrix.as_slice()));
+        Lu::from_parts(SMatrix::from_column_slice(packed.as_slice()), &perm)
+    }
+
+    fn qr(&self) -> Qr<S, SMatrix<S, M, N>> {
+        let Some(matrix) = as_f64::<SMatrix<f64, M, N>, _>(self) else {
+            return Qr::new(self, false);
+        };
+        let (factors, tau, perm) =
+            nalgebra_native::qr(&DMatrix::from_column_slice(M, N, matrix.as_slice()), false);
+        Qr::from_parts(SMatrix::from_column_slice(factors.as_slice()), tau, perm)
+    }
+
+    fn col_piv_qr(&self) -> Qr<S, SMatrix<S, M, N>> {
+        let Some(matrix) = as_f64::<SMatrix<f64, M, N>, _>(self) else {
+            return Qr::new(self, true);
+        };
+        let (factors, tau, perm) =
+            nalgebra_native::qr(&DMatrix::from_column_slice(M, N, matrix.as_slice()), true);
+        Qr::from_parts(SMatrix::from_column_slice(factors.as_slice()), tau, perm)
+    }
+
+    fn transpose(&self) -> SMatrix<S, N, M> {
+        SMatrix::transpose(self)
+    }
+
+    fn transpose_in_place(&mut self) {
+        // nalgebra's in-place transpose requires the matrix to be square at compile time.
+        self.assert_square();
+        for row in 0..M {
+            for col in (row + 1)..N {
+                self.swap((row, col), (col, row));
+            }
+        }
+    }
+
+    fn map<F: FnMut(S) -> S>(&self, f: F) -> Self {
+        self.map(f)
+    }
+
+    fn map_in_place<F: FnMut(S) -> S>(&mut self, mut f: F) {
+        self.apply(|a| *a = f(*a));
+    }
+
+    fn zip_map<F: FnMut(S, S) -> S>(&self, other: &Self, f: F) -> Self {
+        self.assert_same_shape(other);
+        self.zip_map(other, f)
+    }
+
+    fn zip_apply<F: FnMut(&mut S, S)>(&mut self, other: &Self, f: F) {
+        self.assert_same_shape(other);
+        self.zip_apply(other, f);
+    }
+
+    fn fold<T, F: FnMut(T, S) -> T>(&self, init: T, f: F) -> T {
+        self.fold(init, f)
+    }
+
+    fn map_scalar<T: Scalar, F: FnMut(S) -> T>(&self, f: F) -> SMatrix<T, M, N> {
+        self.map(f)
+    }
+
+    fn row(&self, row: usize) -> SVector<S, N> {
+        assert_row_index(self, row);
+        self.row(row).transpose()
+    }
+
+    fn column(&self, col: usize) -> SVector<S, M> {
+        assert_column_index(self, col);
+        self.column(col).into_owned()
+    }
+
+    fn set_row(&mut self, row: usize, values: &SVector<S, N>) {
+        assert_row_index(self, row);
+        assert_row_length(self, values.len());
+        self.set_row(row, &values.transpose());
+    }
+
+    fn set_column(&mut self, col: usize, values: &SVector<S, M>) {
+        assert_column_index(self, col);
+        assert_column_length(self, values.len());
+        self.set_column(col, values);
+    }
+
+    fn submatrix(&self, row0: usize, col0: usize, nrows: usize, ncols: usize) -> DMatrix<S> {
+        assert_block_in_bounds(self, row0, col0, nrows, ncols);
+        self.view((row0, col0), (nrows, ncols)).clone_owned()
+    }
+
+    fn set_block<B: Matrix<S>>(&mut self, row0: usize, col0: usize, block: &B) {
+        let (nrows, ncols) = block.shape();
+        assert_block_in_bounds(self, row0, col0, nrows, ncols);
+        match block.try_as_contiguous_slice() {
+            Some(slice) => nalgebra_native::copy_block(
+                self,
+                (row0, col0),
+                (nrows, ncols),
+                slice,
+                B::is_row_major(),
+            ),
+            None => default_set_block(self, row0, col0, block),
+        }
+    }
+
+    fn identity(n: usize) -> Self {
+        assert_eq!(n, M, "Row count mismatch.");
+        assert_eq!(n, N, "Column count mismatch.");
+        SMatrix::identity()
+    }
+
+    fn from_element(rows: usize, cols: usize, value: S) -> Self {
+        assert_eq!(rows, M, "Row count mismatch.");
+        assert_eq!(cols, N, "Column count mismatch.");
+        SMatrix::from_element(value)
+    }
+
+    fn from_fn<F: FnMut(usize, usize) -> S>(rows: usize, cols: usize, f: F) -> Self {
+        assert_eq!(rows, M, "Row count mismatch.");
+        assert_eq!(cols, N, "Column count mismatch.");
+        SMatrix::from_fn(f)
+    }
+
+    fn row_sums(&self, nan_policy: NanPolicy) -> SVector<S, M> {
+        match nan_policy {
+            NanPolicy::Propagate => self.column_sum(),
+            NanPolicy::Ignore => {
+                reduce_rows(
+                    self,
+                    S::zero(),
+                    |sum, x| if x.is_nan() { sum } else { sum + x },
+                )
+            }
+        }
+    }
+
+    fn column_sums(&self, nan_policy: NanPolicy) -> SVector<S, N> {
+        match nan_policy {
+            NanPolicy::Propagate => self.row_sum_tr(),
+            NanPolicy::Ignore => {
+                reduce_columns(
+                    self,
+                    S::zero(),
+                    |sum, x| if x.is_nan() { sum } else { sum + x },
+                )
+            }
+        }
+    }
+
+    fn add_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_shape(other);
+        assert_output_shape(self, out);
+        self.add_to(other, out);
+    }
+
+    fn sub_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_shape(other);
+        assert_output_shape(self, out);
+        self.sub_to(other, out);
+    }
 }
@@ -1,5 +1,13 @@
-use crate::{Matrix, Scalar};
-use ndarray::{Array1, Array2, LinalgScalar, ScalarOperand};
+use crate::matrix::matrix_trait::{
+    assert_block_in_bounds, assert_column_index, assert_column_length, assert_output_shape,
+    assert_row_index, assert_row_length, default_set_block, reduce_columns, reduce_rows,
+};
+use crate::{Matrix, NanPolicy, Scalar};
+use ndarray::linalg::{general_mat_mul, general_mat_vec_mul};
+use ndarray::{
+    Array1, Array2, ArrayView2, Axis, Ix2, LinalgScalar, ScalarOperand, ShapeBuilder, StrideShape,
+    Zip, s,
+};
 use std::borrow::Cow;
 
 impl<S> Matrix<S> for Array2<S>
@@ -10,6 +18,20 @@ where
 
     type VectorN = Array1<S>;
 
+    type MatrixNxP<const P: usize> = Array2<S>;
+
+    type MatrixMxP<const P: usize> = Array2<S>;
+
+    type Transpose = Array2<S>;
+
+    type MatrixMxM = Array2<S>;
+
+    type MatrixNxN = Array2<S>;
+
+    type MatrixT<T: Scalar> = Array2<T>;
+
+    type DMatrixMxN = Array2<S>;
+
     fn is_statically_sized() -> bool {
         false
     }
@@ -18,6 +40,10 @@ where
         true
     }
 
+    fn static_shape() -> Option<(usize, usize)> {
+        None
+    }
+
     fn is_row_major() -> bool {
         true
     }
@@ -66,12 +92,20 @@ where
     }
 
     fn as_slice(&self) -> Cow<'_, [S]> {
-        match self.as_slice_memory_order() {
+        match self.try_as_contiguous_slice() {
             Some(slice) => Cow::Borrowed(slice),
             None => Cow::Owned(self.iter().copied().collect()),
         }
     }
 
+    fn try_as_contiguous_slice(&self) -> Option<&[S]> {
+        self.view().to_slice()
+    }
+
+    fn try_as_contiguous_mut_slice(&mut self) -> Option<&mut [S]> {
+        self.view_mut().into_slice()
+    }
+
     fn get(&self, index: (usize, usize)) -> Option<&S> {
         let (row, col) = index;
         if row < self.nrows() && col < self.ncols() {
@@ -112,4 +146,200 @@ where
     fn div_assign(&mut self, scalar: S) {
         *self /= scalar;
     }
+
+    fn mul_vector(&self, vector: &Array1<S>) -> Array1<S> {
+        self.dot(vector)
+    }
+
+    fn mul_vector_to(&self, vector: &Array1<S>, out: &mut Array1<S>) {
+        general_mat_vec_mul(S::one(), self, vector, S::zero(), out);
+    }
+
+    fn vector_mul(&self, vector: &Array1<S>) -> Array1<S> {
+        vector.dot(self)
+    }
+
+    fn vector_mul_to(&self, vector: &Array1<S>, out: &mut Array1<S>) {
+        general_mat_vec_mul(S::one(), &self.t(), vector, S::zero(), out);
+    }
+
+    fn matmul<const P: usize>(&self, other: &Array2<S>) -> Array2<S> {
+        self.dot(other)
+    }
+
+    fn matmul_to<const P: usize>(&self, other: &Array2<S>, out: &mut Array2<S>) {
+        general_mat_mul(S::one(), self, other, S::zero(), out);
+    }
+
+    fn gemm(
+        &mut self,
+        alpha: S,
+        a: &Self,
+        transpose_a: bool,
+        b: &Self,
+        transpose_b: bool,
+        beta: S,
+    ) {
+        let a = if transpose_a { a.t() } else { a.view() };
+        let b = if transpose_b { b.t() } else { b.view() };
+        general_mat_mul(alpha, &a, &b, beta, self);
+    }
+
+    fn transpose(&self) -> Array2<S> {
+        // Copy into standard (row-major) layout since ndarray would otherwise just reverse the
+        // strides.
+        self.t().as_standard_layout().into_owned()
+    }
+
+    fn transpose_in_place(&mut self) {
+        self.assert_square();
+        for row in 0..self.nrows() {
+            for col in (row + 1)..self.ncols() {
+                self.swap((row, col), (col, row));
+            }
+        }
+    }
+
+    fn map<F: FnMut(S) -> S>(&self, f: F) -> Self {
+        self.mapv(f)
+    }
+
+    fn map_in_place<F: FnMut(S) -> S>(&mut self, f: F) {
+        self.mapv_inplace(f);
+    }
+
+    fn zip_map<F: FnMut(S, S) -> S>(&self, other: &Self, mut f: F) -> Self {
+        self.assert_same_shape(other);
+        Zip::from(self).and(other).map_collect(|a, b| f(*a, *b))
+    }
+
+    fn zip_apply<F: FnMut(&mut S, S)>(&mut self, other: &Self, mut f: F) {
+        self.assert_same_shape(other);
+        Zip::from(self).and(other).for_each(|a, b| f(a, *b));
+    }
+
+    fn fold<T, F: FnMut(T, S) -> T>(&self, init: T, mut f: F) -> T {
+        self.view().fold(init, |acc, a| f(acc, *a))
+    }
+
+    fn map_scalar<T: Scalar, F: FnMut(S) -> T>(&self, f: F) -> Array2<T> {
+        self.mapv(f)
+    }
+
+    fn row(&self, row: usize) -> Array1<S> {
+        assert_row_index(self, row);
+        self.view().row(row).to_owned()
+    }
+
+    fn column(&self, col: usize) -> Array1<S> {
+        assert_column_index(self, col);
+        self.view().column(col).to_owned()
+    }
+
+    fn set_row(&mut self, row: usize, values: &Array1<S>) {
+        assert_row_index(self, row);
+        assert_row_length(self, values.len());
+        self.row_mut(row).assign(values);
+    }
+
+    fn set_column(&mut self, col: usize, values: &Array1<S>) {
+        assert_column_index(self, col);
+        assert_column_length(self, values.len());
+        self.column_mut(col).assign(values);
+    }
+
+    fn submatrix(&self, row0: usize, col0: usize, nrows: usize, ncols: usize) -> Array2<S> {
+        assert_block_in_bounds(self, row0, col0, nrows, ncols);
+        self.slice(s![row0..row0 + nrows, col0..col0 + ncols])
+            .to_owned()
+    }
+
+    fn set_block<B: Matrix<S>>(&mut self, row0: usize, col0: usize, block: &B) {
+        let (nrows, ncols) = block.shape();
+        assert_block_in_bounds(self, row0, col0, nrows, ncols);
+        let shape: StrideShape<Ix2> = if B::is_row_major() {
+            (nrows, ncols).into()
+        } else {
+            (nrows, ncols).f().into()
+        };
+        match block
+            .try_as_contiguous_slice()
+            .map(|slice| ArrayView2::from_shape(shape, slice))
+        {
+            Some(Ok(view)) => self
+                .slice_mut(s![row0..row0 + nrows, col0..col0 + ncols])
+                .assign(&view),
+            _ => default_set_block(self, row0, col0, block),
+        }
+    }
+
+    fn identity(n: usize) -> Self {
+        Array2::eye(n)
+    }
+
+    fn from_element(rows: usize, cols: usize, value: S) -> Self {
+        Array2::from_elem((rows, cols), value)
+    }
+
+    fn from_fn<F: FnMut(usize, usize) -> S>(rows: usize, cols: usize, mut f: F) -> Self {
+        Array2::from_shape_fn((rows, cols), |(row, col)| f(row, col))
+    }
+
+    fn from_diagonal(diagonal: &Array1<S>) -> Self {
+        Array2::from_diag(diagonal)
+    }
+
+    fn diagonal(&self) -> Array1<S> {
+        self.assert_square();
+        self.diag().to_owned()
+    }
+
+    fn trace(&self) -> S {
+        self.assert_square();
+        self.diag().sum()
+    }
+
+    fn row_sums(&self, nan_policy: NanPolicy) -> Array1<S> {
+        match nan_policy {
+            NanPolicy::Propagate => self.sum_axis(Axis(1)),
+            NanPolicy::Ignore => {
+                reduce_rows(
+                    self,
+                    S::zero(),
+                    |sum, x| if x.is_nan() { sum } else { sum + x },
+                )
+            }
+        }
+    }
+
+    fn column_sums(&self, nan_policy: NanPolicy) -> Array1<S> {
+        match nan_policy {
+            NanPolicy::Propagate => self.sum_axis(Axis(0)),
+            NanPolicy::Ignore => {
+                reduce_columns(
+                    self,
+                    S::zero(),
+                    |sum, x| if x.is_nan() { sum } else { sum + x },
+                )
+            }
+        }
+    }
+
+    fn add_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_shape(other);
+        assert_output_shape(self, out);
+        Zip::from(out)
+            .and(self)
+            .and(other)
+            .for_each(|o, &a, &b| *o = a + b);
+    }
+
+    fn sub_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_shape(other);
+        assert_output_shape(self, out);
+        Zip::from(out)
+            .and(self)
+            .and(other)
+            .for_each(|o, &a, &b| *o = a - b);
+    }
 }
@@ -0,0 +1,452 @@
+use crate::matrix::mat::Mat;
+use crate::matrix::matrix_trait::{
+    Matrix, assert_column_index, assert_column_length, assert_row_index, assert_row_length,
+};
+use crate::scalar::Scalar;
+use std::borrow::Cow;
+use std::iter::Iterator;
+use std::ops::{Index, IndexMut};
+
+/// Extremely basic statically-sized matrix type, written as `SMat<S, M, N>`, short for "static
+/// matrix".
+///
+/// # Implementation Details
+///
+/// * The underlying data structure is a `[[S; N]; M]` (i.e. an array of `M` rows, each of length
+///   `N`).
+/// * This matrix implementation is row-major; the rows of the matrix are stored contiguously, so
+///   the elements can also be viewed as a one-dimensional "flat" slice of length `M * N`.
+/// * The vector type compatible with this matrix type is the fixed-size array `[S; N]`.
+///
+/// # Motivation
+///
+/// [`Mat<S>`] provides a dependency-free dynamically-sized matrix type. `SMat<S, M, N>` is its
+/// statically-sized counterpart, for use with fixed-size arrays (e.g. in code without a heap
+/// allocator, or with const-generic state types).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SMat<S, const M: usize, const N: usize>
+where
+    S: Scalar,
+{
+    data: [[S; N]; M],
+}
+
+impl<S, const M: usize, const N: usize> SMat<S, M, N>
+where
+    S: Scalar,
+{
+    /// Returns an iterator over the elements of the matrix (in row-major order).
+    ///
+    /// # Returns
+    ///
+    /// An iterator that yields references to the elements of the matrix.
+    pub fn iter(&self) -> impl Iterator<Item = &S> {
+        self.data.as_flattened().iter()
+    }
+
+    /// Returns a mutable iterator over the elements of the matrix (in row-major order).
+    ///
+    /// # Returns
+    ///
+    /// An iterator that yields mutable references to the elements of the matrix.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut S> {
+        self.data.as_flattened_mut().iter_mut()
+    }
+}
+
+impl<S, const M: usize, const N: usize> From<[[S; N]; M]> for SMat<S, M, N>
+where
+    S: Scalar,
+{
+    fn from(data: [[S; N]; M]) -> Self {
+        SMat { data }
+    }
+}
+
+impl<S: Scalar, const M: usize, const N: usize> Index<(usize, usize)> for SMat<S, M, N> {
+    type Output = S;
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.data[row][col]
+    }
+}
+
+impl<S: Scalar, const M: usize, const N: usize> IndexMut<(usize, usize)> for SMat<S, M, N> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        &mut self.data[row][col]
+    }
+}
+
+impl<S, const M: usize, const N: usize> Matrix<S> for SMat<S, M, N>
+where
+    S: Scalar,
+{
+    type VectorM = [S; M];
+
+    type VectorN = [S; N];
+
+    type MatrixNxP<const P: usize> = SMat<S, N, P>;
+
+    type MatrixMxP<const P: usize> = SMat<S, M, P>;
+
+    type Transpose = SMat<S, N, M>;
+
+    type MatrixMxM = SMat<S, M, M>;
+
+    type MatrixNxN = SMat<S, N, N>;
+
+    type MatrixT<T: Scalar> = SMat<T, M, N>;
+
+    type DMatrixMxN = Mat<S>;
+
+    fn is_statically_sized() -> bool {
+        true
+    }
+
+    fn is_dynamically_sized() -> bool {
+        false
+    }
+
+    fn static_shape() -> Option<(usize, usize)> {
+        Some((M, N))
+    }
+
+    fn is_row_major() -> bool {
+        true
+    }
+
+    fn is_column_major() -> bool {
+        false
+    }
+
+    fn new_with_shape(rows: usize, cols: usize) -> Self {
+        assert_eq!(rows, M, "Row count mismatch.");
+        assert_eq!(cols, N, "Column count mismatch.");
+        SMat {
+            data: [[S::zero(); N]; M],
+        }
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (M, N)
+    }
+
+    fn from_row_slice(rows: usize, cols: usize, slice: &[S]) -> Self {
+        assert_eq!(rows, M, "Row count mismatch.");
+        assert_eq!(cols, N, "Column count mismatch.");
+        assert_eq!(
+            slice.len(),
+            M * N,
+            "Slice length ({}) not compatible with matrix dimensions ({}x{}).",
+            slice.len(),
+            M,
+            N,
+        );
+        SMat {
+            data: std::array::from_fn(|row| std::array::from_fn(|col| slice[row * N + col])),
+        }
+    }
+
+    fn from_col_slice(rows: usize, cols: usize, slice: &[S]) -> Self {
+        assert_eq!(rows, M, "Row count mismatch.");
+        assert_eq!(cols, N, "Column count mismatch.");
+        assert_eq!(
+            slice.len(),
+            M * N,
+            "Slice length ({}) not compatible with matrix dimensions ({}x{}).",
+            slice.len(),
+            M,
+            N,
+        );
+        SMat {
+            data: std::array::from_fn(|row| std::array::from_fn(|col| slice[row + col * M])),
+        }
+    }
+
+    fn as_slice(&self) -> Cow<'_, [S]> {
+        Cow::from(self.data.as_flattened())
+    }
+
+    fn try_as_contiguous_slice(&self) -> Option<&[S]> {
+        Some(self.data.as_flattened())
+    }
+
+    fn try_as_contiguous_mut_slice(&mut self) -> Option<&mut [S]> {
+        Some(self.data.as_flattened_mut())
+    }
+
+    fn get(&self, index: (usize, usize)) -> Option<&S> {
+        let (row, col) = index;
+        self.data.get(row).and_then(|data_row| data_row.get(col))
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a + b)
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        self.zip_apply(other, |a, b| *a += b);
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a - b)
+    }
+
+    fn sub_assign(&mut self, other: &Self) {
+        self.zip_apply(other, |a, b| *a -= b);
+    }
+
+    fn mul(&self, scalar: S) -> Self {
+        self.map(|a| a * scalar)
+    }
+
+    fn mul_assign(&mut self, scalar: S) {
+        self.map_in_place(|a| a * scalar);
+    }
+
+    fn div(&self, scalar: S) -> Self {
+        self.map(|a| a / scalar)
+    }
+
+    fn div_assign(&mut self, scalar: S) {
+        self.map_in_place(|a| a / scalar);
+    }
+
+    fn mul_vector(&self, vector: &[S; N]) -> [S; M] {
+        let mut out = [S::zero(); M];
+        self.mul_vector_to(vector, &mut out);
+        out
+    }
+
+    fn mul_vector_to(&self, vector: &[S; N], out: &mut [S; M]) {
+        for (out_row, data_row) in out.iter_mut().zip(self.data.iter()) {
+            *out_row = data_row
+                .iter()
+                .zip(vector.iter())
+                .fold(S::zero(), |acc, (a, b)| acc + *a * *b);
+        }
+    }
+
+    fn vector_mul(&self, vector: &[S; M]) -> [S; N] {
+        let mut out = [S::zero(); N];
+        self.vector_mul_to(vector, &mut out);
+        out
+    }
+
+    fn vector_mul_to(&self, vector: &[S; M], out: &mut [S; N]) {
+        // Accumulate row-by-row so that the matrix data is traversed contiguously.
+        out.fill(S::zero());
+        for (&scale, data_row) in vector.iter().zip(self.data.iter()) {
+            for (out_col, &a) in out.iter_mut().zip(data_row.iter()) {
+                *out_col += scale * a;
+            }
+        }
+    }
+
+    fn matmul<const P: usize>(&self, other: &SMat<S, N, P>) -> SMat<S, M, P> {
+        let mut out = SMat::new_with_shape(M, P);
+        self.matmul_to::<P>(other, &mut out);
+        out
+    }
+
+    fn matmul_to<const P: usize>(&self, other: &SMat<S, N, P>, out: &mut SMat<S, M, P>) {
+        for (out_row, data_row) in out.data.iter_mut().zip(self.data.iter()) {
+            out_row.fill(S::zero());
+            for (&scale, other_row) in data_row.iter().zip(other.data.iter()) {
+                for (out_col, &b) in out_row.iter_mut().zip(other_row.iter()) {
+                    *out_col += scale * b;
+                }
+            }
+        }
+    }
+
+    fn gemm(
+        &mut self,
+        alpha: S,
+        a: &Self,
+        transpose_a: bool,
+        b: &Self,
+        transpose_b: bool,
+        beta: S,
+    ) {
+        // All three matrices have shape (M, N), but op(a) and op(b) may be transposed, so the
+        // shapes can only be checked at runtime.
+        let (rows, inner) = if transpose_a { (N, M) } else { (M, N) };
+        let (inner_b, cols) = if transpose_b { (N, M) } else { (M, N) };
+        assert_eq!(
+            inner, inner_b,
+            "Matrices have incompatible shapes for multiplication ({rows}x{inner} and \
+            {inner_b}x{cols}).",
+        );
+        assert_eq!(
+            (M, N),
+            (rows, cols),
+            "Shape of the output matrix ({M}x{N}) does not match the shape of the product \
+            ({rows}x{cols}).",
+        );
+
+        // Elements of op(a) and op(b).
+        let a_at = |row: usize, col: usize| {
+            if transpose_a {
+                a.data[col][row]
+            } else {
+                a.data[row][col]
+            }
+        };
+        let b_at = |row: usize, col: usize| {
+            if transpose_b {
+                b.data[col][row]
+            } else {
+                b.data[row][col]
+            }
+        };
+
+        // Scale this matrix by beta (overwriting it without reading it if beta is zero).
+        if beta == S::zero() {
+            self.data = [[S::zero(); N]; M];
+        } else if beta != S::one() {
+            self.mul_assign(beta);
+        }
+
+        // Accumulate the product, traversing the rows of this matrix contiguously.
+        for (row, out_row) in self.data.iter_mut().enumerate() {
+            for idx in 0..inner {
+                let scale = alpha * a_at(row, idx);
+                for (col, out) in out_row.iter_mut().enumerate() {
+                    *out += scale * b_at(idx, col);
+                }
+            }
+        }
+    }
+
+    fn transpose(&self) -> SMat<S, N, M> {
+        SMat {
+            data: std::array::from_fn(|row| std::array::from_fn(|col| self.data[col][row])),
+        }
+    }
+
+    fn transpose_in_place(&mut self) {
+        // The shape is fixed at compile time, so only square matrices can be transposed in place.
+        self.assert_square();
+        for row in 0..M {
+            for col in (row + 1)..N {
+                let upper = self.data[row][col];
+                self.data[row][col] = self.data[col][row];
+                self.data[col][row] = upper;
+            }
+        }
+    }
+
+    fn map<F: FnMut(S) -> S>(&self, mut f: F) -> Self {
+        SMat {
+            data: self.data.map(|data_row| data_row.map(&mut f)),
+        }
+    }
+
+    fn map_in_place<F: FnMut(S) -> S>(&mut self, mut f: F) {
+        for a in self.iter_mut() {
+            *a = f(*a);
+        }
+    }
+
+    fn zip_map<F: FnMut(S, S) -> S>(&self, other: &Self, mut f: F) -> Self {
+        SMat {
+            data: std::array::from_fn(|row| {
+                std::array::from_fn(|col| f(self.data[row][col], other.data[row][col]))
+            }),
+        }
+    }
+
+    fn zip_apply<F: FnMut(&mut S, S)>(&mut self, other: &Self, mut f: F) {
+        for (a, b) in self.iter_mut().zip(other.iter()) {
+            f(a, *b);
+        }
+    }
+
+    fn fold<T, F: FnMut(T, S) -> T>(&self, init: T, f: F) -> T {
+        self.iter().copied().fold(init, f)
+    }
+
+    fn map_scalar<T: Scalar, F: FnMut(S) -> T>(&self, mut f: F) -> SMat<T, M, N> {
+        SMat {
+            data: self.data.map(|data_row| data_row.map(&mut f)),
+        }
+    }
+
+    fn row(&self, row: usize) -> [S; N] {
+        assert_row_index(self, row);
+        self.data[row]
+    }
+
+    fn column(&self, col: usize) -> [S; M] {
+        assert_column_index(self, col);
+        self.data.map(|data_row| data_row[col])
+    }
+
+    fn set_row(&mut self, row: usize, values: &[S; N]) {
+        assert_row_index(self, row);
+        assert_row_length(self, values.len());
+        self.data[row] = *values;
+    }
+
+    fn set_column(&mut self, col: usize, values: &[S; M]) {
+        assert_column_index(self, col);
+        assert_column_length(self, values.len());
+        for (data_row, &value) in self.data.iter_mut().zip(values.iter()) {
+            data_row[col] = value;
+        }
+    }
+
+    fn from_element(rows: usize, cols: usize, value: S) -> Self {
+        assert_eq!(rows, M, "Row count mismatch.");
+        assert_eq!(cols, N, "Column count mismatch.");
+        SMat {
+            data: [[value; N]; M],
+        }
+    }
+
+    fn from_fn<F: FnMut(usize, usize) -> S>(rows: usize, cols: usize, mut f: F) -> Self {
+        assert_eq!(rows, M, "Row count mismatch.");
+        assert_eq!(cols, N, "Column count mismatch.");
+        SMat {
+            data: std::array::from_fn(|row| std::array::from_fn(|col| f(row, col))),
+        }
+    }
+
+    fn add_to(&self, other: &Self, out: &mut Self) {
+        for ((o, a), b) in out.iter_mut().zip(self.iter()).zip(other.iter()) {
+            *o = *a + *b;
+        }
+    }
+
+    fn sub_to(&self, other: &Self, out: &mut Self) {
+        for ((o, a), b) in out.iter_mut().zip(self.iter()).zip(other.iter()) {
+            *o = *a - *b;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indexing() {
+        let mut mat = SMat::<f64, 2, 2>::new_with_shape(2, 2);
+        mat[(0, 0)] = 1.0;
+        mat[(0, 1)] = 2.0;
+        mat[(1, 0)] = 3.0;
+        mat[(1, 1)] = 4.0;
+        assert_eq!(mat[(0, 0)], 1.0);
+        assert_eq!(mat[(0, 1)], 2.0);
+        assert_eq!(mat[(1, 0)], 3.0);
+        assert_eq!(mat[(1, 1)], 4.0);
+    }
+
+    #[test]
+    fn test_from_nested_array() {
+        let mat = SMat::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(mat.shape(), (2, 3));
+        assert_eq!(mat[(1, 0)], 4.0);
+        assert_eq!(mat.as_slice().as_ref(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+}
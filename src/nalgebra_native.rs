@@ -0,0 +1,144 @@
+use crate::Scalar;
+use nalgebra::{ColPivQR, DMatrix, DMatrixView, DVector, Dim, LU, QR, StorageMut};
+
+/// LU decomposition with partial (row) pivoting computed using nalgebra.
+///
+/// # Arguments
+///
+/// * `matrix` - The square matrix to factor.
+///
+/// # Returns
+///
+/// A tuple containing `L` (strictly lower triangular part, with its unit diagonal implied) and `U`
+/// (upper triangular part) packed into a single matrix, and the row permutation (where row `i` of
+/// `P * A` is row `perm[i]` of `A`).
+pub(crate) fn lu<S: Scalar>(matrix: &DMatrix<f64>) -> (DMatrix<S>, Vec<usize>) {
+    let lu = LU::new(matrix.clone());
+
+    // Recover the row permutation by permuting a vector of row indices.
+    let mut indices = DVector::from_fn(matrix.nrows(), |row, _| row);
+    lu.p().permute_rows(&mut indices);
+    let perm = indices.iter().copied().collect();
+
+    // nalgebra stores the factors packed in the same layout as `Lu`.
+    (lu.lu_internal().map(S::new), perm)
+}
+
+/// QR decomposition (optionally with column pivoting) computed using nalgebra.
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix to factor.
+/// * `col_pivoting` - `true` to use nalgebra's column-pivoted QR decomposition, `false` to use its
+///   unpivoted QR decomposition.
+///
+/// # Returns
+///
+/// A tuple containing `R` (upper trapezoidal part) and the Householder vectors (strictly lower
+/// trapezoidal part, with their unit first elements implied) packed into a single matrix, the
+/// scaling factors of the Householder reflections, and the column permutation (where column `j` of
+/// `A * P` is column `perm[j]` of `A`).
+///
+/// # Note
+///
+/// nalgebra stores each Householder vector `uₖ` with unit norm, and applies each reflection with
+/// a sign that makes the diagonal of its `R` non-negative. Scaling `uₖ` to have a unit first
+/// element gives `vₖ = uₖ / uₖ₀` and `τₖ = 2 uₖ₀²`, and undoing the signs flips row `k` of `R`
+/// for every reflection with `uₖ₀ > 0` up to and including the `k`th one.
+pub(crate) fn qr<S: Scalar>(
+    matrix: &DMatrix<f64>,
+    col_pivoting: bool,
+) -> (DMatrix<S>, Vec<S>, Vec<usize>) {
+    let (rows, cols) = matrix.shape();
+    let (packed, r, perm) = if col_pivoting {
+        let qr = ColPivQR::new(matrix.clone());
+
+        // Recover the column permutation by permuting a vector of column indices.
+        let mut indices = DVector::from_fn(cols, |col, _| col);
+        qr.p().permute_rows(&mut indices);
+        (
+            qr.col_piv_qr_internal().clone(),
+            qr.r(),
+            indices.iter().copied().collect(),
+        )
+    } else {
+        let qr = QR::new(matrix.clone());
+        (qr.qr_internal().clone(), qr.r(), (0..cols).collect())
+    };
+
+    // Signs of the rows of `R` and scaling factors of the Householder reflections.
+    let size = rows.min(cols);
+    let mut sign = 1.0;
+    let mut signs = Vec::with_capacity(size);
+    let mut tau = Vec::with_capacity(size);
+    for k in 0..size {
+        let u0 = packed[(k, k)];
+        if u0 > 0.0 {
+            sign = -sign;
+        }
+        if u0 == 0.0 {
+            // nalgebra skips the reflection of a column that is already zero.
+            signs.push(sign);
+            tau.push(S::zero());
+        } else if packed.view_range(k + 1.., k).iter().all(|&u| u == 0.0) {
+            // A reflection with no elements below the diagonal only flips the sign of row k, so
+            // it is replaced by the identity (as in the default implementation).
+            signs.push(-sign);
+            tau.push(S::zero());
+        } else {
+            signs.push(sign);
+            tau.push(S::new(2.0 * u0 * u0));
+        }
+    }
+
+    let factors = DMatrix::from_fn(rows, cols, |row, col| {
+        if row <= col {
+            if row < size {
+                S::new(signs[row] * r[(row, col)])
+            } else {
+                S::zero()
+            }
+        } else if col < size && packed[(col, col)] != 0.0 {
+            S::new(packed[(row, col)] / packed[(col, col)])
+        } else {
+            S::zero()
+        }
+    });
+    (factors, tau, perm)
+}
+
+/// Overwrite a block of a nalgebra matrix with elements stored contiguously in a slice.
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix to write the block into.
+/// * `start` - Row and column indices at which the top-left element of the block is placed.
+/// * `shape` - Number of rows and columns of the block.
+/// * `slice` - Elements of the block.
+/// * `row_major` - `true` if the elements of the block are stored in row-major order, `false` if
+///   they are stored in column-major order.
+///
+/// # Panics
+///
+/// * If the block does not fit within the matrix.
+/// * If the length of `slice` does not match the number of elements of the block.
+pub(crate) fn copy_block<S, R, C, SA>(
+    matrix: &mut nalgebra::Matrix<S, R, C, SA>,
+    start: (usize, usize),
+    shape: (usize, usize),
+    slice: &[S],
+    row_major: bool,
+) where
+    S: Scalar,
+    R: Dim,
+    C: Dim,
+    SA: StorageMut<S, R, C>,
+{
+    let (nrows, ncols) = shape;
+    let mut target = matrix.view_mut(start, shape);
+    if row_major {
+        target.tr_copy_from(&DMatrixView::from_slice(slice, ncols, nrows));
+    } else {
+        target.copy_from(&DMatrixView::from_slice(slice, nrows, ncols));
+    }
+}
@@ -1,4 +1,6 @@
 use num_traits::Float;
+#[cfg(any(feature = "nalgebra", feature = "ndarray"))]
+use std::any::Any;
 use std::fmt::Debug;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
 
@@ -153,3 +155,28 @@ pub trait Scalar: ScalarBase + NdarrayScalar + FaerScalar {
 }
 
 impl<T> Scalar for T where T: ScalarBase + NdarrayScalar + FaerScalar {}
+
+/// View a backend vector or matrix as the same type with `f64` as its scalar type.
+///
+/// # Arguments
+///
+/// * `value` - The vector or matrix to view.
+///
+/// # Returns
+///
+/// The vector or matrix as a `U` (e.g. a `DMatrix<f64>`) if its scalar type is `f64`, or `None`
+/// otherwise.
+///
+/// # Note
+///
+/// Some backend implementations have trait bounds that are not implied by [`Scalar`] (e.g.
+/// nalgebra's decompositions and norms require [`nalgebra::ComplexField`], which is not
+/// implemented for dual numbers, and ndarray's mean requires [`num_traits::FromPrimitive`]). This
+/// helper lets us use these implementations when the scalar type is `f64`, falling back to the
+/// default implementations otherwise. `f64` is the only primitive floating-point type implementing
+/// [`Scalar`] (`f32` does not support arithmetic with `f64`s), so it is the only scalar type we
+/// dispatch on.
+#[cfg(any(feature = "nalgebra", feature = "ndarray"))]
+pub(crate) fn as_f64<U: Any, T: Any>(value: &T) -> Option<&U> {
+    (value as &dyn Any).downcast_ref::<U>()
+}
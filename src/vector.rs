@@ -1,4 +1,6 @@
 // Module declarations.
+pub(crate) mod array;
+
 #[cfg(feature = "faer")]
 pub(crate) mod faer_col;
 
@@ -11,5 +13,6 @@ pub(crate) mod nalgebra_svector;
 #[cfg(feature = "ndarray")]
 pub(crate) mod ndarray_array1;
 
+pub(crate) mod nan_policy;
 pub(crate) mod vec;
 pub(crate) mod vector_trait;
@@ -0,0 +1,194 @@
+use crate::error::LinalgError;
+use crate::matrix::mat::Mat;
+use crate::matrix::smat::SMat;
+use crate::scalar::Scalar;
+use crate::vector::vector_trait::{Vector, assert_output_length};
+use std::borrow::Cow;
+
+impl<S: Scalar, const N: usize> Vector<S> for [S; N] {
+    type VectorT<T: Scalar> = [T; N];
+
+    type DVectorT<T: Scalar> = Vec<T>;
+
+    type Vectorf64 = [f64; N];
+
+    type DVectorf64 = Vec<f64>;
+
+    type MatrixNxN = SMat<S, N, N>;
+
+    type MatrixMxN<const M: usize> = SMat<S, M, N>;
+
+    type DMatrixMxN = Mat<S>;
+
+    type DMatrixMxNf64 = Mat<f64>;
+
+    type MatrixNxM<const M: usize> = SMat<S, N, M>;
+
+    type DMatrixNxM = Mat<S>;
+
+    fn is_statically_sized() -> bool {
+        true
+    }
+
+    fn is_dynamically_sized() -> bool {
+        false
+    }
+
+    fn static_length() -> Option<usize> {
+        Some(N)
+    }
+
+    fn new_with_length(len: usize) -> Self {
+        assert_eq!(len, N, "Length must match the fixed size of the array.");
+        [S::zero(); N]
+    }
+
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    fn from_slice(slice: &[S]) -> Self {
+        assert_eq!(
+            slice.len(),
+            N,
+            "Length must match the fixed size of the array."
+        );
+        std::array::from_fn(|i| slice[i])
+    }
+
+    fn as_slice(&self) -> Cow<'_, [S]> {
+        Cow::from(&self[..])
+    }
+
+    fn try_as_contiguous_slice(&self) -> Option<&[S]> {
+        Some(self)
+    }
+
+    fn try_as_contiguous_mut_slice(&mut self) -> Option<&mut [S]> {
+        Some(self)
+    }
+
+    fn get(&self, idx: usize) -> Option<&S> {
+        self[..].get(idx)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        std::array::from_fn(|i| self[i] + other[i])
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        for (a, b) in self.iter_mut().zip(other.iter()) {
+            *a += *b;
+        }
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        std::array::from_fn(|i| self[i] - other[i])
+    }
+
+    fn sub_assign(&mut self, other: &Self) {
+        for (a, b) in self.iter_mut().zip(other.iter()) {
+            *a -= *b;
+        }
+    }
+
+    fn mul(&self, scalar: S) -> Self {
+        std::array::from_fn(|i| self[i] * scalar)
+    }
+
+    fn mul_assign(&mut self, scalar: S) {
+        for a in self.iter_mut() {
+            *a *= scalar;
+        }
+    }
+
+    fn div(&self, scalar: S) -> Self {
+        std::array::from_fn(|i| self[i] / scalar)
+    }
+
+    fn div_assign(&mut self, scalar: S) {
+        for a in self.iter_mut() {
+            *a /= scalar;
+        }
+    }
+
+    fn dot(&self, other: &Self) -> S {
+        self.iter()
+            .zip(other.iter())
+            .fold(S::zero(), |acc, (a, b)| acc + *a * *b)
+    }
+
+    fn map<F: FnMut(S) -> S>(&self, mut f: F) -> Self {
+        std::array::from_fn(|i| f(self[i]))
+    }
+
+    fn map_in_place<F: FnMut(S) -> S>(&mut self, mut f: F) {
+        for a in self.iter_mut() {
+            *a = f(*a);
+        }
+    }
+
+    fn zip_map<F: FnMut(S, S) -> S>(&self, other: &Self, mut f: F) -> Self {
+        std::array::from_fn(|i| f(self[i], other[i]))
+    }
+
+    fn zip_apply<F: FnMut(&mut S, S)>(&mut self, other: &Self, mut f: F) {
+        for (a, b) in self.iter_mut().zip(other.iter()) {
+            f(a, *b);
+        }
+    }
+
+    fn fold<T, F: FnMut(T, S) -> T>(&self, init: T, f: F) -> T {
+        self.iter().copied().fold(init, f)
+    }
+
+    fn map_scalar<T: Scalar, F: FnMut(S) -> T>(&self, mut f: F) -> [T; N] {
+        std::array::from_fn(|i| f(self[i]))
+    }
+
+    fn try_from_iter<I: IntoIterator<Item = S>>(iter: I) -> Result<[S; N], LinalgError> {
+        let mut iter = iter.into_iter();
+        let mut result = [S::zero(); N];
+        for (i, item) in result.iter_mut().enumerate() {
+            *item = iter.next().ok_or(LinalgError::LengthMismatch {
+                expected: N,
+                actual: i,
+            })?;
+        }
+        match iter.count() {
+            0 => Ok(result),
+            extra => Err(LinalgError::LengthMismatch {
+                expected: N,
+                actual: N + extra,
+            }),
+        }
+    }
+
+    fn from_fn<F: FnMut(usize) -> S>(len: usize, f: F) -> [S; N] {
+        assert_eq!(len, N, "Length must match the fixed size of the array.");
+        std::array::from_fn(f)
+    }
+
+    fn from_element(len: usize, value: S) -> [S; N] {
+        assert_eq!(len, N, "Length must match the fixed size of the array.");
+        [value; N]
+    }
+
+    fn add_to(&self, other: &Self, out: &mut Self) {
+        assert_output_length(self, out);
+        for ((o, a), b) in out.iter_mut().zip(self.iter()).zip(other.iter()) {
+            *o = *a + *b;
+        }
+    }
+
+    fn sub_to(&self, other: &Self, out: &mut Self) {
+        assert_output_length(self, out);
+        for ((o, a), b) in out.iter_mut().zip(self.iter()).zip(other.iter()) {
+            *o = *a - *b;
+        }
+    }
+}
@@ -1,5 +1,6 @@
-use crate::{Scalar, Vector};
-use faer::{Col, Mat, Scale};
+use crate::vector::vector_trait::{assert_output_length, default_sum};
+use crate::{NanPolicy, Scalar, Vector};
+use faer::{Col, ColMut, ColRef, Mat, Scale, unzip, zip};
 use faer_traits::RealField;
 use std::borrow::Cow;
 
@@ -32,6 +33,10 @@ impl<S: Scalar + RealField> Vector<S> for Col<S> {
         true
     }
 
+    fn static_length() -> Option<usize> {
+        None
+    }
+
     fn new_with_length(len: usize) -> Self {
         Col::<S>::zeros(len)
     }
@@ -49,7 +54,18 @@ impl<S: Scalar + RealField> Vector<S> for Col<S> {
     }
 
     fn as_slice(&self) -> Cow<'_, [S]> {
-        Cow::Owned(self.iter().copied().collect())
+        match self.try_as_contiguous_slice() {
+            Some(slice) => Cow::Borrowed(slice),
+            None => Cow::Owned(self.iter().copied().collect()),
+        }
+    }
+
+    fn try_as_contiguous_slice(&self) -> Option<&[S]> {
+        self.try_as_col_major().map(ColRef::as_slice)
+    }
+
+    fn try_as_contiguous_mut_slice(&mut self) -> Option<&mut [S]> {
+        self.try_as_col_major_mut().map(ColMut::as_slice_mut)
     }
 
     fn get(&self, idx: usize) -> Option<&S> {
@@ -100,4 +116,83 @@ impl<S: Scalar + RealField> Vector<S> for Col<S> {
         }
         dot_product
     }
+
+    fn map<F: FnMut(S) -> S>(&self, mut f: F) -> Self {
+        zip!(self).map(|unzip!(a)| f(*a))
+    }
+
+    fn map_in_place<F: FnMut(S) -> S>(&mut self, mut f: F) {
+        zip!(self).for_each(|unzip!(a)| *a = f(*a));
+    }
+
+    fn zip_map<F: FnMut(S, S) -> S>(&self, other: &Self, mut f: F) -> Self {
+        self.assert_same_length(other);
+        zip!(self, other).map(|unzip!(a, b)| f(*a, *b))
+    }
+
+    fn zip_apply<F: FnMut(&mut S, S)>(&mut self, other: &Self, mut f: F) {
+        self.assert_same_length(other);
+        zip!(self, other).for_each(|unzip!(a, b)| f(a, *b));
+    }
+
+    fn fold<T, F: FnMut(T, S) -> T>(&self, init: T, f: F) -> T {
+        self.iter().copied().fold(init, f)
+    }
+
+    fn map_scalar<T: Scalar, F: FnMut(S) -> T>(&self, mut f: F) -> Col<T> {
+        zip!(self).map(|unzip!(a)| f(*a))
+    }
+
+    fn from_fn<F: FnMut(usize) -> S>(len: usize, f: F) -> Col<S> {
+        Col::from_fn(len, f)
+    }
+
+    fn from_element(len: usize, value: S) -> Col<S> {
+        Col::full(len, value)
+    }
+
+    fn norm_squared(&self) -> S {
+        self.as_ref().squared_norm_l2()
+    }
+
+    fn norm(&self) -> S {
+        self.as_ref().norm_l2()
+    }
+
+    fn norm_l1(&self) -> S {
+        self.as_ref().norm_l1()
+    }
+
+    fn add_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_length(other);
+        assert_output_length(self, out);
+        zip!(out, self, other).for_each(|unzip!(o, a, b)| *o = *a + *b);
+    }
+
+    fn sub_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_length(other);
+        assert_output_length(self, out);
+        zip!(out, self, other).for_each(|unzip!(o, a, b)| *o = *a - *b);
+    }
+
+    fn axpy(&mut self, alpha: S, x: &Self) {
+        self.assert_same_length(x);
+        zip!(self, x).for_each(|unzip!(a, b)| *a += alpha * *b);
+    }
+
+    fn axpby(&mut self, alpha: S, x: &Self, beta: S) {
+        self.assert_same_length(x);
+        if beta.is_zero() {
+            zip!(self, x).for_each(|unzip!(a, b)| *a = alpha * *b);
+        } else {
+            zip!(self, x).for_each(|unzip!(a, b)| *a = alpha * *b + beta * *a);
+        }
+    }
+
+    fn sum(&self, nan_policy: NanPolicy) -> S {
+        match nan_policy {
+            NanPolicy::Propagate => self.as_ref().sum(),
+            NanPolicy::Ignore => default_sum(self, nan_policy),
+        }
+    }
 }
@@ -1,4 +1,9 @@
-use crate::{Scalar, Vector};
+use crate::scalar::as_f64;
+use crate::vector::vector_trait::{
+    assert_output_length, default_mean, default_product, default_sum, default_variance,
+    scaled_l2_norm,
+};
+use crate::{LinalgError, NanPolicy, Scalar, Vector};
 use nalgebra::{DMatrix, DVector, SMatrix, SVector};
 use std::borrow::Cow;
 
@@ -31,6 +36,10 @@ impl<const N: usize, S: Scalar> Vector<S> for SVector<S, N> {
         false
     }
 
+    fn static_length() -> Option<usize> {
+        Some(N)
+    }
+
     fn new_with_length(len: usize) -> Self {
         assert_eq!(len, N, "Length must match the fixed size of the SVector.");
         SVector::from_element(S::zero())
@@ -56,6 +65,14 @@ impl<const N: usize, S: Scalar> Vector<S> for SVector<S, N> {
         Cow::from(SVector::as_slice(self))
     }
 
+    fn try_as_contiguous_slice(&self) -> Option<&[S]> {
+        Some(SVector::as_slice(self))
+    }
+
+    fn try_as_contiguous_mut_slice(&mut self) -> Option<&mut [S]> {
+        Some(SVector::as_mut_slice(self))
+    }
+
     fn get(&self, idx: usize) -> Option<&S> {
         SVector::get(self, idx)
     }
@@ -95,4 +112,130 @@ impl<const N: usize, S: Scalar> Vector<S> for SVector<S, N> {
     fn dot(&self, other: &Self) -> S {
         self.dot(other)
     }
+
+    fn map<F: FnMut(S) -> S>(&self, f: F) -> Self {
+        self.map(f)
+    }
+
+    fn map_in_place<F: FnMut(S) -> S>(&mut self, mut f: F) {
+        self.apply(|a| *a = f(*a));
+    }
+
+    fn zip_map<F: FnMut(S, S) -> S>(&self, other: &Self, f: F) -> Self {
+        self.assert_same_length(other);
+        self.zip_map(other, f)
+    }
+
+    fn zip_apply<F: FnMut(&mut S, S)>(&mut self, other: &Self, f: F) {
+        self.assert_same_length(other);
+        self.zip_apply(other, f);
+    }
+
+    fn fold<T, F: FnMut(T, S) -> T>(&self, init: T, f: F) -> T {
+        self.fold(init, f)
+    }
+
+    fn map_scalar<T: Scalar, F: FnMut(S) -> T>(&self, f: F) -> SVector<T, N> {
+        self.map(f)
+    }
+
+    fn try_from_iter<I: IntoIterator<Item = S>>(iter: I) -> Result<SVector<S, N>, LinalgError> {
+        let mut iter = iter.into_iter();
+        let mut result = SVector::<S, N>::from_element(S::zero());
+        for (i, item) in result.iter_mut().enumerate() {
+            *item = iter.next().ok_or(LinalgError::LengthMismatch {
+                expected: N,
+                actual: i,
+            })?;
+        }
+        match iter.count() {
+            0 => Ok(result),
+            extra => Err(LinalgError::LengthMismatch {
+                expected: N,
+                actual: N + extra,
+            }),
+        }
+    }
+
+    fn from_fn<F: FnMut(usize) -> S>(len: usize, mut f: F) -> SVector<S, N> {
+        assert_eq!(len, N, "Length must match the fixed size of the SVector.");
+        SVector::from_fn(|i, _| f(i))
+    }
+
+    fn from_element(len: usize, value: S) -> SVector<S, N> {
+        assert_eq!(len, N, "Length must match the fixed size of the SVector.");
+        SVector::from_element(value)
+    }
+
+    fn norm(&self) -> S {
+        // nalgebra does not rescale when computing the norm, so fall back to the scaled algorithm
+        // if the sum of squares overflowed or underflowed.
+        if let Some(vector) = as_f64::<SVector<f64, N>, _>(self) {
+            let norm = vector.norm();
+            if norm.is_finite() && norm >= f64::MIN_POSITIVE.sqrt() {
+                return S::new(norm);
+            }
+        }
+        scaled_l2_norm(self.iter().copied())
+    }
+
+    fn add_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_length(other);
+        assert_output_length(self, out);
+        self.add_to(other, out);
+    }
+
+    fn sub_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_length(other);
+        assert_output_length(self, out);
+        self.sub_to(other, out);
+    }
+
+    fn axpy(&mut self, alpha: S, x: &Self) {
+        self.assert_same_length(x);
+        self.axpy(alpha, x, S::one());
+    }
+
+    fn axpby(&mut self, alpha: S, x: &Self, beta: S) {
+        self.assert_same_length(x);
+        self.axpy(alpha, x, beta);
+    }
+
+    fn sum(&self, nan_policy: NanPolicy) -> S {
+        match nan_policy {
+            NanPolicy::Propagate => self.sum(),
+            NanPolicy::Ignore => default_sum(self, nan_policy),
+        }
+    }
+
+    fn product(&self, nan_policy: NanPolicy) -> S {
+        match nan_policy {
+            NanPolicy::Propagate => self.product(),
+            NanPolicy::Ignore => default_product(self, nan_policy),
+        }
+    }
+
+    fn mean(&self, nan_policy: NanPolicy) -> S {
+        // nalgebra returns 0 for the mean of an empty vector (instead of NaN), so it is only used
+        // for non-empty vectors.
+        if nan_policy == NanPolicy::Propagate
+            && !self.is_empty()
+            && let Some(vector) = as_f64::<SVector<f64, N>, _>(self)
+        {
+            return S::new(vector.mean());
+        }
+        default_mean(self, nan_policy)
+    }
+
+    fn variance(&self, nan_policy: NanPolicy) -> S {
+        // nalgebra returns 0 for the variance of an empty vector (instead of NaN), so it is only
+        // used for non-empty vectors.
+        if nan_policy == NanPolicy::Propagate
+            && !self.is_empty()
+            && let Some(vector) = as_f64::<SVector<f64, N>, _>(self)
+        {
+            return S::new(vector.variance());
+        }
+        default_variance(self, nan_policy)
+    }
 }
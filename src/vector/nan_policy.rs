@@ -0,0 +1,39 @@
+use crate::scalar::Scalar;
+
+/// Policy for handling NaN elements in reductions (e.g. [`crate::Vector::sum`] or
+/// [`crate::Vector::max`]).
+///
+/// # Example
+///
+/// ```
+/// use linalg_traits::{NanPolicy, Vector};
+///
+/// let x = vec![1.0, f64::NAN, 3.0];
+/// assert!(x.max(NanPolicy::Propagate).is_nan());
+/// assert_eq!(x.max(NanPolicy::Ignore), 3.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NanPolicy {
+    /// The result is NaN if any element is NaN (for reductions returning an index, the index of
+    /// the first NaN is returned).
+    #[default]
+    Propagate,
+
+    /// NaN elements are skipped, so that the result is computed from the remaining elements only.
+    Ignore,
+}
+
+impl NanPolicy {
+    /// Determine whether a reduction should skip an element under this policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The element.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `x` is NaN and NaNs are ignored, `false` otherwise.
+    pub(crate) fn skips<S: Scalar>(self, x: S) -> bool {
+        self == NanPolicy::Ignore && x.is_nan()
+    }
+}
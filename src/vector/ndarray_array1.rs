@@ -1,6 +1,10 @@
-use crate::{Scalar, Vector};
+use crate::scalar::as_f64;
+use crate::vector::vector_trait::{
+    assert_output_length, default_mean, default_product, default_sum,
+};
+use crate::{LinalgError, NanPolicy, Scalar, Vector};
 use ndarray::linalg::Dot;
-use ndarray::{Array1, Array2, LinalgScalar, ScalarOperand};
+use ndarray::{Array1, Array2, LinalgScalar, ScalarOperand, Zip};
 use std::borrow::Cow;
 
 impl<S: Scalar + ScalarOperand + LinalgScalar> Vector<S> for Array1<S> {
@@ -32,6 +36,10 @@ impl<S: Scalar + ScalarOperand + LinalgScalar> Vector<S> for Array1<S> {
         true
     }
 
+    fn static_length() -> Option<usize> {
+        None
+    }
+
     fn new_with_length(len: usize) -> Self {
         Array1::<S>::zeros(len)
     }
@@ -49,12 +57,20 @@ impl<S: Scalar + ScalarOperand + LinalgScalar> Vector<S> for Array1<S> {
     }
 
     fn as_slice(&self) -> Cow<'_, [S]> {
-        match self.as_slice_memory_order() {
-            Some(slice) => Cow::from(slice),
-            None => panic!("Array1 is not in standard layout for as_slice conversion"),
+        match self.try_as_contiguous_slice() {
+            Some(slice) => Cow::Borrowed(slice),
+            None => Cow::Owned(self.iter().copied().collect()),
         }
     }
 
+    fn try_as_contiguous_slice(&self) -> Option<&[S]> {
+        self.view().to_slice()
+    }
+
+    fn try_as_contiguous_mut_slice(&mut self) -> Option<&mut [S]> {
+        self.view_mut().into_slice()
+    }
+
     fn get(&self, idx: usize) -> Option<&S> {
         match self.as_slice_memory_order() {
             Some(slice) => slice.get(idx),
@@ -113,4 +129,101 @@ impl<S: Scalar + ScalarOperand + LinalgScalar> Vector<S> for Array1<S> {
     fn dot(&self, other: &Self) -> S {
         Dot::dot(self, other)
     }
+
+    fn map<F: FnMut(S) -> S>(&self, f: F) -> Self {
+        self.mapv(f)
+    }
+
+    fn map_in_place<F: FnMut(S) -> S>(&mut self, f: F) {
+        self.mapv_inplace(f);
+    }
+
+    fn zip_map<F: FnMut(S, S) -> S>(&self, other: &Self, mut f: F) -> Self {
+        self.assert_same_length(other);
+        Zip::from(self).and(other).map_collect(|a, b| f(*a, *b))
+    }
+
+    fn zip_apply<F: FnMut(&mut S, S)>(&mut self, other: &Self, mut f: F) {
+        self.assert_same_length(other);
+        Zip::from(self).and(other).for_each(|a, b| f(a, *b));
+    }
+
+    fn fold<T, F: FnMut(T, S) -> T>(&self, init: T, mut f: F) -> T {
+        self.view().fold(init, |acc, a| f(acc, *a))
+    }
+
+    fn map_scalar<T: Scalar, F: FnMut(S) -> T>(&self, f: F) -> Array1<T> {
+        self.mapv(f)
+    }
+
+    fn try_from_iter<I: IntoIterator<Item = S>>(iter: I) -> Result<Array1<S>, LinalgError> {
+        Ok(Array1::from_iter(iter))
+    }
+
+    fn from_fn<F: FnMut(usize) -> S>(len: usize, f: F) -> Array1<S> {
+        Array1::from_shape_fn(len, f)
+    }
+
+    fn from_element(len: usize, value: S) -> Array1<S> {
+        Array1::from_elem(len, value)
+    }
+
+    fn add_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_length(other);
+        assert_output_length(self, out);
+        Zip::from(out)
+            .and(self)
+            .and(other)
+            .for_each(|o, &a, &b| *o = a + b);
+    }
+
+    fn sub_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_length(other);
+        assert_output_length(self, out);
+        Zip::from(out)
+            .and(self)
+            .and(other)
+            .for_each(|o, &a, &b| *o = a - b);
+    }
+
+    fn axpy(&mut self, alpha: S, x: &Self) {
+        self.assert_same_length(x);
+        self.scaled_add(alpha, x);
+    }
+
+    fn axpby(&mut self, alpha: S, x: &Self, beta: S) {
+        self.assert_same_length(x);
+        if beta.is_zero() {
+            Zip::from(self).and(x).for_each(|a, &b| *a = alpha * b);
+        } else {
+            Zip::from(self)
+                .and(x)
+                .for_each(|a, &b| *a = alpha * b + beta * *a);
+        }
+    }
+
+    fn sum(&self, nan_policy: NanPolicy) -> S {
+        match nan_policy {
+            NanPolicy::Propagate => self.view().sum(),
+            NanPolicy::Ignore => default_sum(self, nan_policy),
+        }
+    }
+
+    fn product(&self, nan_policy: NanPolicy) -> S {
+        match nan_policy {
+            NanPolicy::Propagate => self.view().product(),
+            NanPolicy::Ignore => default_product(self, nan_policy),
+        }
+    }
+
+    fn mean(&self, nan_policy: NanPolicy) -> S {
+        // ndarray returns `None` for the mean of an empty array, in which case the default
+        // implementation returns NaN.
+        if nan_policy == NanPolicy::Propagate
+            && let Some(mean) = as_f64::<Array1<f64>, _>(self).and_then(|array| array.view().mean())
+        {
+            return S::new(mean);
+        }
+        default_mean(self, nan_policy)
+    }
 }
@@ -1,6 +1,7 @@
+use crate::error::LinalgError;
 use crate::matrix::mat::Mat;
 use crate::scalar::Scalar;
-use crate::vector::vector_trait::Vector;
+use crate::vector::vector_trait::{Vector, assert_output_length};
 use std::borrow::Cow;
 
 impl<S: Scalar> Vector<S> for Vec<S> {
@@ -32,6 +33,10 @@ impl<S: Scalar> Vector<S> for Vec<S> {
         true
     }
 
+    fn static_length() -> Option<usize> {
+        None
+    }
+
     fn new_with_length(len: usize) -> Vec<S> {
         vec![S::zero(); len]
     }
@@ -52,6 +57,14 @@ impl<S: Scalar> Vector<S> for Vec<S> {
         Cow::from(self.as_slice())
     }
 
+    fn try_as_contiguous_slice(&self) -> Option<&[S]> {
+        Some(self.as_slice())
+    }
+
+    fn try_as_contiguous_mut_slice(&mut self) -> Option<&mut [S]> {
+        Some(self.as_mut_slice())
+    }
+
     fn get(&self, idx: usize) -> Option<&S> {
         self.as_slice().get(idx)
     }
@@ -118,4 +131,65 @@ impl<S: Scalar> Vector<S> for Vec<S> {
         }
         result
     }
+
+    fn map<F: FnMut(S) -> S>(&self, f: F) -> Self {
+        self.iter().copied().map(f).collect()
+    }
+
+    fn map_in_place<F: FnMut(S) -> S>(&mut self, mut f: F) {
+        for a in self.iter_mut() {
+            *a = f(*a);
+        }
+    }
+
+    fn zip_map<F: FnMut(S, S) -> S>(&self, other: &Self, mut f: F) -> Self {
+        self.assert_same_length(other);
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| f(*a, *b))
+            .collect()
+    }
+
+    fn zip_apply<F: FnMut(&mut S, S)>(&mut self, other: &Self, mut f: F) {
+        self.assert_same_length(other);
+        for (a, b) in self.iter_mut().zip(other.iter()) {
+            f(a, *b);
+        }
+    }
+
+    fn fold<T, F: FnMut(T, S) -> T>(&self, init: T, f: F) -> T {
+        self.iter().copied().fold(init, f)
+    }
+
+    fn map_scalar<T: Scalar, F: FnMut(S) -> T>(&self, f: F) -> Vec<T> {
+        self.iter().copied().map(f).collect()
+    }
+
+    fn try_from_iter<I: IntoIterator<Item = S>>(iter: I) -> Result<Vec<S>, LinalgError> {
+        Ok(iter.into_iter().collect())
+    }
+
+    fn from_fn<F: FnMut(usize) -> S>(len: usize, f: F) -> Vec<S> {
+        (0..len).map(f).collect()
+    }
+
+    fn from_element(len: usize, value: S) -> Vec<S> {
+        vec![value; len]
+    }
+
+    fn add_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_length(other);
+        assert_output_length(self, out);
+        for ((o, a), b) in out.iter_mut().zip(self.iter()).zip(other.iter()) {
+            *o = *a + *b;
+        }
+    }
+
+    fn sub_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_length(other);
+        assert_output_length(self, out);
+        for ((o, a), b) in out.iter_mut().zip(self.iter()).zip(other.iter()) {
+            *o = *a - *b;
+        }
+    }
 }
@@ -1,5 +1,7 @@
+use crate::error::LinalgError;
 use crate::matrix::matrix_trait::Matrix;
 use crate::scalar::Scalar;
+use crate::vector::nan_policy::NanPolicy;
 use std::borrow::Cow;
 use std::fmt::Debug;
 use std::ops::{Index, IndexMut};
@@ -487,23 +489,1385 @@ pub trait Vector<S: Scalar>:
         Self::DMatrixNxM::new_with_shape(self.len(), m)
     }
 
+    /// Create a vector by evaluating a function at each index.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `len` - Desired length of the vector.
+    /// * `f` - Function mapping an index `i` to the element `xᵢ`.
+    /// 
+    /// # Returns
+    /// 
+    /// Vector with the specified length whose elements are `f(i)`.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `len` does not match the length of the vector (for statically-sized vectors only).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use linalg_traits::Vector;
+    /// 
+    /// let x: Vec<f64> = Vector::from_fn(4, |i| (i * i) as f64);
+    /// assert_eq!(x, vec![0.0, 1.0, 4.0, 9.0]);
+    /// ```
+    fn from_fn<F: FnMut(usize) -> S>(len: usize, mut f: F) -> Self {
+        let mut out = Self::new_with_length(len);
+        for i in 0..len {
+            out[i] = f(i);
+        }
+        out
+    }
+
+    /// Create a vector with every element set to the same value.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `len` - Desired length of the vector.
+    /// * `value` - Value of every element.
+    /// 
+    /// # Returns
+    /// 
+    /// Vector with the specified length, with each element set to `value`.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `len` does not match the length of the vector (for statically-sized vectors only).
+    fn from_element(len: usize, value: S) -> Self {
+        Self::from_fn(len, |_| value)
+    }
+
+    /// Create a vector from an iterator over scalars.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `iter` - The iterator whose items initialize the vector.
+    /// 
+    /// # Returns
+    /// 
+    /// A vector containing the items of the iterator.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if the number of items does not match the length of the
+    ///   vector (for statically-sized vectors only).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use linalg_traits::Vector;
+    /// 
+    /// let x: Vec<f64> = Vector::try_from_iter((1..=3).map(f64::from)).unwrap();
+    /// assert_eq!(x, vec![1.0, 2.0, 3.0]);
+    /// ```
+    fn try_from_iter<I: IntoIterator<Item = S>>(iter: I) -> Result<Self, LinalgError> {
+        let values: Vec<S> = iter.into_iter().collect();
+        Self::try_from_slice(&values)
+    }
+
+    /// Create a vector of evenly spaced values over a closed interval.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `start` - First element of the vector.
+    /// * `stop` - Last element of the vector.
+    /// * `len` - Desired length of the vector.
+    /// 
+    /// # Returns
+    /// 
+    /// Vector of `len` evenly spaced values from `start` to `stop` (inclusive). A length-1 vector
+    /// contains only `start`.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `len` does not match the length of the vector (for statically-sized vectors only).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use linalg_traits::Vector;
+    /// 
+    /// let x: Vec<f64> = Vector::linspace(0.0, 1.0, 5);
+    /// assert_eq!(x, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// ```
+    #[allow(clippy::cast_precision_loss)]
+    fn linspace(start: S, stop: S, len: usize) -> Self {
+        if len == 1 {
+            return Self::from_element(1, start);
+        }
+        let step = (stop - start) / S::new(len.saturating_sub(1) as f64);
+        Self::from_fn(len, |i| {
+            if i + 1 == len {
+                stop
+            } else {
+                start + step * S::new(i as f64)
+            }
+        })
+    }
+
+    /// Create a vector of values evenly spaced on a logarithmic scale.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `start` - Exponent of the first element of the vector (i.e. the first element is
+    ///   `base^start`).
+    /// * `stop` - Exponent of the last element of the vector (i.e. the last element is
+    ///   `base^stop`).
+    /// * `len` - Desired length of the vector.
+    /// * `base` - Base of the logarithmic scale.
+    /// 
+    /// # Returns
+    /// 
+    /// Vector whose elements are `base^yᵢ`, where `y` is [`Vector::linspace`]`(start, stop, len)`.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `len` does not match the length of the vector (for statically-sized vectors only).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use linalg_traits::Vector;
+    /// 
+    /// let x: Vec<f64> = Vector::logspace(0.0, 3.0, 4, 10.0);
+    /// assert_eq!(x, vec![1.0, 10.0, 100.0, 1000.0]);
+    /// ```
+    fn logspace(start: S, stop: S, len: usize, base: S) -> Self {
+        let mut out = Self::linspace(start, stop, len);
+        out.map_in_place(|y| base.powf(y));
+        out
+    }
+
+    /// Create a standard basis vector (i.e. a unit vector along one axis).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `len` - Desired length of the vector.
+    /// * `idx` - Index of the element set to 1.
+    /// 
+    /// # Returns
+    /// 
+    /// Vector with the specified length whose element at index `idx` is 1, with every other
+    /// element set to 0.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `idx` is out of bounds (i.e. `idx >= len`).
+    /// * If `len` does not match the length of the vector (for statically-sized vectors only).
+    #[must_use]
+    fn basis(len: usize, idx: usize) -> Self {
+        assert!(
+            idx < len,
+            "Index ({idx}) is out of bounds for a vector of length {len}."
+        );
+        let mut out = Self::new_with_length(len);
+        out[idx] = S::one();
+        out
+    }
+
     /// Assert that this vector and another vector have the same length. 
     /// 
     /// # Arguments
     /// 
-    /// * `other` - The other vector whose length we are comparing with this vector.
+    /// * `other` - The other vector whose length we are comparing with this vector.
+    /// 
+    /// # Panics
+    /// 
+    /// * If the length of the other vector is not equal to the length of this vector.
+    fn assert_same_length(&self, other: &Self) {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "Length of the other vector ({}) does not match the length of this vector ({}).",
+            other.len(),
+            self.len()
+        );
+    }
+
+    /// Call a function with a mutable slice of the vector's elements.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Function to call with a mutable slice of the vector's elements.
+    /// 
+    /// # Returns
+    /// 
+    /// The value returned by `f`.
+    /// 
+    /// # Note
+    /// 
+    /// If the elements are stored contiguously (see [`Vector::try_as_contiguous_mut_slice`]), `f`
+    /// writes to them directly. Otherwise, `f` is given a temporary copy of the elements, which is
+    /// written back to this vector once `f` returns.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use linalg_traits::Vector;
+    /// 
+    /// let mut x = vec![3.0, 1.0, 2.0];
+    /// x.with_mut_slice(|slice| slice.sort_by(f64::total_cmp));
+    /// assert_eq!(x, vec![1.0, 2.0, 3.0]);
+    /// ```
+    fn with_mut_slice<R, F: FnOnce(&mut [S]) -> R>(&mut self, f: F) -> R {
+        if let Some(slice) = self.try_as_contiguous_mut_slice() {
+            return f(slice);
+        }
+        let mut values = self.as_slice().into_owned();
+        let result = f(&mut values);
+        *self = Self::from_slice(&values);
+        result
+    }
+
+    /// Convert this vector into another vector type.
+    /// 
+    /// # Returns
+    /// 
+    /// Vector of type `W` with the same elements as this vector.
+    /// 
+    /// # Panics
+    /// 
+    /// * If the length of this vector does not match the length of `W` (for statically-sized `W`
+    ///   only).
+    /// 
+    /// # Note
+    /// 
+    /// The elements are copied directly from a slice of this vector's elements, so no intermediate
+    /// copy is made when this vector is stored contiguously.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # #[cfg(all(feature = "nalgebra", feature = "ndarray"))]
+    /// # {
+    /// use linalg_traits::Vector;
+    /// use nalgebra::DVector;
+    /// use ndarray::Array1;
+    /// 
+    /// let x: Array1<f64> = Array1::from_slice(&[1.0, 2.0, 3.0]);
+    /// let y: DVector<f64> = x.convert();
+    /// assert_eq!(y, DVector::from_slice(&[1.0, 2.0, 3.0]));
+    /// # }
+    /// ```
+    fn convert<W: Vector<S>>(&self) -> W {
+        W::from_slice(&self.as_slice())
+    }
+
+    /// Copy the elements of another vector (of any vector type) into this vector.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The vector to copy the elements from.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `other` does not have the same length as this vector.
+    fn copy_from<W: Vector<S>>(&mut self, other: &W) {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "Length of the other vector ({}) does not match the length of this vector ({}).",
+            other.len(),
+            self.len()
+        );
+        let values = other.as_slice();
+        if let Some(slice) = self.try_as_contiguous_mut_slice() {
+            slice.copy_from_slice(&values);
+        } else {
+            for (i, &value) in values.iter().enumerate() {
+                self[i] = value;
+            }
+        }
+    }
+
+    /// Create a vector with the specified length, with each element set to 0, without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `len` - Desired length of the vector.
+    /// 
+    /// # Returns
+    /// 
+    /// Vector with the specified length, with each element set to 0.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `len` does not match the length of the vector (for
+    ///   statically-sized vectors only).
+    fn try_new_with_length(len: usize) -> Result<Self, LinalgError> {
+        check_static_length::<S, Self>(len)?;
+        Ok(Self::new_with_length(len))
+    }
+
+    /// Create a vector from a slice of scalars without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `slice` - The slice of scalar values to initialize the vector.
+    /// 
+    /// # Returns
+    /// 
+    /// A vector containing the elements from the slice.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if the length of the slice does not match the length of
+    ///   the vector (for statically-sized vectors only).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # #[cfg(feature = "nalgebra")]
+    /// # {
+    /// use linalg_traits::{LinalgError, Vector};
+    /// use nalgebra::SVector;
+    /// 
+    /// let x = SVector::<f64, 3>::try_from_slice(&[1.0, 2.0]);
+    /// assert_eq!(x, Err(LinalgError::LengthMismatch { expected: 3, actual: 2 }));
+    /// # }
+    /// ```
+    fn try_from_slice(slice: &[S]) -> Result<Self, LinalgError> {
+        check_static_length::<S, Self>(slice.len())?;
+        Ok(Self::from_slice(slice))
+    }
+
+    /// Vector addition (elementwise) without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector to add to this vector.
+    /// 
+    /// # Returns
+    /// 
+    /// Sum of this vector with the other vector (i.e. `self + other`).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `self` and `other` do not have the same length.
+    fn try_add(&self, other: &Self) -> Result<Self, LinalgError> {
+        check_length(self.len(), other.len())?;
+        Ok(self.add(other))
+    }
+
+    /// Vector subtraction (elementwise) without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector to subtract from this vector.
+    /// 
+    /// # Returns
+    /// 
+    /// The difference of vector with the other vector (i.e. `self - other`).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `self` and `other` do not have the same length.
+    fn try_sub(&self, other: &Self) -> Result<Self, LinalgError> {
+        check_length(self.len(), other.len())?;
+        Ok(self.sub(other))
+    }
+
+    /// Dot product of two vectors without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector to take the dot product with.
+    /// 
+    /// # Returns
+    /// 
+    /// Dot product of this vector with the other vector.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if the two vectors do not have the same length.
+    fn try_dot(&self, other: &Self) -> Result<S, LinalgError> {
+        check_length(self.len(), other.len())?;
+        Ok(self.dot(other))
+    }
+
+    /// Create a vector by evaluating a function at each index without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `len` - Desired length of the vector.
+    /// * `f` - Function mapping an index `i` to the element `xᵢ`.
+    /// 
+    /// # Returns
+    /// 
+    /// Vector whose `i`th element is `f(i)`.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `len` does not match the length of the vector (for
+    ///   statically-sized vectors only).
+    fn try_from_fn<F: FnMut(usize) -> S>(len: usize, f: F) -> Result<Self, LinalgError> {
+        check_static_length::<S, Self>(len)?;
+        Ok(Self::from_fn(len, f))
+    }
+
+    /// Create a standard basis vector without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `len` - Desired length of the vector.
+    /// * `idx` - Index of the element set to 1.
+    /// 
+    /// # Returns
+    /// 
+    /// Vector with the element at index `idx` set to 1, and every other element set to 0.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::IndexOutOfBounds`] if `idx` is out of bounds (i.e. `idx >= len`).
+    /// * [`LinalgError::LengthMismatch`] if `len` does not match the length of the vector (for
+    ///   statically-sized vectors only).
+    fn try_basis(len: usize, idx: usize) -> Result<Self, LinalgError> {
+        check_index(idx, len)?;
+        check_static_length::<S, Self>(len)?;
+        Ok(Self::basis(len, idx))
+    }
+
+    /// In-place vector addition (elementwise) without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector to add to this vector.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `self` and `other` do not have the same length (in
+    ///   which case this vector is left unchanged).
+    fn try_add_assign(&mut self, other: &Self) -> Result<(), LinalgError> {
+        check_length(self.len(), other.len())?;
+        self.add_assign(other);
+        Ok(())
+    }
+
+    /// In-place vector subtraction (elementwise) without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector to subtract from this vector.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `self` and `other` do not have the same length (in
+    ///   which case this vector is left unchanged).
+    fn try_sub_assign(&mut self, other: &Self) -> Result<(), LinalgError> {
+        check_length(self.len(), other.len())?;
+        self.sub_assign(other);
+        Ok(())
+    }
+
+    /// Elementwise (Hadamard) product of two vectors without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector to multiply this vector by (elementwise).
+    /// 
+    /// # Returns
+    /// 
+    /// Elementwise product of this vector with the other vector (i.e. `self ⊙ other`).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `self` and `other` do not have the same length.
+    fn try_hadamard(&self, other: &Self) -> Result<Self, LinalgError> {
+        check_length(self.len(), other.len())?;
+        Ok(self.hadamard(other))
+    }
+
+    /// Scaled vector addition (`self = alpha * x + self`) without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `alpha` - The scalar to multiply `x` by.
+    /// * `x` - The vector to add (after scaling) to this vector.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `self` and `x` do not have the same length (in which
+    ///   case this vector is left unchanged).
+    fn try_axpy(&mut self, alpha: S, x: &Self) -> Result<(), LinalgError> {
+        check_length(self.len(), x.len())?;
+        self.axpy(alpha, x);
+        Ok(())
+    }
+
+    /// Scaled vector addition (`self = alpha * x + beta * self`) without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `alpha` - The scalar to multiply `x` by.
+    /// * `x` - The vector to add (after scaling) to this vector.
+    /// * `beta` - The scalar to multiply this vector by.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `self` and `x` do not have the same length (in which
+    ///   case this vector is left unchanged).
+    fn try_axpby(&mut self, alpha: S, x: &Self, beta: S) -> Result<(), LinalgError> {
+        check_length(self.len(), x.len())?;
+        self.axpby(alpha, x, beta);
+        Ok(())
+    }
+
+    /// Combine this vector with another vector elementwise without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector.
+    /// * `f` - Function to apply to each pair of elements.
+    /// 
+    /// # Returns
+    /// 
+    /// Vector whose `i`th element is `f(xᵢ, yᵢ)`, where `x` is this vector and `y` is the other
+    /// vector.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `self` and `other` do not have the same length.
+    fn try_zip_map<F: FnMut(S, S) -> S>(&self, other: &Self, f: F) -> Result<Self, LinalgError> {
+        check_length(self.len(), other.len())?;
+        Ok(self.zip_map(other, f))
+    }
+
+    /// Update this vector elementwise using another vector without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector.
+    /// * `f` - Function called as `f(&mut xᵢ, yᵢ)`, where `x` is this vector and `y` is the other
+    ///   vector.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `self` and `other` do not have the same length (in
+    ///   which case this vector is left unchanged).
+    fn try_zip_apply<F: FnMut(&mut S, S)>(&mut self, other: &Self, f: F) -> Result<(), LinalgError> {
+        check_length(self.len(), other.len())?;
+        self.zip_apply(other, f);
+        Ok(())
+    }
+
+    /// Elementwise (Hadamard) quotient of two vectors without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector to divide this vector by (elementwise).
+    /// 
+    /// # Returns
+    /// 
+    /// Elementwise quotient of this vector with the other vector (i.e. `self ⊘ other`).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `self` and `other` do not have the same length.
+    fn try_hadamard_div(&self, other: &Self) -> Result<Self, LinalgError> {
+        check_length(self.len(), other.len())?;
+        Ok(self.hadamard_div(other))
+    }
+
+    /// Vector addition (elementwise), storing the result in a preallocated vector
+    /// (`out = self + other`), without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector to add to this vector.
+    /// * `out` - The vector to store the result in.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `other` or `out` does not have the same length as this
+    ///   vector (in which case `out` is left unchanged).
+    fn try_add_to(&self, other: &Self, out: &mut Self) -> Result<(), LinalgError> {
+        check_length(self.len(), other.len())?;
+        check_length(self.len(), out.len())?;
+        self.add_to(other, out);
+        Ok(())
+    }
+
+    /// Vector subtraction (elementwise), storing the result in a preallocated vector
+    /// (`out = self - other`), without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector to subtract from this vector.
+    /// * `out` - The vector to store the result in.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `other` or `out` does not have the same length as this
+    ///   vector (in which case `out` is left unchanged).
+    fn try_sub_to(&self, other: &Self, out: &mut Self) -> Result<(), LinalgError> {
+        check_length(self.len(), other.len())?;
+        check_length(self.len(), out.len())?;
+        self.sub_to(other, out);
+        Ok(())
+    }
+
+    /// Vector-scalar multiplication, storing the result in a preallocated vector
+    /// (`out = self * scalar`), without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `scalar` - The scalar to multiply each element of this vector by.
+    /// * `out` - The vector to store the result in.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `out` does not have the same length as this vector (in
+    ///   which case `out` is left unchanged).
+    fn try_mul_to(&self, scalar: S, out: &mut Self) -> Result<(), LinalgError> {
+        check_length(self.len(), out.len())?;
+        self.mul_to(scalar, out);
+        Ok(())
+    }
+
+    /// Vector-scalar division, storing the result in a preallocated vector
+    /// (`out = self / scalar`), without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `scalar` - The scalar to divide each element of this vector by.
+    /// * `out` - The vector to store the result in.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `out` does not have the same length as this vector (in
+    ///   which case `out` is left unchanged).
+    fn try_div_to(&self, scalar: S, out: &mut Self) -> Result<(), LinalgError> {
+        check_length(self.len(), out.len())?;
+        self.div_to(scalar, out);
+        Ok(())
+    }
+
+    /// Overwrite this vector with a linear combination of vectors (`self = Σ αᵢ xᵢ`) without
+    /// panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `terms` - Pairs `(αᵢ, xᵢ)` of coefficients and vectors.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if any of the vectors does not have the same length as
+    ///   this vector (in which case this vector is left unchanged).
+    fn try_linear_combination(&mut self, terms: &[(S, &Self)]) -> Result<(), LinalgError> {
+        for (_, x) in terms {
+            check_length(self.len(), x.len())?;
+        }
+        self.linear_combination(terms);
+        Ok(())
+    }
+
+    /// Weighted root-mean-square (RMS) norm of this vector without panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `reference` - Reference vector used to scale the relative tolerance (e.g. the current
+    ///   state).
+    /// * `atol` - Absolute tolerance.
+    /// * `rtol` - Relative tolerance.
+    /// 
+    /// # Returns
+    /// 
+    /// Weighted RMS norm of this vector (see [`Vector::norm_wrms`]).
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `reference` does not have the same length as this
+    ///   vector.
+    fn try_norm_wrms(&self, reference: &Self, atol: S, rtol: S) -> Result<S, LinalgError> {
+        check_length(self.len(), reference.len())?;
+        Ok(self.norm_wrms(reference, atol, rtol))
+    }
+
+    /// Convert this vector into another vector type without panicking.
+    /// 
+    /// # Returns
+    /// 
+    /// Vector of type `W` with the same elements as this vector.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if the length of this vector does not match the length of
+    ///   `W` (for statically-sized `W` only).
+    fn try_convert<W: Vector<S>>(&self) -> Result<W, LinalgError> {
+        check_static_length::<S, W>(self.len())?;
+        Ok(self.convert())
+    }
+
+    /// Copy the elements of another vector (of any vector type) into this vector without
+    /// panicking.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The vector to copy the elements from.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::LengthMismatch`] if `other` does not have the same length as this vector
+    ///   (in which case this vector is left unchanged).
+    fn try_copy_from<W: Vector<S>>(&mut self, other: &W) -> Result<(), LinalgError> {
+        check_length(self.len(), other.len())?;
+        self.copy_from(other);
+        Ok(())
+    }
+
+    /// Check that every element of this vector is finite.
+    /// 
+    /// # Errors
+    /// 
+    /// * [`LinalgError::NonFinite`] if any element of this vector is NaN or infinite.
+    fn check_finite(&self) -> Result<(), LinalgError> {
+        if self.fold(true, |finite, x| finite && x.is_finite()) {
+            Ok(())
+        } else {
+            Err(LinalgError::NonFinite)
+        }
+    }
+
+    /// Squared Euclidean norm (squared 2-norm) of this vector.
+    /// 
+    /// # Returns
+    /// 
+    /// Squared Euclidean norm of this vector.
+    fn norm_squared(&self) -> S {
+        (0..self.len()).fold(S::zero(), |sum, i| sum + self[i] * self[i])
+    }
+
+    /// Euclidean norm (2-norm) of this vector.
+    /// 
+    /// # Returns
+    /// 
+    /// Euclidean norm of this vector.
+    /// 
+    /// # Note
+    /// 
+    /// The default implementation scales the elements by the running maximum magnitude while
+    /// accumulating the sum of squares (as in the reference BLAS `nrm2`), so it does not overflow
+    /// or underflow unless the norm itself does.
+    fn norm(&self) -> S {
+        scaled_l2_norm((0..self.len()).map(|i| self[i]))
+    }
+
+    /// 1-norm of this vector (sum of the absolute values of its elements).
+    /// 
+    /// # Returns
+    /// 
+    /// 1-norm of this vector.
+    fn norm_l1(&self) -> S {
+        (0..self.len()).fold(S::zero(), |sum, i| sum + self[i].abs())
+    }
+
+    /// Infinity norm of this vector (maximum absolute value of its elements).
+    /// 
+    /// # Returns
+    /// 
+    /// Infinity norm of this vector (0 for an empty vector, NaN if any element is NaN).
+    fn norm_inf(&self) -> S {
+        (0..self.len()).fold(S::zero(), |max, i| nan_max(max, self[i].abs()))
+    }
+
+    /// p-norm of this vector.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `p` - Order of the norm (must satisfy `p >= 1`, and may be infinite).
+    /// 
+    /// # Returns
+    /// 
+    /// p-norm of this vector (i.e. `(Σ|xᵢ|ᵖ)^(1/p)`).
+    /// 
+    /// # Panics
+    /// 
+    /// * If `p < 1` or `p` is NaN.
+    /// 
+    /// # Note
+    /// 
+    /// The elements are scaled by the infinity norm of this vector before being raised to the
+    /// `p`th power to avoid overflow and underflow.
+    fn norm_p(&self, p: S) -> S {
+        assert!(p >= S::one(), "Order of the p-norm must be at least 1 (got {p:?}).");
+        if p == S::one() {
+            return self.norm_l1();
+        }
+        if p == S::one() + S::one() {
+            return self.norm();
+        }
+        let max = self.norm_inf();
+        if p.is_infinite() || max == S::zero() || !max.is_finite() {
+            return max;
+        }
+        let sum = (0..self.len()).fold(S::zero(), |sum, i| sum + (self[i].abs() / max).powf(p));
+        max * sum.powf(p.recip())
+    }
+
+    /// Normalize this vector so that it has a Euclidean norm of 1.
+    /// 
+    /// # Returns
+    /// 
+    /// This vector divided by its Euclidean norm.
+    /// 
+    /// # Note
+    /// 
+    /// Normalizing a zero vector results in a vector of NaNs.
+    #[must_use]
+    fn normalize(&self) -> Self {
+        self.div(self.norm())
+    }
+
+    /// Normalize this vector in place so that it has a Euclidean norm of 1.
+    /// 
+    /// # Returns
+    /// 
+    /// Euclidean norm of this vector before it was normalized.
+    /// 
+    /// # Note
+    /// 
+    /// Normalizing a zero vector results in a vector of NaNs.
+    fn normalize_in_place(&mut self) -> S {
+        let norm = self.norm();
+        self.div_assign(norm);
+        norm
+    }
+
+    /// Weighted root-mean-square (RMS) norm of this vector, as used for error control in adaptive
+    /// step size algorithms.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `reference` - Reference vector used to scale the relative tolerance (e.g. the current
+    ///   state).
+    /// * `atol` - Absolute tolerance.
+    /// * `rtol` - Relative tolerance.
+    /// 
+    /// # Returns
+    /// 
+    /// Weighted RMS norm of this vector (0 for an empty vector), i.e.
+    /// 
+    /// `sqrt((1/N) * Σ(xᵢ / (atol + rtol * |refᵢ|))²)`
+    /// 
+    /// # Panics
+    /// 
+    /// * If `reference` does not have the same length as this vector.
+    /// 
+    /// # Note
+    /// 
+    /// A value less than or equal to 1 indicates that every element of this vector (e.g. a local
+    /// error estimate) is within its tolerance.
+    #[allow(clippy::cast_precision_loss)]
+    fn norm_wrms(&self, reference: &Self, atol: S, rtol: S) -> S {
+        self.assert_same_length(reference);
+        let n = self.len();
+        if n == 0 {
+            return S::zero();
+        }
+        let weighted = (0..n).map(|i| self[i] / (atol + rtol * reference[i].abs()));
+        scaled_l2_norm(weighted) / S::new(n as f64).sqrt()
+    }
+
+    /// Elementwise (Hadamard) product of two vectors.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector to multiply this vector by (elementwise).
+    /// 
+    /// # Returns
+    /// 
+    /// Elementwise product of this vector with the other vector (i.e. `self ⊙ other`).
+    /// 
+    /// # Panics
+    /// 
+    /// * If the two vectors do not have the same length.
+    #[must_use]
+    fn hadamard(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a * b)
+    }
+
+    /// Elementwise (Hadamard) quotient of two vectors.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector to divide this vector by (elementwise).
+    /// 
+    /// # Returns
+    /// 
+    /// Elementwise quotient of this vector with the other vector (i.e. `self ⊘ other`).
+    /// 
+    /// # Panics
+    /// 
+    /// * If the two vectors do not have the same length.
+    #[must_use]
+    fn hadamard_div(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a / b)
+    }
+
+    /// Vector addition (elementwise), storing the result in a preallocated vector
+    /// (`out = self + other`).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector to add to this vector.
+    /// * `out` - The vector to store the result in.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `self` and `other` do not have the same length.
+    /// * If `out` does not have the same length as this vector.
+    fn add_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_length(other);
+        assert_output_length(self, out);
+        out.zip_apply(self, |o, a| *o = a);
+        out.zip_apply(other, |o, b| *o += b);
+    }
+
+    /// Vector subtraction (elementwise), storing the result in a preallocated vector
+    /// (`out = self - other`).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector to subtract from this vector.
+    /// * `out` - The vector to store the result in.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `self` and `other` do not have the same length.
+    /// * If `out` does not have the same length as this vector.
+    fn sub_to(&self, other: &Self, out: &mut Self) {
+        self.assert_same_length(other);
+        assert_output_length(self, out);
+        out.zip_apply(self, |o, a| *o = a);
+        out.zip_apply(other, |o, b| *o -= b);
+    }
+
+    /// Vector-scalar multiplication, storing the result in a preallocated vector
+    /// (`out = self * scalar`).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `scalar` - The scalar to multiply each element of this vector by.
+    /// * `out` - The vector to store the result in.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `out` does not have the same length as this vector.
+    fn mul_to(&self, scalar: S, out: &mut Self) {
+        assert_output_length(self, out);
+        out.zip_apply(self, |o, a| *o = a * scalar);
+    }
+
+    /// Vector-scalar division, storing the result in a preallocated vector
+    /// (`out = self / scalar`).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `scalar` - The scalar to divide each element of this vector by.
+    /// * `out` - The vector to store the result in.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `out` does not have the same length as this vector.
+    fn div_to(&self, scalar: S, out: &mut Self) {
+        assert_output_length(self, out);
+        out.zip_apply(self, |o, a| *o = a / scalar);
+    }
+
+    /// In-place scaled vector addition (`self += alpha * x`).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `alpha` - The scalar to multiply `x` by.
+    /// * `x` - The vector to add (after scaling) to this vector.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `self` and `x` do not have the same length.
+    /// 
+    /// # Note
+    /// 
+    /// Unlike `self.add_assign(&x.mul(alpha))`, this does not allocate a temporary vector.
+    fn axpy(&mut self, alpha: S, x: &Self) {
+        self.zip_apply(x, |a, b| *a += alpha * b);
+    }
+
+    /// In-place scaled vector addition with a scaled accumulator (`self = alpha * x + beta *
+    /// self`).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `alpha` - The scalar to multiply `x` by.
+    /// * `x` - The vector to add (after scaling) to this vector.
+    /// * `beta` - The scalar to multiply this vector by.
+    /// 
+    /// # Panics
+    /// 
+    /// * If `self` and `x` do not have the same length.
+    /// 
+    /// # Note
+    /// 
+    /// If `beta` is 0, the current elements of this vector are never read, so any NaNs or
+    /// infinities in this vector are overwritten instead of being propagated (as in BLAS).
+    fn axpby(&mut self, alpha: S, x: &Self, beta: S) {
+        if beta.is_zero() {
+            self.zip_apply(x, |a, b| *a = alpha * b);
+        } else {
+            self.zip_apply(x, |a, b| *a = alpha * b + beta * *a);
+        }
+    }
+
+    /// Overwrite this vector with a linear combination of vectors (`self = Σ αᵢ xᵢ`).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `terms` - Pairs `(αᵢ, xᵢ)` of coefficients and vectors.
+    /// 
+    /// # Panics
+    /// 
+    /// * If any of the vectors does not have the same length as this vector.
+    /// 
+    /// # Note
+    /// 
+    /// The current elements of this vector are never read; if `terms` is empty, every element of
+    /// this vector is set to 0. No temporary vectors are allocated.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use linalg_traits::Vector;
+    /// 
+    /// // Runge-Kutta update y₁ = y₀ + h(b₁k₁ + b₂k₂).
+    /// let (h, b1, b2) = (0.5, 0.25, 0.75);
+    /// let y0 = vec![1.0, 2.0];
+    /// let k1 = vec![4.0, -4.0];
+    /// let k2 = vec![8.0, 0.0];
+    /// let mut y1 = vec![0.0; 2];
+    /// y1.linear_combination(&[(1.0, &y0), (h * b1, &k1), (h * b2, &k2)]);
+    /// assert_eq!(y1, vec![4.5, 1.5]);
+    /// ```
+    fn linear_combination(&mut self, terms: &[(S, &Self)]) {
+        match terms.split_first() {
+            Some(((alpha, x), rest)) => {
+                self.axpby(*alpha, x, S::zero());
+                for (alpha, x) in rest {
+                    self.axpy(*alpha, x);
+                }
+            }
+            None => self.map_in_place(|_| S::zero()),
+        }
+    }
+
+    /// Sum of the elements of this vector.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Sum of the elements of this vector (0 for an empty vector).
+    fn sum(&self, nan_policy: NanPolicy) -> S {
+        default_sum(self, nan_policy)
+    }
+
+    /// Product of the elements of this vector.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Product of the elements of this vector (1 for an empty vector).
+    fn product(&self, nan_policy: NanPolicy) -> S {
+        default_product(self, nan_policy)
+    }
+
+    /// Minimum element of this vector.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Minimum element of this vector (positive infinity if there are no elements to compare).
+    fn min(&self, nan_policy: NanPolicy) -> S {
+        match nan_policy {
+            NanPolicy::Propagate => self.fold(S::infinity(), nan_min),
+            NanPolicy::Ignore => self.fold(S::infinity(), S::min),
+        }
+    }
+
+    /// Maximum element of this vector.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Maximum element of this vector (negative infinity if there are no elements to compare).
+    fn max(&self, nan_policy: NanPolicy) -> S {
+        match nan_policy {
+            NanPolicy::Propagate => self.fold(S::neg_infinity(), nan_max),
+            NanPolicy::Ignore => self.fold(S::neg_infinity(), S::max),
+        }
+    }
+
+    /// Maximum absolute value of the elements of this vector.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Maximum absolute value of the elements of this vector (0 if there are no elements to
+    /// compare).
+    /// 
+    /// # Note
+    /// 
+    /// With [`NanPolicy::Propagate`], this is the same as [`Vector::norm_inf`].
+    fn amax(&self, nan_policy: NanPolicy) -> S {
+        match nan_policy {
+            NanPolicy::Propagate => self.norm_inf(),
+            NanPolicy::Ignore => self.fold(S::zero(), |max, x| max.max(x.abs())),
+        }
+    }
+
+    /// Index of the minimum element of this vector.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Index of the minimum element of this vector (the first one in case of ties), or `None` if
+    /// there are no elements to compare. With [`NanPolicy::Propagate`], the index of the first NaN
+    /// is returned if there is one.
+    fn argmin(&self, nan_policy: NanPolicy) -> Option<usize> {
+        arg_extremum(self, nan_policy, |x, best| x < best)
+    }
+
+    /// Index of the maximum element of this vector.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Index of the maximum element of this vector (the first one in case of ties), or `None` if
+    /// there are no elements to compare. With [`NanPolicy::Propagate`], the index of the first NaN
+    /// is returned if there is one.
+    fn argmax(&self, nan_policy: NanPolicy) -> Option<usize> {
+        arg_extremum(self, nan_policy, |x, best| x > best)
+    }
+
+    /// Arithmetic mean of the elements of this vector.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Mean of the elements of this vector (NaN if there are no elements to average).
+    fn mean(&self, nan_policy: NanPolicy) -> S {
+        default_mean(self, nan_policy)
+    }
+
+    /// Population variance of the elements of this vector.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN elements are handled.
+    /// 
+    /// # Returns
+    /// 
+    /// Population variance of the elements of this vector, i.e. `(1/N) * Σ(xᵢ - x̄)²` (NaN if there
+    /// are no elements).
+    /// 
+    /// # Note
+    /// 
+    /// * The variance is computed using two passes (the mean is computed first) to avoid the
+    ///   catastrophic cancellation of the one-pass formula `E[x²] - E[x]²`.
+    /// * The sample variance can be obtained by multiplying the result by `N / (N - 1)`.
+    fn variance(&self, nan_policy: NanPolicy) -> S {
+        default_variance(self, nan_policy)
+    }
+
+    /// Get the fixed length of the vector type.
+    /// 
+    /// # Returns
+    /// 
+    /// The length of every vector of this type if the vector is statically-sized, `None` if the
+    /// vector is dynamically-sized.
+    /// 
+    /// # Note
+    /// 
+    /// The default implementation returns `None`. Statically-sized vectors should override it so
+    /// that the fallible constructors (e.g. [`Vector::try_from_slice`]) can report a length
+    /// mismatch instead of panicking.
+    #[must_use]
+    fn static_length() -> Option<usize> {
+        None
+    }
+
+    /// Return a slice of the vector's elements without copying, if they are stored contiguously.
+    /// 
+    /// # Returns
+    /// 
+    /// A slice of the vector's elements, or `None` if the elements are not stored contiguously in
+    /// memory.
+    /// 
+    /// # Note
+    /// 
+    /// Unlike [`Vector::as_slice`], this method never allocates.
+    fn try_as_contiguous_slice(&self) -> Option<&[S]> {
+        None
+    }
+
+    /// Return a mutable slice of the vector's elements, if they are stored contiguously.
+    /// 
+    /// # Returns
+    /// 
+    /// A mutable slice of the vector's elements, or `None` if the elements are not stored
+    /// contiguously in memory.
+    /// 
+    /// # Note
+    /// 
+    /// To write to the elements of any vector through a slice, use [`Vector::with_mut_slice`].
+    fn try_as_contiguous_mut_slice(&mut self) -> Option<&mut [S]> {
+        None
+    }
+
+    /// Apply a function to each element of this vector.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Function to apply to each element.
+    /// 
+    /// # Returns
+    /// 
+    /// Vector whose elements are `f(xᵢ)`.
+    #[must_use]
+    fn map<F: FnMut(S) -> S>(&self, f: F) -> Self {
+        let mut out = self.clone();
+        out.map_in_place(f);
+        out
+    }
+
+    /// Apply a function to each element of this vector in place.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Function to apply to each element (`xᵢ = f(xᵢ)`).
+    fn map_in_place<F: FnMut(S) -> S>(&mut self, mut f: F) {
+        for i in 0..self.len() {
+            self[i] = f(self[i]);
+        }
+    }
+
+    /// Apply a function to each pair of corresponding elements of two vectors.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector.
+    /// * `f` - Function to apply to each pair of elements.
+    /// 
+    /// # Returns
+    /// 
+    /// Vector whose elements are `f(xᵢ, yᵢ)`, where `x` is this vector and `y` is the other vector.
     /// 
     /// # Panics
     /// 
-    /// * If the length of the other vector is not equal to the length of this vector.
-    fn assert_same_length(&self, other: &Self) {
-        assert_eq!(
-            self.len(),
-            other.len(),
-            "Length of the other vector ({}) does not match the length of this vector ({}).",
-            other.len(),
-            self.len()
-        );
+    /// * If the two vectors do not have the same length.
+    #[must_use]
+    fn zip_map<F: FnMut(S, S) -> S>(&self, other: &Self, mut f: F) -> Self {
+        let mut out = self.clone();
+        out.zip_apply(other, |a, b| *a = f(*a, b));
+        out
+    }
+
+    /// Apply a function to each pair of corresponding elements of two vectors, updating this
+    /// vector in place.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The other vector.
+    /// * `f` - Function called as `f(&mut xᵢ, yᵢ)`, where `x` is this vector and `y` is the other
+    ///   vector.
+    /// 
+    /// # Panics
+    /// 
+    /// * If the two vectors do not have the same length.
+    fn zip_apply<F: FnMut(&mut S, S)>(&mut self, other: &Self, mut f: F) {
+        self.assert_same_length(other);
+        for i in 0..self.len() {
+            f(&mut self[i], other[i]);
+        }
+    }
+
+    /// Fold the elements of this vector into a single value.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `init` - Initial value of the accumulator.
+    /// * `f` - Function combining the accumulator with each element.
+    /// 
+    /// # Returns
+    /// 
+    /// Final value of the accumulator.
+    /// 
+    /// # Note
+    /// 
+    /// The order in which the elements are visited is not specified.
+    fn fold<T, F: FnMut(T, S) -> T>(&self, init: T, mut f: F) -> T {
+        (0..self.len()).fold(init, |acc, i| f(acc, self[i]))
+    }
+
+    /// Apply a function to each element of this vector, producing a vector with a different
+    /// scalar type.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - Function mapping each element to the new scalar type.
+    /// 
+    /// # Returns
+    /// 
+    /// Vector of type [`Vector::VectorT`] with the same length as this vector, whose elements are
+    /// `f(xᵢ)`.
+    /// 
+    /// # Note
+    /// 
+    /// This can be used to lift a vector of [`f64`]s into a vector of some other scalar type (e.g.
+    /// a dual number type used for forward-mode automatic differentiation).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use linalg_traits::{Scalar, Vector};
+    /// 
+    /// // Lift a vector of `f64`s into a vector of any other scalar type.
+    /// fn lift<S: Scalar, V: Vector<f64>>(x: &V) -> V::VectorT<S> {
+    ///     x.map_scalar(S::new)
+    /// }
+    /// 
+    /// let x: Vec<f64> = vec![1.0, 2.0, 3.0];
+    /// let y: Vec<f64> = lift(&x);
+    /// assert_eq!(y, x);
+    /// ```
+    fn map_scalar<T: Scalar, F: FnMut(S) -> T>(&self, mut f: F) -> Self::VectorT<T> {
+        let mut out = Self::VectorT::<T>::new_with_length(self.len());
+        for i in 0..self.len() {
+            out[i] = f(self[i]);
+        }
+        out
     }
 
     // -----------------------------
@@ -702,4 +2066,252 @@ pub trait Vector<S: Scalar>:
     /// 
     /// * If the two vectors do not have the same length.
     fn dot(&self, other: &Self) -> S;
+
+}
+
+/// Euclidean norm of a sequence of scalars, computed without overflow or underflow.
+///
+/// # Arguments
+///
+/// * `values` - The scalars.
+///
+/// # Returns
+///
+/// Euclidean norm of the scalars.
+///
+/// # Note
+///
+/// The sum of squares is accumulated relative to the largest magnitude seen so far (as in the
+/// reference BLAS `nrm2`).
+pub(crate) fn scaled_l2_norm<S: Scalar>(values: impl Iterator<Item = S>) -> S {
+    let (scale, sum_squares) = values.fold((S::zero(), S::one()), |(scale, sum_squares), value| {
+        let abs = value.abs();
+        if abs == S::zero() {
+            (scale, sum_squares)
+        } else if abs > scale {
+            let ratio = scale / abs;
+            (abs, S::one() + sum_squares * ratio * ratio)
+        } else if abs == scale {
+            // Handled separately so that repeated infinite elements do not produce NaN.
+            (scale, sum_squares + S::one())
+        } else {
+            let ratio = abs / scale;
+            (scale, sum_squares + ratio * ratio)
+        }
+    });
+    scale * sum_squares.sqrt()
+}
+
+/// Maximum of two scalars that propagates NaNs.
+///
+/// # Arguments
+///
+/// * `a` - First scalar.
+/// * `b` - Second scalar.
+///
+/// # Returns
+///
+/// The larger of the two scalars, or NaN if either of them is NaN.
+pub(crate) fn nan_max<S: Scalar>(a: S, b: S) -> S {
+    if b > a || b.is_nan() { b } else { a }
+}
+
+/// Minimum of two scalars that propagates NaNs.
+///
+/// # Arguments
+///
+/// * `a` - First scalar.
+/// * `b` - Second scalar.
+///
+/// # Returns
+///
+/// The smaller of the two scalars, or NaN if either of them is NaN.
+pub(crate) fn nan_min<S: Scalar>(a: S, b: S) -> S {
+    if b < a || b.is_nan() { b } else { a }
+}
+
+/// Index of the "best" element of a vector (e.g. its minimum or maximum element).
+///
+/// # Arguments
+///
+/// * `vector` - The vector.
+/// * `nan_policy` - How NaN elements are handled.
+/// * `is_better` - Function returning `true` if its first argument is strictly better than its
+///   second argument.
+///
+/// # Returns
+///
+/// Index of the first best element, or `None` if there are no elements to compare. With
+/// [`NanPolicy::Propagate`], the index of the first NaN is returned if there is one.
+fn arg_extremum<S: Scalar, V: Vector<S>>(
+    vector: &V,
+    nan_policy: NanPolicy,
+    is_better: impl Fn(S, S) -> bool,
+) -> Option<usize> {
+    let mut best: Option<(usize, S)> = None;
+    for i in 0..vector.len() {
+        let x = vector[i];
+        if x.is_nan() {
+            match nan_policy {
+                NanPolicy::Propagate => return Some(i),
+                NanPolicy::Ignore => continue,
+            }
+        }
+        if best.is_none_or(|(_, value)| is_better(x, value)) {
+            best = Some((i, x));
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+/// Default implementation of [`Vector::sum`].
+///
+/// # Arguments
+///
+/// * `vector` - The vector.
+/// * `nan_policy` - How NaN elements are handled.
+///
+/// # Returns
+///
+/// Sum of the elements of the vector (0 for an empty vector).
+pub(crate) fn default_sum<S: Scalar, V: Vector<S>>(vector: &V, nan_policy: NanPolicy) -> S {
+    vector.fold(
+        S::zero(),
+        |sum, x| if nan_policy.skips(x) { sum } else { sum + x },
+    )
+}
+
+/// Default implementation of [`Vector::product`].
+///
+/// # Arguments
+///
+/// * `vector` - The vector.
+/// * `nan_policy` - How NaN elements are handled.
+///
+/// # Returns
+///
+/// Product of the elements of the vector (1 for an empty vector).
+pub(crate) fn default_product<S: Scalar, V: Vector<S>>(vector: &V, nan_policy: NanPolicy) -> S {
+    vector.fold(S::one(), |product, x| {
+        if nan_policy.skips(x) {
+            product
+        } else {
+            product * x
+        }
+    })
+}
+
+/// Default implementation of [`Vector::mean`].
+///
+/// # Arguments
+///
+/// * `vector` - The vector.
+/// * `nan_policy` - How NaN elements are handled.
+///
+/// # Returns
+///
+/// Mean of the elements of the vector (NaN if there are no elements to average).
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn default_mean<S: Scalar, V: Vector<S>>(vector: &V, nan_policy: NanPolicy) -> S {
+    let count = match nan_policy {
+        NanPolicy::Propagate => vector.len(),
+        NanPolicy::Ignore => vector.fold(0, |count, x| count + usize::from(!x.is_nan())),
+    };
+    vector.sum(nan_policy) / S::new(count as f64)
+}
+
+/// Default implementation of [`Vector::variance`].
+///
+/// # Arguments
+///
+/// * `vector` - The vector.
+/// * `nan_policy` - How NaN elements are handled.
+///
+/// # Returns
+///
+/// Population variance of the elements of the vector (NaN if there are no elements).
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn default_variance<S: Scalar, V: Vector<S>>(vector: &V, nan_policy: NanPolicy) -> S {
+    let mean = vector.mean(nan_policy);
+    if mean.is_nan() {
+        return mean;
+    }
+    let (sum, count) = vector.fold((S::zero(), 0_usize), |(sum, count), x| {
+        if nan_policy.skips(x) {
+            (sum, count)
+        } else {
+            (sum + (x - mean) * (x - mean), count + 1)
+        }
+    });
+    sum / S::new(count as f64)
+}
+
+/// Assert that an output vector has the same length as a vector.
+///
+/// # Arguments
+///
+/// * `vector` - The vector.
+/// * `out` - The output vector.
+///
+/// # Panics
+///
+/// * If `out` does not have the same length as `vector`.
+pub(crate) fn assert_output_length<S: Scalar, V: Vector<S>>(vector: &V, out: &V) {
+    assert_eq!(
+        out.len(),
+        vector.len(),
+        "Length of the output vector ({}) does not match the length of this vector ({}).",
+        out.len(),
+        vector.len()
+    );
+}
+
+/// Check that two lengths are equal.
+///
+/// # Arguments
+///
+/// * `expected` - The required length.
+/// * `actual` - The actual length.
+///
+/// # Errors
+///
+/// * [`LinalgError::LengthMismatch`] if `actual` is not equal to `expected`.
+pub(crate) fn check_length(expected: usize, actual: usize) -> Result<(), LinalgError> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(LinalgError::LengthMismatch { expected, actual })
+    }
+}
+
+/// Check that an index is in bounds.
+///
+/// # Arguments
+///
+/// * `index` - The index.
+/// * `len` - Number of elements (or rows/columns) being indexed into.
+///
+/// # Errors
+///
+/// * [`LinalgError::IndexOutOfBounds`] if `index` is not less than `len`.
+pub(crate) fn check_index(index: usize, len: usize) -> Result<(), LinalgError> {
+    if index < len {
+        Ok(())
+    } else {
+        Err(LinalgError::IndexOutOfBounds { index, len })
+    }
+}
+
+/// Check that a length is compatible with a vector type.
+///
+/// # Arguments
+///
+/// * `len` - The length.
+///
+/// # Errors
+///
+/// * [`LinalgError::LengthMismatch`] if `V` is statically-sized and `len` does not match its
+///   length.
+fn check_static_length<S: Scalar, V: Vector<S>>(len: usize) -> Result<(), LinalgError> {
+    V::static_length().map_or(Ok(()), |expected| check_length(expected, len))
 }
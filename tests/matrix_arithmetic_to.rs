@@ -0,0 +1,216 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+use linalg_traits::{Mat, Matrix, SMat};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, SMatrix};
+#[cfg(feature = "ndarray")]
+use ndarray::{Array2, ShapeBuilder};
+use numtest::*;
+
+// Test conditions.
+static A: &[f64; 6] = &[1.0, -2.0, 3.0, 4.0, 5.0, -6.0];
+static B: &[f64; 6] = &[0.5, 1.5, -2.5, 3.5, 0.0, 1.0];
+
+// Expected results.
+static SUM: &[f64; 6] = &[1.5, -0.5, 0.5, 7.5, 5.0, -5.0];
+static DIFFERENCE: &[f64; 6] = &[0.5, -3.5, 5.5, 0.5, 5.0, -7.0];
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static SUM_COL: &[f64; 6] = &[1.5, 7.5, -0.5, 5.0, 0.5, -5.0];
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static DIFFERENCE_COL: &[f64; 6] = &[0.5, 0.5, -3.5, 5.0, 5.5, -7.0];
+
+/// Check the out-parameter arithmetic on a 2x3 matrix.
+fn arithmetic_to_test_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(2, 3, A);
+    let b = M::from_row_slice(2, 3, B);
+
+    // The previous contents of the output matrix are overwritten.
+    let mut out = M::from_row_slice(2, 3, &[f64::NAN; 6]);
+    a.add_to(&b, &mut out);
+    assert_eq!(out, M::from_row_slice(2, 3, SUM));
+    a.sub_to(&b, &mut out);
+    assert_eq!(out, M::from_row_slice(2, 3, DIFFERENCE));
+    a.mul_to(-3.0, &mut out);
+    assert_eq!(out, a.mul(-3.0));
+    a.div_to(2.0, &mut out);
+    assert_eq!(out, a.div(2.0));
+}
+
+#[test]
+fn test_mat() {
+    arithmetic_to_test_helper::<Mat<f64>>();
+}
+
+#[test]
+#[should_panic(expected = "Matrices have incompatible shapes.")]
+fn test_mat_add_to_panic() {
+    let mut out = Mat::new_with_shape(2, 3);
+    Mat::from_row_slice(2, 3, A).add_to(&Mat::from_row_slice(3, 2, B), &mut out);
+}
+
+#[test]
+#[should_panic(
+    expected = "Shape of the output matrix (3x2) does not match the shape of this matrix (2x3)."
+)]
+fn test_mat_sub_to_output_panic() {
+    let mut out = Mat::new_with_shape(3, 2);
+    Mat::from_row_slice(2, 3, A).sub_to(&Mat::from_row_slice(2, 3, B), &mut out);
+}
+
+#[test]
+#[should_panic(
+    expected = "Shape of the output matrix (2x2) does not match the shape of this matrix (2x3)."
+)]
+fn test_mat_div_to_output_panic() {
+    let mut out = Mat::new_with_shape(2, 2);
+    Mat::from_row_slice(2, 3, A).div_to(2.0, &mut out);
+}
+
+#[test]
+fn test_mat_empty() {
+    let a = Mat::<f64>::new_with_shape(0, 3);
+    let mut out = Mat::new_with_shape(0, 3);
+    a.add_to(&a, &mut out);
+    a.sub_to(&a, &mut out);
+    assert_eq!(out.shape(), (0, 3));
+}
+
+#[test]
+fn test_smat() {
+    let a = SMat::<f64, 2, 3>::from_row_slice(2, 3, A);
+    let b = SMat::<f64, 2, 3>::from_row_slice(2, 3, B);
+    let mut out = SMat::from_row_slice(2, 3, &[f64::NAN; 6]);
+    a.add_to(&b, &mut out);
+    assert_arrays_equal!(out.as_slice(), SUM);
+    a.sub_to(&b, &mut out);
+    assert_arrays_equal!(out.as_slice(), DIFFERENCE);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let a = DMatrix::from_row_slice(2, 3, A);
+    let b = DMatrix::from_row_slice(2, 3, B);
+    let mut out = DMatrix::from_element(2, 3, f64::NAN);
+    Matrix::add_to(&a, &b, &mut out);
+    assert_arrays_equal!(out.as_slice(), SUM_COL);
+    Matrix::sub_to(&a, &b, &mut out);
+    assert_arrays_equal!(out.as_slice(), DIFFERENCE_COL);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    let a = DMatrix::<f64>::zeros(2, 0);
+    let mut out = DMatrix::zeros(2, 0);
+    Matrix::add_to(&a, &a, &mut out);
+    Matrix::sub_to(&a, &a, &mut out);
+    assert_eq!(out.shape(), (2, 0));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(expected = "Matrices have incompatible shapes.")]
+fn test_nalgebra_dmatrix_sub_to_panic() {
+    let mut out = DMatrix::zeros(2, 3);
+    let a = DMatrix::from_row_slice(2, 3, A);
+    Matrix::sub_to(&a, &DMatrix::from_row_slice(3, 2, B), &mut out);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(
+    expected = "Shape of the output matrix (3x2) does not match the shape of this matrix (2x3)."
+)]
+fn test_nalgebra_dmatrix_add_to_output_panic() {
+    let mut out = DMatrix::new_with_shape(3, 2);
+    let a = DMatrix::from_row_slice(2, 3, A);
+    Matrix::add_to(&a, &DMatrix::from_row_slice(2, 3, B), &mut out);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    let a = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, A);
+    let b = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, B);
+    let mut out = SMatrix::<f64, 2, 3>::from_element(f64::NAN);
+    Matrix::add_to(&a, &b, &mut out);
+    assert_arrays_equal!(out.as_slice(), SUM_COL);
+    Matrix::sub_to(&a, &b, &mut out);
+    assert_arrays_equal!(out.as_slice(), DIFFERENCE_COL);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2() {
+    let a = Array2::from_row_slice(2, 3, A);
+    let b = Array2::from_row_slice(2, 3, B);
+    let mut out = Array2::from_elem((2, 3), f64::NAN);
+    a.add_to(&b, &mut out);
+    assert_arrays_equal!(Matrix::as_slice(&out), SUM);
+    a.sub_to(&b, &mut out);
+    assert_arrays_equal!(Matrix::as_slice(&out), DIFFERENCE);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_column_major_output() {
+    // The output is written by index, whatever its memory layout.
+    let a = Array2::from_row_slice(2, 3, A);
+    let b = Array2::from_row_slice(2, 3, B);
+    let mut out = Array2::from_elem((2, 3).f(), f64::NAN);
+    a.add_to(&b, &mut out);
+    assert_eq!(out, Array2::from_row_slice(2, 3, SUM));
+    a.sub_to(&b, &mut out);
+    assert_eq!(out, Array2::from_row_slice(2, 3, DIFFERENCE));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+#[should_panic(
+    expected = "Shape of the output matrix (3x2) does not match the shape of this matrix (2x3)."
+)]
+fn test_ndarray_array2_sub_to_output_panic() {
+    let mut out = Array2::new_with_shape(3, 2);
+    Array2::from_row_slice(2, 3, A).sub_to(&Array2::from_row_slice(2, 3, B), &mut out);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    let a = FMat::from_row_slice(2, 3, A);
+    let b = FMat::from_row_slice(2, 3, B);
+    let mut out = FMat::from_element(2, 3, f64::NAN);
+    a.add_to(&b, &mut out);
+    assert_arrays_equal!(out.as_slice(), SUM_COL);
+    a.sub_to(&b, &mut out);
+    assert_arrays_equal!(out.as_slice(), DIFFERENCE_COL);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    let a = FMat::<f64>::zeros(0, 3);
+    let mut out = FMat::zeros(0, 3);
+    a.add_to(&a, &mut out);
+    a.sub_to(&a, &mut out);
+    assert_eq!(out.shape(), (0, 3));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+#[should_panic(expected = "Matrices have incompatible shapes.")]
+fn test_faer_mat_add_to_panic() {
+    let mut out = FMat::zeros(2, 3);
+    FMat::from_row_slice(2, 3, A).add_to(&FMat::from_row_slice(3, 2, B), &mut out);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+#[should_panic(
+    expected = "Shape of the output matrix (3x2) does not match the shape of this matrix (2x3)."
+)]
+fn test_faer_mat_add_to_output_panic() {
+    let mut out = FMat::new_with_shape(3, 2);
+    FMat::from_row_slice(2, 3, A).add_to(&FMat::from_row_slice(2, 3, B), &mut out);
+}
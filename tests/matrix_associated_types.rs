@@ -2,7 +2,7 @@
 use faer::{Col, Mat as FMat};
 #[cfg(feature = "faer")]
 use linalg_traits::Vector;
-use linalg_traits::{Mat, Matrix};
+use linalg_traits::{Mat, Matrix, SMat};
 #[cfg(feature = "nalgebra")]
 use nalgebra::{DMatrix, DVector, SMatrix, SVector};
 #[cfg(feature = "ndarray")]
@@ -22,6 +22,15 @@ fn test_vec_from_mat() {
     assert_arrays_equal!(vec_n, [0.0; N]);
 }
 
+#[test]
+fn test_array_from_smat() {
+    let mat: SMat<f64, M, N> = SMat::new_with_shape(M, N);
+    let vec_m: [f64; M] = mat.new_vector_m();
+    let vec_n: [f64; N] = mat.new_vector_n();
+    assert_arrays_equal!(vec_m, [0.0; M]);
+    assert_arrays_equal!(vec_n, [0.0; N]);
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dvector_from_dmatrix() {
@@ -0,0 +1,299 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+use linalg_traits::{Mat, Matrix, SMat, Vector};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, SMatrix};
+#[cfg(feature = "ndarray")]
+use ndarray::Array2;
+#[cfg(any(feature = "nalgebra", feature = "ndarray", feature = "faer"))]
+use numtest::*;
+
+// Test conditions.
+static A_ROW: &[f64; 9] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+static B_ROW: &[f64; 4] = &[10.0, 11.0, 12.0, 13.0];
+static C_ROW: &[f64; 6] = &[10.0, 11.0, 12.0, 13.0, 14.0, 15.0];
+
+// Expected results.
+static SUBMATRIX_ROW: &[f64; 4] = &[4.0, 5.0, 7.0, 8.0];
+static SET_BLOCK_ROW: &[f64; 9] = &[1.0, 2.0, 3.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0];
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static SUBMATRIX_COL: &[f64; 4] = &[4.0, 7.0, 5.0, 8.0];
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static SET_BLOCK_COL: &[f64; 9] = &[1.0, 10.0, 13.0, 2.0, 11.0, 14.0, 3.0, 12.0, 15.0];
+
+/// Check submatrix extraction and block assignment for a 3x3 matrix.
+fn block_test_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(3, 3, A_ROW);
+
+    // Submatrix extraction.
+    assert_eq!(
+        a.submatrix(1, 0, 2, 2),
+        M::DMatrixMxN::from_row_slice(2, 2, SUBMATRIX_ROW)
+    );
+    assert_eq!(
+        a.submatrix(0, 2, 3, 1),
+        M::DMatrixMxN::from_row_slice(3, 1, &[3.0, 6.0, 9.0])
+    );
+    assert_eq!(a.submatrix(3, 3, 0, 0).shape(), (0, 0));
+
+    // Block assignment.
+    let mut c = a.clone();
+    c.set_block(0, 1, &M::DMatrixMxN::from_row_slice(2, 2, B_ROW));
+    assert_eq!(
+        c,
+        M::from_row_slice(3, 3, &[1.0, 10.0, 11.0, 4.0, 12.0, 13.0, 7.0, 8.0, 9.0])
+    );
+    c.set_block(0, 0, &a);
+    assert_eq!(c, a);
+
+    // Block assignment from row-major and column-major blocks.
+    let expected = M::from_row_slice(3, 3, SET_BLOCK_ROW);
+    let mut c = a.clone();
+    c.set_block(1, 0, &Mat::from_row_slice(2, 3, C_ROW));
+    assert_eq!(c, expected);
+    #[cfg(feature = "nalgebra")]
+    {
+        let mut c = a.clone();
+        c.set_block(1, 0, &DMatrix::from_row_slice(2, 3, C_ROW));
+        assert_eq!(c, expected);
+    }
+}
+
+/// Check stacking of dynamically-sized matrices.
+fn stack_test_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+    let b = M::from_row_slice(2, 1, &[5.0, 6.0]);
+    let c = M::from_row_slice(1, 2, &[7.0, 8.0]);
+
+    // Horizontal and vertical stacking.
+    let ab = M::hstack(&[&a, &b]);
+    assert_eq!(ab, M::from_row_slice(2, 3, &[1.0, 2.0, 5.0, 3.0, 4.0, 6.0]));
+    let ac = M::vstack(&[&a, &c]);
+    assert_eq!(ac, M::from_row_slice(3, 2, &[1.0, 2.0, 3.0, 4.0, 7.0, 8.0]));
+
+    // Augmented matrix [[A, B], [0, 0]].
+    let augmented = M::vstack(&[&ab, &M::new_with_shape(1, 3)]);
+    assert_eq!(augmented.submatrix(0, 0, 2, 2), a.submatrix(0, 0, 2, 2));
+    assert_eq!(augmented.submatrix(0, 2, 2, 1), b.submatrix(0, 0, 2, 1));
+    assert_eq!(augmented.row(2), M::VectorN::new_with_length(3));
+
+    // Block diagonal matrix.
+    let d = M::block_diag(&[&a, &b, &c]);
+    assert_eq!(
+        d,
+        M::from_row_slice(
+            5,
+            5,
+            &[
+                1.0, 2.0, 0.0, 0.0, 0.0, //
+                3.0, 4.0, 0.0, 0.0, 0.0, //
+                0.0, 0.0, 5.0, 0.0, 0.0, //
+                0.0, 0.0, 6.0, 0.0, 0.0, //
+                0.0, 0.0, 0.0, 7.0, 8.0,
+            ]
+        )
+    );
+
+    // No blocks.
+    assert_eq!(M::hstack::<M>(&[]).shape(), (0, 0));
+}
+
+#[test]
+fn test_mat() {
+    block_test_helper::<Mat<f64>>();
+    stack_test_helper::<Mat<f64>>();
+}
+
+#[test]
+#[should_panic(expected = "Block of size 2x2 at (2, 0) does not fit in a matrix of size 3x3.")]
+fn test_mat_submatrix_panic() {
+    let a = Mat::from_row_slice(3, 3, A_ROW);
+    a.submatrix(2, 0, 2, 2);
+}
+
+#[test]
+#[should_panic(expected = "Block of size 2x2 at (0, 2) does not fit in a matrix of size 3x3.")]
+fn test_mat_set_block_panic() {
+    let mut a = Mat::from_row_slice(3, 3, A_ROW);
+    a.set_block(0, 2, &Mat::from_row_slice(2, 2, B_ROW));
+}
+
+#[test]
+#[should_panic(
+    expected = "Blocks must all have the same number of rows to be stacked horizontally."
+)]
+fn test_mat_hstack_panic() {
+    let a = Mat::from_row_slice(3, 3, A_ROW);
+    let b = Mat::from_row_slice(2, 2, B_ROW);
+    Mat::hstack(&[&a, &b]);
+}
+
+#[test]
+#[should_panic(
+    expected = "Blocks must all have the same number of columns to be stacked vertically."
+)]
+fn test_mat_vstack_panic() {
+    let a = Mat::from_row_slice(3, 3, A_ROW);
+    let b = Mat::from_row_slice(2, 2, B_ROW);
+    Mat::vstack(&[&a, &b]);
+}
+
+#[test]
+fn test_smat() {
+    // Statically-sized matrices can be assembled from dynamically-sized blocks.
+    let a = Mat::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+    let b = Mat::from_row_slice(2, 1, &[5.0, 6.0]);
+    let ab = SMat::<f64, 2, 3>::hstack(&[&a, &b]);
+    assert_eq!(ab, SMat::from([[1.0, 2.0, 5.0], [3.0, 4.0, 6.0]]));
+}
+
+#[test]
+#[should_panic(expected = "Column count mismatch.")]
+fn test_smat_hstack_panic() {
+    let a = Mat::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+    SMat::<f64, 2, 3>::hstack(&[&a, &a]);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let a = DMatrix::from_row_slice(3, 3, A_ROW);
+    assert_arrays_equal!(Matrix::submatrix(&a, 1, 0, 2, 2).as_slice(), SUBMATRIX_COL);
+
+    // Row-major and column-major blocks.
+    let mut c = a.clone();
+    Matrix::set_block(&mut c, 1, 0, &Mat::from_row_slice(2, 3, C_ROW));
+    assert_arrays_equal!(c.as_slice(), SET_BLOCK_COL);
+    let mut c = a.clone();
+    Matrix::set_block(&mut c, 1, 0, &DMatrix::from_row_slice(2, 3, C_ROW));
+    assert_arrays_equal!(c.as_slice(), SET_BLOCK_COL);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    let a = DMatrix::from_row_slice(3, 3, A_ROW);
+    assert_eq!(Matrix::submatrix(&a, 3, 1, 0, 2).shape(), (0, 2));
+    let mut c = a.clone();
+    Matrix::set_block(&mut c, 1, 3, &DMatrix::<f64>::zeros(2, 0));
+    assert_eq!(c, a);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(expected = "Block of size 2x2 at (2, 0) does not fit in a matrix of size 3x3.")]
+fn test_nalgebra_dmatrix_submatrix_panic() {
+    let a = DMatrix::from_row_slice(3, 3, A_ROW);
+    Matrix::submatrix(&a, 2, 0, 2, 2);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    let a = <SMatrix<f64, 3, 3> as Matrix<f64>>::from_row_slice(3, 3, A_ROW);
+    assert_arrays_equal!(Matrix::submatrix(&a, 1, 0, 2, 2).as_slice(), SUBMATRIX_COL);
+    let mut c = a;
+    Matrix::set_block(&mut c, 1, 0, &Mat::from_row_slice(2, 3, C_ROW));
+    assert_arrays_equal!(c.as_slice(), SET_BLOCK_COL);
+
+    // Statically-sized matrices can be assembled from dynamically-sized blocks.
+    let a = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+    let b = DMatrix::from_row_slice(2, 1, &[5.0, 6.0]);
+    let ab = SMatrix::<f64, 2, 3>::hstack(&[&a, &b]);
+    assert_eq!(ab, SMatrix::<f64, 2, 3>::new(1.0, 2.0, 5.0, 3.0, 4.0, 6.0));
+
+    // Statically-sized blocks of different shapes can be stacked after converting them to a
+    // common dynamically-sized type.
+    let a = SMatrix::<f64, 2, 2>::new(1.0, 2.0, 3.0, 4.0);
+    let b = SMatrix::<f64, 2, 1>::new(5.0, 6.0);
+    let a: DMatrix<f64> = Matrix::convert(&a);
+    let b: DMatrix<f64> = Matrix::convert(&b);
+    let ab = SMatrix::<f64, 2, 3>::hstack(&[&a, &b]);
+    assert_eq!(ab, SMatrix::<f64, 2, 3>::new(1.0, 2.0, 5.0, 3.0, 4.0, 6.0));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(expected = "Column count mismatch.")]
+fn test_nalgebra_smatrix_hstack_panic() {
+    let a = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+    SMatrix::<f64, 2, 3>::hstack(&[&a, &a]);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2() {
+    let a = Array2::from_row_slice(3, 3, A_ROW);
+    assert_arrays_equal!(Matrix::as_slice(&a.submatrix(1, 0, 2, 2)), SUBMATRIX_ROW);
+
+    // Row-major and column-major blocks.
+    let mut c = a.clone();
+    c.set_block(1, 0, &Mat::from_row_slice(2, 3, C_ROW));
+    assert_arrays_equal!(Matrix::as_slice(&c), SET_BLOCK_ROW);
+    let mut c = a.clone();
+    c.set_block(1, 0, &Array2::from_row_slice(3, 2, C_ROW).reversed_axes());
+    assert_eq!(
+        c,
+        Array2::from_row_slice(3, 3, &[1.0, 2.0, 3.0, 10.0, 12.0, 14.0, 11.0, 13.0, 15.0])
+    );
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_column_major() {
+    // Blocks are assigned by index into a column-major array.
+    let mut c = Array2::from_row_slice(3, 3, A_ROW).reversed_axes();
+    c.set_block(0, 1, &Mat::from_row_slice(3, 2, C_ROW));
+    assert_eq!(
+        c,
+        Array2::from_row_slice(3, 3, &[1.0, 10.0, 11.0, 2.0, 12.0, 13.0, 3.0, 14.0, 15.0])
+    );
+    assert_eq!(
+        c.submatrix(1, 1, 2, 2),
+        Array2::from_row_slice(2, 2, &[12.0, 13.0, 14.0, 15.0])
+    );
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_empty() {
+    let a = Array2::from_row_slice(3, 3, A_ROW);
+    assert_eq!(a.submatrix(3, 1, 0, 2).dim(), (0, 2));
+    let mut c = a.clone();
+    c.set_block(1, 3, &Array2::<f64>::zeros((2, 0)));
+    assert_eq!(c, a);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    let a = FMat::from_row_slice(3, 3, A_ROW);
+    assert_arrays_equal!(Matrix::submatrix(&a, 1, 0, 2, 2).as_slice(), SUBMATRIX_COL);
+
+    // Row-major blocks, and column-major blocks whose columns may be padded.
+    let mut c = a.clone();
+    Matrix::set_block(&mut c, 1, 0, &Mat::from_row_slice(2, 3, C_ROW));
+    assert_arrays_equal!(c.as_slice(), SET_BLOCK_COL);
+    let mut c = a.clone();
+    let block = FMat::from_row_slice(2, 3, C_ROW);
+    Matrix::set_block(&mut c, 1, 0, &block);
+    assert_arrays_equal!(c.as_slice(), SET_BLOCK_COL);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    let a = FMat::from_row_slice(3, 3, A_ROW);
+    assert_eq!(Matrix::submatrix(&a, 3, 1, 0, 2).shape(), (0, 2));
+    let mut c = a.clone();
+    Matrix::set_block(&mut c, 1, 3, &FMat::<f64>::zeros(2, 0));
+    assert_eq!(c, a);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+#[should_panic(expected = "Block of size 2x2 at (0, 2) does not fit in a matrix of size 3x3.")]
+fn test_faer_mat_set_block_panic() {
+    let mut a = FMat::from_row_slice(3, 3, A_ROW);
+    Matrix::set_block(&mut a, 0, 2, &FMat::from_row_slice(2, 2, B_ROW));
+}
@@ -0,0 +1,196 @@
+#[cfg(feature = "faer")]
+use faer::{Col, Mat as FMat};
+use linalg_traits::{LinalgError, Mat, Matrix, Vector};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, DVector};
+use numtest::*;
+
+// Test conditions.
+static A_ROW: &[f64; 9] = &[4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0];
+static B: &[f64; 3] = &[-12.0, -31.0, 82.0];
+static NOT_PD_ROW: &[f64; 9] = &[4.0, 2.0, 0.0, 2.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+
+// Expected results.
+static L_ROW: &[f64; 9] = &[2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0];
+static X: &[f64; 3] = &[1.0, 0.0, 1.0];
+static DET: f64 = 36.0;
+
+/// Check the Cholesky decomposition of a 3x3 symmetric positive definite matrix, as well as the
+/// error reported for a matrix that is not positive definite.
+fn cholesky_test_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(3, 3, A_ROW);
+    let chol = a.cholesky().unwrap();
+    assert_arrays_equal_to_decimal!(chol.l().as_row_slice(), L_ROW, 14);
+
+    // Linear solve.
+    let b = M::VectorM::from_slice(B);
+    assert_arrays_equal_to_decimal!(chol.solve(&b).unwrap().as_slice(), X, 13);
+    let mut x = b.clone();
+    chol.solve_in_place(&mut x).unwrap();
+    assert_arrays_equal_to_decimal!(x.as_slice(), X, 13);
+
+    // Determinant.
+    assert_equal_to_decimal!(chol.determinant(), DET, 12);
+    assert_equal_to_decimal!(chol.log_det(), DET.ln(), 14);
+
+    // Inverse.
+    let a_inv = chol.inverse();
+    let mut identity = M::new_with_shape(3, 3);
+    identity.gemm(1.0, &a, false, &a_inv, false, 0.0);
+    for i in 0..3 {
+        for j in 0..3 {
+            assert_equal_to_decimal!(identity[(i, j)], if i == j { 1.0 } else { 0.0 }, 12);
+        }
+    }
+
+    // Matrix that is not positive definite (it is only positive semidefinite).
+    let not_pd = M::from_row_slice(3, 3, NOT_PD_ROW);
+    assert_eq!(
+        not_pd.cholesky(),
+        Err(LinalgError::NotPositiveDefinite { pivot: 1 })
+    );
+}
+
+#[test]
+fn test_mat() {
+    cholesky_test_helper::<Mat<f64>>();
+}
+
+#[test]
+fn test_mat_nan() {
+    let a = Mat::from_row_slice(2, 2, &[f64::NAN, 0.0, 0.0, 1.0]);
+    assert_eq!(
+        a.cholesky(),
+        Err(LinalgError::NotPositiveDefinite { pivot: 0 })
+    );
+}
+
+#[test]
+fn test_mat_solve_length_mismatch() {
+    let chol = Mat::from_row_slice(3, 3, A_ROW).cholesky().unwrap();
+    let mut b = vec![1.0, 2.0];
+    assert_eq!(
+        chol.solve_in_place(&mut b),
+        Err(LinalgError::LengthMismatch {
+            expected: 3,
+            actual: 2
+        })
+    );
+    assert_eq!(b, vec![1.0, 2.0]);
+}
+
+#[test]
+fn test_mat_not_square() {
+    let a = Mat::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(
+        a.cholesky(),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 2),
+            actual: (2, 3)
+        })
+    );
+}
+
+#[test]
+fn test_error_display() {
+    assert_eq!(
+        LinalgError::NotPositiveDefinite { pivot: 1 }.to_string(),
+        "Matrix is not positive definite (non-positive pivot at index 1)."
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let a = DMatrix::from_row_slice(3, 3, A_ROW);
+    let chol = Matrix::cholesky(&a).unwrap();
+    assert_arrays_equal_to_decimal!(chol.l().as_row_slice(), L_ROW, 14);
+    let x = chol.solve(&DVector::from_slice(B)).unwrap();
+    assert_arrays_equal_to_decimal!(x.as_slice(), X, 13);
+    assert_equal_to_decimal!(chol.determinant(), DET, 12);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_not_positive_definite() {
+    // The pivot at which the native factorization fails is reported.
+    let not_pd = DMatrix::from_row_slice(3, 3, NOT_PD_ROW);
+    assert_eq!(
+        Matrix::cholesky(&not_pd),
+        Err(LinalgError::NotPositiveDefinite { pivot: 1 })
+    );
+    let a = DMatrix::from_row_slice(2, 2, &[f64::NAN, 0.0, 0.0, 1.0]);
+    assert_eq!(
+        Matrix::cholesky(&a),
+        Err(LinalgError::NotPositiveDefinite { pivot: 0 })
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    let chol = Matrix::cholesky(&DMatrix::<f64>::zeros(0, 0)).unwrap();
+    assert_eq!(Matrix::shape(&chol.l()), (0, 0));
+    assert_eq!(chol.determinant(), 1.0);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_not_square() {
+    let a = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(
+        Matrix::cholesky(&a),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 2),
+            actual: (2, 3)
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    let a = FMat::from_row_slice(3, 3, A_ROW);
+    let chol = Matrix::cholesky(&a).unwrap();
+    assert_arrays_equal_to_decimal!(chol.l().as_row_slice(), L_ROW, 14);
+    let x = chol.solve(&Col::from_slice(B)).unwrap();
+    assert_arrays_equal_to_decimal!(x.as_slice(), X, 13);
+    assert_equal_to_decimal!(chol.determinant(), DET, 12);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_not_positive_definite() {
+    // The pivot at which the native factorization fails is reported.
+    let not_pd = FMat::from_row_slice(3, 3, NOT_PD_ROW);
+    assert_eq!(
+        Matrix::cholesky(&not_pd),
+        Err(LinalgError::NotPositiveDefinite { pivot: 1 })
+    );
+    let a = FMat::from_row_slice(2, 2, &[f64::NAN, 0.0, 0.0, 1.0]);
+    assert_eq!(
+        Matrix::cholesky(&a),
+        Err(LinalgError::NotPositiveDefinite { pivot: 0 })
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    let chol = Matrix::cholesky(&FMat::<f64>::zeros(0, 0)).unwrap();
+    assert_eq!(Matrix::shape(&chol.l()), (0, 0));
+    assert_eq!(chol.determinant(), 1.0);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_not_square() {
+    let a = FMat::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(
+        Matrix::cholesky(&a),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 2),
+            actual: (2, 3)
+        })
+    );
+}
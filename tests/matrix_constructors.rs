@@ -0,0 +1,334 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+use linalg_traits::{Mat, Matrix, SMat, Vector};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, DVector, SMatrix};
+#[cfg(feature = "ndarray")]
+use ndarray::{Array1, Array2};
+use numtest::*;
+
+// Test conditions.
+#[cfg(any(feature = "nalgebra", feature = "ndarray"))]
+static DIAGONAL: &[f64; 3] = &[2.0, -3.0, 5.0];
+#[cfg(any(feature = "nalgebra", feature = "ndarray", feature = "faer"))]
+static FULL_ROW: &[f64; 9] = &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+
+// Expected results.
+static FROM_FN_ROW: &[f64; 6] = &[0.0, 1.0, 2.0, 10.0, 11.0, 12.0];
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static FROM_FN_COL: &[f64; 6] = &[0.0, 10.0, 1.0, 11.0, 2.0, 12.0];
+#[cfg(any(feature = "nalgebra", feature = "ndarray", feature = "faer"))]
+static IDENTITY: &[f64; 9] = &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+#[cfg(any(feature = "nalgebra", feature = "ndarray"))]
+static FROM_DIAGONAL: &[f64; 9] = &[2.0, 0.0, 0.0, 0.0, -3.0, 0.0, 0.0, 0.0, 5.0];
+#[cfg(any(feature = "nalgebra", feature = "ndarray", feature = "faer"))]
+static FULL_DIAGONAL: &[f64; 3] = &[0.0, 4.0, 8.0];
+
+/// Check the square matrix constructors and accessors for a 3x3 matrix.
+fn square_test_helper<M: Matrix<f64>>() {
+    // Identity.
+    let eye = M::identity(3);
+    assert_eq!(
+        eye,
+        M::from_row_slice(3, 3, &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0])
+    );
+    assert_eq!(eye.trace(), 3.0);
+
+    // Diagonal matrix and diagonal.
+    let d = M::VectorM::from_slice(&[2.0, -3.0, 5.0]);
+    let a = M::from_diagonal(&d);
+    assert_eq!(
+        a,
+        M::from_row_slice(3, 3, &[2.0, 0.0, 0.0, 0.0, -3.0, 0.0, 0.0, 0.0, 5.0])
+    );
+    assert_eq!(a.diagonal(), d);
+    assert_eq!(a.trace(), 4.0);
+
+    // Diagonal and trace of a full matrix.
+    let b = M::from_fn(3, 3, |i, j| (3 * i + j) as f64);
+    assert_eq!(b.diagonal(), M::VectorM::from_slice(&[0.0, 4.0, 8.0]));
+    assert_eq!(b.trace(), 12.0);
+}
+
+/// Check the general matrix constructors for a 2x3 matrix.
+fn general_test_helper<M: Matrix<f64>>() {
+    let expected = M::from_row_slice(2, 3, FROM_FN_ROW);
+
+    // From a function.
+    assert_eq!(M::from_fn(2, 3, |i, j| (10 * i + j) as f64), expected);
+
+    // From an element.
+    assert_eq!(
+        M::from_element(2, 3, 1.5),
+        M::from_row_slice(2, 3, &[1.5; 6])
+    );
+
+    // From rows and columns.
+    let rows = [
+        M::VectorN::from_slice(&[0.0, 1.0, 2.0]),
+        M::VectorN::from_slice(&[10.0, 11.0, 12.0]),
+    ];
+    assert_eq!(M::from_rows(&rows), expected);
+    let columns = [
+        M::VectorM::from_slice(&[0.0, 10.0]),
+        M::VectorM::from_slice(&[1.0, 11.0]),
+        M::VectorM::from_slice(&[2.0, 12.0]),
+    ];
+    assert_eq!(M::from_columns(&columns), expected);
+}
+
+#[test]
+fn test_mat() {
+    square_test_helper::<Mat<f64>>();
+    general_test_helper::<Mat<f64>>();
+}
+
+#[test]
+fn test_mat_empty() {
+    assert_eq!(Mat::<f64>::identity(0).shape(), (0, 0));
+    assert_eq!(Mat::<f64>::identity(0).trace(), 0.0);
+    assert_eq!(Mat::<f64>::from_rows(&[]).shape(), (0, 0));
+    assert_eq!(Mat::<f64>::from_columns(&[]).shape(), (0, 0));
+    assert_eq!(Mat::<f64>::from_fn(2, 0, |_, _| 1.0).shape(), (2, 0));
+}
+
+#[test]
+#[should_panic(expected = "Rows must all have the same length.")]
+fn test_mat_from_rows_panic() {
+    Mat::<f64>::from_rows(&[vec![1.0, 2.0], vec![3.0]]);
+}
+
+#[test]
+#[should_panic(expected = "Columns must all have the same length.")]
+fn test_mat_from_columns_panic() {
+    Mat::<f64>::from_columns(&[vec![1.0, 2.0], vec![3.0]]);
+}
+
+#[test]
+#[should_panic(expected = "Matrix is not square (2x3).")]
+fn test_mat_trace_panic() {
+    Mat::<f64>::new_with_shape(2, 3).trace();
+}
+
+#[test]
+fn test_smat() {
+    let a = <SMat<f64, 2, 3> as Matrix<f64>>::from_fn(2, 3, |i, j| (10 * i + j) as f64);
+    assert_arrays_equal!(a.as_slice(), FROM_FN_ROW);
+    let a = <SMat<f64, 2, 3> as Matrix<f64>>::from_element(2, 3, 1.5);
+    assert_arrays_equal!(a.as_slice(), &[1.5; 6]);
+}
+
+#[test]
+#[should_panic(expected = "Column count mismatch.")]
+fn test_smat_from_fn_panic() {
+    let _ = <SMat<f64, 2, 3> as Matrix<f64>>::from_fn(2, 2, |_, _| 0.0);
+}
+
+#[test]
+#[should_panic(expected = "Row count mismatch.")]
+fn test_smat_identity_panic() {
+    let _ = <SMat<f64, 3, 3> as Matrix<f64>>::identity(2);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let a = <DMatrix<f64> as Matrix<f64>>::from_fn(2, 3, |i, j| (10 * i + j) as f64);
+    assert_arrays_equal!(a.as_slice(), FROM_FN_COL);
+    let a = <DMatrix<f64> as Matrix<f64>>::from_element(2, 3, 1.5);
+    assert_eq!(a.shape(), (2, 3));
+    assert_arrays_equal!(a.as_slice(), &[1.5; 6]);
+    let columns = [
+        DVector::from_slice(&[0.0, 10.0]),
+        DVector::from_slice(&[1.0, 11.0]),
+        DVector::from_slice(&[2.0, 12.0]),
+    ];
+    let a = <DMatrix<f64> as Matrix<f64>>::from_columns(&columns);
+    assert_arrays_equal!(a.as_slice(), FROM_FN_COL);
+
+    let eye = <DMatrix<f64> as Matrix<f64>>::identity(3);
+    assert_arrays_equal!(eye.as_slice(), IDENTITY);
+    let d = DVector::from_slice(DIAGONAL);
+    let a = <DMatrix<f64> as Matrix<f64>>::from_diagonal(&d);
+    assert_arrays_equal!(a.as_slice(), FROM_DIAGONAL);
+    let b = <DMatrix<f64> as Matrix<f64>>::from_row_slice(3, 3, FULL_ROW);
+    assert_eq!(Matrix::diagonal(&b), DVector::from_slice(FULL_DIAGONAL));
+    assert_eq!(Matrix::trace(&b), 12.0);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    let eye = <DMatrix<f64> as Matrix<f64>>::identity(0);
+    assert_eq!(eye.shape(), (0, 0));
+    assert_eq!(Matrix::trace(&eye), 0.0);
+    assert_eq!(Matrix::diagonal(&eye).len(), 0);
+    let d = DVector::<f64>::zeros(0);
+    assert_eq!(
+        <DMatrix<f64> as Matrix<f64>>::from_diagonal(&d).shape(),
+        (0, 0)
+    );
+    assert_eq!(
+        <DMatrix<f64> as Matrix<f64>>::from_fn(2, 0, |_, _| 1.0).shape(),
+        (2, 0)
+    );
+    assert_eq!(
+        <DMatrix<f64> as Matrix<f64>>::from_element(0, 3, 1.0).shape(),
+        (0, 3)
+    );
+    assert_eq!(
+        <DMatrix<f64> as Matrix<f64>>::from_columns(&[]).shape(),
+        (0, 0)
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(expected = "Columns must all have the same length.")]
+fn test_nalgebra_dmatrix_from_columns_panic() {
+    let columns = [
+        DVector::from_slice(&[1.0, 2.0]),
+        DVector::from_slice(&[3.0]),
+    ];
+    <DMatrix<f64> as Matrix<f64>>::from_columns(&columns);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(expected = "Matrix is not square (2x3).")]
+fn test_nalgebra_dmatrix_trace_panic() {
+    Matrix::trace(&DMatrix::<f64>::zeros(2, 3));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(expected = "Matrix is not square (2x3).")]
+fn test_nalgebra_dmatrix_diagonal_panic() {
+    Matrix::diagonal(&DMatrix::<f64>::zeros(2, 3));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    let a = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_fn(2, 3, |i, j| (10 * i + j) as f64);
+    assert_arrays_equal!(a.as_slice(), FROM_FN_COL);
+    let a = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_element(2, 3, 1.5);
+    assert_arrays_equal!(a.as_slice(), &[1.5; 6]);
+    let eye = <SMatrix<f64, 3, 3> as Matrix<f64>>::identity(3);
+    assert_arrays_equal!(eye.as_slice(), IDENTITY);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(expected = "Row count mismatch.")]
+fn test_nalgebra_smatrix_identity_panic() {
+    let _ = <SMatrix<f64, 3, 3> as Matrix<f64>>::identity(2);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(expected = "Column count mismatch.")]
+fn test_nalgebra_smatrix_from_element_panic() {
+    let _ = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_element(2, 2, 0.0);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2() {
+    let a = <Array2<f64> as Matrix<f64>>::from_fn(2, 3, |i, j| (10 * i + j) as f64);
+    assert_arrays_equal!(Matrix::as_slice(&a), FROM_FN_ROW);
+    let a = <Array2<f64> as Matrix<f64>>::from_element(2, 3, 1.5);
+    assert_eq!(Matrix::shape(&a), (2, 3));
+    assert_arrays_equal!(Matrix::as_slice(&a), &[1.5; 6]);
+
+    let eye = <Array2<f64> as Matrix<f64>>::identity(3);
+    assert_arrays_equal!(Matrix::as_slice(&eye), IDENTITY);
+    let d = Array1::from_vec(DIAGONAL.to_vec());
+    let a = <Array2<f64> as Matrix<f64>>::from_diagonal(&d);
+    assert_arrays_equal!(Matrix::as_slice(&a), FROM_DIAGONAL);
+    let b = Array2::from_row_slice(3, 3, FULL_ROW);
+    assert_eq!(
+        Matrix::diagonal(&b),
+        Array1::from_vec(FULL_DIAGONAL.to_vec())
+    );
+    assert_eq!(Matrix::trace(&b), 12.0);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_column_major() {
+    // The diagonal and trace of a column-major array are read by index, not by memory order.
+    let b = Array2::from_row_slice(3, 3, FULL_ROW).reversed_axes();
+    assert_eq!(
+        Matrix::diagonal(&b),
+        Array1::from_vec(FULL_DIAGONAL.to_vec())
+    );
+    assert_eq!(Matrix::trace(&b), 12.0);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_empty() {
+    let eye = <Array2<f64> as Matrix<f64>>::identity(0);
+    assert_eq!(Matrix::shape(&eye), (0, 0));
+    assert_eq!(Matrix::trace(&eye), 0.0);
+    assert_eq!(Matrix::diagonal(&eye).len(), 0);
+    let d = Array1::<f64>::zeros(0);
+    assert_eq!(
+        Matrix::shape(&<Array2<f64> as Matrix<f64>>::from_diagonal(&d)),
+        (0, 0)
+    );
+    assert_eq!(
+        Matrix::shape(&<Array2<f64> as Matrix<f64>>::from_fn(2, 0, |_, _| 1.0)),
+        (2, 0)
+    );
+    assert_eq!(
+        Matrix::shape(&<Array2<f64> as Matrix<f64>>::from_element(0, 3, 1.0)),
+        (0, 3)
+    );
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+#[should_panic(expected = "Matrix is not square (2x3).")]
+fn test_ndarray_array2_trace_panic() {
+    Matrix::trace(&Array2::<f64>::zeros((2, 3)));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    let a = <FMat<f64> as Matrix<f64>>::from_fn(2, 3, |i, j| (10 * i + j) as f64);
+    assert_arrays_equal!(a.as_slice(), FROM_FN_COL);
+    let a = <FMat<f64> as Matrix<f64>>::from_element(2, 3, 1.5);
+    assert_eq!(Matrix::shape(&a), (2, 3));
+    assert_arrays_equal!(a.as_slice(), &[1.5; 6]);
+
+    let eye = <FMat<f64> as Matrix<f64>>::identity(3);
+    assert_arrays_equal!(eye.as_slice(), IDENTITY);
+    let b = <FMat<f64> as Matrix<f64>>::from_row_slice(3, 3, FULL_ROW);
+    assert_arrays_equal!(Matrix::diagonal(&b).as_slice(), FULL_DIAGONAL);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    let eye = <FMat<f64> as Matrix<f64>>::identity(0);
+    assert_eq!(Matrix::shape(&eye), (0, 0));
+    assert_eq!(Vector::len(&Matrix::diagonal(&eye)), 0);
+    assert_eq!(
+        Matrix::shape(&<FMat<f64> as Matrix<f64>>::from_fn(2, 0, |_, _| 1.0)),
+        (2, 0)
+    );
+    assert_eq!(
+        Matrix::shape(&<FMat<f64> as Matrix<f64>>::from_element(0, 3, 1.0)),
+        (0, 3)
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+#[should_panic(expected = "Matrix is not square (2x3).")]
+fn test_faer_mat_diagonal_panic() {
+    Matrix::diagonal(&FMat::<f64>::zeros(2, 3));
+}
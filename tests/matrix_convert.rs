@@ -0,0 +1,139 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+use linalg_traits::{Mat, Matrix, SMat};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, SMatrix};
+#[cfg(feature = "ndarray")]
+use ndarray::{Array2, ShapeBuilder};
+
+// Test conditions.
+static A: &[f64; 6] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+
+/// Check the conversion from a 2x3 matrix of type `M` into a matrix of type `N`, and the copy
+/// from a matrix of type `M` into a matrix of type `N`.
+fn convert_test_helper<M: Matrix<f64>, N: Matrix<f64>>(a: &M) {
+    assert_eq!(a.convert::<N>(), N::from_row_slice(2, 3, A));
+    let mut b = N::new_with_shape(2, 3);
+    Matrix::copy_from(&mut b, a);
+    assert_eq!(b, N::from_row_slice(2, 3, A));
+}
+
+/// Check the conversion from a 2x3 matrix of type `M` into every matrix type.
+fn convert_to_all_test_helper<M: Matrix<f64>>(a: &M) {
+    convert_test_helper::<M, Mat<f64>>(a);
+    convert_test_helper::<M, SMat<f64, 2, 3>>(a);
+    #[cfg(feature = "nalgebra")]
+    {
+        convert_test_helper::<M, DMatrix<f64>>(a);
+        convert_test_helper::<M, SMatrix<f64, 2, 3>>(a);
+    }
+    #[cfg(feature = "ndarray")]
+    convert_test_helper::<M, Array2<f64>>(a);
+    #[cfg(feature = "faer")]
+    convert_test_helper::<M, FMat<f64>>(a);
+}
+
+#[test]
+fn test_mat() {
+    convert_to_all_test_helper(&Mat::from_row_slice(2, 3, A));
+}
+
+#[test]
+fn test_mat_empty() {
+    let a = Mat::<f64>::new_with_shape(0, 3);
+    assert_eq!(a.convert::<Mat<f64>>().shape(), (0, 3));
+}
+
+#[test]
+#[should_panic(
+    expected = "Shape of the other matrix (3x2) does not match the shape of this matrix (2x3)."
+)]
+fn test_mat_copy_from_panic() {
+    let mut a = Mat::from_row_slice(2, 3, A);
+    Matrix::copy_from(&mut a, &Mat::from_row_slice(3, 2, A));
+}
+
+#[test]
+fn test_smat() {
+    convert_to_all_test_helper(&SMat::<f64, 2, 3>::from_row_slice(2, 3, A));
+}
+
+#[test]
+#[should_panic(expected = "Row count mismatch.")]
+fn test_smat_convert_panic() {
+    let _ = Mat::from_row_slice(3, 2, A).convert::<SMat<f64, 2, 3>>();
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    convert_to_all_test_helper(&DMatrix::from_row_slice(2, 3, A));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    let a = DMatrix::<f64>::zeros(0, 3);
+    assert_eq!(a.convert::<Mat<f64>>().shape(), (0, 3));
+    let mut b = DMatrix::<f64>::zeros(2, 0);
+    Matrix::copy_from(&mut b, &Mat::new_with_shape(2, 0));
+    assert_eq!(b.shape(), (2, 0));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    convert_to_all_test_helper(&SMatrix::<f64, 2, 3>::from_row_slice(A));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(expected = "Row count mismatch.")]
+fn test_nalgebra_smatrix_convert_panic() {
+    let _ = Mat::from_row_slice(3, 2, A).convert::<SMatrix<f64, 2, 3>>();
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2() {
+    convert_to_all_test_helper(&Array2::from_row_slice(2, 3, A));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_empty() {
+    let a = Array2::<f64>::zeros((0, 3));
+    assert_eq!(a.convert::<Mat<f64>>().shape(), (0, 3));
+    let mut b = Array2::<f64>::zeros((2, 0).f());
+    Matrix::copy_from(&mut b, &Mat::new_with_shape(2, 0));
+    assert_eq!(b.dim(), (2, 0));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_column_major() {
+    // An array in column-major (Fortran) layout is not stored contiguously in row-major order.
+    let a = Array2::from_shape_vec((2, 3).f(), vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]).unwrap();
+    convert_to_all_test_helper(&a);
+
+    // Copy into an array in column-major layout.
+    let mut b = Array2::from_shape_vec((2, 3).f(), vec![0.0; 6]).unwrap();
+    Matrix::copy_from(&mut b, &Mat::from_row_slice(2, 3, A));
+    assert_eq!(b, Array2::from_row_slice(2, 3, A));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    convert_to_all_test_helper(&FMat::from_row_slice(2, 3, A));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    let a = FMat::<f64>::zeros(0, 3);
+    assert_eq!(a.convert::<Mat<f64>>().shape(), (0, 3));
+    let mut b = FMat::<f64>::zeros(2, 0);
+    Matrix::copy_from(&mut b, &Mat::new_with_shape(2, 0));
+    assert_eq!(b.shape(), (2, 0));
+}
@@ -0,0 +1,229 @@
+use linalg_traits::{Mat, Matrix};
+use std::borrow::Cow;
+use std::ops::{Index, IndexMut};
+
+/// Matrix type implementing only the required methods of the [`Matrix`] trait, used to check the
+/// default method implementations.
+#[derive(Clone, Debug, PartialEq)]
+struct MinimalMatrix(Mat<f64>);
+
+impl Index<(usize, usize)> for MinimalMatrix {
+    type Output = f64;
+
+    fn index(&self, index: (usize, usize)) -> &f64 {
+        &self.0[index]
+    }
+}
+
+impl IndexMut<(usize, usize)> for MinimalMatrix {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut f64 {
+        &mut self.0[index]
+    }
+}
+
+impl Matrix<f64> for MinimalMatrix {
+    type VectorM = Vec<f64>;
+
+    type VectorN = Vec<f64>;
+
+    type MatrixNxP<const P: usize> = MinimalMatrix;
+
+    type MatrixMxP<const P: usize> = MinimalMatrix;
+
+    type Transpose = MinimalMatrix;
+
+    type MatrixMxM = MinimalMatrix;
+
+    type MatrixNxN = MinimalMatrix;
+
+    type MatrixT<T: linalg_traits::Scalar> = Mat<T>;
+
+    type DMatrixMxN = MinimalMatrix;
+
+    fn get(&self, index: (usize, usize)) -> Option<&f64> {
+        self.0.get(index)
+    }
+
+    fn is_statically_sized() -> bool {
+        false
+    }
+
+    fn is_dynamically_sized() -> bool {
+        true
+    }
+
+    fn is_row_major() -> bool {
+        true
+    }
+
+    fn is_column_major() -> bool {
+        false
+    }
+
+    fn new_with_shape(rows: usize, cols: usize) -> Self {
+        MinimalMatrix(Mat::new_with_shape(rows, cols))
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        self.0.shape()
+    }
+
+    fn from_row_slice(rows: usize, cols: usize, slice: &[f64]) -> Self {
+        MinimalMatrix(Mat::from_row_slice(rows, cols, slice))
+    }
+
+    fn from_col_slice(rows: usize, cols: usize, slice: &[f64]) -> Self {
+        MinimalMatrix(Mat::from_col_slice(rows, cols, slice))
+    }
+
+    fn as_slice(&self) -> Cow<'_, [f64]> {
+        self.0.as_slice()
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        MinimalMatrix(self.0.add(&other.0))
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        self.0.add_assign(&other.0);
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        MinimalMatrix(self.0.sub(&other.0))
+    }
+
+    fn sub_assign(&mut self, other: &Self) {
+        self.0.sub_assign(&other.0);
+    }
+
+    fn mul(&self, scalar: f64) -> Self {
+        MinimalMatrix(self.0.mul(scalar))
+    }
+
+    fn mul_assign(&mut self, scalar: f64) {
+        self.0.mul_assign(scalar);
+    }
+
+    fn div(&self, scalar: f64) -> Self {
+        MinimalMatrix(self.0.div(scalar))
+    }
+
+    fn div_assign(&mut self, scalar: f64) {
+        self.0.div_assign(scalar);
+    }
+
+    fn mul_vector(&self, vector: &Vec<f64>) -> Vec<f64> {
+        self.0.mul_vector(vector)
+    }
+
+    fn mul_vector_to(&self, vector: &Vec<f64>, out: &mut Vec<f64>) {
+        self.0.mul_vector_to(vector, out);
+    }
+
+    fn vector_mul(&self, vector: &Vec<f64>) -> Vec<f64> {
+        self.0.vector_mul(vector)
+    }
+
+    fn vector_mul_to(&self, vector: &Vec<f64>, out: &mut Vec<f64>) {
+        self.0.vector_mul_to(vector, out);
+    }
+
+    fn matmul<const P: usize>(&self, other: &Self) -> Self {
+        MinimalMatrix(self.0.matmul::<P>(&other.0))
+    }
+
+    fn matmul_to<const P: usize>(&self, other: &Self, out: &mut Self) {
+        self.0.matmul_to::<P>(&other.0, &mut out.0);
+    }
+
+    fn gemm(
+        &mut self,
+        alpha: f64,
+        a: &Self,
+        transpose_a: bool,
+        b: &Self,
+        transpose_b: bool,
+        beta: f64,
+    ) {
+        self.0
+            .gemm(alpha, &a.0, transpose_a, &b.0, transpose_b, beta);
+    }
+
+    fn transpose(&self) -> Self {
+        MinimalMatrix(self.0.transpose())
+    }
+
+    fn transpose_in_place(&mut self) {
+        self.0.transpose_in_place();
+    }
+}
+
+// Test conditions.
+static A_ROW: &[f64; 6] = &[1.0, -2.0, 3.0, -4.0, 5.0, -6.0];
+static B_ROW: &[f64; 6] = &[2.0, 4.0, -8.0, 1.0, -10.0, 12.0];
+
+#[test]
+fn test_static_shape() {
+    assert_eq!(MinimalMatrix::static_shape(), None);
+}
+
+#[test]
+fn test_try_as_contiguous_slice() {
+    let mut a = MinimalMatrix::from_row_slice(2, 3, A_ROW);
+    assert!(a.try_as_contiguous_slice().is_none());
+    assert!(a.try_as_contiguous_mut_slice().is_none());
+}
+
+#[test]
+fn test_map() {
+    let a = MinimalMatrix::from_row_slice(2, 3, A_ROW);
+    assert_eq!(
+        a.map(|x| x * x),
+        MinimalMatrix::from_row_slice(2, 3, &[1.0, 4.0, 9.0, 16.0, 25.0, 36.0])
+    );
+    let mut c = a.clone();
+    c.map_in_place(f64::abs);
+    assert_eq!(
+        c,
+        MinimalMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+    );
+}
+
+#[test]
+fn test_zip() {
+    let a = MinimalMatrix::from_row_slice(2, 3, A_ROW);
+    let b = MinimalMatrix::from_row_slice(2, 3, B_ROW);
+    assert_eq!(
+        a.zip_map(&b, |x, y| x + y),
+        MinimalMatrix::from_row_slice(2, 3, &[3.0, 2.0, -5.0, -3.0, -5.0, 6.0])
+    );
+    let mut c = a.clone();
+    c.zip_apply(&b, |x, y| *x = x.min(y));
+    assert_eq!(
+        c,
+        MinimalMatrix::from_row_slice(2, 3, &[1.0, -2.0, -8.0, -4.0, -10.0, -6.0])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Matrices have incompatible shapes.")]
+fn test_zip_apply_panic() {
+    let mut a = MinimalMatrix::from_row_slice(2, 3, A_ROW);
+    a.zip_apply(&MinimalMatrix::new_with_shape(3, 2), |x, y| *x += y);
+}
+
+#[test]
+fn test_fold() {
+    let a = MinimalMatrix::from_row_slice(2, 3, A_ROW);
+    assert_eq!(a.fold(0.0, |sum, x| sum + x), -3.0);
+    assert_eq!(a.fold(0, |count, x| count + usize::from(x > 0.0)), 3);
+}
+
+#[test]
+fn test_map_scalar() {
+    let a = MinimalMatrix::from_row_slice(2, 3, A_ROW);
+    assert_eq!(
+        a.map_scalar(|x| x + 0.5),
+        Mat::from_row_slice(2, 3, &[1.5, -1.5, 3.5, -3.5, 5.5, -5.5])
+    );
+}
@@ -1,6 +1,6 @@
 #[cfg(feature = "faer")]
 use faer::Mat as FMat;
-use linalg_traits::{Mat, Matrix};
+use linalg_traits::{Mat, Matrix, SMat};
 #[cfg(feature = "nalgebra")]
 use nalgebra::{DMatrix, SMatrix};
 #[cfg(feature = "ndarray")]
@@ -12,6 +12,12 @@ fn test_mat() {
     assert!(!Mat::<f64>::is_statically_sized());
 }
 
+#[test]
+fn test_smat() {
+    assert!(!SMat::<f64, 3, 3>::is_dynamically_sized());
+    assert!(SMat::<f64, 3, 3>::is_statically_sized());
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dmatrix() {
@@ -0,0 +1,333 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+use linalg_traits::{Eigen, LinalgError, Mat, Matrix, Schur, Vector};
+#[cfg(feature = "nalgebra")]
+use nalgebra::DMatrix;
+use numtest::*;
+
+// Test conditions (non-symmetric matrix similar to diag(-1, 2, [1 2; -2 1]), i.e. whose
+// eigenvalues are -1, 2, and 1 ± 2i).
+static A_ROW: &[f64; 16] = &[
+    3.5, -0.5, -4.5, -0.5, -2.5, 3.5, 2.5, 0.5, 4.0, -4.0, -5.0, 0.0, 0.0, 2.0, 0.0, 1.0,
+];
+
+static JORDAN_ROW: &[f64; 9] = &[2.0, 1.0, 0.0, 0.0, 2.0, 1.0, 0.0, 0.0, 2.0];
+
+// Expected results (sorted by real part, then by imaginary part).
+static EIGENVALUES_REAL: &[f64; 4] = &[-1.0, 1.0, 1.0, 2.0];
+static EIGENVALUES_IMAG: &[f64; 4] = &[0.0, -2.0, 2.0, 0.0];
+
+/// Sort eigenvalues by their real parts, then by their imaginary parts.
+fn sorted(re: &[f64], im: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let mut pairs: Vec<(f64, f64)> = re.iter().copied().zip(im.iter().copied()).collect();
+    pairs.sort_by(|a, b| {
+        ((a.0 * 1e8).round(), a.1)
+            .partial_cmp(&((b.0 * 1e8).round(), b.1))
+            .unwrap()
+    });
+    pairs.into_iter().unzip()
+}
+
+/// Check that the eigenvalues of a matrix match their expected values.
+fn assert_eigenvalues(re: &[f64], im: &[f64], expected_re: &[f64], expected_im: &[f64]) {
+    let (re, im) = sorted(re, im);
+    assert_arrays_equal_to_decimal!(re, expected_re, 12);
+    assert_arrays_equal_to_decimal!(im, expected_im, 12);
+}
+
+/// Check every eigenpair of an eigendecomposition (`A * v = λ * v` with `‖v‖ = 1`).
+fn assert_eigenpairs<M: Matrix<f64>>(a: &M, eigen: &Eigen<f64, M>, decimal: i32) {
+    let n = a.shape().0;
+    let (re, im) = (eigen.eigenvalues_real(), eigen.eigenvalues_imag());
+    for k in 0..n {
+        let (vr, vi) = eigen.eigenvector(k);
+        let norm_squared = vr.dot(&vr) + vi.dot(&vi);
+        assert_equal_to_decimal!(norm_squared, 1.0, 13);
+
+        // A * (vr + i * vi) = (re + i * im) * (vr + i * vi).
+        for i in 0..n {
+            let a_vr = (0..n).map(|j| a[(i, j)] * vr[j]).sum::<f64>();
+            let a_vi = (0..n).map(|j| a[(i, j)] * vi[j]).sum::<f64>();
+            assert_equal_to_decimal!(a_vr, re[k] * vr[i] - im[k] * vi[i], decimal);
+            assert_equal_to_decimal!(a_vi, im[k] * vr[i] + re[k] * vi[i], decimal);
+        }
+    }
+}
+
+/// Check the real Schur decomposition of the 4x4 matrix `a` (`A = Z * T * Zᵀ`), along with the
+/// eigenvalues and eigenvectors derived from it.
+fn assert_schur<M: Matrix<f64>>(a: &M, schur: &Schur<f64, M>) {
+    let (z, t) = (schur.z(), schur.t());
+    for i in 0..4 {
+        for j in 0..4 {
+            // Orthogonal Schur vectors.
+            let dot = (0..4).map(|k| z[(k, i)] * z[(k, j)]).sum::<f64>();
+            assert_equal_to_decimal!(dot, if i == j { 1.0 } else { 0.0 }, 14);
+
+            // A = Z * T * Zᵀ.
+            let z_t_zt = (0..4)
+                .flat_map(|k| (0..4).map(move |l| (k, l)))
+                .map(|(k, l)| z[(i, k)] * t[(k, l)] * z[(j, l)])
+                .sum::<f64>();
+            assert_equal_to_decimal!(z_t_zt, a[(i, j)], 12);
+
+            // T is upper quasi-triangular.
+            if i > j + 1 {
+                assert_eq!(t[(i, j)], 0.0);
+            }
+        }
+    }
+    let (re, im) = schur.eigenvalues();
+    assert_eigenvalues(
+        re.as_slice().as_ref(),
+        im.as_slice().as_ref(),
+        EIGENVALUES_REAL,
+        EIGENVALUES_IMAG,
+    );
+    assert_eigenpairs(a, &schur.eigen(), 12);
+}
+
+/// Check the general eigendecomposition and real Schur decomposition of a 4x4 matrix.
+fn eigen_test_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(4, 4, A_ROW);
+
+    // Eigenvalues only.
+    let (re, im) = a.eigenvalues().unwrap();
+    assert_eigenvalues(
+        re.as_slice().as_ref(),
+        im.as_slice().as_ref(),
+        EIGENVALUES_REAL,
+        EIGENVALUES_IMAG,
+    );
+
+    // Full eigendecomposition.
+    let eigen = a.eigen().unwrap();
+    assert_eigenvalues(
+        eigen.eigenvalues_real().as_slice().as_ref(),
+        eigen.eigenvalues_imag().as_slice().as_ref(),
+        EIGENVALUES_REAL,
+        EIGENVALUES_IMAG,
+    );
+    assert_eigenpairs(&a, &eigen, 12);
+
+    // Real Schur decomposition.
+    assert_schur(&a, &a.schur().unwrap());
+}
+
+/// Check that the eigendecompositions of matrices containing NaNs or infinities fail.
+fn eigen_non_finite_helper<M: Matrix<f64>>() {
+    for value in [f64::NAN, f64::INFINITY] {
+        let mut a = M::from_row_slice(4, 4, A_ROW);
+        a[(2, 1)] = value;
+        assert_eq!(a.schur().unwrap_err(), LinalgError::NonFinite);
+        assert_eq!(a.eigen().unwrap_err(), LinalgError::NonFinite);
+        assert_eq!(a.eigenvalues().unwrap_err(), LinalgError::NonFinite);
+    }
+}
+
+#[test]
+fn test_mat() {
+    eigen_test_helper::<Mat<f64>>();
+    eigen_non_finite_helper::<Mat<f64>>();
+}
+
+#[test]
+fn test_mat_large() {
+    // Deterministic pseudo-random non-symmetric matrix.
+    let n = 40;
+    let mut state: u64 = 12345;
+    let mut a = Mat::<f64>::new_with_shape(n, n);
+    for i in 0..n {
+        for j in 0..n {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            a[(i, j)] = (state >> 11) as f64 / (1u64 << 53) as f64 - 0.5;
+        }
+    }
+    let eigen = a.eigen().unwrap();
+    assert_eigenpairs(&a, &eigen, 11);
+
+    // The eigenvalues sum to the trace and complex eigenvalues come in conjugate pairs.
+    let trace = (0..n).map(|i| a[(i, i)]).sum::<f64>();
+    assert_equal_to_decimal!(eigen.eigenvalues_real().iter().sum::<f64>(), trace, 11);
+    assert_equal_to_decimal!(eigen.eigenvalues_imag().iter().sum::<f64>(), 0.0, 11);
+}
+
+#[test]
+fn test_mat_defective() {
+    // Jordan block (a single eigenvalue with a single independent eigenvector).
+    let a = Mat::from_row_slice(3, 3, JORDAN_ROW);
+    let (re, im) = a.eigenvalues().unwrap();
+    assert_arrays_equal_to_decimal!(re, [2.0, 2.0, 2.0], 14);
+    assert_arrays_equal_to_decimal!(im, [0.0, 0.0, 0.0], 14);
+    let eigen = a.eigen().unwrap();
+    for k in 0..3 {
+        let (vr, vi) = eigen.eigenvector(k);
+        assert!(vr.iter().chain(vi.iter()).all(|x| x.is_finite()));
+    }
+}
+
+#[test]
+fn test_mat_empty() {
+    let a = Mat::<f64>::new_with_shape(0, 0);
+    let (re, im) = a.eigenvalues().unwrap();
+    assert!(re.is_empty() && im.is_empty());
+    assert!(a.eigen().unwrap().eigenvalues_real().is_empty());
+}
+
+#[test]
+fn test_no_convergence_error() {
+    assert_eq!(
+        LinalgError::NoConvergence.to_string(),
+        "Iterative algorithm failed to converge."
+    );
+}
+
+#[test]
+fn test_mat_not_square() {
+    let a = Mat::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(
+        a.eigen(),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 2),
+            actual: (2, 3)
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Index of the eigenvalue (4) is out of bounds for a matrix of size 4.")]
+fn test_mat_eigenvector_panic() {
+    let a = Mat::from_row_slice(4, 4, A_ROW);
+    a.eigen().unwrap().eigenvector(4);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let a = DMatrix::from_row_slice(4, 4, A_ROW);
+    assert_schur(&a, &Matrix::schur(&a).unwrap());
+    let (re, im) = Matrix::eigenvalues(&a).unwrap();
+    assert_eigenvalues(
+        re.as_slice(),
+        im.as_slice(),
+        EIGENVALUES_REAL,
+        EIGENVALUES_IMAG,
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_defective() {
+    let a = DMatrix::from_row_slice(3, 3, JORDAN_ROW);
+    let (re, im) = Matrix::eigenvalues(&a).unwrap();
+    assert_arrays_equal_to_decimal!(re.as_slice(), [2.0, 2.0, 2.0], 14);
+    assert_arrays_equal_to_decimal!(im.as_slice(), [0.0, 0.0, 0.0], 14);
+    let eigen = Matrix::eigen(&a).unwrap();
+    for k in 0..3 {
+        let (vr, vi) = eigen.eigenvector(k);
+        assert!(vr.iter().chain(vi.iter()).all(|x| x.is_finite()));
+    }
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_non_finite() {
+    for value in [f64::NAN, f64::INFINITY] {
+        let mut a = DMatrix::from_row_slice(4, 4, A_ROW);
+        a[(2, 1)] = value;
+        assert_eq!(Matrix::schur(&a).unwrap_err(), LinalgError::NonFinite);
+        assert_eq!(Matrix::eigen(&a).unwrap_err(), LinalgError::NonFinite);
+        assert_eq!(Matrix::eigenvalues(&a).unwrap_err(), LinalgError::NonFinite);
+    }
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    let a = DMatrix::<f64>::zeros(0, 0);
+    let (re, im) = Matrix::eigenvalues(&a).unwrap();
+    assert_eq!((Vector::len(&re), Vector::len(&im)), (0, 0));
+    assert!(Matrix::eigen(&a).unwrap().eigenvalues_real().is_empty());
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_not_square() {
+    let a = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let err = LinalgError::ShapeMismatch {
+        expected: (2, 2),
+        actual: (2, 3),
+    };
+    assert_eq!(Matrix::eigen(&a).unwrap_err(), err);
+    assert_eq!(Matrix::eigenvalues(&a).unwrap_err(), err);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    let a = FMat::from_row_slice(4, 4, A_ROW);
+    let eigen = Matrix::eigen(&a).unwrap();
+    assert_eigenvalues(
+        eigen.eigenvalues_real().as_slice().as_ref(),
+        eigen.eigenvalues_imag().as_slice().as_ref(),
+        EIGENVALUES_REAL,
+        EIGENVALUES_IMAG,
+    );
+    assert_eigenpairs(&a, &eigen, 12);
+    let (re, im) = Matrix::eigenvalues(&a).unwrap();
+    assert_eigenvalues(
+        re.as_slice().as_ref(),
+        im.as_slice().as_ref(),
+        EIGENVALUES_REAL,
+        EIGENVALUES_IMAG,
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_defective() {
+    let a = FMat::from_row_slice(3, 3, JORDAN_ROW);
+    let (re, im) = Matrix::eigenvalues(&a).unwrap();
+    assert_arrays_equal_to_decimal!(re.as_slice(), [2.0, 2.0, 2.0], 14);
+    assert_arrays_equal_to_decimal!(im.as_slice(), [0.0, 0.0, 0.0], 14);
+    let eigen = Matrix::eigen(&a).unwrap();
+    for k in 0..3 {
+        let (vr, vi) = eigen.eigenvector(k);
+        assert!(vr.iter().chain(vi.iter()).all(|x| x.is_finite()));
+    }
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_non_finite() {
+    for value in [f64::NAN, f64::INFINITY] {
+        let mut a = FMat::from_row_slice(4, 4, A_ROW);
+        a[(2, 1)] = value;
+        assert_eq!(Matrix::schur(&a).unwrap_err(), LinalgError::NonFinite);
+        assert_eq!(Matrix::eigen(&a).unwrap_err(), LinalgError::NonFinite);
+        assert_eq!(Matrix::eigenvalues(&a).unwrap_err(), LinalgError::NonFinite);
+    }
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    let a = FMat::<f64>::zeros(0, 0);
+    let (re, im) = Matrix::eigenvalues(&a).unwrap();
+    assert_eq!((Vector::len(&re), Vector::len(&im)), (0, 0));
+    assert!(Matrix::eigen(&a).unwrap().eigenvalues_real().is_empty());
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_not_square() {
+    let a = FMat::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let err = LinalgError::ShapeMismatch {
+        expected: (2, 2),
+        actual: (2, 3),
+    };
+    assert_eq!(Matrix::eigen(&a).unwrap_err(), err);
+    assert_eq!(Matrix::eigenvalues(&a).unwrap_err(), err);
+}
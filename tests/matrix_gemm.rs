@@ -0,0 +1,128 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+use linalg_traits::{Mat, Matrix, SMat};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, Matrix2};
+#[cfg(feature = "ndarray")]
+use ndarray::{Array2, ShapeBuilder};
+use numtest::*;
+
+// Test conditions.
+static A_ROW: &[f64; 4] = &[1.0, 2.0, 3.0, 4.0];
+static B_ROW: &[f64; 4] = &[5.0, 6.0, 7.0, 8.0];
+static C_ROW: &[f64; 4] = &[1.0, 1.0, 1.0, 1.0];
+static ALPHA: f64 = 2.0;
+static BETA: f64 = 3.0;
+
+// Expected results for each combination of (transpose_a, transpose_b).
+static EXPECTED: [(bool, bool, [f64; 4]); 4] = [
+    (false, false, [41.0, 47.0, 89.0, 103.0]),
+    (true, false, [55.0, 63.0, 79.0, 91.0]),
+    (false, true, [37.0, 49.0, 81.0, 109.0]),
+    (true, true, [49.0, 65.0, 71.0, 95.0]),
+];
+
+// Expected result when beta is zero (i.e. alpha * a * b).
+static EXPECTED_BETA_ZERO: &[f64; 4] = &[38.0, 44.0, 86.0, 100.0];
+
+/// Check all combinations of the transposition flags, and check that the output matrix is not read
+/// when beta is zero.
+fn gemm_test_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(2, 2, A_ROW);
+    let b = M::from_row_slice(2, 2, B_ROW);
+    for (transpose_a, transpose_b, expected) in EXPECTED {
+        let mut c = M::from_row_slice(2, 2, C_ROW);
+        c.gemm(ALPHA, &a, transpose_a, &b, transpose_b, BETA);
+        assert_arrays_equal!(c.as_row_slice(), expected);
+    }
+    let mut c = M::from_row_slice(2, 2, &[f64::NAN; 4]);
+    c.gemm(ALPHA, &a, false, &b, false, 0.0);
+    assert_arrays_equal!(c.as_row_slice(), EXPECTED_BETA_ZERO);
+}
+
+/// Check that a product with an empty inner dimension only scales the output matrix by beta.
+fn gemm_empty_test_helper<M: Matrix<f64>>() {
+    let a = M::new_with_shape(2, 0);
+    let b = M::new_with_shape(0, 2);
+    let mut c = M::from_row_slice(2, 2, C_ROW);
+    c.gemm(ALPHA, &a, false, &b, false, BETA);
+    assert_arrays_equal!(c.as_row_slice(), [3.0; 4]);
+    c.gemm(ALPHA, &b, true, &a, true, 0.0);
+    assert_arrays_equal!(c.as_row_slice(), [0.0; 4]);
+
+    // Empty output matrix.
+    let mut c = M::new_with_shape(0, 0);
+    c.gemm(ALPHA, &b, false, &a, false, BETA);
+    assert_eq!(c.shape(), (0, 0));
+}
+
+/// Check a product of non-square matrices (i.e. `a^T * b` where `a` and `b` are 3x2).
+fn gemm_non_square_test_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(3, 2, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let mut c = M::new_with_shape(2, 2);
+    c.gemm(1.0, &a, true, &a, false, 0.0);
+    assert_arrays_equal!(c.as_row_slice(), [35.0, 44.0, 44.0, 56.0]);
+}
+
+#[test]
+fn test_mat() {
+    gemm_test_helper::<Mat<f64>>();
+    gemm_non_square_test_helper::<Mat<f64>>();
+    gemm_empty_test_helper::<Mat<f64>>();
+}
+
+#[test]
+#[should_panic(
+    expected = "Shape of the output matrix (3x2) does not match the shape of the product (2x2)."
+)]
+fn test_mat_panic() {
+    let a = Mat::from_row_slice(3, 2, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let mut c = Mat::new_with_shape(3, 2);
+    c.gemm(1.0, &a, true, &a, false, 0.0);
+}
+
+#[test]
+fn test_smat() {
+    gemm_test_helper::<SMat<f64, 2, 2>>();
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    gemm_test_helper::<DMatrix<f64>>();
+    gemm_non_square_test_helper::<DMatrix<f64>>();
+    gemm_empty_test_helper::<DMatrix<f64>>();
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    gemm_test_helper::<Matrix2<f64>>();
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2() {
+    gemm_test_helper::<Array2<f64>>();
+    gemm_non_square_test_helper::<Array2<f64>>();
+    gemm_empty_test_helper::<Array2<f64>>();
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_column_major() {
+    // Column-major operands and output.
+    let a = Array2::from_row_slice(2, 2, A_ROW).reversed_axes();
+    let b = Array2::from_shape_vec((2, 2).f(), B_ROW.to_vec()).unwrap();
+    let mut c = Array2::from_shape_vec((2, 2).f(), C_ROW.to_vec()).unwrap();
+    Matrix::gemm(&mut c, ALPHA, &a, true, &b, true, BETA);
+    assert_eq!(c, Array2::from_row_slice(2, 2, &EXPECTED[0].2));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    gemm_test_helper::<FMat<f64>>();
+    gemm_non_square_test_helper::<FMat<f64>>();
+    gemm_empty_test_helper::<FMat<f64>>();
+}
@@ -1,6 +1,6 @@
 #[cfg(feature = "faer")]
 use faer::Mat as FMat;
-use linalg_traits::Matrix;
+use linalg_traits::{Matrix, SMat};
 #[cfg(feature = "nalgebra")]
 use nalgebra::{DMatrix, SMatrix};
 #[cfg(feature = "ndarray")]
@@ -25,6 +25,12 @@ fn test_mat() {
     assert_get(&x);
 }
 
+#[test]
+fn test_smat() {
+    let x = <SMat<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, X);
+    assert_get(&x);
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dmatrix() {
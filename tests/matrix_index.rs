@@ -1,6 +1,6 @@
 #[cfg(feature = "faer")]
 use faer::Mat as FMat;
-use linalg_traits::Matrix;
+use linalg_traits::{Matrix, SMat};
 #[cfg(feature = "nalgebra")]
 use nalgebra::{DMatrix, SMatrix};
 #[cfg(feature = "ndarray")]
@@ -27,6 +27,24 @@ fn test_mat_out_of_bounds() {
     _ = x[(2, 0)];
 }
 
+#[test]
+fn test_smat() {
+    let x = <SMat<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, X);
+    assert_eq!(x[(0, 0)], 1.0);
+    assert_eq!(x[(0, 1)], 2.0);
+    assert_eq!(x[(0, 2)], 3.0);
+    assert_eq!(x[(1, 0)], 4.0);
+    assert_eq!(x[(1, 1)], 5.0);
+    assert_eq!(x[(1, 2)], 6.0);
+}
+
+#[test]
+#[should_panic]
+fn test_smat_out_of_bounds() {
+    let x = <SMat<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, X);
+    _ = x[(2, 0)];
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dmatrix() {
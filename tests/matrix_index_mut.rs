@@ -1,6 +1,6 @@
 #[cfg(feature = "faer")]
 use faer::Mat as FMat;
-use linalg_traits::Matrix;
+use linalg_traits::{Matrix, SMat};
 #[cfg(feature = "nalgebra")]
 use nalgebra::{DMatrix, SMatrix};
 #[cfg(feature = "ndarray")]
@@ -30,6 +30,30 @@ fn test_mat_out_of_bounds() {
     x[(2, 0)] = 4.0;
 }
 
+#[test]
+fn test_smat() {
+    let mut x = <SMat<f64, 2, 3> as Matrix<f64>>::new_with_shape(2, 3);
+    x[(0, 0)] = 1.0;
+    x[(0, 1)] = 2.0;
+    x[(0, 2)] = 3.0;
+    x[(1, 0)] = 4.0;
+    x[(1, 1)] = 5.0;
+    x[(1, 2)] = 6.0;
+    assert_eq!(x[(0, 0)], 1.0);
+    assert_eq!(x[(0, 1)], 2.0);
+    assert_eq!(x[(0, 2)], 3.0);
+    assert_eq!(x[(1, 0)], 4.0);
+    assert_eq!(x[(1, 1)], 5.0);
+    assert_eq!(x[(1, 2)], 6.0);
+}
+
+#[test]
+#[should_panic]
+fn test_smat_out_of_bounds() {
+    let mut x = <SMat<f64, 2, 3> as Matrix<f64>>::new_with_shape(2, 3);
+    x[(2, 0)] = 4.0;
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dmatrix() {
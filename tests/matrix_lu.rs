@@ -0,0 +1,301 @@
+#[cfg(feature = "faer")]
+use faer::{Col, Mat as FMat};
+use linalg_traits::{LinalgError, Mat, Matrix, Vector};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, DVector, SMatrix, SVector};
+use numtest::*;
+
+// Test conditions.
+static A_ROW: &[f64; 9] = &[2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0];
+static B: &[f64; 3] = &[5.0, -2.0, 9.0];
+static B_TRANSPOSE: &[f64; 3] = &[2.0, 9.0, 5.0];
+static CYCLIC_ROW: &[f64; 9] = &[0.0, 0.0, 1.0, 3.0, 0.0, 0.0, 0.0, 2.0, 0.0];
+static SINGULAR_ROW: &[f64; 4] = &[1.0, 2.0, 2.0, 4.0];
+
+// Expected results.
+static X: &[f64; 3] = &[1.0, 1.0, 2.0];
+static DET: f64 = -16.0;
+static CYCLIC_P: &[usize; 3] = &[1, 2, 0];
+static CYCLIC_DET: f64 = 6.0;
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static P: &[usize; 3] = &[1, 0, 2];
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static L_ROW: &[f64; 9] = &[1.0, 0.0, 0.0, 0.5, 1.0, 0.0, -0.5, 1.0, 1.0];
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static U_ROW: &[f64; 9] = &[4.0, -6.0, 0.0, 0.0, 4.0, 1.0, 0.0, 0.0, 1.0];
+
+/// Check the LU decomposition of a 3x3 matrix, as well as the solve, determinant, and inverse
+/// convenience methods.
+fn lu_test_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(3, 3, A_ROW);
+    let lu = a.lu();
+
+    // P * A = L * U.
+    let p = lu.p();
+    let (l, u) = (lu.l(), lu.u());
+    let mut l_u = M::new_with_shape(3, 3);
+    l_u.gemm(1.0, &l, false, &u, false, 0.0);
+    for i in 0..3 {
+        assert_eq!(l[(i, i)], 1.0);
+        for j in 0..3 {
+            if j > i {
+                assert_eq!(l[(i, j)], 0.0);
+            }
+            if j < i {
+                assert_eq!(u[(i, j)], 0.0);
+            }
+            assert_equal_to_decimal!(l_u[(i, j)], a[(p[i], j)], 14);
+        }
+    }
+
+    // Linear solve.
+    assert!(lu.is_invertible());
+    let b = M::VectorM::from_slice(B);
+    assert_arrays_equal_to_decimal!(lu.solve(&b).unwrap().as_slice(), X, 14);
+    assert_arrays_equal_to_decimal!(a.solve(&b).unwrap().as_slice(), X, 14);
+    let mut x = b.clone();
+    lu.solve_in_place(&mut x).unwrap();
+    assert_arrays_equal_to_decimal!(x.as_slice(), X, 14);
+
+    // Linear solve with the transpose.
+    let b_transpose = M::VectorM::from_slice(B_TRANSPOSE);
+    assert_arrays_equal_to_decimal!(lu.solve_transpose(&b_transpose).unwrap().as_slice(), X, 14);
+    let mut x = b_transpose.clone();
+    lu.solve_transpose_in_place(&mut x).unwrap();
+    assert_arrays_equal_to_decimal!(x.as_slice(), X, 14);
+
+    // Determinant.
+    assert_equal_to_decimal!(lu.determinant(), DET, 14);
+    assert_equal_to_decimal!(a.determinant(), DET, 14);
+
+    // Inverse.
+    let a_inv = a.inverse().unwrap();
+    let mut identity = M::new_with_shape(3, 3);
+    identity.gemm(1.0, &a, false, &a_inv, false, 0.0);
+    for i in 0..3 {
+        for j in 0..3 {
+            assert_equal_to_decimal!(identity[(i, j)], if i == j { 1.0 } else { 0.0 }, 14);
+        }
+    }
+
+    // Matrix whose row permutation is a cycle (i.e. not its own inverse).
+    let cyclic = M::from_row_slice(3, 3, CYCLIC_ROW);
+    let lu = cyclic.lu();
+    assert_eq!(lu.p(), CYCLIC_P);
+    assert_equal_to_decimal!(lu.determinant(), CYCLIC_DET, 14);
+    let b_transpose = M::VectorM::from_slice(&[6.0, 6.0, 1.0]);
+    assert_arrays_equal_to_decimal!(
+        lu.solve_transpose(&b_transpose).unwrap().as_slice(),
+        [1.0, 2.0, 3.0],
+        14
+    );
+
+    // Singular matrix.
+    let singular = M::from_row_slice(2, 2, SINGULAR_ROW);
+    let lu = singular.lu();
+    assert!(!lu.is_invertible());
+    assert_eq!(lu.determinant(), 0.0);
+    assert_eq!(
+        singular.solve(&M::VectorM::from_slice(&[1.0, 2.0])),
+        Err(LinalgError::SingularMatrix)
+    );
+    assert_eq!(singular.inverse(), Err(LinalgError::SingularMatrix));
+}
+
+#[test]
+fn test_mat() {
+    lu_test_helper::<Mat<f64>>();
+}
+
+#[test]
+#[should_panic(expected = "Matrix is not square (2x3).")]
+fn test_mat_panic() {
+    let a = Mat::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    a.lu();
+}
+
+#[test]
+fn test_mat_shape_errors() {
+    let a = Mat::from_row_slice(3, 3, A_ROW);
+    assert_eq!(
+        a.solve(&vec![1.0, 2.0]),
+        Err(LinalgError::LengthMismatch {
+            expected: 3,
+            actual: 2
+        })
+    );
+    let mut b = vec![1.0, 2.0];
+    assert_eq!(
+        a.lu().solve_transpose_in_place(&mut b),
+        Err(LinalgError::LengthMismatch {
+            expected: 3,
+            actual: 2
+        })
+    );
+    assert_eq!(b, vec![1.0, 2.0]);
+
+    let not_square = Mat::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(
+        not_square.solve(&vec![1.0, 2.0]),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 2),
+            actual: (2, 3)
+        })
+    );
+    assert_eq!(
+        not_square.inverse(),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 2),
+            actual: (2, 3)
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let a = <DMatrix<f64> as Matrix<f64>>::from_row_slice(3, 3, A_ROW);
+    let lu = Matrix::lu(&a);
+    assert_eq!(lu.p(), P);
+    assert_arrays_equal_to_decimal!(lu.l().as_row_slice(), L_ROW, 15);
+    assert_arrays_equal_to_decimal!(lu.u().as_row_slice(), U_ROW, 15);
+    assert_equal_to_decimal!(lu.determinant(), DET, 14);
+    let x = lu.solve(&DVector::from_slice(B)).unwrap();
+    assert_arrays_equal_to_decimal!(x.as_slice(), X, 14);
+    let x = lu
+        .solve_transpose(&DVector::from_slice(B_TRANSPOSE))
+        .unwrap();
+    assert_arrays_equal_to_decimal!(x.as_slice(), X, 14);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_cyclic() {
+    let cyclic = <DMatrix<f64> as Matrix<f64>>::from_row_slice(3, 3, CYCLIC_ROW);
+    let lu = Matrix::lu(&cyclic);
+    assert_eq!(lu.p(), CYCLIC_P);
+    assert_equal_to_decimal!(lu.determinant(), CYCLIC_DET, 14);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_singular() {
+    let singular = <DMatrix<f64> as Matrix<f64>>::from_row_slice(2, 2, SINGULAR_ROW);
+    let lu = Matrix::lu(&singular);
+    assert!(!lu.is_invertible());
+    assert_eq!(lu.determinant(), 0.0);
+    assert_eq!(
+        lu.solve(&DVector::from_slice(&[1.0, 2.0])),
+        Err(LinalgError::SingularMatrix)
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    let lu = Matrix::lu(&DMatrix::<f64>::zeros(0, 0));
+    assert!(lu.p().is_empty());
+    assert_eq!(lu.determinant(), 1.0);
+    assert_eq!(Vector::len(&lu.solve(&DVector::zeros(0)).unwrap()), 0);
+}
+
+#[test]
+#[should_panic(expected = "Matrix is not square (2x3).")]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_panic() {
+    let a = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let _ = Matrix::lu(&a);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    let a = <SMatrix<f64, 3, 3> as Matrix<f64>>::from_row_slice(3, 3, A_ROW);
+    let lu = Matrix::lu(&a);
+    assert_eq!(lu.p(), P);
+    assert_arrays_equal_to_decimal!(lu.l().as_row_slice(), L_ROW, 15);
+    assert_arrays_equal_to_decimal!(lu.u().as_row_slice(), U_ROW, 15);
+    assert_equal_to_decimal!(lu.determinant(), DET, 14);
+    let x = lu.solve(&SVector::<f64, 3>::from_slice(B)).unwrap();
+    assert_arrays_equal_to_decimal!(x.as_slice(), X, 14);
+    let x = lu
+        .solve_transpose(&SVector::<f64, 3>::from_slice(B_TRANSPOSE))
+        .unwrap();
+    assert_arrays_equal_to_decimal!(x.as_slice(), X, 14);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix_cyclic() {
+    let cyclic = <SMatrix<f64, 3, 3> as Matrix<f64>>::from_row_slice(3, 3, CYCLIC_ROW);
+    let lu = Matrix::lu(&cyclic);
+    assert_eq!(lu.p(), CYCLIC_P);
+    assert_equal_to_decimal!(lu.determinant(), CYCLIC_DET, 14);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix_singular() {
+    let singular = <SMatrix<f64, 2, 2> as Matrix<f64>>::from_row_slice(2, 2, SINGULAR_ROW);
+    let lu = Matrix::lu(&singular);
+    assert!(!lu.is_invertible());
+    assert_eq!(lu.determinant(), 0.0);
+    assert_eq!(
+        lu.solve(&SVector::<f64, 2>::from_slice(&[1.0, 2.0])),
+        Err(LinalgError::SingularMatrix)
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    let a = <FMat<f64> as Matrix<f64>>::from_row_slice(3, 3, A_ROW);
+    let lu = Matrix::lu(&a);
+    assert_eq!(lu.p(), P);
+    assert_arrays_equal_to_decimal!(lu.l().as_row_slice(), L_ROW, 15);
+    assert_arrays_equal_to_decimal!(lu.u().as_row_slice(), U_ROW, 15);
+    assert_equal_to_decimal!(lu.determinant(), DET, 14);
+    let x = lu.solve(&Col::from_slice(B)).unwrap();
+    assert_arrays_equal_to_decimal!(x.as_slice(), X, 14);
+    let x = lu.solve_transpose(&Col::from_slice(B_TRANSPOSE)).unwrap();
+    assert_arrays_equal_to_decimal!(x.as_slice(), X, 14);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_cyclic() {
+    let cyclic = <FMat<f64> as Matrix<f64>>::from_row_slice(3, 3, CYCLIC_ROW);
+    let lu = Matrix::lu(&cyclic);
+    assert_eq!(lu.p(), CYCLIC_P);
+    assert_equal_to_decimal!(lu.determinant(), CYCLIC_DET, 14);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_singular() {
+    let singular = <FMat<f64> as Matrix<f64>>::from_row_slice(2, 2, SINGULAR_ROW);
+    let lu = Matrix::lu(&singular);
+    assert!(!lu.is_invertible());
+    assert_eq!(lu.determinant(), 0.0);
+    assert_eq!(
+        lu.solve(&Col::from_slice(&[1.0, 2.0])),
+        Err(LinalgError::SingularMatrix)
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    let lu = Matrix::lu(&FMat::<f64>::zeros(0, 0));
+    assert!(lu.p().is_empty());
+    assert_eq!(lu.determinant(), 1.0);
+    assert_eq!(Vector::len(&lu.solve(&Col::zeros(0)).unwrap()), 0);
+}
+
+#[test]
+#[should_panic(expected = "Matrix is not square (2x3).")]
+#[cfg(feature = "faer")]
+fn test_faer_mat_panic() {
+    let a = FMat::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let _ = Matrix::lu(&a);
+}
@@ -0,0 +1,234 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+use linalg_traits::{Mat, Matrix, SMat};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, SMatrix};
+#[cfg(feature = "ndarray")]
+use ndarray::{Array2, ShapeBuilder};
+use numtest::*;
+
+// Test conditions.
+static A_ROW: &[f64; 6] = &[1.0, -2.0, 3.0, -4.0, 5.0, -6.0];
+static B_ROW: &[f64; 6] = &[2.0, 4.0, -8.0, 1.0, -10.0, 12.0];
+
+// Expected results.
+static SQUARE_ROW: &[f64; 6] = &[1.0, 4.0, 9.0, 16.0, 25.0, 36.0];
+static ZIP_ROW: &[f64; 6] = &[5.0, 6.0, -13.0, -2.0, -15.0, 18.0];
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static A_COL: &[f64; 6] = &[1.0, -4.0, -2.0, 5.0, 3.0, -6.0];
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static SQUARE_COL: &[f64; 6] = &[1.0, 16.0, 4.0, 25.0, 9.0, 36.0];
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static ZIP_COL: &[f64; 6] = &[5.0, -2.0, 6.0, -15.0, -13.0, 18.0];
+
+/// Check the elementwise operations on a 2x3 matrix.
+fn map_test_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(2, 3, A_ROW);
+    let b = M::from_row_slice(2, 3, B_ROW);
+
+    // Map.
+    let c = a.map(|x| x * x);
+    assert_eq!(c, M::from_row_slice(2, 3, SQUARE_ROW));
+    let mut c = a.clone();
+    c.map_in_place(f64::abs);
+    assert_eq!(c, M::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+
+    // Zip.
+    let c = a.zip_map(&b, |x, y| x + 2.0 * y);
+    assert_eq!(c, M::from_row_slice(2, 3, ZIP_ROW));
+    let mut c = a.clone();
+    c.zip_apply(&b, |x, y| *x = x.min(y));
+    assert_eq!(
+        c,
+        M::from_row_slice(2, 3, &[1.0, -2.0, -8.0, -4.0, -10.0, -6.0])
+    );
+
+    // Hadamard product and quotient.
+    let c = a.hadamard(&b);
+    assert_eq!(
+        c,
+        M::from_row_slice(2, 3, &[2.0, -8.0, -24.0, -4.0, -50.0, -72.0])
+    );
+    let c = a.hadamard_div(&b);
+    assert_eq!(
+        c,
+        M::from_row_slice(2, 3, &[0.5, -0.5, -0.375, -4.0, -0.5, -0.5])
+    );
+
+    // Fold.
+    assert_eq!(a.fold(0.0, |sum, x| sum + x), -3.0);
+    assert_eq!(a.fold(f64::NEG_INFINITY, f64::max), 5.0);
+}
+
+/// Collect the elements of a matrix in the order they are visited by [`Matrix::fold`].
+fn fold_order<M: Matrix<f64>>(a: &M) -> Vec<f64> {
+    Matrix::fold(a, Vec::new(), |mut acc, x| {
+        acc.push(x);
+        acc
+    })
+}
+
+#[test]
+fn test_mat() {
+    map_test_helper::<Mat<f64>>();
+}
+
+#[test]
+fn test_mat_empty() {
+    let a = Mat::<f64>::new_with_shape(0, 3);
+    assert_eq!(a.map(|x| x + 1.0).shape(), (0, 3));
+    assert_eq!(a.zip_map(&a, |x, y| x + y).shape(), (0, 3));
+    assert_eq!(a.fold(1.0, |acc, x| acc * x), 1.0);
+}
+
+#[test]
+#[should_panic(expected = "Matrices have incompatible shapes.")]
+fn test_mat_zip_map_panic() {
+    let a = Mat::from_row_slice(2, 3, A_ROW);
+    let b = Mat::from_row_slice(3, 2, B_ROW);
+    let _ = a.zip_map(&b, |x, y| x + y);
+}
+
+#[test]
+#[should_panic(expected = "Matrices have incompatible shapes.")]
+fn test_mat_zip_apply_panic() {
+    let mut a = Mat::from_row_slice(2, 3, A_ROW);
+    let b = Mat::from_row_slice(3, 2, B_ROW);
+    a.zip_apply(&b, |x, y| *x += y);
+}
+
+#[test]
+fn test_smat() {
+    let a = SMat::<f64, 2, 3>::from_row_slice(2, 3, A_ROW);
+    let b = SMat::<f64, 2, 3>::from_row_slice(2, 3, B_ROW);
+    assert_arrays_equal!(a.map(|x| x * x).as_slice(), SQUARE_ROW);
+    assert_arrays_equal!(a.zip_map(&b, |x, y| x + 2.0 * y).as_slice(), ZIP_ROW);
+    assert_eq!(fold_order(&a), A_ROW);
+    let mut c = a;
+    c.zip_apply(&b, |x, y| *x += 2.0 * y);
+    c.map_in_place(|x| x * x);
+    assert_arrays_equal!(c.as_slice(), &[25.0, 36.0, 169.0, 4.0, 225.0, 324.0]);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let a = DMatrix::from_row_slice(2, 3, A_ROW);
+    let b = DMatrix::from_row_slice(2, 3, B_ROW);
+    assert_arrays_equal!(Matrix::map(&a, |x| x * x).as_slice(), SQUARE_COL);
+    assert_arrays_equal!(
+        Matrix::zip_map(&a, &b, |x, y| x + 2.0 * y).as_slice(),
+        ZIP_COL
+    );
+    assert_eq!(fold_order(&a), A_COL);
+    let mut c = a.clone();
+    Matrix::zip_apply(&mut c, &b, |x, y| *x += 2.0 * y);
+    Matrix::map_in_place(&mut c, |x| x * x);
+    assert_arrays_equal!(c.as_slice(), &[25.0, 4.0, 36.0, 225.0, 169.0, 324.0]);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    let a = DMatrix::<f64>::zeros(0, 3);
+    assert_eq!(Matrix::map(&a, |x| x + 1.0).shape(), (0, 3));
+    assert_eq!(Matrix::zip_map(&a, &a, |x, y| x + y).shape(), (0, 3));
+    assert_eq!(Matrix::fold(&a, 1.0, |acc, x| acc * x), 1.0);
+}
+
+#[test]
+#[should_panic(expected = "Matrices have incompatible shapes.")]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_zip_map_panic() {
+    let a = DMatrix::from_row_slice(2, 3, A_ROW);
+    let b = DMatrix::from_row_slice(3, 2, B_ROW);
+    let _ = Matrix::zip_map(&a, &b, |x, y| x + y);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    let a = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, A_ROW);
+    let b = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, B_ROW);
+    assert_arrays_equal!(Matrix::map(&a, |x| x * x).as_slice(), SQUARE_COL);
+    assert_arrays_equal!(
+        Matrix::zip_map(&a, &b, |x, y| x + 2.0 * y).as_slice(),
+        ZIP_COL
+    );
+    assert_eq!(fold_order(&a), A_COL);
+    let mut c = a;
+    Matrix::zip_apply(&mut c, &b, |x, y| *x += 2.0 * y);
+    Matrix::map_in_place(&mut c, |x| x * x);
+    assert_arrays_equal!(c.as_slice(), &[25.0, 4.0, 36.0, 225.0, 169.0, 324.0]);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2() {
+    let a = Array2::from_row_slice(2, 3, A_ROW);
+    let b = Array2::from_row_slice(2, 3, B_ROW);
+    assert_arrays_equal!(Matrix::as_slice(&Matrix::map(&a, |x| x * x)), SQUARE_ROW);
+    assert_arrays_equal!(
+        Matrix::as_slice(&Matrix::zip_map(&a, &b, |x, y| x + 2.0 * y)),
+        ZIP_ROW
+    );
+    assert_eq!(fold_order(&a), A_ROW);
+    let mut c = a.clone();
+    Matrix::zip_apply(&mut c, &b, |x, y| *x += 2.0 * y);
+    Matrix::map_in_place(&mut c, |x| x * x);
+    assert_arrays_equal!(
+        Matrix::as_slice(&c),
+        &[25.0, 36.0, 169.0, 4.0, 225.0, 324.0]
+    );
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_mixed_layout() {
+    // Zipping a row-major array with a column-major one must pair elements by index, not by
+    // position in memory.
+    let a = Array2::from_row_slice(2, 3, A_ROW);
+    let b = Array2::from_shape_vec((2, 3).f(), vec![2.0, 1.0, 4.0, -10.0, -8.0, 12.0]).unwrap();
+    assert_eq!(
+        Matrix::zip_map(&a, &b, |x, y| x + 2.0 * y),
+        Array2::from_row_slice(2, 3, ZIP_ROW)
+    );
+    let mut c = b.clone();
+    Matrix::zip_apply(&mut c, &a, |y, x| *y = x + 2.0 * *y);
+    assert_eq!(c, Array2::from_row_slice(2, 3, ZIP_ROW));
+}
+
+#[test]
+#[should_panic(expected = "Matrices have incompatible shapes.")]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_zip_apply_panic() {
+    let mut a = Array2::from_row_slice(2, 3, A_ROW);
+    let b = Array2::from_row_slice(3, 2, B_ROW);
+    Matrix::zip_apply(&mut a, &b, |x, y| *x += y);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    let a = FMat::from_row_slice(2, 3, A_ROW);
+    let b = FMat::from_row_slice(2, 3, B_ROW);
+    assert_arrays_equal!(Matrix::map(&a, |x| x * x).as_slice(), SQUARE_COL);
+    assert_arrays_equal!(
+        Matrix::zip_map(&a, &b, |x, y| x + 2.0 * y).as_slice(),
+        ZIP_COL
+    );
+    assert_eq!(fold_order(&a), A_COL);
+    let mut c = a.clone();
+    Matrix::zip_apply(&mut c, &b, |x, y| *x += 2.0 * y);
+    Matrix::map_in_place(&mut c, |x| x * x);
+    assert_arrays_equal!(c.as_slice(), &[25.0, 4.0, 36.0, 225.0, 169.0, 324.0]);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    let a = FMat::<f64>::zeros(2, 0);
+    assert_eq!(Matrix::map(&a, |x| x + 1.0).shape(), (2, 0));
+    assert_eq!(Matrix::zip_map(&a, &a, |x, y| x + y).shape(), (2, 0));
+    assert_eq!(Matrix::fold(&a, 1.0, |acc, x| acc * x), 1.0);
+}
@@ -0,0 +1,94 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+use linalg_traits::{Mat, Matrix, SMat, Scalar};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, SMatrix};
+#[cfg(feature = "ndarray")]
+use ndarray::Array2;
+use numtest::*;
+
+// Test conditions.
+static A_ROW: &[f64; 6] = &[1.0, -2.0, 3.0, -4.0, 5.0, -6.0];
+
+// Expected result.
+static B_ROW: &[f64; 6] = &[2.0, -4.0, 6.0, -8.0, 10.0, -12.0];
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static B_COL: &[f64; 6] = &[2.0, -8.0, -4.0, 10.0, 6.0, -12.0];
+
+/// Lift a matrix of `f64`s into a matrix of another scalar type.
+fn lift<S: Scalar, M: Matrix<f64>>(a: &M, scale: f64) -> M::MatrixT<S> {
+    a.map_scalar(|x| S::new(x * scale))
+}
+
+/// Check mapping a 2x3 matrix to a matrix of another scalar type.
+fn map_scalar_test_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(2, 3, A_ROW);
+    let b = lift::<f64, M>(&a, 2.0);
+    assert_eq!(b.shape(), (2, 3));
+    assert_eq!(b, M::MatrixT::<f64>::from_row_slice(2, 3, B_ROW));
+}
+
+#[test]
+fn test_mat() {
+    map_scalar_test_helper::<Mat<f64>>();
+}
+
+#[test]
+fn test_mat_empty() {
+    let a = Mat::<f64>::new_with_shape(0, 3);
+    assert_eq!(lift::<f64, _>(&a, 2.0).shape(), (0, 3));
+}
+
+#[test]
+fn test_smat() {
+    let a = SMat::<f64, 2, 3>::from_row_slice(2, 3, A_ROW);
+    let b: SMat<f64, 2, 3> = lift(&a, 2.0);
+    assert_arrays_equal!(b.as_slice(), B_ROW);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let a = DMatrix::from_row_slice(2, 3, A_ROW);
+    let b: DMatrix<f64> = lift(&a, 2.0);
+    assert_arrays_equal!(b.as_slice(), B_COL);
+    let b: DMatrix<f64> = lift(&DMatrix::<f64>::zeros(2, 0), 2.0);
+    assert_eq!(b.shape(), (2, 0));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    let a = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, A_ROW);
+    let b: SMatrix<f64, 2, 3> = lift(&a, 2.0);
+    assert_arrays_equal!(b.as_slice(), B_COL);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2() {
+    let a = Array2::from_row_slice(2, 3, A_ROW);
+    let b: Array2<f64> = lift(&a, 2.0);
+    assert_arrays_equal!(Matrix::as_slice(&b), B_ROW);
+    let b: Array2<f64> = lift(&Array2::<f64>::zeros((0, 3)), 2.0);
+    assert_eq!(b.dim(), (0, 3));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_transposed() {
+    // Mapping a column-major array preserves the logical layout of its elements.
+    let a = Array2::from_row_slice(3, 2, &[1.0, -4.0, -2.0, 5.0, 3.0, -6.0]).reversed_axes();
+    let b: Array2<f64> = lift(&a, 2.0);
+    assert_eq!(b, Array2::from_row_slice(2, 3, B_ROW));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    let a = FMat::from_row_slice(2, 3, A_ROW);
+    let b: FMat<f64> = lift(&a, 2.0);
+    assert_arrays_equal!(b.as_slice(), B_COL);
+    let b: FMat<f64> = lift(&FMat::<f64>::zeros(0, 3), 2.0);
+    assert_eq!(b.shape(), (0, 3));
+}
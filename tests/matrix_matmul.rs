@@ -0,0 +1,161 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+use linalg_traits::{Mat, Matrix, SMat};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, SMatrix};
+#[cfg(feature = "ndarray")]
+use ndarray::Array2;
+use numtest::*;
+
+// Test conditions.
+static A_ROW: &[f64; 6] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+static B_ROW: &[f64; 6] = &[7.0, 8.0, 9.0, 10.0, 11.0, 12.0];
+
+// Expected result.
+static C_ROW: &[f64; 4] = &[58.0, 64.0, 139.0, 154.0];
+
+#[test]
+fn test_mat() {
+    let a = Mat::from_row_slice(2, 3, A_ROW);
+    let b = Mat::from_row_slice(3, 2, B_ROW);
+    let mut c = Mat::new_with_shape(2, 2);
+    a.matmul_to::<0>(&b, &mut c);
+    assert_arrays_equal!(a.matmul::<0>(&b).as_row_slice(), C_ROW);
+    assert_arrays_equal!(c.as_row_slice(), C_ROW);
+}
+
+#[test]
+fn test_mat_blocked() {
+    // Dimensions spanning multiple (partial) blocks.
+    let (m, k, n) = (70, 130, 65);
+    let a_row: Vec<f64> = (0..m * k).map(|i| ((i * 7) % 11) as f64 - 5.0).collect();
+    let b_row: Vec<f64> = (0..k * n).map(|i| ((i * 3) % 13) as f64 - 6.0).collect();
+
+    // Naive matrix multiplication.
+    let mut c_row = vec![0.0; m * n];
+    for i in 0..m {
+        for j in 0..n {
+            for p in 0..k {
+                c_row[i * n + j] += a_row[i * k + p] * b_row[p * n + j];
+            }
+        }
+    }
+
+    let a = Mat::from_row_slice(m, k, &a_row);
+    let b = Mat::from_row_slice(k, n, &b_row);
+    assert_arrays_equal!(a.matmul::<0>(&b).as_row_slice(), c_row);
+}
+
+#[test]
+fn test_mat_empty() {
+    // An empty inner dimension gives a matrix of zeros, overwriting the output matrix.
+    let a = Mat::<f64>::new_with_shape(2, 0);
+    let b = Mat::<f64>::new_with_shape(0, 2);
+    let mut c = Mat::from_row_slice(2, 2, &[f64::NAN; 4]);
+    a.matmul_to::<0>(&b, &mut c);
+    assert_arrays_equal!(a.matmul::<0>(&b).as_row_slice(), [0.0; 4]);
+    assert_arrays_equal!(c.as_row_slice(), [0.0; 4]);
+    assert_eq!(b.matmul::<0>(&a).shape(), (0, 0));
+}
+
+#[test]
+#[should_panic(expected = "Matrices have incompatible shapes for multiplication (2x3 and 2x3).")]
+fn test_mat_panic() {
+    let a = Mat::from_row_slice(2, 3, A_ROW);
+    let _ = a.matmul::<0>(&a);
+}
+
+#[test]
+fn test_smat() {
+    let a = <SMat<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, A_ROW);
+    let b = <SMat<f64, 3, 2> as Matrix<f64>>::from_row_slice(3, 2, B_ROW);
+    let mut c = SMat::<f64, 2, 2>::new_with_shape(2, 2);
+    a.matmul_to::<2>(&b, &mut c);
+    let c_new: SMat<f64, 2, 2> = a.matmul::<2>(&b);
+    assert_arrays_equal!(c_new.as_row_slice(), C_ROW);
+    assert_arrays_equal!(c.as_row_slice(), C_ROW);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let a = DMatrix::from_row_slice(2, 3, A_ROW);
+    let b = DMatrix::from_row_slice(3, 2, B_ROW);
+    let mut c = DMatrix::new_with_shape(2, 2);
+    a.matmul_to::<0>(&b, &mut c);
+    assert_arrays_equal!(a.matmul::<0>(&b).as_row_slice(), C_ROW);
+    assert_arrays_equal!(c.as_row_slice(), C_ROW);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    // An empty inner dimension gives a matrix of zeros, overwriting the output matrix.
+    let a = DMatrix::<f64>::new_with_shape(2, 0);
+    let b = DMatrix::<f64>::new_with_shape(0, 2);
+    let mut c = DMatrix::from_row_slice(2, 2, &[f64::NAN; 4]);
+    a.matmul_to::<0>(&b, &mut c);
+    assert_arrays_equal!(a.matmul::<0>(&b).as_row_slice(), [0.0; 4]);
+    assert_arrays_equal!(c.as_row_slice(), [0.0; 4]);
+    assert_eq!(b.matmul::<0>(&a).shape(), (0, 0));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    let a = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, A_ROW);
+    let b = <SMatrix<f64, 3, 2> as Matrix<f64>>::from_row_slice(3, 2, B_ROW);
+    let mut c = SMatrix::<f64, 2, 2>::new_with_shape(2, 2);
+    a.matmul_to::<2>(&b, &mut c);
+    let c_new: SMatrix<f64, 2, 2> = a.matmul::<2>(&b);
+    assert_arrays_equal!(c_new.as_row_slice(), C_ROW);
+    assert_arrays_equal!(c.as_row_slice(), C_ROW);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2() {
+    let a = Array2::from_row_slice(2, 3, A_ROW);
+    let b = Array2::from_row_slice(3, 2, B_ROW);
+    let mut c = Array2::new_with_shape(2, 2);
+    a.matmul_to::<0>(&b, &mut c);
+    assert_arrays_equal!(a.matmul::<0>(&b).as_row_slice(), C_ROW);
+    assert_arrays_equal!(c.as_row_slice(), C_ROW);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_empty() {
+    // An empty inner dimension gives a matrix of zeros, overwriting the output matrix.
+    let a = Array2::<f64>::new_with_shape(2, 0);
+    let b = Array2::<f64>::new_with_shape(0, 2);
+    let mut c = Array2::from_row_slice(2, 2, &[f64::NAN; 4]);
+    a.matmul_to::<0>(&b, &mut c);
+    assert_arrays_equal!(a.matmul::<0>(&b).as_row_slice(), [0.0; 4]);
+    assert_arrays_equal!(c.as_row_slice(), [0.0; 4]);
+    assert_eq!(Matrix::shape(&b.matmul::<0>(&a)), (0, 0));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    let a = FMat::from_row_slice(2, 3, A_ROW);
+    let b = FMat::from_row_slice(3, 2, B_ROW);
+    let mut c = FMat::new_with_shape(2, 2);
+    a.matmul_to::<0>(&b, &mut c);
+    assert_arrays_equal!(a.matmul::<0>(&b).as_row_slice(), C_ROW);
+    assert_arrays_equal!(c.as_row_slice(), C_ROW);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    // An empty inner dimension gives a matrix of zeros, overwriting the output matrix.
+    let a = FMat::<f64>::new_with_shape(2, 0);
+    let b = FMat::<f64>::new_with_shape(0, 2);
+    let mut c = FMat::from_row_slice(2, 2, &[f64::NAN; 4]);
+    a.matmul_to::<0>(&b, &mut c);
+    assert_arrays_equal!(a.matmul::<0>(&b).as_row_slice(), [0.0; 4]);
+    assert_arrays_equal!(c.as_row_slice(), [0.0; 4]);
+    assert_eq!(b.matmul::<0>(&a).shape(), (0, 0));
+}
@@ -0,0 +1,170 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+use linalg_traits::{Mat, Matrix, SMat};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, SMatrix};
+#[cfg(feature = "ndarray")]
+use ndarray::Array2;
+#[cfg(feature = "faer")]
+use std::borrow::Cow;
+
+// Test conditions.
+static A: &[f64; 6] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+
+// Storage order of a column-major 2x3 matrix.
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static A_COL: &[f64; 6] = &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0];
+
+/// Check the closure-based slice access on a 2x3 matrix.
+fn with_mut_slice_test_helper<M: Matrix<f64>>() {
+    let mut a = M::from_row_slice(2, 3, A);
+
+    // The slice is in storage order.
+    let second = a.with_mut_slice(|slice| {
+        slice.iter_mut().for_each(|x| *x *= 2.0);
+        slice[1]
+    });
+    assert_eq!(second, if M::is_row_major() { 4.0 } else { 8.0 });
+    assert_eq!(
+        a,
+        M::from_row_slice(2, 3, &[2.0, 4.0, 6.0, 8.0, 10.0, 12.0])
+    );
+}
+
+/// Check the zero-copy slice access on a 2x3 matrix whose elements are stored contiguously.
+fn contiguous_slice_test_helper<M: Matrix<f64>>() {
+    let mut a = M::from_row_slice(2, 3, A);
+    let expected = a.as_slice().into_owned();
+    assert_eq!(a.try_as_contiguous_slice(), Some(expected.as_slice()));
+    a.try_as_contiguous_mut_slice().unwrap()[5] = 0.0;
+    assert_eq!(a[(1, 2)], 0.0);
+}
+
+#[test]
+fn test_mat() {
+    with_mut_slice_test_helper::<Mat<f64>>();
+    contiguous_slice_test_helper::<Mat<f64>>();
+}
+
+#[test]
+fn test_smat() {
+    let mut a = SMat::<f64, 2, 3>::from_row_slice(2, 3, A);
+    assert_eq!(a.try_as_contiguous_slice(), Some(&A[..]));
+    a.try_as_contiguous_mut_slice().unwrap()[3] = 0.0;
+    assert_eq!(a[(1, 0)], 0.0);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let mut a = DMatrix::from_row_slice(2, 3, A);
+    assert_eq!(a.try_as_contiguous_slice(), Some(&A_COL[..]));
+    a.try_as_contiguous_mut_slice().unwrap()[3] = 0.0;
+    assert_eq!(a[(1, 1)], 0.0);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    let mut a = DMatrix::<f64>::zeros(0, 3);
+    assert_eq!(a.try_as_contiguous_slice(), Some(&[][..]));
+    assert_eq!(a.with_mut_slice(|slice| slice.len()), 0);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    let mut a = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, A);
+    assert_eq!(a.try_as_contiguous_slice(), Some(&A_COL[..]));
+    a.try_as_contiguous_mut_slice().unwrap()[3] = 0.0;
+    assert_eq!(a[(1, 1)], 0.0);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2() {
+    let mut a = Array2::from_row_slice(2, 3, A);
+    assert_eq!(a.try_as_contiguous_slice(), Some(&A[..]));
+    a.try_as_contiguous_mut_slice().unwrap()[3] = 0.0;
+    assert_eq!(a[(1, 0)], 0.0);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_empty() {
+    let mut a = Array2::<f64>::zeros((2, 0));
+    assert_eq!(a.try_as_contiguous_slice(), Some(&[][..]));
+    assert_eq!(a.with_mut_slice(|slice| slice.len()), 0);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_non_contiguous() {
+    // A column-major array is not in standard (row-major) layout.
+    let mut a = Array2::from_row_slice(2, 3, A).reversed_axes();
+    assert_eq!(a.try_as_contiguous_slice(), None);
+    a.with_mut_slice(|slice| slice[1] = 0.0);
+    assert_eq!(
+        a,
+        Array2::from_row_slice(3, 2, &[1.0, 0.0, 2.0, 5.0, 3.0, 6.0])
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_with_mut_slice() {
+    // Padded columns are copied into a temporary slice in column-major order and written back.
+    let mut a = FMat::from_row_slice(2, 3, A);
+    let second = a.with_mut_slice(|slice| {
+        slice.iter_mut().for_each(|x| *x *= 2.0);
+        slice[1]
+    });
+    assert_eq!(second, 8.0);
+    assert_eq!(
+        a,
+        FMat::from_row_slice(2, 3, &[2.0, 4.0, 6.0, 8.0, 10.0, 12.0])
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    let mut a = FMat::<f64>::zeros(0, 3);
+    assert_eq!(Matrix::as_slice(&a), &[][..]);
+    assert_eq!(a.with_mut_slice(|slice| slice.len()), 0);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_contiguous() {
+    // The columns of an 8-row matrix are not padded, so its elements are contiguous.
+    let mut a = FMat::from_fn(8, 2, |i, j| (10 * i + j) as f64);
+    let expected: Vec<f64> = (0..2)
+        .flat_map(|j| (0..8).map(move |i| (10 * i + j) as f64))
+        .collect();
+    assert_eq!(a.try_as_contiguous_slice(), Some(expected.as_slice()));
+    assert!(matches!(Matrix::as_slice(&a), Cow::Borrowed(_)));
+    a.try_as_contiguous_mut_slice().unwrap()[8] = -1.0;
+    assert_eq!(a[(0, 1)], -1.0);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_single_column() {
+    let mut a = FMat::from_row_slice(3, 1, &[1.0, 2.0, 3.0]);
+    assert_eq!(a.try_as_contiguous_slice(), Some(&[1.0, 2.0, 3.0][..]));
+    a.try_as_contiguous_mut_slice().unwrap()[2] = 0.0;
+    assert_eq!(a[(2, 0)], 0.0);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_padded() {
+    // The columns of a 2-row matrix are padded, so the matrix is only borrowed column by column.
+    let a = FMat::from_row_slice(2, 3, A);
+    if a.col_stride() != 2 {
+        assert_eq!(a.try_as_contiguous_slice(), None);
+        assert!(matches!(Matrix::as_slice(&a), Cow::Owned(_)));
+    }
+    assert_eq!(Matrix::as_slice(&a), &A_COL[..]);
+}
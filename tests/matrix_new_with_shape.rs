@@ -1,6 +1,6 @@
 #[cfg(feature = "faer")]
 use faer::Mat as FMat;
-use linalg_traits::{Mat, Matrix};
+use linalg_traits::{Mat, Matrix, SMat};
 #[cfg(feature = "nalgebra")]
 use nalgebra::{DMatrix, SMatrix};
 #[cfg(feature = "ndarray")]
@@ -15,6 +15,38 @@ fn test_mat() {
     );
 }
 
+#[test]
+fn test_smat() {
+    assert_arrays_equal!(
+        <SMat<f64, 3, 2> as Matrix<f64>>::new_with_shape(3, 2),
+        [0.0, 0.0, 0.0, 0.0, 0.0, 0.0]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Row count mismatch.\n  left: 2\n right: 3")]
+fn test_smat_panic_1() {
+    let _ = <SMat<f64, 3, 2> as Matrix<f64>>::new_with_shape(2, 3);
+}
+
+#[test]
+#[should_panic(expected = "Row count mismatch.\n  left: 3\n right: 2")]
+fn test_smat_panic_2() {
+    let _ = <SMat<f64, 2, 3> as Matrix<f64>>::new_with_shape(3, 2);
+}
+
+#[test]
+#[should_panic(expected = "Row count mismatch.\n  left: 2\n right: 3")]
+fn test_smat_panic_3() {
+    let _ = <SMat<f64, 3, 3> as Matrix<f64>>::new_with_shape(2, 3);
+}
+
+#[test]
+#[should_panic(expected = "Column count mismatch.\n  left: 2\n right: 3")]
+fn test_smat_panic_4() {
+    let _ = <SMat<f64, 3, 3> as Matrix<f64>>::new_with_shape(3, 2);
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dmatrix() {
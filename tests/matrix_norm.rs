@@ -0,0 +1,217 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+use linalg_traits::{Mat, Matrix};
+#[cfg(feature = "nalgebra")]
+use nalgebra::DMatrix;
+use numtest::*;
+
+// Test conditions.
+static A_ROW: &[f64; 6] = &[1.0, -2.0, 3.0, -4.0, 5.0, -6.0];
+static A_LARGE_ROW: &[f64; 6] = &[1e300, -2e300, 3e300, -4e300, 5e300, -6e300];
+static SINGULAR_ROW: &[f64; 4] = &[1.0, 2.0, 2.0, 4.0];
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static A_SMALL_ROW: &[f64; 6] = &[1e-300, -2e-300, 3e-300, -4e-300, 5e-300, -6e-300];
+
+// Expected results.
+static NORM_1: f64 = 9.0;
+static NORM_INF: f64 = 15.0;
+static NORM_MAX: f64 = 6.0;
+
+/// 4x4 Hilbert matrix, whose 1-norm condition number is 28375.
+fn hilbert<M: Matrix<f64>>() -> M {
+    let mut h = M::new_with_shape(4, 4);
+    for i in 0..4 {
+        for j in 0..4 {
+            h[(i, j)] = 1.0 / (i + j + 1) as f64;
+        }
+    }
+    h
+}
+
+/// Check the norms of empty matrices and of matrices containing NaNs.
+fn norm_edge_case_helper<M: Matrix<f64>>() {
+    for (rows, cols) in [(0, 0), (0, 3), (3, 0)] {
+        let a = M::new_with_shape(rows, cols);
+        assert_eq!(a.norm_frobenius(), 0.0);
+        assert_eq!(a.norm_1(), 0.0);
+        assert_eq!(a.norm_inf(), 0.0);
+        assert_eq!(a.norm_max(), 0.0);
+    }
+    assert_eq!(M::new_with_shape(0, 0).lu().inverse_norm_1_estimate(), 0.0);
+
+    // NaNs propagate wherever they appear.
+    for idx in 0..4 {
+        let mut a = M::from_row_slice(2, 2, &[1.0, -2.0, 3.0, -4.0]);
+        a[(idx / 2, idx % 2)] = f64::NAN;
+        assert!(a.norm_frobenius().is_nan());
+        assert!(a.norm_1().is_nan());
+        assert!(a.norm_inf().is_nan());
+        assert!(a.norm_max().is_nan());
+    }
+}
+
+/// Check the norms and the 1-norm condition number estimate of a matrix.
+fn norm_test_helper<M2x3: Matrix<f64>, M4x4: Matrix<f64>, M2x2: Matrix<f64>>() {
+    let a = M2x3::from_row_slice(2, 3, A_ROW);
+
+    // Norms.
+    assert_equal_to_decimal!(a.norm_frobenius(), 91.0_f64.sqrt(), 14);
+    assert_eq!(a.norm_1(), NORM_1);
+    assert_eq!(a.norm_inf(), NORM_INF);
+    assert_eq!(a.norm_max(), NORM_MAX);
+
+    // The spectral norm is bounded by the other norms.
+    let norm_2 = a.norm_2().unwrap();
+    assert!(norm_2 <= a.norm_frobenius());
+    assert!(norm_2 >= a.norm_max());
+    assert!(norm_2 * norm_2 <= a.norm_1() * a.norm_inf() * (1.0 + 1e-15));
+
+    // Overflow safety of the Frobenius norm.
+    let a_large = M2x3::from_row_slice(2, 3, A_LARGE_ROW);
+    assert_equal_to_rtol!(a_large.norm_frobenius(), 91.0_f64.sqrt() * 1e300, 1e-15);
+
+    // 1-norm condition number estimate.
+    assert_equal_to_rtol!(hilbert::<M4x4>().cond_1_estimate(), 28375.0, 1e-9);
+    let singular = M2x2::from_row_slice(2, 2, SINGULAR_ROW);
+    assert_eq!(singular.cond_1_estimate(), f64::INFINITY);
+    assert_eq!(singular.lu().inverse_norm_1_estimate(), f64::INFINITY);
+}
+
+#[test]
+fn test_mat() {
+    norm_test_helper::<Mat<f64>, Mat<f64>, Mat<f64>>();
+    norm_edge_case_helper::<Mat<f64>>();
+}
+
+#[test]
+fn test_mat_cond_1_estimate_large() {
+    // Tridiagonal matrix whose inverse has a known 1-norm, with a bound on the estimate.
+    let n = 50;
+    let mut a = Mat::<f64>::new_with_shape(n, n);
+    for i in 0..n {
+        a[(i, i)] = 2.0;
+        if i > 0 {
+            a[(i, i - 1)] = -1.0;
+            a[(i - 1, i)] = -1.0;
+        }
+    }
+    let exact = a.norm_1() * a.inverse().unwrap().norm_1();
+    let estimate = a.cond_1_estimate();
+    assert!(estimate <= exact * (1.0 + 1e-12));
+    assert!(estimate >= exact / 3.0);
+}
+
+#[test]
+#[should_panic(expected = "Matrix is not square (2x3).")]
+fn test_mat_cond_1_estimate_panic() {
+    let a = Mat::from_row_slice(2, 3, A_ROW);
+    a.cond_1_estimate();
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let a = DMatrix::from_row_slice(2, 3, A_ROW);
+    assert_equal_to_decimal!(Matrix::norm_frobenius(&a), 91.0_f64.sqrt(), 14);
+    assert_eq!(Matrix::norm_1(&a), NORM_1);
+    assert_eq!(Matrix::norm_inf(&a), NORM_INF);
+    assert_eq!(Matrix::norm_max(&a), NORM_MAX);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_rescaled() {
+    // The Frobenius norm neither overflows nor underflows.
+    let a_large = DMatrix::from_row_slice(2, 3, A_LARGE_ROW);
+    assert_equal_to_rtol!(
+        Matrix::norm_frobenius(&a_large),
+        91.0_f64.sqrt() * 1e300,
+        1e-15
+    );
+    let a_small = DMatrix::from_row_slice(2, 3, A_SMALL_ROW);
+    assert_equal_to_rtol!(
+        Matrix::norm_frobenius(&a_small),
+        91.0_f64.sqrt() * 1e-300,
+        1e-15
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_nan() {
+    // NaNs propagate wherever they appear.
+    for idx in 0..6 {
+        let mut a = DMatrix::from_row_slice(2, 3, A_ROW);
+        a[(idx / 3, idx % 3)] = f64::NAN;
+        assert!(Matrix::norm_frobenius(&a).is_nan());
+        assert!(Matrix::norm_1(&a).is_nan());
+        assert!(Matrix::norm_inf(&a).is_nan());
+        assert!(Matrix::norm_max(&a).is_nan());
+    }
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    for (rows, cols) in [(0, 0), (0, 3), (3, 0)] {
+        let a = DMatrix::<f64>::zeros(rows, cols);
+        assert_eq!(Matrix::norm_frobenius(&a), 0.0);
+        assert_eq!(Matrix::norm_1(&a), 0.0);
+        assert_eq!(Matrix::norm_inf(&a), 0.0);
+        assert_eq!(Matrix::norm_max(&a), 0.0);
+    }
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    let a = FMat::from_row_slice(2, 3, A_ROW);
+    assert_equal_to_decimal!(Matrix::norm_frobenius(&a), 91.0_f64.sqrt(), 14);
+    assert_eq!(Matrix::norm_1(&a), NORM_1);
+    assert_eq!(Matrix::norm_inf(&a), NORM_INF);
+    assert_eq!(Matrix::norm_max(&a), NORM_MAX);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_rescaled() {
+    // The Frobenius norm neither overflows nor underflows.
+    let a_large = FMat::from_row_slice(2, 3, A_LARGE_ROW);
+    assert_equal_to_rtol!(
+        Matrix::norm_frobenius(&a_large),
+        91.0_f64.sqrt() * 1e300,
+        1e-15
+    );
+    let a_small = FMat::from_row_slice(2, 3, A_SMALL_ROW);
+    assert_equal_to_rtol!(
+        Matrix::norm_frobenius(&a_small),
+        91.0_f64.sqrt() * 1e-300,
+        1e-15
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_nan() {
+    // NaNs propagate wherever they appear.
+    for idx in 0..6 {
+        let mut a = FMat::from_row_slice(2, 3, A_ROW);
+        a[(idx / 3, idx % 3)] = f64::NAN;
+        assert!(Matrix::norm_frobenius(&a).is_nan());
+        assert!(Matrix::norm_1(&a).is_nan());
+        assert!(Matrix::norm_inf(&a).is_nan());
+        assert!(Matrix::norm_max(&a).is_nan());
+    }
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    for (rows, cols) in [(0, 0), (0, 3), (3, 0)] {
+        let a = FMat::<f64>::zeros(rows, cols);
+        assert_eq!(Matrix::norm_frobenius(&a), 0.0);
+        assert_eq!(Matrix::norm_1(&a), 0.0);
+        assert_eq!(Matrix::norm_inf(&a), 0.0);
+        assert_eq!(Matrix::norm_max(&a), 0.0);
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(feature = "faer")]
 use faer::Mat as FMat;
-use linalg_traits::{Mat, Matrix};
+use linalg_traits::{Mat, Matrix, SMat};
 #[cfg(feature = "nalgebra")]
 use nalgebra::{DMatrix, SMatrix};
 #[cfg(feature = "ndarray")]
@@ -12,6 +12,12 @@ fn test_mat() {
     assert!(!Mat::<f64>::is_column_major());
 }
 
+#[test]
+fn test_smat() {
+    assert!(SMat::<f64, 3, 3>::is_row_major());
+    assert!(!SMat::<f64, 3, 3>::is_column_major());
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dmatrix() {
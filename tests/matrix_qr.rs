@@ -0,0 +1,358 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+use linalg_traits::Qr;
+use linalg_traits::{LinalgError, Mat, Matrix, Vector};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, DVector, SMatrix};
+use numtest::*;
+
+// Test conditions.
+static A_ROW: &[f64; 12] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0, 1.0, 0.0, 1.0];
+static X_TRUE: &[f64; 3] = &[1.0, -2.0, 0.5];
+static LINE_ROW: &[f64; 8] = &[1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0];
+static LINE_Y: &[f64; 4] = &[1.0, 2.0, 2.0, 4.0];
+static RANK_DEFICIENT_ROW: &[f64; 6] = &[1.0, 2.0, 2.0, 4.0, 3.0, 6.0];
+static RANK_DEFICIENT_B: &[f64; 3] = &[1.0, 2.0, 3.0];
+static WIDE_ROW: &[f64; 6] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static ZERO_COLUMN_ROW: &[f64; 6] = &[0.0, 1.0, 0.0, 2.0, 0.0, 3.0];
+
+// Expected results.
+static LINE_COEFFS: &[f64; 2] = &[0.9, 0.9];
+static RANK_DEFICIENT_X: &[f64; 2] = &[0.0, 0.5];
+
+/// Check that `a * b = c` (to the specified number of decimal places) using indexing.
+fn assert_product<A: Matrix<f64>, B: Matrix<f64>, C: Matrix<f64>>(
+    a: &A,
+    b: &B,
+    c: &C,
+    decimal: i32,
+) {
+    let (rows, inner) = a.shape();
+    let cols = b.shape().1;
+    assert_eq!(b.shape().0, inner);
+    assert_eq!(c.shape(), (rows, cols));
+    for i in 0..rows {
+        for j in 0..cols {
+            let sum = (0..inner).map(|k| a[(i, k)] * b[(k, j)]).sum::<f64>();
+            assert_equal_to_decimal!(sum, c[(i, j)], decimal);
+        }
+    }
+}
+
+/// Check that the columns of `q` are orthonormal.
+fn assert_orthonormal_columns<Q: Matrix<f64>>(q: &Q) {
+    let (rows, cols) = q.shape();
+    for i in 0..cols {
+        for j in 0..cols {
+            let dot = (0..rows).map(|k| q[(k, i)] * q[(k, j)]).sum::<f64>();
+            assert_equal_to_decimal!(dot, if i == j { 1.0 } else { 0.0 }, 14);
+        }
+    }
+}
+
+/// Check that `r` is upper trapezoidal.
+fn assert_upper_trapezoidal<R: Matrix<f64>>(r: &R) {
+    let (rows, cols) = r.shape();
+    for i in 0..rows {
+        for j in 0..i.min(cols) {
+            assert_eq!(r[(i, j)], 0.0);
+        }
+    }
+}
+
+/// Check the full, thin, and column-pivoted QR decompositions of a 4x3 matrix, along with a least
+/// squares solve of a consistent system.
+fn qr_tall_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(4, 3, A_ROW);
+
+    // Full and thin QR decompositions.
+    let qr = a.qr();
+    let (q, r) = (qr.q(), qr.r());
+    assert_eq!(q.shape(), (4, 4));
+    assert_orthonormal_columns(&q);
+    assert_upper_trapezoidal(&r);
+    assert_product(&q, &r, &a, 13);
+    let (q_thin, r_thin) = (qr.q_thin(), qr.r_thin());
+    assert_eq!(r_thin.shape(), (3, 3));
+    assert_orthonormal_columns(&q_thin);
+    assert_upper_trapezoidal(&r_thin);
+    assert_product(&q_thin, &r_thin, &a, 13);
+    assert_eq!(qr.p(), vec![0, 1, 2]);
+    assert_eq!(qr.rank(), 3);
+
+    // Column-pivoted QR decomposition.
+    let qr = a.col_piv_qr();
+    let (q, r, p) = (qr.q(), qr.r(), qr.p());
+    let mut a_p = M::new_with_shape(4, 3);
+    for i in 0..4 {
+        for j in 0..3 {
+            a_p[(i, j)] = a[(i, p[j])];
+        }
+    }
+    assert_orthonormal_columns(&q);
+    assert_upper_trapezoidal(&r);
+    assert_product(&q, &r, &a_p, 13);
+    assert!(r[(0, 0)].abs() >= r[(1, 1)].abs());
+    assert!(r[(1, 1)].abs() >= r[(2, 2)].abs());
+    assert_eq!(qr.rank(), 3);
+
+    // Least squares solve of a consistent system.
+    let b = a.mul_vector(&M::VectorN::from_slice(X_TRUE));
+    assert_arrays_equal_to_decimal!(a.least_squares(&b).unwrap().as_slice(), X_TRUE, 13);
+    assert_arrays_equal_to_decimal!(a.qr().least_squares(&b).unwrap().as_slice(), X_TRUE, 13);
+}
+
+/// Check a least squares line fit.
+fn line_fit_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(4, 2, LINE_ROW);
+    let y = M::VectorM::from_slice(LINE_Y);
+    assert_arrays_equal_to_decimal!(a.least_squares(&y).unwrap().as_slice(), LINE_COEFFS, 14);
+    assert_arrays_equal_to_decimal!(
+        a.qr().least_squares(&y).unwrap().as_slice(),
+        LINE_COEFFS,
+        14
+    );
+}
+
+/// Check the rank and least squares solution of a rank-deficient matrix.
+fn rank_deficient_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(3, 2, RANK_DEFICIENT_ROW);
+    assert_eq!(a.col_piv_qr().rank(), 1);
+    let b = M::VectorM::from_slice(RANK_DEFICIENT_B);
+    assert_arrays_equal_to_decimal!(
+        a.least_squares(&b).unwrap().as_slice(),
+        RANK_DEFICIENT_X,
+        14
+    );
+}
+
+/// Check the full QR decomposition of a 2x3 matrix.
+fn qr_wide_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(2, 3, WIDE_ROW);
+    let qr = a.qr();
+    let (q, r) = (qr.q(), qr.r());
+    assert_eq!(q.shape(), (2, 2));
+    assert_orthonormal_columns(&q);
+    assert_upper_trapezoidal(&r);
+    assert_product(&q, &r, &a, 13);
+}
+
+/// Elements of a larger 70x50 matrix (exercising blocked implementations), in row-major order.
+fn large_row() -> Vec<f64> {
+    (0..70 * 50)
+        .map(|i| ((i * 7919) % 113) as f64 - 56.0)
+        .collect()
+}
+
+/// Check the QR decompositions of a larger matrix.
+fn qr_large_helper<M: Matrix<f64>>() {
+    let (m, n) = (70, 50);
+    let a = M::from_row_slice(m, n, &large_row());
+    for qr in [a.qr(), a.col_piv_qr()] {
+        let (q, r, p) = (qr.q_thin(), qr.r_thin(), qr.p());
+        let mut a_p = M::new_with_shape(m, n);
+        for i in 0..m {
+            for j in 0..n {
+                a_p[(i, j)] = a[(i, p[j])];
+            }
+        }
+        assert_orthonormal_columns(&q);
+        assert_product(&q, &r, &a_p, 11);
+    }
+}
+
+/// Check that a QR decomposition of `a` satisfies `A * P = Q * R` with orthonormal `Q` and upper
+/// trapezoidal `R`.
+#[cfg(feature = "faer")]
+fn assert_factorization<M: Matrix<f64>>(a: &M, qr: &Qr<f64, M>, decimal: i32) {
+    let (rows, cols) = a.shape();
+    let (q, r, p) = (qr.q(), qr.r(), qr.p());
+    let mut a_p = M::new_with_shape(rows, cols);
+    for i in 0..rows {
+        for j in 0..cols {
+            a_p[(i, j)] = a[(i, p[j])];
+        }
+    }
+    assert_orthonormal_columns(&q);
+    assert_upper_trapezoidal(&r);
+    assert_product(&q, &r, &a_p, decimal);
+}
+
+/// Check that a QR decomposition matches the one computed by the default implementation.
+#[cfg(feature = "nalgebra")]
+fn assert_matches_default<M: Matrix<f64>>(qr: &Qr<f64, M>, default: &Qr<f64, Mat<f64>>) {
+    assert_eq!(qr.p(), default.p());
+    assert_eq!(qr.rank(), default.rank());
+    let (q, r) = (qr.q(), qr.r());
+    let (q_default, r_default) = (default.q(), default.r());
+    let (rows, cols) = r.shape();
+    for i in 0..rows {
+        for j in 0..rows {
+            assert_equal_to_decimal!(q[(i, j)], q_default[(i, j)], 14);
+        }
+        for j in 0..cols {
+            assert_equal_to_decimal!(r[(i, j)], r_default[(i, j)], 14);
+        }
+    }
+}
+
+#[test]
+fn test_mat() {
+    qr_tall_helper::<Mat<f64>>();
+    qr_large_helper::<Mat<f64>>();
+    line_fit_helper::<Mat<f64>>();
+    rank_deficient_helper::<Mat<f64>>();
+    qr_wide_helper::<Mat<f64>>();
+}
+
+#[test]
+#[should_panic(expected = "Thin QR decomposition requires at least as many rows as columns (2x3).")]
+fn test_mat_panic() {
+    let a = Mat::from_row_slice(2, 3, WIDE_ROW);
+    a.qr().q_thin();
+}
+
+#[test]
+fn test_mat_least_squares_length_mismatch() {
+    let a = Mat::from_row_slice(4, 3, A_ROW);
+    let error = LinalgError::LengthMismatch {
+        expected: 4,
+        actual: 3,
+    };
+    let mut b = vec![1.0, 2.0, 3.0];
+    assert_eq!(a.least_squares(&b), Err(error));
+    assert_eq!(a.qr().least_squares(&b), Err(error));
+    assert_eq!(a.qr().q_transpose_mul_in_place(&mut b), Err(error));
+    assert_eq!(b, vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    // The native factorizations match the default implementation.
+    let a = DMatrix::from_row_slice(4, 3, A_ROW);
+    let a_mat = Mat::from_row_slice(4, 3, A_ROW);
+    assert_matches_default(&Matrix::qr(&a), &a_mat.qr());
+    assert_matches_default(&Matrix::col_piv_qr(&a), &a_mat.col_piv_qr());
+    let wide = DMatrix::from_row_slice(2, 3, WIDE_ROW);
+    let wide_mat = Mat::from_row_slice(2, 3, WIDE_ROW);
+    assert_matches_default(&Matrix::qr(&wide), &wide_mat.qr());
+    assert_matches_default(&Matrix::col_piv_qr(&wide), &wide_mat.col_piv_qr());
+    assert_matches_default(
+        &Matrix::qr(&DMatrix::from_row_slice(3, 2, ZERO_COLUMN_ROW)),
+        &Mat::from_row_slice(3, 2, ZERO_COLUMN_ROW).qr(),
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_least_squares() {
+    // Overdetermined systems with full column rank.
+    let a = DMatrix::from_row_slice(4, 3, A_ROW);
+    let b = Matrix::mul_vector(&a, &DVector::from_slice(X_TRUE));
+    assert_arrays_equal_to_decimal!(
+        Matrix::least_squares(&a, &b).unwrap().as_slice(),
+        X_TRUE,
+        13
+    );
+    let a = DMatrix::from_row_slice(4, 2, LINE_ROW);
+    let y = DVector::from_slice(LINE_Y);
+    assert_arrays_equal_to_decimal!(
+        Matrix::least_squares(&a, &y).unwrap().as_slice(),
+        LINE_COEFFS,
+        14
+    );
+
+    // Rank-deficient systems fall back to the default implementation.
+    let a = DMatrix::from_row_slice(3, 2, RANK_DEFICIENT_ROW);
+    let b = DVector::from_slice(RANK_DEFICIENT_B);
+    assert_arrays_equal_to_decimal!(
+        Matrix::least_squares(&a, &b).unwrap().as_slice(),
+        RANK_DEFICIENT_X,
+        14
+    );
+
+    let a = DMatrix::from_row_slice(4, 3, A_ROW);
+    assert_eq!(
+        Matrix::least_squares(&a, &DVector::from_row_slice(&[1.0, 2.0, 3.0])),
+        Err(LinalgError::LengthMismatch {
+            expected: 4,
+            actual: 3
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    let empty = DMatrix::<f64>::zeros(0, 3);
+    assert_eq!(Matrix::qr(&empty).r().shape(), (0, 3));
+    assert_eq!(Matrix::col_piv_qr(&empty).p(), vec![0, 1, 2]);
+    let empty = DMatrix::<f64>::zeros(3, 0);
+    assert_eq!(Matrix::qr(&empty).q().shape(), (3, 3));
+    assert_eq!(Matrix::col_piv_qr(&empty).rank(), 0);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    // The native factorizations match the default implementation.
+    let a = <SMatrix<f64, 4, 3> as Matrix<f64>>::from_row_slice(4, 3, A_ROW);
+    let a_mat = Mat::from_row_slice(4, 3, A_ROW);
+    assert_matches_default(&Matrix::qr(&a), &a_mat.qr());
+    assert_matches_default(&Matrix::col_piv_qr(&a), &a_mat.col_piv_qr());
+    let wide = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, WIDE_ROW);
+    let wide_mat = Mat::from_row_slice(2, 3, WIDE_ROW);
+    assert_matches_default(&Matrix::qr(&wide), &wide_mat.qr());
+    assert_matches_default(&Matrix::col_piv_qr(&wide), &wide_mat.col_piv_qr());
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    let a = FMat::from_row_slice(4, 3, A_ROW);
+    let qr = Matrix::qr(&a);
+    assert_factorization(&a, &qr, 13);
+    assert_eq!(qr.p(), vec![0, 1, 2]);
+    let qr = Matrix::col_piv_qr(&a);
+    assert_factorization(&a, &qr, 13);
+    let r = qr.r();
+    assert!(r[(0, 0)].abs() >= r[(1, 1)].abs());
+    assert!(r[(1, 1)].abs() >= r[(2, 2)].abs());
+    assert_eq!(qr.rank(), 3);
+
+    let wide = FMat::from_row_slice(2, 3, WIDE_ROW);
+    assert_factorization(&wide, &Matrix::qr(&wide), 13);
+    assert_factorization(&wide, &Matrix::col_piv_qr(&wide), 13);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_rank_deficient() {
+    let a = FMat::from_row_slice(3, 2, RANK_DEFICIENT_ROW);
+    assert_eq!(Matrix::col_piv_qr(&a).rank(), 1);
+    let zero_column = FMat::from_row_slice(3, 2, ZERO_COLUMN_ROW);
+    assert_factorization(&zero_column, &Matrix::qr(&zero_column), 14);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_large() {
+    // faer groups the Householder reflections into blocks.
+    let a = FMat::from_row_slice(70, 50, &large_row());
+    assert_factorization(&a, &Matrix::qr(&a), 11);
+    assert_factorization(&a, &Matrix::col_piv_qr(&a), 11);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    let empty = FMat::<f64>::zeros(0, 3);
+    assert_eq!(Matrix::shape(&Matrix::qr(&empty).r()), (0, 3));
+    assert_eq!(Matrix::col_piv_qr(&empty).p(), vec![0, 1, 2]);
+    let empty = FMat::<f64>::zeros(3, 0);
+    assert_eq!(Matrix::shape(&Matrix::qr(&empty).q()), (3, 3));
+    assert_eq!(Matrix::col_piv_qr(&empty).rank(), 0);
+}
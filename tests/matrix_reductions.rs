@@ -0,0 +1,155 @@
+use linalg_traits::{Mat, Matrix, NanPolicy, Vector};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, SMatrix};
+#[cfg(feature = "ndarray")]
+use ndarray::Array2;
+use numtest::*;
+
+// Test conditions.
+static A_ROW: &[f64; 6] = &[1.0, -2.0, 3.0, -4.0, 5.0, -6.0];
+static A_NAN_ROW: &[f64; 6] = &[1.0, f64::NAN, 3.0, f64::NAN, f64::NAN, -6.0];
+
+/// Check that a vector matches its expected elements (treating NaNs as equal to each other).
+fn assert_elements<V: Vector<f64>>(actual: &V, expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (i, &value) in expected.iter().enumerate() {
+        if value.is_nan() {
+            assert!(actual[i].is_nan(), "Element {i} is {}, not NaN.", actual[i]);
+        } else {
+            assert_equal_to_decimal!(actual[i], value, 15);
+        }
+    }
+}
+
+/// Check every row-wise and column-wise reduction of a 2x3 matrix, with and without NaNs.
+fn reductions_test_helper<M: Matrix<f64>>() {
+    use NanPolicy::{Ignore, Propagate};
+    let nan = f64::NAN;
+
+    // No NaNs (both policies agree).
+    let a = M::from_row_slice(2, 3, A_ROW);
+    for policy in [Propagate, Ignore] {
+        assert_elements(&a.row_sums(policy), &[2.0, -5.0]);
+        assert_elements(&a.column_sums(policy), &[-3.0, 3.0, -3.0]);
+        assert_elements(&a.row_means(policy), &[2.0 / 3.0, -5.0 / 3.0]);
+        assert_elements(&a.column_means(policy), &[-1.5, 1.5, -1.5]);
+        assert_elements(&a.row_mins(policy), &[-2.0, -6.0]);
+        assert_elements(&a.column_mins(policy), &[-4.0, -2.0, -6.0]);
+        assert_elements(&a.row_maxes(policy), &[3.0, 5.0]);
+        assert_elements(&a.column_maxes(policy), &[1.0, 5.0, 3.0]);
+    }
+    assert_elements(&a.row_norms(), &[14.0_f64.sqrt(), 77.0_f64.sqrt()]);
+    assert_elements(
+        &a.column_norms(),
+        &[17.0_f64.sqrt(), 29.0_f64.sqrt(), 45.0_f64.sqrt()],
+    );
+
+    // NaNs propagated.
+    let a = M::from_row_slice(2, 3, A_NAN_ROW);
+    assert_elements(&a.row_sums(Propagate), &[nan, nan]);
+    assert_elements(&a.column_sums(Propagate), &[nan, nan, -3.0]);
+    assert_elements(&a.row_means(Propagate), &[nan, nan]);
+    assert_elements(&a.column_means(Propagate), &[nan, nan, -1.5]);
+    assert_elements(&a.row_mins(Propagate), &[nan, nan]);
+    assert_elements(&a.column_mins(Propagate), &[nan, nan, -6.0]);
+    assert_elements(&a.row_maxes(Propagate), &[nan, nan]);
+    assert_elements(&a.column_maxes(Propagate), &[nan, nan, 3.0]);
+    assert_elements(&a.column_norms(), &[nan, nan, 45.0_f64.sqrt()]);
+
+    // NaNs ignored.
+    assert_elements(&a.row_sums(Ignore), &[4.0, -6.0]);
+    assert_elements(&a.column_sums(Ignore), &[1.0, 0.0, -3.0]);
+    assert_elements(&a.row_means(Ignore), &[2.0, -6.0]);
+    assert_elements(&a.column_means(Ignore), &[1.0, nan, -1.5]);
+    assert_elements(&a.row_mins(Ignore), &[1.0, -6.0]);
+    assert_elements(&a.column_mins(Ignore), &[1.0, f64::INFINITY, -6.0]);
+    assert_elements(&a.row_maxes(Ignore), &[3.0, -6.0]);
+    assert_elements(&a.column_maxes(Ignore), &[1.0, f64::NEG_INFINITY, 3.0]);
+}
+
+#[test]
+fn test_mat() {
+    reductions_test_helper::<Mat<f64>>();
+}
+
+#[test]
+fn test_mat_empty() {
+    let a = Mat::<f64>::new_with_shape(2, 0);
+    assert_elements(&a.row_sums(NanPolicy::Propagate), &[0.0, 0.0]);
+    assert_elements(&a.row_means(NanPolicy::Propagate), &[f64::NAN, f64::NAN]);
+    assert_elements(&a.row_norms(), &[0.0, 0.0]);
+    assert!(a.column_sums(NanPolicy::Propagate).is_empty());
+}
+
+#[test]
+fn test_mat_norms_large() {
+    let a = Mat::from_row_slice(1, 2, &[3e300, 4e300]);
+    assert_equal_to_rtol!(a.row_norms()[0], 5e300, 1e-15);
+    assert_eq!(a.column_norms(), vec![3e300, 4e300]);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let a = DMatrix::from_row_slice(2, 3, A_ROW);
+    assert_elements(&a.row_sums(NanPolicy::Propagate), &[2.0, -5.0]);
+    assert_elements(&a.column_sums(NanPolicy::Propagate), &[-3.0, 3.0, -3.0]);
+    let a = DMatrix::from_row_slice(2, 3, A_NAN_ROW);
+    assert_elements(&a.row_sums(NanPolicy::Propagate), &[f64::NAN, f64::NAN]);
+    assert_elements(
+        &a.column_sums(NanPolicy::Propagate),
+        &[f64::NAN, f64::NAN, -3.0],
+    );
+    assert_elements(&a.column_sums(NanPolicy::Ignore), &[1.0, 0.0, -3.0]);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    let a = DMatrix::<f64>::zeros(2, 0);
+    assert_elements(&a.row_sums(NanPolicy::Propagate), &[0.0, 0.0]);
+    assert!(Vector::is_empty(&a.column_sums(NanPolicy::Propagate)));
+    let a = DMatrix::<f64>::zeros(0, 3);
+    assert!(Vector::is_empty(&a.row_sums(NanPolicy::Propagate)));
+    assert_elements(&a.column_sums(NanPolicy::Propagate), &[0.0, 0.0, 0.0]);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    let a = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, A_ROW);
+    assert_elements(&a.row_sums(NanPolicy::Propagate), &[2.0, -5.0]);
+    assert_elements(&a.column_sums(NanPolicy::Propagate), &[-3.0, 3.0, -3.0]);
+    let a = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, A_NAN_ROW);
+    assert_elements(
+        &a.column_sums(NanPolicy::Propagate),
+        &[f64::NAN, f64::NAN, -3.0],
+    );
+    assert_elements(&a.row_sums(NanPolicy::Ignore), &[4.0, -6.0]);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2() {
+    let a = Array2::from_row_slice(2, 3, A_ROW);
+    assert_elements(&a.row_sums(NanPolicy::Propagate), &[2.0, -5.0]);
+    assert_elements(&a.column_sums(NanPolicy::Propagate), &[-3.0, 3.0, -3.0]);
+    let a = Array2::from_row_slice(2, 3, A_NAN_ROW);
+    assert_elements(&a.row_sums(NanPolicy::Propagate), &[f64::NAN, f64::NAN]);
+    assert_elements(
+        &a.column_sums(NanPolicy::Propagate),
+        &[f64::NAN, f64::NAN, -3.0],
+    );
+    assert_elements(&a.row_sums(NanPolicy::Ignore), &[4.0, -6.0]);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_empty() {
+    let a = Array2::<f64>::zeros((2, 0));
+    assert_elements(&a.row_sums(NanPolicy::Propagate), &[0.0, 0.0]);
+    assert!(Vector::is_empty(&a.column_sums(NanPolicy::Propagate)));
+    let a = Array2::<f64>::zeros((0, 3));
+    assert!(Vector::is_empty(&a.row_sums(NanPolicy::Propagate)));
+    assert_elements(&a.column_sums(NanPolicy::Propagate), &[0.0, 0.0, 0.0]);
+}
@@ -0,0 +1,202 @@
+#[cfg(feature = "faer")]
+use faer::{Col, Mat as FMat};
+use linalg_traits::{Mat, Matrix, SMat, Vector};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, DVector, SMatrix, SVector};
+#[cfg(feature = "ndarray")]
+use ndarray::{Array1, Array2};
+use numtest::*;
+
+// Test conditions.
+static A_ROW: &[f64; 6] = &[1.0, -2.0, 3.0, -4.0, 5.0, -6.0];
+
+// Expected result after setting row 1 to [7, 8, 9] and column 2 to [10, 11].
+static SET_ROW: &[f64; 6] = &[1.0, -2.0, 10.0, 7.0, 8.0, 11.0];
+#[cfg(feature = "nalgebra")]
+static SET_COL: &[f64; 6] = &[1.0, 7.0, -2.0, 8.0, 10.0, 11.0];
+
+/// Check row and column extraction, assignment, and iteration for a 2x3 matrix.
+fn row_column_test_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(2, 3, A_ROW);
+
+    // Extraction.
+    assert_eq!(a.row(0), M::VectorN::from_slice(&[1.0, -2.0, 3.0]));
+    assert_eq!(a.row(1), M::VectorN::from_slice(&[-4.0, 5.0, -6.0]));
+    assert_eq!(a.column(0), M::VectorM::from_slice(&[1.0, -4.0]));
+    assert_eq!(a.column(2), M::VectorM::from_slice(&[3.0, -6.0]));
+
+    // Iteration.
+    let rows: Vec<M::VectorN> = a.rows().collect();
+    assert_eq!(rows, vec![a.row(0), a.row(1)]);
+    let columns: Vec<M::VectorM> = a.columns().collect();
+    assert_eq!(columns, vec![a.column(0), a.column(1), a.column(2)]);
+
+    // Assignment.
+    let mut b = a.clone();
+    b.set_row(1, &M::VectorN::from_slice(&[7.0, 8.0, 9.0]));
+    assert_eq!(b, M::from_row_slice(2, 3, &[1.0, -2.0, 3.0, 7.0, 8.0, 9.0]));
+    b.set_column(2, &M::VectorM::from_slice(&[10.0, 11.0]));
+    assert_eq!(b, M::from_row_slice(2, 3, SET_ROW));
+
+    // Column-by-column assembly (e.g. of a Jacobian) reproduces the original matrix.
+    let mut c = M::new_with_shape(2, 3);
+    for (col, column) in a.columns().enumerate() {
+        c.set_column(col, &column);
+    }
+    assert_eq!(c, a);
+}
+
+#[test]
+fn test_mat() {
+    row_column_test_helper::<Mat<f64>>();
+}
+
+#[test]
+#[should_panic(expected = "Row index (2) is out of bounds for a matrix with 2 rows.")]
+fn test_mat_row_panic() {
+    let a = Mat::from_row_slice(2, 3, A_ROW);
+    a.row(2);
+}
+
+#[test]
+#[should_panic(expected = "Column index (3) is out of bounds for a matrix with 3 columns.")]
+fn test_mat_column_panic() {
+    let a = Mat::from_row_slice(2, 3, A_ROW);
+    a.column(3);
+}
+
+#[test]
+#[should_panic(
+    expected = "Length of the row (2) does not match the number of columns of this matrix (3)."
+)]
+fn test_mat_set_row_panic() {
+    let mut a = Mat::from_row_slice(2, 3, A_ROW);
+    a.set_row(0, &vec![1.0, 2.0]);
+}
+
+#[test]
+#[should_panic(
+    expected = "Length of the column (3) does not match the number of rows of this matrix (2)."
+)]
+fn test_mat_set_column_panic() {
+    let mut a = Mat::from_row_slice(2, 3, A_ROW);
+    a.set_column(0, &vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_smat() {
+    let mut a = SMat::<f64, 2, 3>::from_row_slice(2, 3, A_ROW);
+    assert_eq!(a.row(1), [-4.0, 5.0, -6.0]);
+    assert_eq!(a.column(2), [3.0, -6.0]);
+    a.set_row(1, &[7.0, 8.0, 9.0]);
+    a.set_column(2, &[10.0, 11.0]);
+    assert_arrays_equal!(a.as_slice(), SET_ROW);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let mut a = DMatrix::from_row_slice(2, 3, A_ROW);
+    assert_arrays_equal!(Matrix::row(&a, 1).as_slice(), &[-4.0, 5.0, -6.0]);
+    assert_arrays_equal!(Matrix::column(&a, 2).as_slice(), &[3.0, -6.0]);
+    Matrix::set_row(&mut a, 1, &DVector::from_slice(&[7.0, 8.0, 9.0]));
+    Matrix::set_column(&mut a, 2, &DVector::from_slice(&[10.0, 11.0]));
+    assert_arrays_equal!(a.as_slice(), SET_COL);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    let mut a = DMatrix::<f64>::zeros(2, 0);
+    assert!(Vector::is_empty(&Matrix::row(&a, 1)));
+    Matrix::set_row(&mut a, 1, &DVector::zeros(0));
+    let mut a = DMatrix::<f64>::zeros(0, 3);
+    assert!(Vector::is_empty(&Matrix::column(&a, 2)));
+    Matrix::set_column(&mut a, 2, &DVector::zeros(0));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(expected = "Row index (2) is out of bounds for a matrix with 2 rows.")]
+fn test_nalgebra_dmatrix_row_panic() {
+    let a = DMatrix::from_row_slice(2, 3, A_ROW);
+    Matrix::row(&a, 2);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(
+    expected = "Length of the column (3) does not match the number of rows of this matrix (2)."
+)]
+fn test_nalgebra_dmatrix_set_column_panic() {
+    let mut a = DMatrix::from_row_slice(2, 3, A_ROW);
+    Matrix::set_column(&mut a, 0, &DVector::from_slice(&[1.0, 2.0, 3.0]));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    let mut a = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, A_ROW);
+    assert_arrays_equal!(Matrix::row(&a, 1).as_slice(), &[-4.0, 5.0, -6.0]);
+    assert_arrays_equal!(Matrix::column(&a, 2).as_slice(), &[3.0, -6.0]);
+    Matrix::set_row(&mut a, 1, &SVector::from([7.0, 8.0, 9.0]));
+    Matrix::set_column(&mut a, 2, &SVector::from([10.0, 11.0]));
+    assert_arrays_equal!(a.as_slice(), SET_COL);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2() {
+    let mut a = Array2::from_row_slice(2, 3, A_ROW);
+    assert_eq!(Matrix::row(&a, 1), Array1::from_slice(&[-4.0, 5.0, -6.0]));
+    assert_eq!(Matrix::column(&a, 2), Array1::from_slice(&[3.0, -6.0]));
+    a.set_row(1, &Array1::from_slice(&[7.0, 8.0, 9.0]));
+    a.set_column(2, &Array1::from_slice(&[10.0, 11.0]));
+    assert_arrays_equal!(Matrix::as_slice(&a), SET_ROW);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_empty() {
+    let mut a = Array2::<f64>::zeros((2, 0));
+    assert!(Vector::is_empty(&Matrix::row(&a, 1)));
+    a.set_row(1, &Array1::zeros(0));
+    let mut a = Array2::<f64>::zeros((0, 3));
+    assert!(Vector::is_empty(&Matrix::column(&a, 2)));
+    a.set_column(2, &Array1::zeros(0));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+#[should_panic(
+    expected = "Length of the row (2) does not match the number of columns of this matrix (3)."
+)]
+fn test_ndarray_array2_set_row_panic() {
+    let mut a = Array2::from_row_slice(2, 3, A_ROW);
+    a.set_row(0, &Array1::from_slice(&[1.0, 2.0]));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    let mut a = FMat::from_row_slice(2, 3, A_ROW);
+    assert_eq!(Matrix::column(&a, 2), Col::from_slice(&[3.0, -6.0]));
+    Matrix::set_column(&mut a, 2, &Col::from_slice(&[10.0, 11.0]));
+    assert_arrays_equal!(a.as_slice(), &[1.0, -4.0, -2.0, 5.0, 10.0, 11.0]);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    let mut a = FMat::<f64>::zeros(0, 3);
+    assert!(Vector::is_empty(&Matrix::column(&a, 2)));
+    Matrix::set_column(&mut a, 2, &Col::zeros(0));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+#[should_panic(expected = "Column index (3) is out of bounds for a matrix with 3 columns.")]
+fn test_faer_mat_column_panic() {
+    let a = FMat::from_row_slice(2, 3, A_ROW);
+    Matrix::column(&a, 3);
+}
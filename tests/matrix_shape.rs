@@ -1,6 +1,6 @@
 #[cfg(feature = "faer")]
 use faer::Mat as FMat;
-use linalg_traits::{Mat, Matrix};
+use linalg_traits::{Mat, Matrix, SMat};
 #[cfg(feature = "nalgebra")]
 use nalgebra::{DMatrix, SMatrix};
 #[cfg(feature = "ndarray")]
@@ -14,6 +14,14 @@ fn test_mat() {
     );
 }
 
+#[test]
+fn test_smat() {
+    assert_eq!(
+        <SMat<f64, 3, 2> as Matrix<f64>>::new_with_shape(3, 2).shape(),
+        (3, 2)
+    );
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dmatrix() {
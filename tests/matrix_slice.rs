@@ -1,6 +1,6 @@
 #[cfg(feature = "faer")]
 use faer::Mat as FMat;
-use linalg_traits::{Mat, Matrix};
+use linalg_traits::{Mat, Matrix, SMat};
 #[cfg(feature = "nalgebra")]
 use nalgebra::{DMatrix, SMatrix};
 #[cfg(feature = "ndarray")]
@@ -46,6 +46,52 @@ fn test_mat_panic_2() {
     _ = <Mat<f64> as Matrix<f64>>::from_col_slice(2, 2, COL_SLICE)
 }
 
+#[test]
+fn test_smat() {
+    // Testing from a row slice.
+    let x1 = <SMat<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, ROW_SLICE);
+
+    // Testing from a column slice.
+    let x2 = <SMat<f64, 2, 3> as Matrix<f64>>::from_col_slice(2, 3, COL_SLICE);
+
+    // Testing equality of the two matrices.
+    assert_arrays_equal!(x1, x2);
+
+    // Testing slice representations of x1.
+    assert_arrays_equal!(x1.as_slice(), ROW_SLICE);
+    assert_arrays_equal!(x1.as_row_slice(), ROW_SLICE);
+    assert_arrays_equal!(x1.as_col_slice(), COL_SLICE);
+
+    // Testing slice representations of x2.
+    assert_arrays_equal!(x2.as_slice(), ROW_SLICE);
+    assert_arrays_equal!(x2.as_row_slice(), ROW_SLICE);
+    assert_arrays_equal!(x2.as_col_slice(), COL_SLICE);
+}
+
+#[test]
+#[should_panic(expected = "Column count mismatch.")]
+fn test_smat_panic_1() {
+    _ = <SMat<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 2, ROW_SLICE)
+}
+
+#[test]
+#[should_panic(expected = "Row count mismatch.")]
+fn test_smat_panic_2() {
+    _ = <SMat<f64, 2, 3> as Matrix<f64>>::from_row_slice(3, 3, ROW_SLICE)
+}
+
+#[test]
+#[should_panic(expected = "Column count mismatch.")]
+fn test_smat_panic_3() {
+    _ = <SMat<f64, 2, 3> as Matrix<f64>>::from_col_slice(2, 2, COL_SLICE)
+}
+
+#[test]
+#[should_panic(expected = "Row count mismatch.")]
+fn test_smat_panic_4() {
+    _ = <SMat<f64, 2, 3> as Matrix<f64>>::from_col_slice(3, 3, COL_SLICE)
+}
+
 // Note that `nalgebra::DMatrix` is column-major.
 #[test]
 #[cfg(feature = "nalgebra")]
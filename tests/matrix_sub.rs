@@ -1,6 +1,6 @@
 #[cfg(feature = "faer")]
 use faer::Mat as FMat;
-use linalg_traits::{Mat, Matrix};
+use linalg_traits::{Mat, Matrix, SMat};
 #[cfg(feature = "nalgebra")]
 use nalgebra::{DMatrix, Matrix2};
 #[cfg(feature = "ndarray")]
@@ -45,6 +45,16 @@ fn test_mat_sub_assign_panic() {
     x.sub_assign(&w);
 }
 
+#[test]
+fn test_smat() {
+    let mut x = <SMat<f64, 2, 2> as Matrix<f64>>::from_row_slice(2, 2, X_ROW);
+    let y = <SMat<f64, 2, 2> as Matrix<f64>>::from_row_slice(2, 2, Y_ROW);
+    let z = x.sub(&y);
+    x.sub_assign(&y);
+    assert_arrays_equal!(z.as_slice(), Z_ROW);
+    assert_arrays_equal!(x.as_slice(), Z_ROW);
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dmatrix() {
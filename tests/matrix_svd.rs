@@ -0,0 +1,389 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+use linalg_traits::{LinalgError, Mat, Matrix, Svd, Vector};
+#[cfg(feature = "nalgebra")]
+use nalgebra::DMatrix;
+use numtest::*;
+
+// Test conditions.
+static A_ROW: &[f64; 12] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0, 1.0, 0.0, 1.0];
+static DIAG_ROW: &[f64; 6] = &[3.0, 0.0, 0.0, -4.0, 0.0, 0.0];
+static RANK_DEFICIENT_ROW: &[f64; 6] = &[1.0, 2.0, 2.0, 4.0, 3.0, 6.0];
+static SINGULAR_ROW: &[f64; 4] = &[1.0, 0.0, 0.0, 0.0];
+static WIDE_ROW: &[f64; 6] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+
+// Expected results.
+static DIAG_SIGMA: &[f64; 2] = &[4.0, 3.0];
+static RANK_DEFICIENT_PINV_ROW: &[f64; 6] = &[
+    1.0 / 70.0,
+    2.0 / 70.0,
+    3.0 / 70.0,
+    2.0 / 70.0,
+    4.0 / 70.0,
+    6.0 / 70.0,
+];
+
+/// Check that the columns of `q` are orthonormal.
+fn assert_orthonormal_columns<Q: Matrix<f64>>(q: &Q) {
+    let (rows, cols) = q.shape();
+    for i in 0..cols {
+        for j in 0..cols {
+            let dot = (0..rows).map(|k| q[(k, i)] * q[(k, j)]).sum::<f64>();
+            assert_equal_to_decimal!(dot, if i == j { 1.0 } else { 0.0 }, 14);
+        }
+    }
+}
+
+/// Check a singular value decomposition of `a`.
+fn assert_svd<M: Matrix<f64>, U: Matrix<f64>, VT: Matrix<f64>>(a: &M, svd: &Svd<f64, U, VT>) {
+    let (u, sigma, v_t) = (svd.u(), svd.sigma(), svd.v_t());
+    let (rows, cols) = a.shape();
+    assert_eq!(sigma.len(), rows.min(cols));
+    assert_eq!(u.shape().0, rows);
+    assert_eq!(v_t.shape().1, cols);
+    assert_orthonormal_columns(u);
+    assert_orthonormal_columns(&v_t.transpose());
+    for k in 1..sigma.len() {
+        assert!(sigma[k - 1] >= sigma[k]);
+        assert!(sigma[k] >= 0.0);
+    }
+    for i in 0..rows {
+        for j in 0..cols {
+            let sum = (0..sigma.len())
+                .map(|k| u[(i, k)] * sigma[k] * v_t[(k, j)])
+                .sum::<f64>();
+            assert_equal_to_decimal!(sum, a[(i, j)], 13);
+        }
+    }
+}
+
+/// Check that `a * b` is the identity matrix using indexing.
+fn assert_identity_product<A: Matrix<f64>, B: Matrix<f64>>(a: &A, b: &B) {
+    let (rows, inner) = a.shape();
+    let cols = b.shape().1;
+    for i in 0..rows {
+        for j in 0..cols {
+            let sum = (0..inner).map(|k| a[(i, k)] * b[(k, j)]).sum::<f64>();
+            assert_equal_to_decimal!(sum, if i == j { 1.0 } else { 0.0 }, 13);
+        }
+    }
+}
+
+/// Check the full and thin singular value decompositions of a 4x3 matrix, along with the
+/// quantities derived from them.
+fn svd_tall_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(4, 3, A_ROW);
+
+    // Full and thin decompositions.
+    let svd = a.svd().unwrap();
+    assert_eq!(svd.u().shape(), (4, 4));
+    assert_eq!(svd.v_t().shape(), (3, 3));
+    assert_svd(&a, &svd);
+    let svd_thin = a.svd_thin().unwrap();
+    assert_eq!(svd_thin.u().shape(), (4, 3));
+    assert_eq!(svd_thin.v_t().shape(), (3, 3));
+    assert_svd(&a, &svd_thin);
+
+    // Singular values only.
+    let sigma = a.singular_values().unwrap();
+    assert_arrays_equal_to_decimal!(sigma.as_slice(), svd.sigma().as_slice(), 13);
+
+    // Derived quantities.
+    assert_eq!(a.rank().unwrap(), 3);
+    assert_eq!(svd.rank(), 3);
+    assert_equal_to_decimal!(a.norm_2().unwrap(), sigma[0], 14);
+    assert_equal_to_decimal!(svd.norm_2(), sigma[0], 14);
+    assert_equal_to_decimal!(a.cond().unwrap(), sigma[0] / sigma[2], 12);
+    assert_equal_to_decimal!(svd.cond(), sigma[0] / sigma[2], 12);
+    assert_identity_product(&a.pinv().unwrap(), &a);
+    assert_identity_product(&svd.pinv::<M::Transpose>(), &a);
+}
+
+/// Check the singular values of a matrix with known singular values.
+fn svd_diag_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(3, 2, DIAG_ROW);
+    assert_arrays_equal_to_decimal!(a.singular_values().unwrap().as_slice(), DIAG_SIGMA, 14);
+    assert_arrays_equal_to_decimal!(a.svd().unwrap().sigma().as_slice(), DIAG_SIGMA, 14);
+    assert_svd(&a, &a.svd().unwrap());
+}
+
+/// Check the rank and pseudo-inverse of a rank-deficient 3x2 matrix.
+fn svd_rank_deficient_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(3, 2, RANK_DEFICIENT_ROW);
+    assert_eq!(a.rank().unwrap(), 1);
+    assert_svd(&a, &a.svd().unwrap());
+    assert_svd(&a, &a.svd_thin().unwrap());
+    assert_arrays_equal_to_decimal!(
+        a.pinv().unwrap().as_row_slice(),
+        RANK_DEFICIENT_PINV_ROW,
+        14
+    );
+}
+
+/// Check the rank, condition number, and pseudo-inverse of an (exactly) singular 2x2 matrix.
+fn svd_singular_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(2, 2, SINGULAR_ROW);
+    assert_eq!(a.rank().unwrap(), 1);
+    assert_eq!(a.cond().unwrap(), f64::INFINITY);
+    assert_eq!(a.svd().unwrap().cond(), f64::INFINITY);
+    assert_arrays_equal_to_decimal!(a.pinv().unwrap().as_row_slice(), SINGULAR_ROW, 14);
+}
+
+/// Check the full singular value decomposition and pseudo-inverse of a 2x3 matrix.
+fn svd_wide_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(2, 3, WIDE_ROW);
+    let svd = a.svd().unwrap();
+    assert_eq!(svd.u().shape(), (2, 2));
+    assert_eq!(svd.v_t().shape(), (3, 3));
+    assert_svd(&a, &svd);
+    assert_eq!(a.rank().unwrap(), 2);
+    assert_identity_product(&a, &a.pinv().unwrap());
+    assert_identity_product(&a, &svd.pinv::<Mat<f64>>());
+    assert_eq!(
+        a.svd_thin().unwrap_err(),
+        LinalgError::ShapeMismatch {
+            expected: (3, 3),
+            actual: (2, 3)
+        }
+    );
+}
+
+/// Check that the singular value decomposition of a matrix containing a NaN fails.
+fn svd_non_finite_helper<M: Matrix<f64>>() {
+    let mut a = M::from_row_slice(2, 2, SINGULAR_ROW);
+    a[(1, 0)] = f64::NAN;
+    assert_eq!(a.svd().unwrap_err(), LinalgError::NonFinite);
+    assert_eq!(a.svd_thin().unwrap_err(), LinalgError::NonFinite);
+    assert_eq!(a.singular_values().unwrap_err(), LinalgError::NonFinite);
+    assert_eq!(a.rank(), Err(LinalgError::NonFinite));
+    assert_eq!(a.norm_2(), Err(LinalgError::NonFinite));
+    assert_eq!(a.cond(), Err(LinalgError::NonFinite));
+    assert_eq!(a.pinv().unwrap_err(), LinalgError::NonFinite);
+}
+
+/// Check the singular value decompositions of empty matrices.
+fn svd_empty_helper<M: Matrix<f64>>() {
+    for (rows, cols) in [(0, 0), (0, 3), (3, 0)] {
+        let a = M::new_with_shape(rows, cols);
+        let svd = a.svd().unwrap();
+        assert_eq!(svd.u().shape(), (rows, rows));
+        assert_eq!(svd.v_t().shape(), (cols, cols));
+        assert!(svd.sigma().is_empty());
+        assert!(a.singular_values().unwrap().is_empty());
+        assert_eq!(a.rank(), Ok(0));
+        assert_eq!(a.norm_2(), Ok(0.0));
+        assert_eq!(a.cond(), Ok(0.0));
+        assert_eq!(a.pinv().unwrap().shape(), (cols, rows));
+        if rows >= cols {
+            assert!(a.svd_thin().unwrap().sigma().is_empty());
+        } else {
+            assert_eq!(
+                a.svd_thin().unwrap_err(),
+                LinalgError::ShapeMismatch {
+                    expected: (cols, cols),
+                    actual: (rows, cols)
+                }
+            );
+        }
+    }
+}
+
+#[test]
+fn test_mat() {
+    svd_tall_helper::<Mat<f64>>();
+    svd_diag_helper::<Mat<f64>>();
+    svd_rank_deficient_helper::<Mat<f64>>();
+    svd_singular_helper::<Mat<f64>>();
+    svd_wide_helper::<Mat<f64>>();
+    svd_non_finite_helper::<Mat<f64>>();
+    svd_empty_helper::<Mat<f64>>();
+}
+
+#[test]
+fn test_mat_large() {
+    // Matrix with rank 3 (the outer product of two rank-3 factors) and repeated columns.
+    let (m, n) = (40, 25);
+    let mut a = Mat::<f64>::new_with_shape(m, n);
+    for i in 0..m {
+        for j in 0..n {
+            a[(i, j)] = (0..3)
+                .map(|k| ((i * (k + 2) + 1) % 7) as f64 * ((j * (k + 3) + 2) % 5) as f64)
+                .sum::<f64>();
+        }
+    }
+    assert_svd(&a, &a.svd().unwrap());
+    assert_svd(&a, &a.svd_thin().unwrap());
+    assert_eq!(a.rank().unwrap(), 3);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let a = DMatrix::from_row_slice(4, 3, A_ROW);
+    let svd = Matrix::svd(&a).unwrap();
+    assert_eq!(Matrix::shape(svd.u()), (4, 4));
+    assert_eq!(Matrix::shape(svd.v_t()), (3, 3));
+    assert_svd(&a, &svd);
+    let svd_thin = Matrix::svd_thin(&a).unwrap();
+    assert_eq!(Matrix::shape(svd_thin.u()), (4, 3));
+    assert_eq!(Matrix::shape(svd_thin.v_t()), (3, 3));
+    assert_svd(&a, &svd_thin);
+    let sigma = Matrix::singular_values(&a).unwrap();
+    assert_arrays_equal_to_decimal!(sigma.as_slice(), svd.sigma().as_slice(), 13);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_diag() {
+    let a = DMatrix::from_row_slice(3, 2, DIAG_ROW);
+    let sigma = Matrix::singular_values(&a).unwrap();
+    assert_arrays_equal_to_decimal!(sigma.as_slice(), DIAG_SIGMA, 14);
+    let svd = Matrix::svd(&a).unwrap();
+    assert_arrays_equal_to_decimal!(svd.sigma().as_slice(), DIAG_SIGMA, 14);
+    assert_svd(&a, &svd);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_rank_deficient() {
+    let a = DMatrix::from_row_slice(3, 2, RANK_DEFICIENT_ROW);
+    let svd = Matrix::svd(&a).unwrap();
+    assert_svd(&a, &svd);
+    assert_eq!(svd.rank(), 1);
+    assert_svd(&a, &Matrix::svd_thin(&a).unwrap());
+    assert_eq!(Matrix::rank(&a).unwrap(), 1);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_wide() {
+    let a = DMatrix::from_row_slice(2, 3, WIDE_ROW);
+    let svd = Matrix::svd(&a).unwrap();
+    assert_eq!(Matrix::shape(svd.u()), (2, 2));
+    assert_eq!(Matrix::shape(svd.v_t()), (3, 3));
+    assert_svd(&a, &svd);
+    assert_eq!(
+        Matrix::svd_thin(&a).unwrap_err(),
+        LinalgError::ShapeMismatch {
+            expected: (3, 3),
+            actual: (2, 3)
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_non_finite() {
+    let mut a = DMatrix::from_row_slice(2, 2, SINGULAR_ROW);
+    a[(1, 0)] = f64::NAN;
+    assert_eq!(Matrix::svd(&a).unwrap_err(), LinalgError::NonFinite);
+    assert_eq!(Matrix::svd_thin(&a).unwrap_err(), LinalgError::NonFinite);
+    assert_eq!(
+        Matrix::singular_values(&a).unwrap_err(),
+        LinalgError::NonFinite
+    );
+    a[(1, 0)] = f64::INFINITY;
+    assert_eq!(Matrix::svd(&a).unwrap_err(), LinalgError::NonFinite);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    for (rows, cols) in [(0, 0), (0, 3), (3, 0)] {
+        let a = DMatrix::<f64>::zeros(rows, cols);
+        let svd = Matrix::svd(&a).unwrap();
+        assert_eq!(Matrix::shape(svd.u()), (rows, rows));
+        assert_eq!(Matrix::shape(svd.v_t()), (cols, cols));
+        assert!(svd.sigma().is_empty());
+        assert_eq!(Vector::len(&Matrix::singular_values(&a).unwrap()), 0);
+        if rows >= cols {
+            assert!(Matrix::svd_thin(&a).unwrap().sigma().is_empty());
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    let a = FMat::from_row_slice(4, 3, A_ROW);
+    let svd = Matrix::svd(&a).unwrap();
+    assert_eq!(Matrix::shape(svd.u()), (4, 4));
+    assert_eq!(Matrix::shape(svd.v_t()), (3, 3));
+    assert_svd(&a, &svd);
+    let svd_thin = Matrix::svd_thin(&a).unwrap();
+    assert_eq!(Matrix::shape(svd_thin.u()), (4, 3));
+    assert_eq!(Matrix::shape(svd_thin.v_t()), (3, 3));
+    assert_svd(&a, &svd_thin);
+    let sigma = Matrix::singular_values(&a).unwrap();
+    assert_arrays_equal_to_decimal!(sigma.as_slice(), svd.sigma().as_slice(), 13);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_diag() {
+    let a = FMat::from_row_slice(3, 2, DIAG_ROW);
+    let sigma = Matrix::singular_values(&a).unwrap();
+    assert_arrays_equal_to_decimal!(sigma.as_slice(), DIAG_SIGMA, 14);
+    let svd = Matrix::svd(&a).unwrap();
+    assert_arrays_equal_to_decimal!(svd.sigma().as_slice(), DIAG_SIGMA, 14);
+    assert_svd(&a, &svd);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_rank_deficient() {
+    let a = FMat::from_row_slice(3, 2, RANK_DEFICIENT_ROW);
+    let svd = Matrix::svd(&a).unwrap();
+    assert_svd(&a, &svd);
+    assert_eq!(svd.rank(), 1);
+    assert_svd(&a, &Matrix::svd_thin(&a).unwrap());
+    assert_eq!(Matrix::rank(&a).unwrap(), 1);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_wide() {
+    let a = FMat::from_row_slice(2, 3, WIDE_ROW);
+    let svd = Matrix::svd(&a).unwrap();
+    assert_eq!(Matrix::shape(svd.u()), (2, 2));
+    assert_eq!(Matrix::shape(svd.v_t()), (3, 3));
+    assert_svd(&a, &svd);
+    assert_eq!(
+        Matrix::svd_thin(&a).unwrap_err(),
+        LinalgError::ShapeMismatch {
+            expected: (3, 3),
+            actual: (2, 3)
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_non_finite() {
+    let mut a = FMat::from_row_slice(2, 2, SINGULAR_ROW);
+    a[(1, 0)] = f64::NAN;
+    assert_eq!(Matrix::svd(&a).unwrap_err(), LinalgError::NonFinite);
+    assert_eq!(Matrix::svd_thin(&a).unwrap_err(), LinalgError::NonFinite);
+    assert_eq!(
+        Matrix::singular_values(&a).unwrap_err(),
+        LinalgError::NonFinite
+    );
+    a[(1, 0)] = f64::INFINITY;
+    assert_eq!(Matrix::svd(&a).unwrap_err(), LinalgError::NonFinite);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    for (rows, cols) in [(0, 0), (0, 3), (3, 0)] {
+        let a = FMat::<f64>::zeros(rows, cols);
+        let svd = Matrix::svd(&a).unwrap();
+        assert_eq!(Matrix::shape(svd.u()), (rows, rows));
+        assert_eq!(Matrix::shape(svd.v_t()), (cols, cols));
+        assert!(svd.sigma().is_empty());
+        assert_eq!(Vector::len(&Matrix::singular_values(&a).unwrap()), 0);
+        if rows >= cols {
+            assert!(Matrix::svd_thin(&a).unwrap().sigma().is_empty());
+        }
+    }
+}
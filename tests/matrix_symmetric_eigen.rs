@@ -0,0 +1,249 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+use linalg_traits::{LinalgError, Mat, Matrix, Vector};
+#[cfg(feature = "nalgebra")]
+use nalgebra::DMatrix;
+use numtest::*;
+use std::f64::consts::SQRT_2;
+
+// Test conditions (the upper triangular part is never read).
+static A_ROW: &[f64; 9] = &[2.0, 99.0, 99.0, 1.0, 2.0, 99.0, 0.0, 1.0, 2.0];
+static A_SYMMETRIC_ROW: &[f64; 9] = &[2.0, 1.0, 0.0, 1.0, 2.0, 1.0, 0.0, 1.0, 2.0];
+
+// Expected results.
+static EIGENVALUES: &[f64; 3] = &[2.0 - SQRT_2, 2.0, 2.0 + SQRT_2];
+
+/// Check that the eigenvalues of the 3x3 symmetric matrix `a` are [`EIGENVALUES`] and that the
+/// columns of `eigenvectors` are its orthonormal eigenvectors.
+fn assert_eigenvectors<M: Matrix<f64>>(a: &M, eigenvalues: &M::VectorM, eigenvectors: &M) {
+    assert_arrays_equal_to_decimal!(eigenvalues.as_slice(), EIGENVALUES, 14);
+    for i in 0..3 {
+        for j in 0..3 {
+            // Orthonormal eigenvectors.
+            let dot = (0..3)
+                .map(|k| eigenvectors[(k, i)] * eigenvectors[(k, j)])
+                .sum::<f64>();
+            assert_equal_to_decimal!(dot, if i == j { 1.0 } else { 0.0 }, 14);
+
+            // A * v = λ * v.
+            let a_v = (0..3)
+                .map(|k| a[(j, k)] * eigenvectors[(k, i)])
+                .sum::<f64>();
+            assert_equal_to_decimal!(a_v, eigenvalues[i] * eigenvectors[(j, i)], 14);
+        }
+    }
+}
+
+/// Check the eigendecomposition of a 3x3 symmetric matrix in all of its modes.
+fn symmetric_eigen_test_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(3, 3, A_ROW);
+    let a_symmetric = M::from_row_slice(3, 3, A_SYMMETRIC_ROW);
+
+    // Full eigendecomposition.
+    let eigen = a.symmetric_eigen().unwrap();
+    assert_eigenvectors(&a_symmetric, eigen.eigenvalues(), eigen.eigenvectors());
+
+    // Eigenvalues only.
+    assert_arrays_equal_to_decimal!(
+        a.symmetric_eigenvalues().unwrap().as_slice(),
+        EIGENVALUES,
+        14
+    );
+
+    // Largest and smallest eigenpairs.
+    let largest = a.symmetric_eigen_largest(2).unwrap();
+    assert_eq!(largest.len(), 2);
+    assert_equal_to_decimal!(largest[0].0, EIGENVALUES[2], 14);
+    assert_equal_to_decimal!(largest[1].0, EIGENVALUES[1], 14);
+    let smallest = a.symmetric_eigen_smallest(1).unwrap();
+    assert_eq!(smallest.len(), 1);
+    assert_equal_to_decimal!(smallest[0].0, EIGENVALUES[0], 14);
+    for (eigenvalue, eigenvector) in largest.iter().chain(smallest.iter()) {
+        let a_v = a_symmetric.vector_mul(eigenvector);
+        assert_arrays_equal_to_decimal!(
+            a_v.as_slice(),
+            eigenvector.mul(*eigenvalue).as_slice(),
+            14
+        );
+    }
+    assert!(a.symmetric_eigen_largest(0).unwrap().is_empty());
+
+    // Non-finite elements are only detected in the lower triangular part.
+    let mut a_nan = a.clone();
+    a_nan[(0, 2)] = f64::NAN;
+    assert!(a_nan.symmetric_eigen().is_ok());
+    a_nan[(2, 0)] = f64::NAN;
+    assert_eq!(a_nan.symmetric_eigen().unwrap_err(), LinalgError::NonFinite);
+    assert_eq!(
+        a_nan.symmetric_eigenvalues().unwrap_err(),
+        LinalgError::NonFinite
+    );
+    assert_eq!(
+        a_nan.symmetric_eigen_largest(1).unwrap_err(),
+        LinalgError::NonFinite
+    );
+}
+
+/// Check the eigendecomposition of a 0x0 matrix.
+fn symmetric_eigen_empty_helper<M: Matrix<f64>>() {
+    let a = M::new_with_shape(0, 0);
+    let eigen = a.symmetric_eigen().unwrap();
+    assert!(eigen.eigenvalues().is_empty());
+    assert_eq!(eigen.eigenvectors().shape(), (0, 0));
+    assert!(a.symmetric_eigenvalues().unwrap().is_empty());
+    assert!(a.symmetric_eigen_largest(0).unwrap().is_empty());
+}
+
+#[test]
+fn test_mat() {
+    symmetric_eigen_test_helper::<Mat<f64>>();
+    symmetric_eigen_empty_helper::<Mat<f64>>();
+}
+
+#[test]
+fn test_mat_large() {
+    // Symmetric matrix whose eigenvalues are known (tridiagonal Toeplitz matrix).
+    let n = 30;
+    let mut a = Mat::<f64>::new_with_shape(n, n);
+    for i in 0..n {
+        a[(i, i)] = 2.0;
+        if i > 0 {
+            a[(i, i - 1)] = -1.0;
+            a[(i - 1, i)] = -1.0;
+        }
+    }
+    let eigenvalues = a.symmetric_eigenvalues().unwrap();
+    for (k, eigenvalue) in eigenvalues.iter().enumerate() {
+        let expected = 2.0 - 2.0 * ((k + 1) as f64 * std::f64::consts::PI / (n + 1) as f64).cos();
+        assert_equal_to_decimal!(*eigenvalue, expected, 13);
+    }
+}
+
+#[test]
+fn test_mat_not_square() {
+    let a = Mat::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(
+        a.symmetric_eigen(),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 2),
+            actual: (2, 3)
+        })
+    );
+}
+
+#[test]
+fn test_mat_too_many_eigenpairs() {
+    let a = Mat::from_row_slice(3, 3, A_ROW);
+    let err = LinalgError::IndexOutOfBounds { index: 3, len: 3 };
+    assert_eq!(a.symmetric_eigen_largest(4), Err(err));
+    assert_eq!(a.symmetric_eigen_smallest(4), Err(err));
+    assert_eq!(a.symmetric_eigen_largest(3).unwrap().len(), 3);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    // Only the lower triangular part is passed to the native eigensolver.
+    let a = DMatrix::from_row_slice(3, 3, A_ROW);
+    let a_symmetric = DMatrix::from_row_slice(3, 3, A_SYMMETRIC_ROW);
+    let eigen = Matrix::symmetric_eigen(&a).unwrap();
+    assert_eigenvectors(&a_symmetric, eigen.eigenvalues(), eigen.eigenvectors());
+    assert_arrays_equal_to_decimal!(
+        Matrix::symmetric_eigenvalues(&a).unwrap().as_slice(),
+        EIGENVALUES,
+        14
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_non_finite() {
+    let mut a = DMatrix::from_row_slice(3, 3, A_ROW);
+    a[(0, 2)] = f64::NAN;
+    assert!(Matrix::symmetric_eigen(&a).is_ok());
+    a[(2, 0)] = f64::INFINITY;
+    assert_eq!(
+        Matrix::symmetric_eigen(&a).unwrap_err(),
+        LinalgError::NonFinite
+    );
+    assert_eq!(
+        Matrix::symmetric_eigenvalues(&a).unwrap_err(),
+        LinalgError::NonFinite
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    let a = DMatrix::<f64>::zeros(0, 0);
+    let eigen = Matrix::symmetric_eigen(&a).unwrap();
+    assert_eq!(Vector::len(eigen.eigenvalues()), 0);
+    assert_eq!(Matrix::shape(eigen.eigenvectors()), (0, 0));
+    assert_eq!(Vector::len(&Matrix::symmetric_eigenvalues(&a).unwrap()), 0);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_not_square() {
+    let a = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let err = LinalgError::ShapeMismatch {
+        expected: (2, 2),
+        actual: (2, 3),
+    };
+    assert_eq!(Matrix::symmetric_eigen(&a).unwrap_err(), err);
+    assert_eq!(Matrix::symmetric_eigenvalues(&a).unwrap_err(), err);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    // Only the lower triangular part is passed to the native eigensolver.
+    let a = FMat::from_row_slice(3, 3, A_ROW);
+    let a_symmetric = FMat::from_row_slice(3, 3, A_SYMMETRIC_ROW);
+    let eigen = Matrix::symmetric_eigen(&a).unwrap();
+    assert_eigenvectors(&a_symmetric, eigen.eigenvalues(), eigen.eigenvectors());
+    assert_arrays_equal_to_decimal!(
+        Matrix::symmetric_eigenvalues(&a).unwrap().as_slice(),
+        EIGENVALUES,
+        14
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_non_finite() {
+    let mut a = FMat::from_row_slice(3, 3, A_ROW);
+    a[(0, 2)] = f64::NAN;
+    assert!(Matrix::symmetric_eigen(&a).is_ok());
+    a[(2, 0)] = f64::INFINITY;
+    assert_eq!(
+        Matrix::symmetric_eigen(&a).unwrap_err(),
+        LinalgError::NonFinite
+    );
+    assert_eq!(
+        Matrix::symmetric_eigenvalues(&a).unwrap_err(),
+        LinalgError::NonFinite
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    let a = FMat::<f64>::zeros(0, 0);
+    let eigen = Matrix::symmetric_eigen(&a).unwrap();
+    assert_eq!(Vector::len(eigen.eigenvalues()), 0);
+    assert_eq!(Matrix::shape(eigen.eigenvectors()), (0, 0));
+    assert_eq!(Vector::len(&Matrix::symmetric_eigenvalues(&a).unwrap()), 0);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_not_square() {
+    let a = FMat::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let err = LinalgError::ShapeMismatch {
+        expected: (2, 2),
+        actual: (2, 3),
+    };
+    assert_eq!(Matrix::symmetric_eigen(&a).unwrap_err(), err);
+    assert_eq!(Matrix::symmetric_eigenvalues(&a).unwrap_err(), err);
+}
@@ -0,0 +1,210 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+use linalg_traits::{Mat, Matrix, SMat};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, SMatrix};
+#[cfg(feature = "ndarray")]
+use ndarray::Array2;
+use numtest::*;
+
+// Test conditions.
+static A_ROW: &[f64; 6] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+static B_ROW: &[f64; 9] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+
+// Expected results.
+static A_T_ROW: &[f64; 6] = &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0];
+static B_T_ROW: &[f64; 9] = &[1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0];
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static A_T_COL: &[f64; 6] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+static B_T_COL: &[f64; 9] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+
+/// Check the transpose of a 2x3 matrix and the in-place transpose of a 3x3 matrix.
+fn transpose_test_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(2, 3, A_ROW);
+    let a_t = a.transpose();
+    assert_eq!(a_t.shape(), (3, 2));
+    assert_arrays_equal!(a_t.as_row_slice(), A_T_ROW);
+    assert_eq!(a_t.transpose(), a);
+
+    let mut b = M::from_row_slice(3, 3, B_ROW);
+    b.transpose_in_place();
+    assert_arrays_equal!(b.as_row_slice(), B_T_ROW);
+}
+
+#[test]
+fn test_mat() {
+    transpose_test_helper::<Mat<f64>>();
+}
+
+#[test]
+fn test_mat_blocked() {
+    // Dimensions spanning multiple (partial) blocks.
+    let (m, n) = (70, 130);
+    let a_row: Vec<f64> = (0..m * n).map(|i| i as f64).collect();
+    let a = Mat::from_row_slice(m, n, &a_row);
+    let a_t = a.transpose();
+    for i in 0..m {
+        for j in 0..n {
+            assert_eq!(a_t[(j, i)], a[(i, j)]);
+        }
+    }
+
+    // In-place transpose of a square matrix.
+    let mut b = Mat::from_row_slice(n, n, &(0..n * n).map(|i| i as f64).collect::<Vec<f64>>());
+    let b_copy = b.clone();
+    b.transpose_in_place();
+    for i in 0..n {
+        for j in 0..n {
+            assert_eq!(b[(j, i)], b_copy[(i, j)]);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Matrix is not square (2x3).")]
+fn test_mat_panic() {
+    let mut a = Mat::from_row_slice(2, 3, A_ROW);
+    a.transpose_in_place();
+}
+
+#[test]
+fn test_smat() {
+    let a = <SMat<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, A_ROW);
+    let a_t: SMat<f64, 3, 2> = Matrix::transpose(&a);
+    assert_arrays_equal!(a_t.as_row_slice(), A_T_ROW);
+    let mut b = <SMat<f64, 3, 3> as Matrix<f64>>::from_row_slice(3, 3, B_ROW);
+    b.transpose_in_place();
+    assert_arrays_equal!(b.as_row_slice(), B_T_ROW);
+}
+
+#[test]
+#[should_panic(expected = "Matrix is not square (2x3).")]
+fn test_smat_panic() {
+    let mut a = <SMat<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, A_ROW);
+    a.transpose_in_place();
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let a = DMatrix::from_row_slice(2, 3, A_ROW);
+    let a_t = Matrix::transpose(&a);
+    assert_eq!(a_t.shape(), (3, 2));
+    assert_arrays_equal!(a_t.as_slice(), A_T_COL);
+    let mut b = DMatrix::from_row_slice(3, 3, B_ROW);
+    Matrix::transpose_in_place(&mut b);
+    assert_arrays_equal!(b.as_slice(), B_T_COL);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    assert_eq!(
+        Matrix::transpose(&DMatrix::<f64>::zeros(0, 3)).shape(),
+        (3, 0)
+    );
+    let mut b = DMatrix::<f64>::zeros(0, 0);
+    Matrix::transpose_in_place(&mut b);
+    assert_eq!(b.shape(), (0, 0));
+}
+
+#[test]
+#[should_panic(expected = "Matrix is not square (2x3).")]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_panic() {
+    let mut a = DMatrix::from_row_slice(2, 3, A_ROW);
+    Matrix::transpose_in_place(&mut a);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    let a = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, A_ROW);
+    let a_t: SMatrix<f64, 3, 2> = Matrix::transpose(&a);
+    assert_arrays_equal!(a_t.as_row_slice(), A_T_ROW);
+    let mut b = <SMatrix<f64, 3, 3> as Matrix<f64>>::from_row_slice(3, 3, B_ROW);
+    b.transpose_in_place();
+    assert_arrays_equal!(b.as_row_slice(), B_T_ROW);
+}
+
+#[test]
+#[should_panic(expected = "Matrix is not square (2x3).")]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix_panic() {
+    let mut a = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, A_ROW);
+    a.transpose_in_place();
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2() {
+    let a = Array2::from_row_slice(2, 3, A_ROW);
+    let a_t = Matrix::transpose(&a);
+    assert_eq!(Matrix::shape(&a_t), (3, 2));
+    assert_arrays_equal!(Matrix::as_slice(&a_t), A_T_ROW);
+    let mut b = Array2::from_row_slice(3, 3, B_ROW);
+    Matrix::transpose_in_place(&mut b);
+    assert_arrays_equal!(Matrix::as_slice(&b), B_T_ROW);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_column_major() {
+    // The transpose is copied into standard layout rather than just having its strides reversed.
+    let a = Array2::from_row_slice(3, 2, A_T_ROW).reversed_axes();
+    let a_t = Matrix::transpose(&a);
+    assert!(a_t.is_standard_layout());
+    assert_arrays_equal!(Matrix::as_slice(&a_t), A_T_ROW);
+    let mut b = Array2::from_row_slice(3, 3, B_ROW).reversed_axes();
+    Matrix::transpose_in_place(&mut b);
+    assert_eq!(b, Array2::from_row_slice(3, 3, B_ROW));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_empty() {
+    let a_t = Matrix::transpose(&Array2::<f64>::zeros((0, 3)));
+    assert_eq!(Matrix::shape(&a_t), (3, 0));
+    let mut b = Array2::<f64>::zeros((0, 0));
+    Matrix::transpose_in_place(&mut b);
+    assert_eq!(Matrix::shape(&b), (0, 0));
+}
+
+#[test]
+#[should_panic(expected = "Matrix is not square (2x3).")]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_panic() {
+    let mut a = Array2::from_row_slice(2, 3, A_ROW);
+    Matrix::transpose_in_place(&mut a);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    let a = FMat::from_row_slice(2, 3, A_ROW);
+    let a_t = Matrix::transpose(&a);
+    assert_eq!(Matrix::shape(&a_t), (3, 2));
+    assert_arrays_equal!(a_t.as_slice(), A_T_COL);
+    let mut b = FMat::from_row_slice(3, 3, B_ROW);
+    Matrix::transpose_in_place(&mut b);
+    assert_arrays_equal!(b.as_slice(), B_T_COL);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    let a_t = Matrix::transpose(&FMat::<f64>::zeros(0, 3));
+    assert_eq!(Matrix::shape(&a_t), (3, 0));
+    let mut b = FMat::<f64>::zeros(0, 0);
+    Matrix::transpose_in_place(&mut b);
+    assert_eq!(Matrix::shape(&b), (0, 0));
+}
+
+#[test]
+#[should_panic(expected = "Matrix is not square (2x3).")]
+#[cfg(feature = "faer")]
+fn test_faer_mat_panic() {
+    let mut a = FMat::from_row_slice(2, 3, A_ROW);
+    Matrix::transpose_in_place(&mut a);
+}
@@ -0,0 +1,541 @@
+#[cfg(feature = "faer")]
+use faer::Mat as FMat;
+use linalg_traits::{LinalgError, Mat, Matrix, SMat, Vector};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, SMatrix};
+#[cfg(feature = "ndarray")]
+use ndarray::Array2;
+
+// Test conditions.
+static A: &[f64; 6] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+static B: &[f64; 6] = &[0.5, 1.5, -2.5, 3.5, 0.0, 1.0];
+
+/// Check the fallible operations on 2x3 matrices.
+fn try_test_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(2, 3, A);
+    let b = M::from_row_slice(2, 3, B);
+
+    // Constructors.
+    assert_eq!(M::try_new_with_shape(2, 3), Ok(M::new_with_shape(2, 3)));
+    assert_eq!(M::try_from_row_slice(2, 3, A), Ok(a.clone()));
+    assert_eq!(
+        M::try_from_col_slice(2, 3, &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]),
+        Ok(a.clone())
+    );
+    assert_eq!(
+        M::try_from_row_slice(2, 3, &A[..5]),
+        Err(LinalgError::LengthMismatch {
+            expected: 6,
+            actual: 5
+        })
+    );
+    assert_eq!(
+        M::try_from_col_slice(2, 3, &[0.0; 7]),
+        Err(LinalgError::LengthMismatch {
+            expected: 6,
+            actual: 7
+        })
+    );
+
+    // Operations.
+    assert_eq!(a.try_add(&b), Ok(a.add(&b)));
+    assert_eq!(a.try_sub(&b), Ok(a.sub(&b)));
+    let x = M::VectorN::from_slice(&[1.0, 0.0, -1.0]);
+    assert_eq!(
+        a.try_mul_vector(&x),
+        Ok(M::VectorM::from_slice(&[-2.0, -2.0]))
+    );
+
+    // Rows and columns.
+    assert_eq!(a.try_row(1), Ok(M::VectorN::from_slice(&[4.0, 5.0, 6.0])));
+    assert_eq!(a.try_column(2), Ok(M::VectorM::from_slice(&[3.0, 6.0])));
+    assert_eq!(
+        a.try_row(2),
+        Err(LinalgError::IndexOutOfBounds { index: 2, len: 2 })
+    );
+    assert_eq!(
+        a.try_column(3),
+        Err(LinalgError::IndexOutOfBounds { index: 3, len: 3 })
+    );
+
+    // In-place and preallocated operations.
+    let mut c = a.clone();
+    assert_eq!(c.try_add_assign(&b), Ok(()));
+    assert_eq!(c, a.add(&b));
+    assert_eq!(c.try_sub_assign(&b), Ok(()));
+    assert_eq!(c, a);
+    let mut y = M::VectorM::new_with_length(2);
+    assert_eq!(a.try_mul_vector_to(&x, &mut y), Ok(()));
+    assert_eq!(y, M::VectorM::from_slice(&[-2.0, -2.0]));
+    assert_eq!(
+        a.try_vector_mul(&M::VectorM::from_slice(&[1.0, 1.0])),
+        Ok(M::VectorN::from_slice(&[5.0, 7.0, 9.0]))
+    );
+    assert_eq!(a.try_hadamard(&b), Ok(a.hadamard(&b)));
+    assert_eq!(a.try_zip_map(&b, |x, y| x - y), Ok(a.sub(&b)));
+    assert_eq!(
+        a.try_hadamard_div(&a),
+        Ok(M::from_row_slice(2, 3, &[1.0; 6]))
+    );
+    let mut d = a.clone();
+    assert_eq!(d.try_zip_apply(&b, |x, y| *x -= y), Ok(()));
+    assert_eq!(d, a.sub(&b));
+    assert_eq!(a.try_add_to(&b, &mut d), Ok(()));
+    assert_eq!(d, a.add(&b));
+    assert_eq!(a.try_sub_to(&b, &mut d), Ok(()));
+    assert_eq!(d, a.sub(&b));
+    assert_eq!(a.try_mul_to(2.0, &mut d), Ok(()));
+    assert_eq!(d, a.mul(2.0));
+    assert_eq!(a.try_div_to(2.0, &mut d), Ok(()));
+    assert_eq!(d, a.div(2.0));
+    let mut z = M::VectorN::new_with_length(3);
+    assert_eq!(
+        a.try_vector_mul_to(&M::VectorM::from_slice(&[1.0, 1.0]), &mut z),
+        Ok(())
+    );
+    assert_eq!(z, M::VectorN::from_slice(&[5.0, 7.0, 9.0]));
+    assert_eq!(d.try_copy_from(&Mat::from_row_slice(2, 3, B)), Ok(()));
+    assert_eq!(d, b);
+    assert_eq!(
+        a.try_convert::<Mat<f64>>(),
+        Ok(Mat::from_row_slice(2, 3, A))
+    );
+
+    // Square-only operations.
+    let square = LinalgError::ShapeMismatch {
+        expected: (2, 2),
+        actual: (2, 3),
+    };
+    assert_eq!(a.try_diagonal(), Err(square));
+    assert_eq!(a.try_trace(), Err(square));
+    assert_eq!(a.try_lu().unwrap_err(), square);
+    assert_eq!(a.try_determinant(), Err(square));
+    assert_eq!(a.try_cond_1_estimate(), Err(square));
+
+    // Rows, columns, and blocks.
+    assert_eq!(
+        c.try_set_row(1, &M::VectorN::from_slice(&[7.0, 8.0, 9.0])),
+        Ok(())
+    );
+    assert_eq!(
+        c.try_set_column(0, &M::VectorM::from_slice(&[0.0, 0.0])),
+        Ok(())
+    );
+    assert_eq!(c, M::from_row_slice(2, 3, &[0.0, 2.0, 3.0, 0.0, 8.0, 9.0]));
+    assert_eq!(
+        c.try_set_row(2, &M::VectorN::from_slice(&[7.0, 8.0, 9.0])),
+        Err(LinalgError::IndexOutOfBounds { index: 2, len: 2 })
+    );
+    assert_eq!(
+        c.try_set_column(3, &M::VectorM::from_slice(&[0.0, 0.0])),
+        Err(LinalgError::IndexOutOfBounds { index: 3, len: 3 })
+    );
+    assert_eq!(a.try_submatrix(0, 1, 2, 2), Ok(a.submatrix(0, 1, 2, 2)));
+    assert_eq!(
+        a.try_submatrix(1, 1, 2, 2),
+        Err(LinalgError::IndexOutOfBounds { index: 2, len: 2 })
+    );
+    assert_eq!(
+        a.try_submatrix(0, 2, 1, 2),
+        Err(LinalgError::IndexOutOfBounds { index: 3, len: 3 })
+    );
+    let block = Mat::from_row_slice(2, 2, &[2.0, 3.0, 5.0, 6.0]);
+    assert_eq!(c.try_set_block(0, 1, &block), Ok(()));
+    assert_eq!(c, M::from_row_slice(2, 3, &[0.0, 2.0, 3.0, 0.0, 5.0, 6.0]));
+    assert_eq!(
+        c.try_set_block(1, 2, &block),
+        Err(LinalgError::IndexOutOfBounds { index: 2, len: 2 })
+    );
+
+    // Constructors from parts.
+    assert_eq!(M::try_from_fn(2, 3, |i, j| a[(i, j)]), Ok(a.clone()));
+    assert_eq!(M::try_from_rows(&[a.row(0), a.row(1)]), Ok(a.clone()));
+    assert_eq!(
+        M::try_from_columns(&[a.column(0), a.column(1), a.column(2)]),
+        Ok(a.clone())
+    );
+    let left = Mat::from_row_slice(2, 1, &[1.0, 4.0]);
+    let right = Mat::from_row_slice(2, 2, &[2.0, 3.0, 5.0, 6.0]);
+    assert_eq!(M::try_hstack(&[&left, &right]), Ok(a.clone()));
+    let top = Mat::from_row_slice(1, 3, &[1.0, 2.0, 3.0]);
+    let bottom = Mat::from_row_slice(1, 3, &[4.0, 5.0, 6.0]);
+    assert_eq!(M::try_vstack(&[&top, &bottom]), Ok(a.clone()));
+
+    // Finiteness.
+    assert_eq!(a.check_finite(), Ok(()));
+    let mut c = a.clone();
+    c[(1, 0)] = f64::INFINITY;
+    assert_eq!(c.check_finite(), Err(LinalgError::NonFinite));
+}
+
+/// Check that the fallible operations report shape mismatches for dynamically-sized matrices.
+fn try_shape_mismatch_test_helper<M: Matrix<f64>>() {
+    let a = M::from_row_slice(2, 3, A);
+    let b = M::from_row_slice(3, 2, B);
+    let error = LinalgError::ShapeMismatch {
+        expected: (2, 3),
+        actual: (3, 2),
+    };
+    assert_eq!(a.try_add(&b), Err(error));
+    assert_eq!(a.try_sub(&b), Err(error));
+    assert_eq!(
+        a.try_mul_vector(&M::VectorN::from_slice(&[1.0, 2.0])),
+        Err(LinalgError::LengthMismatch {
+            expected: 3,
+            actual: 2
+        })
+    );
+    assert_eq!(a.try_hadamard(&b), Err(error));
+    assert_eq!(a.try_hadamard_div(&b), Err(error));
+    assert_eq!(a.try_zip_map(&b, |x, y| x * y), Err(error));
+
+    // The in-place and preallocated operations leave their outputs unchanged on error.
+    let mut c = a.clone();
+    assert_eq!(c.try_add_assign(&b), Err(error));
+    assert_eq!(c.try_sub_assign(&b), Err(error));
+    assert_eq!(c.try_zip_apply(&b, |x, y| *x *= y), Err(error));
+    assert_eq!(c.try_copy_from(&b), Err(error));
+    assert_eq!(c, a);
+    let mut d = b.clone();
+    assert_eq!(a.try_add_to(&a, &mut d), Err(error));
+    assert_eq!(a.try_sub_to(&b, &mut d), Err(error));
+    assert_eq!(a.try_mul_to(2.0, &mut d), Err(error));
+    assert_eq!(a.try_div_to(2.0, &mut d), Err(error));
+    assert_eq!(d, b);
+    let mut z = M::VectorN::from_slice(&[1.0, 2.0]);
+    assert_eq!(
+        a.try_vector_mul_to(&M::VectorM::from_slice(&[1.0, 1.0]), &mut z),
+        Err(LinalgError::LengthMismatch {
+            expected: 3,
+            actual: 2
+        })
+    );
+    assert_eq!(z, M::VectorN::from_slice(&[1.0, 2.0]));
+
+    // General matrix multiplication, with and without transposes.
+    let mut e = M::new_with_shape(2, 2);
+    assert_eq!(e.try_gemm(1.0, &a, false, &b, false, 0.0), Ok(()));
+    assert_eq!(e, M::from_row_slice(2, 2, &[-4.5, 11.5, -10.5, 29.5]));
+    assert_eq!(e.try_gemm(1.0, &a, false, &a, true, 0.0), Ok(()));
+    assert_eq!(
+        e.try_gemm(1.0, &a, false, &a, false, 0.0),
+        Err(LinalgError::ShapeMismatch {
+            expected: (3, 3),
+            actual: (2, 3)
+        })
+    );
+    assert_eq!(
+        e.try_gemm(1.0, &a, true, &b, true, 0.0),
+        Err(LinalgError::ShapeMismatch {
+            expected: (3, 3),
+            actual: (2, 2)
+        })
+    );
+
+    // Square-only operations.
+    let square = M::from_row_slice(2, 2, &[4.0, 1.0, 2.0, 3.0]);
+    assert_eq!(square.try_diagonal(), Ok(square.diagonal()));
+    assert_eq!(square.try_trace(), Ok(7.0));
+    assert_eq!(square.try_determinant(), Ok(square.determinant()));
+    assert_eq!(square.try_cond_1_estimate(), Ok(square.cond_1_estimate()));
+    assert!(square.try_lu().is_ok());
+    let mut y = M::VectorM::from_slice(&[1.0, 2.0, 3.0]);
+    assert_eq!(
+        a.try_mul_vector_to(&M::VectorN::from_slice(&[1.0, 2.0, 3.0]), &mut y),
+        Err(LinalgError::LengthMismatch {
+            expected: 2,
+            actual: 3
+        })
+    );
+    assert_eq!(y, M::VectorM::from_slice(&[1.0, 2.0, 3.0]));
+    assert_eq!(
+        a.try_vector_mul(&M::VectorM::from_slice(&[1.0, 2.0, 3.0])),
+        Err(LinalgError::LengthMismatch {
+            expected: 2,
+            actual: 3
+        })
+    );
+
+    // Rows and columns of the wrong length.
+    assert_eq!(
+        c.try_set_row(0, &M::VectorN::from_slice(&[1.0, 2.0])),
+        Err(LinalgError::LengthMismatch {
+            expected: 3,
+            actual: 2
+        })
+    );
+    assert_eq!(
+        c.try_set_column(0, &M::VectorM::from_slice(&[1.0, 2.0, 3.0])),
+        Err(LinalgError::LengthMismatch {
+            expected: 2,
+            actual: 3
+        })
+    );
+    assert_eq!(c, a);
+
+    // Parts that do not fit together.
+    assert_eq!(
+        M::try_hstack(&[
+            &Mat::from_row_slice(2, 1, &[1.0, 2.0]),
+            &Mat::from_row_slice(3, 1, &[1.0, 2.0, 3.0])
+        ]),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 1),
+            actual: (3, 1)
+        })
+    );
+    assert_eq!(
+        M::try_vstack(&[
+            &Mat::from_row_slice(1, 3, &[1.0, 2.0, 3.0]),
+            &Mat::from_row_slice(1, 2, &[1.0, 2.0])
+        ]),
+        Err(LinalgError::ShapeMismatch {
+            expected: (1, 3),
+            actual: (1, 2)
+        })
+    );
+    assert_eq!(
+        M::try_from_rows(&[
+            M::VectorN::from_slice(&[1.0, 2.0, 3.0]),
+            M::VectorN::from_slice(&[1.0, 2.0])
+        ]),
+        Err(LinalgError::LengthMismatch {
+            expected: 3,
+            actual: 2
+        })
+    );
+    assert_eq!(
+        M::try_from_columns(&[
+            M::VectorM::from_slice(&[1.0, 2.0]),
+            M::VectorM::from_slice(&[1.0, 2.0, 3.0])
+        ]),
+        Err(LinalgError::LengthMismatch {
+            expected: 2,
+            actual: 3
+        })
+    );
+}
+
+#[test]
+fn test_mat() {
+    try_test_helper::<Mat<f64>>();
+    try_shape_mismatch_test_helper::<Mat<f64>>();
+    assert_eq!(Mat::<f64>::static_shape(), None);
+    assert_eq!(Mat::<f64>::try_identity(2), Ok(Mat::identity(2)));
+}
+
+#[test]
+fn test_mat_try_matmul() {
+    let a = Mat::from_row_slice(2, 3, A);
+    let b = Mat::from_row_slice(3, 2, B);
+    assert_eq!(a.try_matmul::<0>(&b), Ok(a.matmul::<0>(&b)));
+    assert_eq!(
+        a.try_matmul::<0>(&a),
+        Err(LinalgError::ShapeMismatch {
+            expected: (3, 3),
+            actual: (2, 3)
+        })
+    );
+    let mut c = Mat::new_with_shape(2, 2);
+    assert_eq!(a.try_matmul_to::<0>(&b, &mut c), Ok(()));
+    assert_eq!(c, a.matmul::<0>(&b));
+    let mut d = Mat::new_with_shape(3, 3);
+    assert_eq!(
+        a.try_matmul_to::<0>(&b, &mut d),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 2),
+            actual: (3, 3)
+        })
+    );
+    assert_eq!(
+        a.try_matmul_to::<0>(&a, &mut c),
+        Err(LinalgError::ShapeMismatch {
+            expected: (3, 3),
+            actual: (2, 3)
+        })
+    );
+    assert_eq!(c, a.matmul::<0>(&b));
+}
+
+#[test]
+fn test_smat() {
+    assert_eq!(SMat::<f64, 2, 3>::static_shape(), Some((2, 3)));
+    assert_eq!(
+        SMat::<f64, 2, 3>::try_new_with_shape(3, 2),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 3),
+            actual: (3, 2)
+        })
+    );
+    assert_eq!(
+        SMat::<f64, 2, 3>::try_from_row_slice(3, 2, A),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 3),
+            actual: (3, 2)
+        })
+    );
+    assert_eq!(
+        SMat::<f64, 2, 3>::try_identity(2),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 3),
+            actual: (2, 2)
+        })
+    );
+    assert_eq!(
+        SMat::<f64, 2, 3>::try_from_fn(3, 2, |i, j| (i + j) as f64),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 3),
+            actual: (3, 2)
+        })
+    );
+    assert_eq!(
+        SMat::<f64, 2, 3>::try_hstack(&[&Mat::new_with_shape(2, 2)]),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 3),
+            actual: (2, 2)
+        })
+    );
+    assert_eq!(
+        SMat::<f64, 2, 3>::try_block_diag(&[&Mat::new_with_shape(1, 1)]),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 3),
+            actual: (1, 1)
+        })
+    );
+    assert_eq!(
+        SMat::<f64, 2, 3>::try_from_diagonal(&[1.0, 2.0]),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 3),
+            actual: (2, 2)
+        })
+    );
+    assert_eq!(
+        Mat::from_row_slice(3, 2, A).try_convert::<SMat<f64, 2, 3>>(),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 3),
+            actual: (3, 2)
+        })
+    );
+    assert_eq!(
+        SMat::<f64, 2, 2>::try_from_diagonal(&[1.0, 2.0]),
+        Ok(SMat::from_row_slice(2, 2, &[1.0, 0.0, 0.0, 2.0]))
+    );
+    assert_eq!(
+        SMat::<f64, 2, 3>::try_block_diag(&[
+            &Mat::from_row_slice(1, 2, &[1.0, 2.0]),
+            &Mat::from_row_slice(1, 1, &[3.0])
+        ]),
+        Ok(SMat::from_row_slice(2, 3, &[1.0, 2.0, 0.0, 0.0, 0.0, 3.0]))
+    );
+}
+
+#[test]
+fn test_error_display() {
+    let error = Mat::<f64>::from_row_slice(2, 3, A)
+        .try_add(&Mat::from_row_slice(3, 2, B))
+        .unwrap_err();
+    assert_eq!(error.to_string(), "Shape mismatch (expected 2x3, got 3x2).");
+    assert_eq!(
+        LinalgError::LengthMismatch {
+            expected: 3,
+            actual: 2
+        }
+        .to_string(),
+        "Length mismatch (expected 3, got 2)."
+    );
+    assert_eq!(
+        LinalgError::IndexOutOfBounds { index: 4, len: 3 }.to_string(),
+        "Index (4) is out of bounds for length 3."
+    );
+    assert_eq!(LinalgError::NonFinite.to_string(), "Value is not finite.");
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    assert_eq!(DMatrix::<f64>::static_shape(), None);
+    let a = DMatrix::<f64>::zeros(2, 0);
+    assert_eq!(DMatrix::try_new_with_shape(2, 0), Ok(a.clone()));
+    assert_eq!(a.try_add(&a), Ok(a.clone()));
+    let c = DMatrix::<f64>::zeros(0, 2);
+    assert_eq!(
+        a.try_sub(&c),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 0),
+            actual: (0, 2)
+        })
+    );
+
+    // An empty inner dimension gives a matrix of zeros; an empty outer one gives an empty matrix.
+    let b = DMatrix::<f64>::zeros(0, 3);
+    assert_eq!(a.try_matmul::<0>(&b), Ok(DMatrix::<f64>::zeros(2, 3)));
+    assert_eq!(c.try_matmul::<0>(&a), Ok(DMatrix::<f64>::zeros(0, 0)));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    assert_eq!(SMatrix::<f64, 2, 3>::static_shape(), Some((2, 3)));
+    assert_eq!(
+        <SMatrix<f64, 2, 3> as Matrix<f64>>::try_from_row_slice(2, 3, A),
+        Ok(SMatrix::from_row_slice(A))
+    );
+    assert_eq!(
+        SMatrix::<f64, 2, 3>::try_new_with_shape(3, 2),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 3),
+            actual: (3, 2)
+        })
+    );
+    assert_eq!(
+        SMatrix::<f64, 2, 3>::try_from_row_slice(3, 2, A),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 3),
+            actual: (3, 2)
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2() {
+    assert_eq!(Array2::<f64>::static_shape(), None);
+    let a = Array2::<f64>::zeros((2, 0));
+    assert_eq!(Array2::try_new_with_shape(2, 0), Ok(a.clone()));
+    assert_eq!(a.try_add(&a), Ok(a.clone()));
+    let c = Array2::<f64>::zeros((0, 2));
+    assert_eq!(
+        a.try_sub(&c),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 0),
+            actual: (0, 2)
+        })
+    );
+
+    // An empty inner dimension gives a matrix of zeros; an empty outer one gives an empty matrix.
+    let b = Array2::<f64>::zeros((0, 3));
+    assert_eq!(a.try_matmul::<0>(&b), Ok(Array2::<f64>::zeros((2, 3))));
+    assert_eq!(c.try_matmul::<0>(&a), Ok(Array2::<f64>::zeros((0, 0))));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    assert_eq!(FMat::<f64>::static_shape(), None);
+    let a = FMat::<f64>::zeros(2, 0);
+    assert_eq!(FMat::try_new_with_shape(2, 0), Ok(a.clone()));
+    assert_eq!(a.try_add(&a), Ok(a.clone()));
+    let c = FMat::<f64>::zeros(0, 2);
+    assert_eq!(
+        a.try_sub(&c),
+        Err(LinalgError::ShapeMismatch {
+            expected: (2, 0),
+            actual: (0, 2)
+        })
+    );
+
+    // An empty inner dimension gives a matrix of zeros; an empty outer one gives an empty matrix.
+    let b = FMat::<f64>::zeros(0, 3);
+    assert_eq!(a.try_matmul::<0>(&b), Ok(FMat::<f64>::zeros(2, 3)));
+    assert_eq!(c.try_matmul::<0>(&a), Ok(FMat::<f64>::zeros(0, 0)));
+}
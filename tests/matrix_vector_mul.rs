@@ -0,0 +1,150 @@
+#[cfg(feature = "faer")]
+use faer::{Col, Mat as FMat};
+use linalg_traits::{Mat, Matrix, SMat, Vector};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, DVector, SMatrix, SVector};
+#[cfg(feature = "ndarray")]
+use ndarray::{Array1, Array2};
+use numtest::*;
+
+// Test conditions.
+static A_ROW: &[f64; 6] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+static X: &[f64; 2] = &[1.0, 2.0];
+
+// Expected result.
+static Y: &[f64; 3] = &[9.0, 12.0, 15.0];
+
+// Vector with the wrong length.
+static W: &[f64; 3] = &[1.0, 2.0, 3.0];
+
+#[test]
+fn test_mat() {
+    let a = Mat::from_row_slice(2, 3, A_ROW);
+    let x = Vec::from_slice(X);
+    let mut y = Vec::new_with_length(3);
+    a.vector_mul_to(&x, &mut y);
+    assert_arrays_equal!(a.vector_mul(&x), Y);
+    assert_arrays_equal!(y, Y);
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion `left == right` failed: Length of the vector (3) does not match the number of rows of the matrix (2)."
+)]
+fn test_mat_panic() {
+    let a = Mat::from_row_slice(2, 3, A_ROW);
+    let w = Vec::from_slice(W);
+    let _ = a.vector_mul(&w);
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion `left == right` failed: Length of the output vector (2) does not match the number of columns of the matrix (3)."
+)]
+fn test_mat_out_panic() {
+    let a = Mat::from_row_slice(2, 3, A_ROW);
+    let x = Vec::from_slice(X);
+    let mut y = Vec::new_with_length(2);
+    a.vector_mul_to(&x, &mut y);
+}
+
+#[test]
+fn test_mat_empty() {
+    // An empty inner dimension gives a vector of zeros, overwriting the output vector.
+    let a = Mat::<f64>::new_with_shape(0, 2);
+    let x = Vec::<f64>::new_with_length(0);
+    let mut y = Vec::from_slice(&[f64::NAN; 2]);
+    a.vector_mul_to(&x, &mut y);
+    assert_arrays_equal!(a.vector_mul(&x), [0.0; 2]);
+    assert_arrays_equal!(y, [0.0; 2]);
+}
+
+#[test]
+fn test_smat() {
+    let a = <SMat<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, A_ROW);
+    let x = <[f64; 2]>::from_slice(X);
+    let mut y = <[f64; 3]>::new_with_length(3);
+    a.vector_mul_to(&x, &mut y);
+    assert_arrays_equal!(a.vector_mul(&x), Y);
+    assert_arrays_equal!(y, Y);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix() {
+    let a = DMatrix::from_row_slice(2, 3, A_ROW);
+    let x = DVector::from_slice(X);
+    let mut y = DVector::new_with_length(3);
+    a.vector_mul_to(&x, &mut y);
+    assert_arrays_equal!(a.vector_mul(&x), Y);
+    assert_arrays_equal!(y, Y);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dmatrix_empty() {
+    // An empty inner dimension gives a vector of zeros, overwriting the output vector.
+    let a = DMatrix::<f64>::new_with_shape(0, 2);
+    let x = DVector::<f64>::new_with_length(0);
+    let mut y = DVector::from_slice(&[f64::NAN; 2]);
+    a.vector_mul_to(&x, &mut y);
+    assert_arrays_equal!(a.vector_mul(&x), [0.0; 2]);
+    assert_arrays_equal!(y, [0.0; 2]);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_smatrix() {
+    let a = <SMatrix<f64, 2, 3> as Matrix<f64>>::from_row_slice(2, 3, A_ROW);
+    let x = SVector::<f64, 2>::from_slice(X);
+    let mut y = SVector::<f64, 3>::new_with_length(3);
+    a.vector_mul_to(&x, &mut y);
+    assert_arrays_equal!(a.vector_mul(&x), Y);
+    assert_arrays_equal!(y, Y);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2() {
+    let a = Array2::from_row_slice(2, 3, A_ROW);
+    let x = Array1::from_slice(X);
+    let mut y = Array1::new_with_length(3);
+    a.vector_mul_to(&x, &mut y);
+    assert_arrays_equal!(a.vector_mul(&x), Y);
+    assert_arrays_equal!(y, Y);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array2_empty() {
+    // An empty inner dimension gives a vector of zeros, overwriting the output vector.
+    let a = Array2::<f64>::new_with_shape(0, 2);
+    let x = Array1::<f64>::new_with_length(0);
+    let mut y = Array1::from_slice(&[f64::NAN; 2]);
+    a.vector_mul_to(&x, &mut y);
+    assert_arrays_equal!(a.vector_mul(&x), [0.0; 2]);
+    assert_arrays_equal!(y, [0.0; 2]);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat() {
+    let a = FMat::from_row_slice(2, 3, A_ROW);
+    let x = Col::from_slice(X);
+    let mut y = Col::new_with_length(3);
+    a.vector_mul_to(&x, &mut y);
+    assert_arrays_equal!(a.vector_mul(&x), Y);
+    assert_arrays_equal!(y, Y);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_mat_empty() {
+    // An empty inner dimension gives a vector of zeros, overwriting the output vector.
+    let a = FMat::<f64>::new_with_shape(0, 2);
+    let x = Col::<f64>::new_with_length(0);
+    let mut y = Col::from_slice(&[f64::NAN; 2]);
+    a.vector_mul_to(&x, &mut y);
+    assert_arrays_equal!(a.vector_mul(&x), [0.0; 2]);
+    assert_arrays_equal!(y, [0.0; 2]);
+}
@@ -47,6 +47,16 @@ fn test_vec_add_assign_panic() {
     x.add_assign(&w);
 }
 
+#[test]
+fn test_array() {
+    let mut x = <[f64; 3]>::from_slice(X);
+    let y = <[f64; 3]>::from_slice(Y);
+    let z = x.add(&y);
+    x.add_assign(&y);
+    assert_arrays_equal!(z, Z);
+    assert_arrays_equal!(x, Z);
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dvector() {
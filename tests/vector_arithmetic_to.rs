@@ -0,0 +1,196 @@
+#[cfg(feature = "faer")]
+use faer::Col;
+use linalg_traits::Vector;
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DVector, SVector};
+#[cfg(feature = "ndarray")]
+use ndarray::Array1;
+use numtest::*;
+
+// Test conditions.
+static X: &[f64; 3] = &[1.0, -2.0, 3.0];
+static Y: &[f64; 3] = &[4.0, 5.0, -6.0];
+
+// Expected results.
+static SUM: &[f64; 3] = &[5.0, 3.0, -3.0];
+static DIFFERENCE: &[f64; 3] = &[-3.0, -7.0, 9.0];
+
+/// Check the out-parameter arithmetic on a length-3 vector.
+fn arithmetic_to_test_helper<V: Vector<f64>>() {
+    let x = V::from_slice(X);
+    let y = V::from_slice(Y);
+
+    // The previous contents of the output vector are overwritten.
+    let mut out = V::from_slice(&[f64::NAN; 3]);
+    x.add_to(&y, &mut out);
+    assert_eq!(out, V::from_slice(SUM));
+    x.sub_to(&y, &mut out);
+    assert_eq!(out, V::from_slice(DIFFERENCE));
+    x.mul_to(2.0, &mut out);
+    assert_eq!(out, x.mul(2.0));
+    x.div_to(4.0, &mut out);
+    assert_eq!(out, x.div(4.0));
+}
+
+#[test]
+fn test_vec() {
+    arithmetic_to_test_helper::<Vec<f64>>();
+}
+
+#[test]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+fn test_vec_add_to_panic() {
+    let mut out = vec![0.0; 3];
+    Vec::from_slice(X).add_to(&vec![1.0, 2.0], &mut out);
+}
+
+#[test]
+#[should_panic(
+    expected = "Length of the output vector (2) does not match the length of this vector (3)."
+)]
+fn test_vec_sub_to_output_panic() {
+    let mut out = vec![0.0; 2];
+    Vec::from_slice(X).sub_to(&Vec::from_slice(Y), &mut out);
+}
+
+#[test]
+#[should_panic(
+    expected = "Length of the output vector (4) does not match the length of this vector (3)."
+)]
+fn test_vec_mul_to_output_panic() {
+    let mut out = vec![0.0; 4];
+    Vec::from_slice(X).mul_to(2.0, &mut out);
+}
+
+#[test]
+fn test_array() {
+    let mut out = [f64::NAN; 3];
+    X.add_to(Y, &mut out);
+    assert_arrays_equal!(out, SUM);
+    X.sub_to(Y, &mut out);
+    assert_arrays_equal!(out, DIFFERENCE);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector() {
+    let x = DVector::from_slice(X);
+    let y = DVector::from_slice(Y);
+    let mut out = DVector::from_element(3, f64::NAN);
+    Vector::add_to(&x, &y, &mut out);
+    assert_arrays_equal!(out.as_slice(), SUM);
+    Vector::sub_to(&x, &y, &mut out);
+    assert_arrays_equal!(out.as_slice(), DIFFERENCE);
+    let x = DVector::<f64>::zeros(0);
+    let mut out = DVector::zeros(0);
+    Vector::add_to(&x, &x, &mut out);
+    assert!(Vector::is_empty(&out));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+fn test_nalgebra_dvector_sub_to_panic() {
+    let mut out = DVector::zeros(3);
+    let x = DVector::from_slice(X);
+    Vector::sub_to(&x, &DVector::from_slice(&[1.0, 2.0]), &mut out);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(
+    expected = "Length of the output vector (2) does not match the length of this vector (3)."
+)]
+fn test_nalgebra_dvector_add_to_output_panic() {
+    let mut out = DVector::from_slice(&[0.0; 2]);
+    let x = DVector::from_slice(X);
+    Vector::add_to(&x, &DVector::from_slice(Y), &mut out);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_svector() {
+    let x = SVector::<f64, 3>::from_slice(X);
+    let y = SVector::<f64, 3>::from_slice(Y);
+    let mut out = SVector::<f64, 3>::from_element(f64::NAN);
+    Vector::add_to(&x, &y, &mut out);
+    assert_arrays_equal!(out.as_slice(), SUM);
+    Vector::sub_to(&x, &y, &mut out);
+    assert_arrays_equal!(out.as_slice(), DIFFERENCE);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1() {
+    let x = Array1::from_slice(X);
+    let y = Array1::from_slice(Y);
+    let mut out = Array1::from_elem(3, f64::NAN);
+    x.add_to(&y, &mut out);
+    assert_arrays_equal!(Vector::as_slice(&out), SUM);
+    x.sub_to(&y, &mut out);
+    assert_arrays_equal!(Vector::as_slice(&out), DIFFERENCE);
+    let x = Array1::<f64>::zeros(0);
+    let mut out = Array1::zeros(0);
+    x.add_to(&x, &mut out);
+    assert!(Vector::is_empty(&out));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1_non_contiguous_output() {
+    // Reversing the output gives it a negative stride; it is still written by index.
+    let mut out = Array1::from_elem(3, f64::NAN);
+    out.invert_axis(ndarray::Axis(0));
+    Array1::from_slice(X).add_to(&Array1::from_slice(Y), &mut out);
+    assert_eq!(out, Array1::from_slice(SUM));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+#[should_panic(
+    expected = "Length of the output vector (2) does not match the length of this vector (3)."
+)]
+fn test_ndarray_array1_sub_to_output_panic() {
+    let mut out = Array1::from_slice(&[0.0; 2]);
+    Array1::from_slice(X).sub_to(&Array1::from_slice(Y), &mut out);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_col() {
+    let x = Col::from_slice(X);
+    let y = Col::from_slice(Y);
+    let mut out = Col::from_element(3, f64::NAN);
+    x.add_to(&y, &mut out);
+    assert_arrays_equal!(Vector::as_slice(&out), SUM);
+    x.sub_to(&y, &mut out);
+    assert_arrays_equal!(Vector::as_slice(&out), DIFFERENCE);
+    let x = Col::<f64>::zeros(0);
+    let mut out = Col::zeros(0);
+    x.add_to(&x, &mut out);
+    assert!(Vector::is_empty(&out));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+fn test_faer_col_sub_to_panic() {
+    let mut out = Col::zeros(3);
+    Col::from_slice(X).sub_to(&Col::from_slice(&[1.0, 2.0]), &mut out);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+#[should_panic(
+    expected = "Length of the output vector (2) does not match the length of this vector (3)."
+)]
+fn test_faer_col_add_to_output_panic() {
+    let mut out = Col::from_slice(&[0.0; 2]);
+    Col::from_slice(X).add_to(&Col::from_slice(Y), &mut out);
+}
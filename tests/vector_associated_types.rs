@@ -1,6 +1,6 @@
 #[cfg(feature = "faer")]
 use faer::{Col, Mat as FMat};
-use linalg_traits::{Mat, Matrix, Vector};
+use linalg_traits::{Mat, Matrix, SMat, Vector};
 #[cfg(feature = "nalgebra")]
 use nalgebra::{DMatrix, DVector, SMatrix, SVector, dvector};
 #[cfg(feature = "ndarray")]
@@ -28,6 +28,11 @@ fn test_vector_t() {
     let vec_generic_exp: Vec<f64> = vec![0.0, 0.0, 0.0];
     assert_arrays_equal!(vec_generic, vec_generic_exp);
 
+    // [f64; 3] from [f64; 3].
+    let vec_generic: [f64; 3] = vector_t_test_helper(<[f64; 3]>::new_with_length(3));
+    let vec_generic_exp: [f64; 3] = [0.0, 0.0, 0.0];
+    assert_arrays_equal!(vec_generic, vec_generic_exp);
+
     // nalgebra::DVector<f64> from nalgebra::DVector<f64>.
     let vec_generic: DVector<f64> = vector_t_test_helper(DVector::<f64>::new_with_length(3));
     let vec_generic_exp: DVector<f64> = dvector![0.0, 0.0, 0.0];
@@ -66,6 +71,11 @@ fn test_dvector_t() {
     let vec_generic_exp: Vec<f64> = vec![0.0, 0.0, 0.0];
     assert_arrays_equal!(vec_generic, vec_generic_exp);
 
+    // Vec<f64> from [f64; 3].
+    let vec_generic: Vec<f64> = dvector_t_test_helper(<[f64; 3]>::new_with_length(3));
+    let vec_generic_exp: Vec<f64> = vec![0.0, 0.0, 0.0];
+    assert_arrays_equal!(vec_generic, vec_generic_exp);
+
     // nalgebra::DVector<f64> nalgebra::from DVector<f64>.
     let vec_generic: DVector<f64> = dvector_t_test_helper(DVector::<f64>::new_with_length(3));
     let vec_generic_exp: DVector<f64> = dvector![0.0, 0.0, 0.0];
@@ -104,6 +114,11 @@ fn test_dvector_f64() {
     let vec_generic_exp: Vec<f64> = vec![0.0, 0.0, 0.0];
     assert_arrays_equal!(vec_generic, vec_generic_exp);
 
+    // Vec<f64> from [f64; 3].
+    let vec_generic: Vec<f64> = dvector_f64_test_helper(<[f64; 3]>::new_with_length(3));
+    let vec_generic_exp: Vec<f64> = vec![0.0, 0.0, 0.0];
+    assert_arrays_equal!(vec_generic, vec_generic_exp);
+
     // nalgebra::DVector<f64> from nalgebra::DVector<f64>.
     let vec_generic: DVector<f64> = dvector_f64_test_helper(DVector::<f64>::new_with_length(3));
     let vec_generic_exp: DVector<f64> = dvector![0.0, 0.0, 0.0];
@@ -138,6 +153,11 @@ fn test_new_vector_f64() {
     let vec_f64: Vec<f64> = vec.new_vector_f64();
     assert_arrays_equal!(vec_f64, [0.0, 0.0, 0.0]);
 
+    // [f64; 3] from [f64; 3].
+    let vec: [f64; 3] = [1.0, 2.0, 3.0];
+    let vec_f64: [f64; 3] = vec.new_vector_f64();
+    assert_arrays_equal!(vec_f64, [0.0, 0.0, 0.0]);
+
     // nalgebra::DVector<f64> from nalgebra::DVector<f64>.
     let vec: DVector<f64> = dvector![1.0, 2.0, 3.0];
     let vec_f64: DVector<f64> = vec.new_vector_f64();
@@ -197,6 +217,44 @@ fn test_mat_from_vec() {
     assert_eq!(mat_n_by_m_dynamic.shape(), (N, M));
 }
 
+#[test]
+fn test_smat_from_array() {
+    // Vector.
+    let vec: [f64; N] = <[f64; N]>::new_with_length(N);
+
+    // Matrices constructed using `new_matrix_*_by_*`.
+    let mat_n_by_n: SMat<f64, N, N> = vec.new_matrix_n_by_n();
+    let mat_m_by_n: SMat<f64, M, N> = vec.new_matrix_m_by_n::<M>(None);
+    let mat_n_by_m: SMat<f64, N, M> = vec.new_matrix_n_by_m::<M>(None);
+    let mat_m_by_n_dynamic: Mat<f64> = vec.new_dmatrix_m_by_n(M);
+    let mat_m_by_n_dynamic_f64: Mat<f64> = vec.new_dmatrix_m_by_n_f64(M);
+    let mat_n_by_m_dynamic: Mat<f64> = vec.new_dmatrix_n_by_m(M);
+
+    // Expected matrices.
+    let mat_n_by_n_exp: SMat<f64, N, N> = SMat::new_with_shape(N, N);
+    let mat_m_by_n_exp: SMat<f64, M, N> = SMat::new_with_shape(M, N);
+    let mat_n_by_m_exp: SMat<f64, N, M> = SMat::new_with_shape(N, M);
+    let mat_m_by_n_dynamic_exp: Mat<f64> = Mat::new_with_shape(M, N);
+    let mat_m_by_n_dynamic_f64_exp: Mat<f64> = Mat::new_with_shape(M, N);
+    let mat_n_by_m_dynamic_exp: Mat<f64> = Mat::new_with_shape(N, M);
+
+    // Check equality of elements between actual and expected matrices.
+    assert_arrays_equal!(mat_n_by_n, mat_n_by_n_exp);
+    assert_arrays_equal!(mat_m_by_n, mat_m_by_n_exp);
+    assert_arrays_equal!(mat_n_by_m, mat_n_by_m_exp);
+    assert_arrays_equal!(mat_m_by_n_dynamic, mat_m_by_n_dynamic_exp);
+    assert_arrays_equal!(mat_m_by_n_dynamic_f64, mat_m_by_n_dynamic_f64_exp);
+    assert_arrays_equal!(mat_n_by_m_dynamic, mat_n_by_m_dynamic_exp);
+
+    // Check the shapes of the actual matrices.
+    assert_eq!(mat_n_by_n.shape(), (N, N));
+    assert_eq!(mat_m_by_n.shape(), (M, N));
+    assert_eq!(mat_n_by_m.shape(), (N, M));
+    assert_eq!(mat_m_by_n_dynamic.shape(), (M, N));
+    assert_eq!(mat_m_by_n_dynamic_f64.shape(), (M, N));
+    assert_eq!(mat_n_by_m_dynamic.shape(), (N, M));
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dmatrix_from_dvector() {
@@ -0,0 +1,187 @@
+#[cfg(feature = "faer")]
+use faer::Col;
+use linalg_traits::Vector;
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DVector, SVector};
+#[cfg(feature = "ndarray")]
+use ndarray::Array1;
+#[cfg(any(feature = "nalgebra", feature = "ndarray", feature = "faer"))]
+use numtest::*;
+
+// Test conditions.
+static X: &[f64; 3] = &[1.0, -2.0, 3.0];
+static Y: &[f64; 3] = &[4.0, 5.0, -6.0];
+static Z: &[f64; 3] = &[0.5, 0.0, 2.0];
+
+// Expected results.
+static AXPY: &[f64; 3] = &[6.0, 1.0, 0.0];
+static AXPBY: &[f64; 3] = &[-2.0, -9.0, 12.0];
+static SCALED: &[f64; 3] = &[2.0, -4.0, 6.0];
+
+/// Check the fused updates on a length-3 vector.
+fn axpy_test_helper<V: Vector<f64>>() {
+    let x = V::from_slice(X);
+    let y = V::from_slice(Y);
+    let z = V::from_slice(Z);
+
+    // axpy.
+    let mut w = y.clone();
+    w.axpy(2.0, &x);
+    assert_eq!(w, V::from_slice(AXPY));
+
+    // axpby.
+    let mut w = y.clone();
+    w.axpby(2.0, &x, -1.0);
+    assert_eq!(w, V::from_slice(AXPBY));
+
+    // axpby with beta = 0 overwrites non-finite elements.
+    let mut w = V::from_slice(&[f64::NAN, f64::INFINITY, 1.0]);
+    w.axpby(2.0, &x, 0.0);
+    assert_eq!(w, V::from_slice(SCALED));
+
+    // Linear combination.
+    let mut w = V::from_slice(&[f64::NAN; 3]);
+    w.linear_combination(&[(1.0, &x), (0.5, &y), (-2.0, &z)]);
+    assert_eq!(w, V::from_slice(&[2.0, 0.5, -4.0]));
+    w.linear_combination(&[(3.0, &x)]);
+    assert_eq!(w, V::from_slice(&[3.0, -6.0, 9.0]));
+    w.linear_combination(&[]);
+    assert_eq!(w, V::from_slice(&[0.0; 3]));
+}
+
+#[test]
+fn test_vec() {
+    axpy_test_helper::<Vec<f64>>();
+}
+
+#[test]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+fn test_vec_axpy_panic() {
+    let mut x = Vec::from_slice(X);
+    x.axpy(1.0, &vec![1.0, 2.0]);
+}
+
+#[test]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+fn test_vec_linear_combination_panic() {
+    let mut x = vec![0.0; 3];
+    x.linear_combination(&[(1.0, &Vec::from_slice(X)), (1.0, &vec![1.0, 2.0])]);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector() {
+    let x = DVector::from_slice(X);
+    let mut w = DVector::from_slice(Y);
+    Vector::axpy(&mut w, 2.0, &x);
+    assert_arrays_equal!(w.as_slice(), AXPY);
+    let mut w = DVector::from_slice(Y);
+    Vector::axpby(&mut w, 2.0, &x, -1.0);
+    assert_arrays_equal!(w.as_slice(), AXPBY);
+    let mut w = DVector::from_slice(&[f64::NAN, f64::INFINITY, 1.0]);
+    Vector::axpby(&mut w, 2.0, &x, 0.0);
+    assert_arrays_equal!(w.as_slice(), SCALED);
+    let mut w = DVector::<f64>::zeros(0);
+    Vector::axpby(&mut w, 2.0, &DVector::zeros(0), -1.0);
+    assert!(Vector::is_empty(&w));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+fn test_nalgebra_dvector_axpby_panic() {
+    let mut x = DVector::from_slice(X);
+    x.axpby(1.0, &DVector::from_slice(&[1.0, 2.0]), 1.0);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_svector() {
+    let x = SVector::<f64, 3>::from_slice(X);
+    let mut w = SVector::<f64, 3>::from_slice(Y);
+    Vector::axpy(&mut w, 2.0, &x);
+    assert_arrays_equal!(w.as_slice(), AXPY);
+    let mut w = SVector::<f64, 3>::from_slice(Y);
+    Vector::axpby(&mut w, 2.0, &x, -1.0);
+    assert_arrays_equal!(w.as_slice(), AXPBY);
+    let mut w = SVector::<f64, 3>::from_slice(&[f64::NAN, f64::INFINITY, 1.0]);
+    Vector::axpby(&mut w, 2.0, &x, 0.0);
+    assert_arrays_equal!(w.as_slice(), SCALED);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1() {
+    let x = Array1::from_slice(X);
+    let mut w = Array1::from_slice(Y);
+    w.axpy(2.0, &x);
+    assert_arrays_equal!(Vector::as_slice(&w), AXPY);
+    let mut w = Array1::from_slice(Y);
+    w.axpby(2.0, &x, -1.0);
+    assert_arrays_equal!(Vector::as_slice(&w), AXPBY);
+    let mut w = Array1::from_slice(&[f64::NAN, f64::INFINITY, 1.0]);
+    w.axpby(2.0, &x, 0.0);
+    assert_arrays_equal!(Vector::as_slice(&w), SCALED);
+    let mut w = Array1::<f64>::zeros(0);
+    w.axpby(2.0, &Array1::zeros(0), -1.0);
+    assert!(Vector::is_empty(&w));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1_non_contiguous() {
+    // Reversing the vector gives it a negative stride, so elements must be paired by index rather
+    // than by position in memory.
+    let mut x = Array1::from_slice(&[3.0, -2.0, 1.0]);
+    x.invert_axis(ndarray::Axis(0));
+    let mut w = Array1::from_slice(Y);
+    w.axpy(2.0, &x);
+    assert_eq!(w, Array1::from_slice(AXPY));
+    let mut w = Array1::from_slice(Y);
+    w.axpby(2.0, &x, -1.0);
+    assert_eq!(w, Array1::from_slice(AXPBY));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+fn test_ndarray_array1_axpy_panic() {
+    let mut x = Array1::from_slice(X);
+    x.axpy(1.0, &Array1::from_slice(&[1.0, 2.0]));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_col() {
+    let x = Col::from_slice(X);
+    let mut w = Col::from_slice(Y);
+    w.axpy(2.0, &x);
+    assert_arrays_equal!(Vector::as_slice(&w), AXPY);
+    let mut w = Col::from_slice(Y);
+    w.axpby(2.0, &x, -1.0);
+    assert_arrays_equal!(Vector::as_slice(&w), AXPBY);
+    let mut w = Col::from_slice(&[f64::NAN, f64::INFINITY, 1.0]);
+    w.axpby(2.0, &x, 0.0);
+    assert_arrays_equal!(Vector::as_slice(&w), SCALED);
+    let mut w = Col::<f64>::zeros(0);
+    w.axpby(2.0, &Col::zeros(0), -1.0);
+    assert!(Vector::is_empty(&w));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+fn test_faer_col_axpby_panic() {
+    let mut x = Col::from_slice(X);
+    x.axpby(1.0, &Col::from_slice(&[1.0, 2.0]), 0.0);
+}
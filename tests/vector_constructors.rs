@@ -0,0 +1,253 @@
+#[cfg(feature = "faer")]
+use faer::Col;
+use linalg_traits::LinalgError;
+use linalg_traits::Vector;
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DVector, SVector};
+#[cfg(feature = "ndarray")]
+use ndarray::Array1;
+
+// Expected results.
+static SQUARES: &[f64; 4] = &[0.0, 1.0, 4.0, 9.0];
+static HALVES: &[f64; 4] = &[0.0, 0.5, 1.0, 1.5];
+
+/// Check the constructors for a length-4 vector.
+fn constructors_test_helper<V: Vector<f64>>() {
+    // From a function.
+    assert_eq!(V::from_fn(4, |i| (i * i) as f64), V::from_slice(SQUARES));
+
+    // From an element.
+    assert_eq!(V::from_element(4, 2.5), V::from_slice(&[2.5; 4]));
+
+    // From an iterator.
+    assert_eq!(
+        V::try_from_iter([1.0, 2.0, 3.0, 4.0]),
+        Ok(V::from_slice(&[1.0, 2.0, 3.0, 4.0]))
+    );
+    assert_eq!(
+        V::try_from_iter((0..4).map(|i| f64::from(i) / 2.0)),
+        Ok(V::from_slice(HALVES))
+    );
+
+    // Evenly spaced values.
+    assert_eq!(
+        V::linspace(-1.0, 0.5, 4),
+        V::from_slice(&[-1.0, -0.5, 0.0, 0.5])
+    );
+    assert_eq!(
+        V::logspace(0.0, 3.0, 4, 2.0),
+        V::from_slice(&[1.0, 2.0, 4.0, 8.0])
+    );
+
+    // Basis vectors.
+    assert_eq!(V::basis(4, 0), V::from_slice(&[1.0, 0.0, 0.0, 0.0]));
+    assert_eq!(V::basis(4, 2), V::from_slice(&[0.0, 0.0, 1.0, 0.0]));
+}
+
+#[test]
+fn test_vec() {
+    constructors_test_helper::<Vec<f64>>();
+}
+
+#[test]
+fn test_vec_edge_cases() {
+    assert!(<Vec<f64> as Vector<f64>>::from_fn(0, |_| 1.0).is_empty());
+    assert!(<Vec<f64> as Vector<f64>>::linspace(0.0, 1.0, 0).is_empty());
+    assert_eq!(<Vec<f64> as Vector<f64>>::linspace(3.0, 1.0, 1), vec![3.0]);
+    assert_eq!(
+        <Vec<f64> as Vector<f64>>::linspace(1.0, 0.0, 3),
+        vec![1.0, 0.5, 0.0]
+    );
+    assert_eq!(
+        <Vec<f64> as Vector<f64>>::try_from_iter(std::iter::empty()),
+        Ok(Vec::new())
+    );
+}
+
+#[test]
+#[should_panic(expected = "Index (3) is out of bounds for a vector of length 3.")]
+fn test_vec_basis_panic() {
+    let _ = <Vec<f64> as Vector<f64>>::basis(3, 3);
+}
+
+#[test]
+fn test_array() {
+    assert_eq!(<[f64; 4]>::from_fn(4, |i| (i * i) as f64), *SQUARES);
+    assert_eq!(<[f64; 4]>::from_element(4, 2.5), [2.5; 4]);
+    assert_eq!(
+        <[f64; 4]>::try_from_iter((0..4).map(|i| f64::from(i) / 2.0)),
+        Ok(*HALVES)
+    );
+    assert_eq!(<[f64; 0]>::try_from_iter(std::iter::empty()), Ok([]));
+}
+
+#[test]
+fn test_array_try_from_iter_length_mismatch() {
+    assert_eq!(
+        <[f64; 4]>::try_from_iter([1.0, 2.0, 3.0]),
+        Err(LinalgError::LengthMismatch {
+            expected: 4,
+            actual: 3
+        })
+    );
+    assert_eq!(
+        <[f64; 4]>::try_from_iter([1.0, 2.0, 3.0, 4.0, 5.0]),
+        Err(LinalgError::LengthMismatch {
+            expected: 4,
+            actual: 5
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Length must match the fixed size of the array.")]
+fn test_array_from_fn_panic() {
+    let _ = <[f64; 4]>::from_fn(3, |i| i as f64);
+}
+
+#[test]
+#[should_panic(expected = "Length must match the fixed size of the array.")]
+fn test_array_linspace_panic() {
+    let _ = <[f64; 4]>::linspace(0.0, 1.0, 5);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector() {
+    assert_eq!(
+        <DVector<f64> as Vector<f64>>::from_fn(4, |i| (i * i) as f64).as_slice(),
+        SQUARES
+    );
+    assert_eq!(
+        <DVector<f64> as Vector<f64>>::from_element(4, 2.5).as_slice(),
+        &[2.5; 4]
+    );
+    assert_eq!(
+        DVector::try_from_iter((0..4).map(|i| f64::from(i) / 2.0)),
+        Ok(DVector::from_slice(HALVES))
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector_empty() {
+    assert!(Vector::is_empty(&<DVector<f64> as Vector<f64>>::from_fn(
+        0,
+        |_| 1.0
+    )));
+    assert!(Vector::is_empty(
+        &<DVector<f64> as Vector<f64>>::from_element(0, 1.0)
+    ));
+    assert_eq!(
+        DVector::try_from_iter(std::iter::empty()),
+        Ok(DVector::<f64>::zeros(0))
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_svector() {
+    assert_eq!(
+        <SVector<f64, 4> as Vector<f64>>::from_fn(4, |i| (i * i) as f64).as_slice(),
+        SQUARES
+    );
+    assert_eq!(
+        <SVector<f64, 4> as Vector<f64>>::from_element(4, 2.5).as_slice(),
+        &[2.5; 4]
+    );
+    assert_eq!(
+        <SVector<f64, 4> as Vector<f64>>::try_from_iter((0..4).map(|i| f64::from(i) / 2.0)),
+        Ok(SVector::from_column_slice(HALVES))
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_svector_try_from_iter_length_mismatch() {
+    assert_eq!(
+        <SVector<f64, 4> as Vector<f64>>::try_from_iter([1.0, 2.0, 3.0]),
+        Err(LinalgError::LengthMismatch {
+            expected: 4,
+            actual: 3
+        })
+    );
+    assert_eq!(
+        <SVector<f64, 4> as Vector<f64>>::try_from_iter([1.0, 2.0, 3.0, 4.0, 5.0]),
+        Err(LinalgError::LengthMismatch {
+            expected: 4,
+            actual: 5
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(expected = "Length must match the fixed size of the SVector.")]
+fn test_nalgebra_svector_from_fn_panic() {
+    let _ = <SVector<f64, 4> as Vector<f64>>::from_fn(3, |i| i as f64);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(expected = "Length must match the fixed size of the SVector.")]
+fn test_nalgebra_svector_linspace_panic() {
+    let _ = <SVector<f64, 4> as Vector<f64>>::linspace(0.0, 1.0, 5);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1() {
+    assert_eq!(
+        <Array1<f64> as Vector<f64>>::from_fn(4, |i| (i * i) as f64),
+        Array1::from_slice(SQUARES)
+    );
+    assert_eq!(
+        <Array1<f64> as Vector<f64>>::from_element(4, 2.5),
+        Array1::from_slice(&[2.5; 4])
+    );
+    assert_eq!(
+        Array1::try_from_iter((0..4).map(|i| f64::from(i) / 2.0)),
+        Ok(Array1::from_slice(HALVES))
+    );
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1_empty() {
+    assert!(Vector::is_empty(&<Array1<f64> as Vector<f64>>::from_fn(
+        0,
+        |_| 1.0
+    )));
+    assert!(Vector::is_empty(
+        &<Array1<f64> as Vector<f64>>::from_element(0, 1.0)
+    ));
+    assert_eq!(
+        Array1::try_from_iter(std::iter::empty()),
+        Ok(Array1::<f64>::zeros(0))
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_col() {
+    assert_eq!(
+        <Col<f64> as Vector<f64>>::from_fn(4, |i| (i * i) as f64),
+        Col::from_slice(SQUARES)
+    );
+    assert_eq!(
+        <Col<f64> as Vector<f64>>::from_element(4, 2.5),
+        Col::from_slice(&[2.5; 4])
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_col_empty() {
+    assert!(Vector::is_empty(&<Col<f64> as Vector<f64>>::from_fn(
+        0,
+        |_| 1.0
+    )));
+    assert!(Vector::is_empty(&<Col<f64> as Vector<f64>>::from_element(
+        0, 1.0
+    )));
+}
@@ -0,0 +1,135 @@
+#[cfg(feature = "faer")]
+use faer::Col;
+use linalg_traits::Vector;
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DVector, SVector};
+#[cfg(feature = "ndarray")]
+use ndarray::Array1;
+
+// Test conditions.
+static X: &[f64; 3] = &[1.0, -2.0, 3.0];
+
+/// Check the conversion from a length-3 vector of type `V` into a vector of type `W`, and the
+/// copy from a vector of type `V` into a vector of type `W`.
+fn convert_test_helper<V: Vector<f64>, W: Vector<f64>>(x: &V) {
+    assert_eq!(x.convert::<W>(), W::from_slice(X));
+    let mut y = W::new_with_length(3);
+    Vector::copy_from(&mut y, x);
+    assert_eq!(y, W::from_slice(X));
+}
+
+/// Check the conversion from a length-3 vector of type `V` into every vector type.
+fn convert_to_all_test_helper<V: Vector<f64>>(x: &V) {
+    convert_test_helper::<V, Vec<f64>>(x);
+    convert_test_helper::<V, [f64; 3]>(x);
+    #[cfg(feature = "nalgebra")]
+    {
+        convert_test_helper::<V, DVector<f64>>(x);
+        convert_test_helper::<V, SVector<f64, 3>>(x);
+    }
+    #[cfg(feature = "ndarray")]
+    convert_test_helper::<V, Array1<f64>>(x);
+    #[cfg(feature = "faer")]
+    convert_test_helper::<V, Col<f64>>(x);
+}
+
+#[test]
+fn test_vec() {
+    convert_to_all_test_helper(&Vec::from_slice(X));
+}
+
+#[test]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+fn test_vec_copy_from_panic() {
+    let mut x = Vec::from_slice(X);
+    Vector::copy_from(&mut x, &vec![1.0, 2.0]);
+}
+
+#[test]
+fn test_array() {
+    convert_to_all_test_helper(X);
+}
+
+#[test]
+#[should_panic(expected = "Length must match the fixed size of the array.")]
+fn test_array_convert_panic() {
+    let _ = vec![1.0, 2.0].convert::<[f64; 3]>();
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector() {
+    convert_to_all_test_helper(&DVector::from_slice(X));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector_empty() {
+    let x = DVector::<f64>::zeros(0);
+    assert!(x.convert::<Vec<f64>>().is_empty());
+    let mut y = DVector::<f64>::zeros(0);
+    Vector::copy_from(&mut y, &Vec::new());
+    assert!(Vector::is_empty(&y));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_svector() {
+    convert_to_all_test_helper(&SVector::<f64, 3>::from_slice(X));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+#[should_panic(expected = "Length must match the fixed size of the SVector.")]
+fn test_nalgebra_svector_convert_panic() {
+    let _ = vec![1.0, 2.0].convert::<SVector<f64, 3>>();
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1() {
+    convert_to_all_test_helper(&Array1::from_slice(X));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1_empty() {
+    let x = Array1::<f64>::zeros(0);
+    assert!(x.convert::<Vec<f64>>().is_empty());
+    let mut y = Array1::<f64>::zeros(0);
+    Vector::copy_from(&mut y, &Vec::new());
+    assert!(Vector::is_empty(&y));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1_non_contiguous() {
+    // Reversing the array gives it a negative stride.
+    let mut x = Array1::from_slice(&[3.0, -2.0, 1.0]);
+    x.invert_axis(ndarray::Axis(0));
+    convert_to_all_test_helper(&x);
+
+    // Copy into a non-contiguous array.
+    let mut y = Array1::from_slice(&[0.0; 3]);
+    y.invert_axis(ndarray::Axis(0));
+    Vector::copy_from(&mut y, &Vec::from_slice(X));
+    assert_eq!(y, Array1::from_slice(X));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_col() {
+    convert_to_all_test_helper(&Col::from_slice(X));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_col_empty() {
+    let x = Col::<f64>::zeros(0);
+    assert!(x.convert::<Vec<f64>>().is_empty());
+    let mut y = Col::<f64>::zeros(0);
+    Vector::copy_from(&mut y, &Vec::new());
+    assert!(Vector::is_empty(&y));
+}
@@ -0,0 +1,175 @@
+use linalg_traits::{Mat, Vector};
+use std::borrow::Cow;
+use std::ops::{Index, IndexMut};
+
+/// Vector type implementing only the required methods of the [`Vector`] trait, used to check the
+/// default method implementations.
+#[derive(Clone, Debug, PartialEq)]
+struct MinimalVector(Vec<f64>);
+
+impl Index<usize> for MinimalVector {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &f64 {
+        &self.0[index]
+    }
+}
+
+impl IndexMut<usize> for MinimalVector {
+    fn index_mut(&mut self, index: usize) -> &mut f64 {
+        &mut self.0[index]
+    }
+}
+
+impl Vector<f64> for MinimalVector {
+    type VectorT<T: linalg_traits::Scalar> = Vec<T>;
+
+    type DVectorT<T: linalg_traits::Scalar> = Vec<T>;
+
+    type Vectorf64 = Vec<f64>;
+
+    type DVectorf64 = Vec<f64>;
+
+    type MatrixNxN = Mat<f64>;
+
+    type MatrixMxN<const M: usize> = Mat<f64>;
+
+    type DMatrixMxN = Mat<f64>;
+
+    type DMatrixMxNf64 = Mat<f64>;
+
+    type MatrixNxM<const M: usize> = Mat<f64>;
+
+    type DMatrixNxM = Mat<f64>;
+
+    fn is_statically_sized() -> bool {
+        false
+    }
+
+    fn is_dynamically_sized() -> bool {
+        true
+    }
+
+    fn new_with_length(len: usize) -> Self {
+        MinimalVector(vec![0.0; len])
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn from_slice(slice: &[f64]) -> Self {
+        MinimalVector(slice.to_vec())
+    }
+
+    fn as_slice(&self) -> Cow<'_, [f64]> {
+        Cow::Borrowed(&self.0)
+    }
+
+    fn get(&self, idx: usize) -> Option<&f64> {
+        self.0.get(idx)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        MinimalVector(self.0.add(&other.0))
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        self.0.add_assign(&other.0);
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        MinimalVector(self.0.sub(&other.0))
+    }
+
+    fn sub_assign(&mut self, other: &Self) {
+        self.0.sub_assign(&other.0);
+    }
+
+    fn mul(&self, scalar: f64) -> Self {
+        MinimalVector(self.0.mul(scalar))
+    }
+
+    fn mul_assign(&mut self, scalar: f64) {
+        self.0.mul_assign(scalar);
+    }
+
+    fn div(&self, scalar: f64) -> Self {
+        MinimalVector(self.0.div(scalar))
+    }
+
+    fn div_assign(&mut self, scalar: f64) {
+        self.0.div_assign(scalar);
+    }
+
+    fn dot(&self, other: &Self) -> f64 {
+        self.0.dot(&other.0)
+    }
+}
+
+// Test conditions.
+static X: &[f64; 3] = &[1.0, -2.0, 3.0];
+static Y: &[f64; 3] = &[4.0, 5.0, -6.0];
+
+#[test]
+fn test_static_length() {
+    assert_eq!(MinimalVector::static_length(), None);
+}
+
+#[test]
+fn test_try_as_contiguous_slice() {
+    let mut x = MinimalVector::from_slice(X);
+    assert!(x.try_as_contiguous_slice().is_none());
+    assert!(x.try_as_contiguous_mut_slice().is_none());
+}
+
+#[test]
+fn test_map() {
+    let x = MinimalVector::from_slice(X);
+    assert_eq!(
+        x.map(|a| 2.0 * a),
+        MinimalVector::from_slice(&[2.0, -4.0, 6.0])
+    );
+    let mut z = x.clone();
+    z.map_in_place(f64::abs);
+    assert_eq!(z, MinimalVector::from_slice(&[1.0, 2.0, 3.0]));
+}
+
+#[test]
+fn test_zip() {
+    let x = MinimalVector::from_slice(X);
+    let y = MinimalVector::from_slice(Y);
+    assert_eq!(
+        x.zip_map(&y, |a, b| a - 2.0 * b),
+        MinimalVector::from_slice(&[-7.0, -12.0, 15.0])
+    );
+    let mut z = x.clone();
+    z.zip_apply(&y, |a, b| *a = a.max(b));
+    assert_eq!(z, MinimalVector::from_slice(&[4.0, 5.0, 3.0]));
+}
+
+#[test]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+fn test_zip_apply_panic() {
+    let mut x = MinimalVector::from_slice(X);
+    x.zip_apply(&MinimalVector::from_slice(&[1.0, 2.0]), |a, b| *a += b);
+}
+
+#[test]
+fn test_fold() {
+    let x = MinimalVector::from_slice(X);
+    assert_eq!(x.fold(0.0, |sum, a| sum + a), 2.0);
+    assert_eq!(x.fold(0, |count, a| count + usize::from(a > 0.0)), 2);
+}
+
+#[test]
+fn test_map_scalar() {
+    let x = MinimalVector::from_slice(X);
+    assert_eq!(x.map_scalar(|a| a + 0.5), vec![1.5, -1.5, 3.5]);
+}
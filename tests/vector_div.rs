@@ -23,6 +23,15 @@ fn test_vec() {
     assert_arrays_equal!(x, Z);
 }
 
+#[test]
+fn test_array() {
+    let mut x = <[f64; 3]>::from_slice(X);
+    let z = x.div(Y);
+    x.div_assign(Y);
+    assert_arrays_equal!(z, Z);
+    assert_arrays_equal!(x, Z);
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dvector() {
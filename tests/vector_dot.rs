@@ -32,6 +32,22 @@ fn test_vec_panic() {
     let _ = x.dot(&w);
 }
 
+#[test]
+fn test_array() {
+    let x = <[f64; 3]>::from_slice(X);
+    let y = <[f64; 3]>::from_slice(Y);
+    let z = x.dot(&y);
+    assert_eq!(z, Z);
+}
+
+#[test]
+#[should_panic(expected = "Length must match the fixed size of the array.\n  left: 2\n right: 3")]
+fn test_array_panic() {
+    let x = <[f64; 3]>::from_slice(X);
+    let w = <[f64; 3]>::from_slice(W);
+    let _ = x.dot(&w);
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dvector() {
@@ -12,6 +12,12 @@ fn test_mat() {
     assert!(!Vec::<f64>::is_statically_sized());
 }
 
+#[test]
+fn test_array() {
+    assert!(!<[f64; 3]>::is_dynamically_sized());
+    assert!(<[f64; 3]>::is_statically_sized());
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dvector() {
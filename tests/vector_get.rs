@@ -22,6 +22,12 @@ fn test_vec() {
     assert_get(&x);
 }
 
+#[test]
+fn test_array() {
+    let x = <[f64; 3]>::from_slice(X);
+    assert_get(&x);
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dvector() {
@@ -24,6 +24,21 @@ fn test_vec_out_of_bounds() {
     _ = x[3];
 }
 
+#[test]
+fn test_array() {
+    let x = <[f64; 3]>::from_slice(X);
+    assert_eq!(x[0], 1.0);
+    assert_eq!(x[1], 2.0);
+    assert_eq!(x[2], 3.0);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+fn test_array_out_of_bounds() {
+    let x = <[f64; 3]>::from_slice(X);
+    _ = x[std::hint::black_box(3)];
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dvector() {
@@ -26,6 +26,22 @@ fn test_vec_out_of_bounds() {
     x[3] = 4.0;
 }
 
+#[test]
+fn test_array() {
+    let mut x = <[f64; 3]>::new_with_length(3);
+    x[0] = 1.0;
+    x[1] = 2.0;
+    x[2] = 3.0;
+    assert_arrays_equal!(x, X);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+fn test_array_out_of_bounds() {
+    let mut x = <[f64; 3]>::new_with_length(3);
+    _ = &mut x[std::hint::black_box(3)];
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dvector() {
@@ -12,6 +12,12 @@ fn test_vec() {
     assert!(!<Vec<f64> as Vector<f64>>::new_with_length(3).is_empty());
 }
 
+#[test]
+fn test_array() {
+    assert!(<[f64; 0]>::new_with_length(0).is_empty());
+    assert!(!<[f64; 3]>::new_with_length(3).is_empty());
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dvector() {
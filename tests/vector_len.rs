@@ -11,6 +11,11 @@ fn test_len_vec() {
     assert_eq!(<Vec<f64> as Vector<f64>>::new_with_length(3).len(), 3);
 }
 
+#[test]
+fn test_len_array() {
+    assert_eq!(<[f64; 3]>::new_with_length(3).len(), 3);
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_len_nalgebra_dvector() {
@@ -0,0 +1,176 @@
+#[cfg(feature = "faer")]
+use faer::Col;
+use linalg_traits::Vector;
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DVector, SVector};
+#[cfg(feature = "ndarray")]
+use ndarray::Array1;
+use numtest::*;
+
+// Test conditions.
+static X: &[f64; 3] = &[1.0, -2.0, 3.0];
+static Y: &[f64; 3] = &[4.0, 5.0, -6.0];
+
+// Expected results.
+static DOUBLE: &[f64; 3] = &[2.0, -4.0, 6.0];
+static ZIP: &[f64; 3] = &[-7.0, -12.0, 15.0];
+
+/// Check the native elementwise operations on a length-3 vector, including the order in which
+/// [`Vector::fold`] visits the elements.
+fn native_map_test_helper<V: Vector<f64>>(x: &V, y: &V) {
+    assert_arrays_equal!(x.map(|a| 2.0 * a).as_slice(), DOUBLE);
+    assert_arrays_equal!(x.zip_map(y, |a, b| a - 2.0 * b).as_slice(), ZIP);
+    let mut z = x.clone();
+    z.zip_apply(y, |a, b| *a -= 2.0 * b);
+    z.map_in_place(|a| -a);
+    assert_arrays_equal!(z.as_slice(), &[7.0, 12.0, -15.0]);
+    let visited = x.fold(Vec::new(), |mut visited, a| {
+        visited.push(a);
+        visited
+    });
+    assert_eq!(visited, X);
+}
+
+/// Check the elementwise operations on a length-3 vector.
+fn map_test_helper<V: Vector<f64>>() {
+    let x = V::from_slice(X);
+    let y = V::from_slice(Y);
+
+    // Map.
+    assert_eq!(x.map(|a| 2.0 * a), V::from_slice(DOUBLE));
+    let mut z = x.clone();
+    z.map_in_place(f64::abs);
+    assert_eq!(z, V::from_slice(&[1.0, 2.0, 3.0]));
+
+    // Zip.
+    assert_eq!(x.zip_map(&y, |a, b| a - 2.0 * b), V::from_slice(ZIP));
+    let mut z = x.clone();
+    z.zip_apply(&y, |a, b| *a = a.max(b));
+    assert_eq!(z, V::from_slice(&[4.0, 5.0, 3.0]));
+
+    // Hadamard product and quotient.
+    assert_eq!(x.hadamard(&y), V::from_slice(&[4.0, -10.0, -18.0]));
+    assert_eq!(x.hadamard_div(&y), V::from_slice(&[0.25, -0.4, -0.5]));
+
+    // Fold.
+    assert_eq!(x.fold(0.0, |sum, a| sum + a), 2.0);
+    assert_eq!(x.fold(0, |count, a| count + usize::from(a > 0.0)), 2);
+}
+
+#[test]
+fn test_vec() {
+    map_test_helper::<Vec<f64>>();
+}
+
+#[test]
+fn test_vec_empty() {
+    let x: Vec<f64> = Vec::new();
+    assert!(x.map(|a| a + 1.0).is_empty());
+    assert!(x.hadamard(&Vec::new()).is_empty());
+    assert_eq!(x.fold(1.0, |product, a| product * a), 1.0);
+}
+
+#[test]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+fn test_vec_zip_map_panic() {
+    let x = Vec::from_slice(X);
+    let _ = x.zip_map(&vec![1.0, 2.0], |a, b| a + b);
+}
+
+#[test]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+fn test_vec_zip_apply_panic() {
+    let mut x = Vec::from_slice(X);
+    x.zip_apply(&vec![1.0, 2.0], |a, b| *a += b);
+}
+
+#[test]
+fn test_array() {
+    native_map_test_helper(X, Y);
+    let x: [f64; 0] = [];
+    assert!(x.zip_map(&x, |a, b| a + b).is_empty());
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector() {
+    native_map_test_helper(&DVector::from_slice(X), &DVector::from_slice(Y));
+    let x = DVector::<f64>::zeros(0);
+    assert!(Vector::is_empty(&Vector::zip_map(&x, &x, |a, b| a + b)));
+    assert_eq!(Vector::fold(&x, 1.0, |product, a| product * a), 1.0);
+}
+
+#[test]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector_zip_map_panic() {
+    let x = DVector::from_slice(X);
+    let _ = Vector::zip_map(&x, &DVector::from_slice(&[1.0, 2.0]), |a, b| a + b);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_svector() {
+    native_map_test_helper(&SVector::<f64, 3>::from_slice(X), &SVector::from_slice(Y));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1() {
+    native_map_test_helper(&Array1::from_slice(X), &Array1::from_slice(Y));
+    let x = Array1::<f64>::zeros(0);
+    assert!(Vector::is_empty(&Vector::zip_map(&x, &x, |a, b| a + b)));
+    assert_eq!(Vector::fold(&x, 1.0, |product, a| product * a), 1.0);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1_non_contiguous() {
+    // Reversing the array gives it a negative stride, so elements must be paired by index rather
+    // than by position in memory.
+    let mut x = Array1::from_slice(&[3.0, -2.0, 1.0]);
+    x.invert_axis(ndarray::Axis(0));
+    let y = Array1::from_slice(Y);
+    assert_eq!(
+        Vector::zip_map(&x, &y, |a, b| a - 2.0 * b),
+        Array1::from_slice(ZIP)
+    );
+    let mut z = y.clone();
+    Vector::zip_apply(&mut z, &x, |b, a| *b = a - 2.0 * *b);
+    assert_eq!(z, Array1::from_slice(ZIP));
+}
+
+#[test]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1_zip_apply_panic() {
+    let mut x = Array1::from_slice(X);
+    Vector::zip_apply(&mut x, &Array1::from_slice(&[1.0, 2.0]), |a, b| *a += b);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_col() {
+    native_map_test_helper(&Col::from_slice(X), &Col::from_slice(Y));
+    let x = Col::<f64>::zeros(0);
+    assert!(Vector::is_empty(&Vector::zip_map(&x, &x, |a, b| a + b)));
+    assert_eq!(Vector::fold(&x, 1.0, |product, a| product * a), 1.0);
+}
+
+#[test]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+#[cfg(feature = "faer")]
+fn test_faer_col_zip_map_panic() {
+    let x = Col::from_slice(X);
+    let _ = Vector::zip_map(&x, &Col::from_slice(&[1.0, 2.0]), |a, b| a + b);
+}
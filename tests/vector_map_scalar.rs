@@ -0,0 +1,90 @@
+#[cfg(feature = "faer")]
+use faer::Col;
+use linalg_traits::{Scalar, Vector};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DVector, SVector};
+#[cfg(feature = "ndarray")]
+use ndarray::Array1;
+use numtest::*;
+
+// Test conditions.
+static X: &[f64; 3] = &[1.0, -2.0, 3.0];
+
+// Expected result.
+static Y: &[f64; 3] = &[1.5, -1.5, 3.5];
+
+/// Lift a vector of `f64`s into a vector of another scalar type.
+fn lift<S: Scalar, V: Vector<f64>>(x: &V, offset: f64) -> V::VectorT<S> {
+    x.map_scalar(|a| S::new(a + offset))
+}
+
+/// Check mapping a length-3 vector to a vector of another scalar type.
+fn map_scalar_test_helper<V: Vector<f64>>() {
+    let x = V::from_slice(X);
+    let y = lift::<f64, V>(&x, 0.5);
+    assert_eq!(y.len(), 3);
+    assert_eq!(y, V::VectorT::<f64>::from_slice(Y));
+}
+
+#[test]
+fn test_vec() {
+    map_scalar_test_helper::<Vec<f64>>();
+}
+
+#[test]
+fn test_vec_empty() {
+    let x: Vec<f64> = Vec::new();
+    assert!(lift::<f64, _>(&x, 1.0).is_empty());
+}
+
+#[test]
+fn test_array() {
+    let y: [f64; 3] = lift(X, 0.5);
+    assert_arrays_equal!(y, Y);
+    let y: [f64; 0] = lift(&[], 0.5);
+    assert!(y.is_empty());
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector() {
+    let y: DVector<f64> = lift(&DVector::from_slice(X), 0.5);
+    assert_arrays_equal!(y.as_slice(), Y);
+    let y: DVector<f64> = lift(&DVector::<f64>::zeros(0), 0.5);
+    assert!(Vector::is_empty(&y));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_svector() {
+    let y: SVector<f64, 3> = lift(&SVector::<f64, 3>::from_slice(X), 0.5);
+    assert_arrays_equal!(y.as_slice(), Y);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1() {
+    let y: Array1<f64> = lift(&Array1::from_slice(X), 0.5);
+    assert_arrays_equal!(Vector::as_slice(&y), Y);
+    let y: Array1<f64> = lift(&Array1::<f64>::zeros(0), 0.5);
+    assert!(Vector::is_empty(&y));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1_non_contiguous() {
+    // Reversing the array gives it a negative stride.
+    let mut x = Array1::from_slice(&[3.0, -2.0, 1.0]);
+    x.invert_axis(ndarray::Axis(0));
+    let y: Array1<f64> = lift(&x, 0.5);
+    assert_eq!(y, Array1::from_slice(Y));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_col() {
+    let y: Col<f64> = lift(&Col::from_slice(X), 0.5);
+    assert_arrays_equal!(Vector::as_slice(&y), Y);
+    let y: Col<f64> = lift(&Col::<f64>::zeros(0), 0.5);
+    assert!(Vector::is_empty(&y));
+}
@@ -0,0 +1,102 @@
+#[cfg(feature = "faer")]
+use faer::Col;
+use linalg_traits::Vector;
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DVector, SVector};
+#[cfg(feature = "ndarray")]
+use ndarray::Array1;
+
+// Test conditions.
+static X: &[f64; 3] = &[3.0, -1.0, 2.0];
+
+/// Check the slice access on a length-3 vector whose elements are stored contiguously.
+fn mut_slice_test_helper<V: Vector<f64>>() {
+    let mut x = V::from_slice(X);
+
+    // Zero-copy slice access.
+    assert_eq!(x.try_as_contiguous_slice(), Some(&X[..]));
+    x.try_as_contiguous_mut_slice().unwrap()[1] = 5.0;
+    assert_eq!(x, V::from_slice(&[3.0, 5.0, 2.0]));
+
+    // Closure-based slice access.
+    let max = x.with_mut_slice(|slice| {
+        slice.sort_by(f64::total_cmp);
+        slice[2]
+    });
+    assert_eq!(max, 5.0);
+    assert_eq!(x, V::from_slice(&[2.0, 3.0, 5.0]));
+}
+
+#[test]
+fn test_vec() {
+    mut_slice_test_helper::<Vec<f64>>();
+}
+
+#[test]
+fn test_vec_empty() {
+    let mut x: Vec<f64> = Vec::new();
+    assert_eq!(x.try_as_contiguous_slice(), Some(&[][..]));
+    assert_eq!(x.with_mut_slice(|slice| slice.len()), 0);
+}
+
+#[test]
+fn test_array() {
+    let mut x = *X;
+    assert_eq!(x.try_as_contiguous_slice(), Some(&X[..]));
+    x.try_as_contiguous_mut_slice().unwrap()[1] = 5.0;
+    assert_eq!(x, [3.0, 5.0, 2.0]);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector() {
+    let mut x = DVector::from_slice(X);
+    assert_eq!(x.try_as_contiguous_slice(), Some(&X[..]));
+    x.try_as_contiguous_mut_slice().unwrap()[1] = 5.0;
+    assert_eq!(x[1], 5.0);
+    let mut x = DVector::<f64>::zeros(0);
+    assert_eq!(x.try_as_contiguous_mut_slice(), Some(&mut [][..]));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_svector() {
+    let mut x = SVector::<f64, 3>::from_slice(X);
+    assert_eq!(x.try_as_contiguous_slice(), Some(&X[..]));
+    x.try_as_contiguous_mut_slice().unwrap()[1] = 5.0;
+    assert_eq!(x[1], 5.0);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1() {
+    let mut x = Array1::from_slice(X);
+    assert_eq!(x.try_as_contiguous_slice(), Some(&X[..]));
+    x.try_as_contiguous_mut_slice().unwrap()[1] = 5.0;
+    assert_eq!(x[1], 5.0);
+    let mut x = Array1::<f64>::zeros(0);
+    assert_eq!(x.try_as_contiguous_mut_slice(), Some(&mut [][..]));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1_non_contiguous() {
+    // Reversing the array gives it a negative stride.
+    let mut x = Array1::from_slice(X);
+    x.invert_axis(ndarray::Axis(0));
+    assert_eq!(x.try_as_contiguous_slice(), None);
+    assert_eq!(x.try_as_contiguous_mut_slice(), None);
+    x.with_mut_slice(|slice| slice[0] = 0.0);
+    assert_eq!(x, Array1::from_slice(&[0.0, -1.0, 3.0]));
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_col() {
+    let mut x = Col::from_slice(X);
+    assert_eq!(x.try_as_contiguous_slice(), Some(&X[..]));
+    x.try_as_contiguous_mut_slice().unwrap()[1] = 5.0;
+    assert_eq!(x[1], 5.0);
+    let mut x = Col::<f64>::zeros(0);
+    assert_eq!(x.try_as_contiguous_mut_slice(), Some(&mut [][..]));
+}
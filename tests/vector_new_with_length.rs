@@ -15,6 +15,17 @@ fn test_vec() {
     );
 }
 
+#[test]
+fn test_array() {
+    assert_arrays_equal!(<[f64; 3]>::new_with_length(3), [0.0, 0.0, 0.0]);
+}
+
+#[test]
+#[should_panic(expected = "Length must match the fixed size of the array.")]
+fn test_array_panic() {
+    let _ = <[f64; 2]>::new_with_length(3);
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dvector() {
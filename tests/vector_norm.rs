@@ -0,0 +1,167 @@
+#[cfg(feature = "faer")]
+use faer::Col;
+use linalg_traits::Vector;
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DVector, SVector};
+use numtest::*;
+
+// Test conditions.
+static X: &[f64; 3] = &[3.0, -4.0, 12.0];
+static X_LARGE: &[f64; 3] = &[3e300, -4e300, 12e300];
+static X_SMALL: &[f64; 3] = &[3e-300, -4e-300, 12e-300];
+static REFERENCE: &[f64; 3] = &[1.0, -10.0, 100.0];
+
+/// Check every norm of a length-3 vector.
+fn norm_test_helper<V: Vector<f64>>() {
+    let x = V::from_slice(X);
+
+    // Standard norms.
+    assert_eq!(x.norm_squared(), 169.0);
+    assert_equal_to_decimal!(x.norm(), 13.0, 15);
+    assert_eq!(x.norm_l1(), 19.0);
+    assert_eq!(x.norm_inf(), 12.0);
+
+    // p-norms.
+    assert_eq!(x.norm_p(1.0), 19.0);
+    assert_equal_to_decimal!(x.norm_p(2.0), 13.0, 15);
+    assert_equal_to_decimal!(x.norm_p(3.0), 1819.0_f64.cbrt(), 14);
+    assert_eq!(x.norm_p(f64::INFINITY), 12.0);
+
+    // Overflow and underflow safety.
+    assert_equal_to_rtol!(V::from_slice(X_LARGE).norm(), 13e300, 1e-15);
+    assert_equal_to_rtol!(V::from_slice(X_SMALL).norm(), 13e-300, 1e-15);
+    assert_equal_to_rtol!(
+        V::from_slice(X_LARGE).norm_p(3.0),
+        1819.0_f64.cbrt() * 1e300,
+        1e-14
+    );
+
+    // Normalization.
+    let expected = [3.0 / 13.0, -4.0 / 13.0, 12.0 / 13.0];
+    assert_arrays_equal_to_decimal!(x.normalize().as_slice(), expected, 15);
+    let mut y = x.clone();
+    assert_equal_to_decimal!(y.normalize_in_place(), 13.0, 15);
+    assert_arrays_equal_to_decimal!(y.as_slice(), expected, 15);
+
+    // Weighted RMS norm (weights are 1 / (0.1 + 0.1 * |refᵢ|) = [5, 10/11, 10/101]).
+    let reference = V::from_slice(REFERENCE);
+    let expected =
+        ((15.0_f64.powi(2) + (40.0 / 11.0_f64).powi(2) + (120.0 / 101.0_f64).powi(2)) / 3.0).sqrt();
+    assert_equal_to_decimal!(x.norm_wrms(&reference, 0.1, 0.1), expected, 14);
+}
+
+#[test]
+fn test_vec() {
+    norm_test_helper::<Vec<f64>>();
+}
+
+#[test]
+fn test_vec_empty() {
+    let x: Vec<f64> = Vec::new();
+    assert_eq!(x.norm(), 0.0);
+    assert_eq!(x.norm_l1(), 0.0);
+    assert_eq!(x.norm_inf(), 0.0);
+    assert_eq!(x.norm_p(3.0), 0.0);
+    assert_eq!(x.norm_wrms(&Vec::new(), 1e-6, 1e-3), 0.0);
+}
+
+#[test]
+fn test_vec_non_finite() {
+    let x = vec![1.0, f64::NAN, 2.0];
+    assert!(x.norm().is_nan());
+    assert!(x.norm_inf().is_nan());
+    let y = vec![f64::INFINITY, 1.0, f64::INFINITY];
+    assert_eq!(y.norm(), f64::INFINITY);
+    assert_eq!(y.norm_inf(), f64::INFINITY);
+}
+
+#[test]
+#[should_panic(expected = "Order of the p-norm must be at least 1 (got 0.5).")]
+fn test_vec_norm_p_panic() {
+    let x = Vec::from_slice(X);
+    x.norm_p(0.5);
+}
+
+#[test]
+#[should_panic(
+    expected = "Length of the other vector (2) does not match the length of this vector (3)."
+)]
+fn test_vec_norm_wrms_panic() {
+    let x = Vec::from_slice(X);
+    x.norm_wrms(&vec![1.0, 2.0], 1e-6, 1e-3);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector() {
+    assert_equal_to_decimal!(Vector::norm(&DVector::from_slice(X)), 13.0, 15);
+    assert_eq!(Vector::norm(&DVector::<f64>::zeros(3)), 0.0);
+    assert_eq!(Vector::norm(&DVector::<f64>::zeros(0)), 0.0);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector_rescaled() {
+    // nalgebra's unscaled sum of squares overflows or underflows for these vectors.
+    assert_equal_to_rtol!(Vector::norm(&DVector::from_slice(X_LARGE)), 13e300, 1e-15);
+    assert_equal_to_rtol!(Vector::norm(&DVector::from_slice(X_SMALL)), 13e-300, 1e-15);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector_non_finite() {
+    assert!(Vector::norm(&DVector::from_slice(&[1.0, f64::NAN, 2.0])).is_nan());
+    assert_eq!(
+        Vector::norm(&DVector::from_slice(&[f64::INFINITY, 1.0])),
+        f64::INFINITY
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_svector() {
+    assert_equal_to_decimal!(Vector::norm(&SVector::<f64, 3>::from_slice(X)), 13.0, 15);
+    assert_equal_to_rtol!(
+        Vector::norm(&SVector::<f64, 3>::from_slice(X_LARGE)),
+        13e300,
+        1e-15
+    );
+    assert_equal_to_rtol!(
+        Vector::norm(&SVector::<f64, 3>::from_slice(X_SMALL)),
+        13e-300,
+        1e-15
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_col() {
+    let x = Col::from_slice(X);
+    assert_eq!(Vector::norm_squared(&x), 169.0);
+    assert_equal_to_decimal!(Vector::norm(&x), 13.0, 15);
+    assert_eq!(Vector::norm_l1(&x), 19.0);
+    assert_equal_to_rtol!(Vector::norm(&Col::from_slice(X_LARGE)), 13e300, 1e-15);
+    assert_equal_to_rtol!(Vector::norm(&Col::from_slice(X_SMALL)), 13e-300, 1e-15);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_col_empty() {
+    let x = Col::<f64>::zeros(0);
+    assert_eq!(Vector::norm_squared(&x), 0.0);
+    assert_eq!(Vector::norm(&x), 0.0);
+    assert_eq!(Vector::norm_l1(&x), 0.0);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_col_non_finite() {
+    let x = Col::from_slice(&[1.0, f64::NAN, 2.0]);
+    assert!(Vector::norm_squared(&x).is_nan());
+    assert!(Vector::norm(&x).is_nan());
+    assert!(Vector::norm_l1(&x).is_nan());
+    assert_eq!(
+        Vector::norm(&Col::from_slice(&[f64::INFINITY, 1.0])),
+        f64::INFINITY
+    );
+}
@@ -0,0 +1,209 @@
+#[cfg(feature = "faer")]
+use faer::Col;
+use linalg_traits::{NanPolicy, Vector};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DVector, SVector};
+#[cfg(feature = "ndarray")]
+use ndarray::Array1;
+use numtest::*;
+
+// Test conditions.
+static X: &[f64; 5] = &[2.0, -7.0, 4.0, 7.0, -1.0];
+static X_NAN: &[f64; 5] = &[2.0, f64::NAN, 4.0, -7.0, f64::NAN];
+
+/// Check every reduction of a length-5 vector, with and without NaNs.
+fn reductions_test_helper<V: Vector<f64>>() {
+    use NanPolicy::{Ignore, Propagate};
+
+    // No NaNs (both policies agree).
+    let x = V::from_slice(X);
+    for policy in [Propagate, Ignore] {
+        assert_eq!(x.sum(policy), 5.0);
+        assert_eq!(x.product(policy), 392.0);
+        assert_eq!(x.min(policy), -7.0);
+        assert_eq!(x.max(policy), 7.0);
+        assert_eq!(x.amax(policy), 7.0);
+        assert_eq!(x.argmin(policy), Some(1));
+        assert_eq!(x.argmax(policy), Some(3));
+        assert_eq!(x.mean(policy), 1.0);
+        assert_equal_to_decimal!(x.variance(policy), 22.8, 14);
+    }
+
+    // NaNs propagated.
+    let x = V::from_slice(X_NAN);
+    assert!(x.sum(Propagate).is_nan());
+    assert!(x.product(Propagate).is_nan());
+    assert!(x.min(Propagate).is_nan());
+    assert!(x.max(Propagate).is_nan());
+    assert!(x.amax(Propagate).is_nan());
+    assert_eq!(x.argmin(Propagate), Some(1));
+    assert_eq!(x.argmax(Propagate), Some(1));
+    assert!(x.mean(Propagate).is_nan());
+    assert!(x.variance(Propagate).is_nan());
+
+    // NaNs ignored.
+    assert_eq!(x.sum(Ignore), -1.0);
+    assert_eq!(x.product(Ignore), -56.0);
+    assert_eq!(x.min(Ignore), -7.0);
+    assert_eq!(x.max(Ignore), 4.0);
+    assert_eq!(x.amax(Ignore), 7.0);
+    assert_eq!(x.argmin(Ignore), Some(3));
+    assert_eq!(x.argmax(Ignore), Some(2));
+    assert_equal_to_decimal!(x.mean(Ignore), -1.0 / 3.0, 15);
+    assert_equal_to_decimal!(x.variance(Ignore), 206.0 / 9.0, 14);
+}
+
+/// Check every reduction of an empty vector.
+fn empty_reductions_test_helper<V: Vector<f64>>() {
+    let x = V::from_slice(&[]);
+    assert_eq!(x.sum(NanPolicy::Propagate), 0.0);
+    assert_eq!(x.product(NanPolicy::Propagate), 1.0);
+    assert_eq!(x.min(NanPolicy::Propagate), f64::INFINITY);
+    assert_eq!(x.max(NanPolicy::Propagate), f64::NEG_INFINITY);
+    assert_eq!(x.amax(NanPolicy::Propagate), 0.0);
+    assert_eq!(x.argmin(NanPolicy::Propagate), None);
+    assert_eq!(x.argmax(NanPolicy::Propagate), None);
+    assert!(x.mean(NanPolicy::Propagate).is_nan());
+    assert!(x.variance(NanPolicy::Propagate).is_nan());
+}
+
+#[test]
+fn test_vec() {
+    reductions_test_helper::<Vec<f64>>();
+}
+
+#[test]
+fn test_vec_empty() {
+    empty_reductions_test_helper::<Vec<f64>>();
+}
+
+#[test]
+fn test_vec_all_nan() {
+    let x = vec![f64::NAN, f64::NAN];
+    assert_eq!(x.sum(NanPolicy::Ignore), 0.0);
+    assert_eq!(x.max(NanPolicy::Ignore), f64::NEG_INFINITY);
+    assert_eq!(x.argmax(NanPolicy::Ignore), None);
+    assert!(x.mean(NanPolicy::Ignore).is_nan());
+    assert!(x.variance(NanPolicy::Ignore).is_nan());
+}
+
+#[test]
+fn test_vec_ties() {
+    let x = vec![3.0, -1.0, 3.0, -1.0];
+    assert_eq!(x.argmin(NanPolicy::default()), Some(1));
+    assert_eq!(x.argmax(NanPolicy::default()), Some(0));
+}
+
+#[test]
+fn test_vec_variance_large_offset() {
+    // The one-pass formula loses every significant digit here.
+    let x = vec![1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0];
+    assert_eq!(x.variance(NanPolicy::Propagate), 22.5);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector() {
+    let x = DVector::from_slice(X);
+    assert_eq!(Vector::sum(&x, NanPolicy::Propagate), 5.0);
+    assert_eq!(Vector::product(&x, NanPolicy::Propagate), 392.0);
+    assert_eq!(Vector::mean(&x, NanPolicy::Propagate), 1.0);
+    assert_equal_to_decimal!(Vector::variance(&x, NanPolicy::Propagate), 22.8, 14);
+    let x = DVector::from_slice(X_NAN);
+    assert!(Vector::sum(&x, NanPolicy::Propagate).is_nan());
+    assert!(Vector::product(&x, NanPolicy::Propagate).is_nan());
+    assert!(Vector::mean(&x, NanPolicy::Propagate).is_nan());
+    assert!(Vector::variance(&x, NanPolicy::Propagate).is_nan());
+    assert_eq!(Vector::sum(&x, NanPolicy::Ignore), -1.0);
+    assert_equal_to_decimal!(Vector::variance(&x, NanPolicy::Ignore), 206.0 / 9.0, 14);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector_empty() {
+    // nalgebra itself returns 0 for the mean and variance of an empty vector.
+    let x = DVector::<f64>::zeros(0);
+    assert_eq!(Vector::sum(&x, NanPolicy::Propagate), 0.0);
+    assert_eq!(Vector::product(&x, NanPolicy::Propagate), 1.0);
+    assert!(Vector::mean(&x, NanPolicy::Propagate).is_nan());
+    assert!(Vector::variance(&x, NanPolicy::Propagate).is_nan());
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector_variance_large_offset() {
+    let x = DVector::from_slice(&[1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0]);
+    assert_eq!(Vector::variance(&x, NanPolicy::Propagate), 22.5);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_svector() {
+    let x = SVector::<f64, 5>::from_slice(X);
+    assert_eq!(Vector::sum(&x, NanPolicy::Propagate), 5.0);
+    assert_eq!(Vector::product(&x, NanPolicy::Propagate), 392.0);
+    assert_eq!(Vector::mean(&x, NanPolicy::Propagate), 1.0);
+    assert_equal_to_decimal!(Vector::variance(&x, NanPolicy::Propagate), 22.8, 14);
+    let x = SVector::<f64, 5>::from_slice(X_NAN);
+    assert!(Vector::mean(&x, NanPolicy::Propagate).is_nan());
+    assert_eq!(Vector::product(&x, NanPolicy::Ignore), -56.0);
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_svector_empty() {
+    let x = SVector::<f64, 0>::zeros();
+    assert!(Vector::mean(&x, NanPolicy::Propagate).is_nan());
+    assert!(Vector::variance(&x, NanPolicy::Propagate).is_nan());
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1() {
+    let x = Array1::from_slice(X);
+    assert_eq!(Vector::sum(&x, NanPolicy::Propagate), 5.0);
+    assert_eq!(Vector::product(&x, NanPolicy::Propagate), 392.0);
+    assert_eq!(Vector::mean(&x, NanPolicy::Propagate), 1.0);
+    let x = Array1::from_slice(X_NAN);
+    assert!(Vector::sum(&x, NanPolicy::Propagate).is_nan());
+    assert!(Vector::product(&x, NanPolicy::Propagate).is_nan());
+    assert!(Vector::mean(&x, NanPolicy::Propagate).is_nan());
+    assert_equal_to_decimal!(Vector::mean(&x, NanPolicy::Ignore), -1.0 / 3.0, 15);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1_empty() {
+    // ndarray itself returns no mean for an empty array.
+    let x = Array1::<f64>::zeros(0);
+    assert_eq!(Vector::sum(&x, NanPolicy::Propagate), 0.0);
+    assert_eq!(Vector::product(&x, NanPolicy::Propagate), 1.0);
+    assert!(Vector::mean(&x, NanPolicy::Propagate).is_nan());
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1_non_contiguous() {
+    let mut x = Array1::from_slice(X);
+    x.invert_axis(ndarray::Axis(0));
+    assert_eq!(Vector::sum(&x, NanPolicy::Propagate), 5.0);
+    assert_eq!(Vector::product(&x, NanPolicy::Propagate), 392.0);
+    assert_eq!(Vector::mean(&x, NanPolicy::Propagate), 1.0);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_col() {
+    let x = Col::from_slice(X);
+    assert_eq!(Vector::sum(&x, NanPolicy::Propagate), 5.0);
+    let x = Col::from_slice(X_NAN);
+    assert!(Vector::sum(&x, NanPolicy::Propagate).is_nan());
+    assert_eq!(Vector::sum(&x, NanPolicy::Ignore), -1.0);
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_col_empty() {
+    let x = Col::<f64>::zeros(0);
+    assert_eq!(Vector::sum(&x, NanPolicy::Propagate), 0.0);
+}
@@ -17,6 +17,13 @@ fn test_vec() {
     assert_arrays_equal!(<Vec<f64> as Vector<f64>>::as_slice(&x), X);
 }
 
+#[test]
+fn test_array() {
+    let x = <[f64; 3] as Vector<f64>>::from_slice(X);
+    assert_arrays_equal!(x, X);
+    assert_arrays_equal!(<[f64; 3] as Vector<f64>>::as_slice(&x), X);
+}
+
 #[test]
 #[cfg(feature = "nalgebra")]
 fn test_nalgebra_dvector() {
@@ -0,0 +1,241 @@
+#[cfg(feature = "faer")]
+use faer::Col;
+use linalg_traits::{LinalgError, Vector};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DVector, SVector};
+#[cfg(feature = "ndarray")]
+use ndarray::Array1;
+
+// Test conditions.
+static X: &[f64; 3] = &[1.0, -2.0, 3.0];
+static Y: &[f64; 3] = &[4.0, 5.0, -6.0];
+
+/// Check the fallible operations on length-3 vectors.
+fn try_test_helper<V: Vector<f64>>() {
+    let x = V::from_slice(X);
+    let y = V::from_slice(Y);
+
+    // Constructors.
+    assert_eq!(V::try_new_with_length(3), Ok(V::new_with_length(3)));
+    assert_eq!(V::try_from_slice(X), Ok(x.clone()));
+
+    // Operations.
+    assert_eq!(x.try_add(&y), Ok(x.add(&y)));
+    assert_eq!(x.try_sub(&y), Ok(x.sub(&y)));
+    assert_eq!(x.try_dot(&y), Ok(-24.0));
+    assert_eq!(x.try_hadamard(&y), Ok(x.hadamard(&y)));
+    assert_eq!(
+        x.try_zip_map(&y, |a, b| a - b),
+        Ok(V::from_slice(&[-3.0, -7.0, 9.0]))
+    );
+    let mut z = x.clone();
+    assert_eq!(z.try_add_assign(&y), Ok(()));
+    assert_eq!(z, x.add(&y));
+    assert_eq!(z.try_sub_assign(&y), Ok(()));
+    assert_eq!(z, x);
+    assert_eq!(z.try_axpy(2.0, &y), Ok(()));
+    assert_eq!(z, V::from_slice(&[9.0, 8.0, -9.0]));
+    assert_eq!(z.try_axpby(1.0, &y, 0.0), Ok(()));
+    assert_eq!(z, y);
+    assert_eq!(x.try_hadamard_div(&x), Ok(V::from_slice(&[1.0; 3])));
+    assert_eq!(z.try_zip_apply(&x, |a, b| *a += b), Ok(()));
+    assert_eq!(z, x.add(&y));
+    assert_eq!(x.try_add_to(&y, &mut z), Ok(()));
+    assert_eq!(z, x.add(&y));
+    assert_eq!(x.try_sub_to(&y, &mut z), Ok(()));
+    assert_eq!(z, x.sub(&y));
+    assert_eq!(x.try_mul_to(2.0, &mut z), Ok(()));
+    assert_eq!(z, x.mul(2.0));
+    assert_eq!(x.try_div_to(2.0, &mut z), Ok(()));
+    assert_eq!(z, x.div(2.0));
+    assert_eq!(z.try_linear_combination(&[(1.0, &x), (-1.0, &y)]), Ok(()));
+    assert_eq!(z, x.sub(&y));
+    assert_eq!(x.try_norm_wrms(&y, 1.0, 0.0), Ok(x.norm_wrms(&y, 1.0, 0.0)));
+
+    // Conversions.
+    assert_eq!(z.try_copy_from(&X.to_vec()), Ok(()));
+    assert_eq!(z, x);
+    assert_eq!(x.try_convert::<Vec<f64>>(), Ok(X.to_vec()));
+    assert_eq!(
+        x.try_convert::<[f64; 2]>(),
+        Err(LinalgError::LengthMismatch {
+            expected: 2,
+            actual: 3
+        })
+    );
+
+    // Basis vectors and generated vectors.
+    assert_eq!(V::try_basis(3, 1), Ok(V::from_slice(&[0.0, 1.0, 0.0])));
+    assert_eq!(
+        V::try_basis(3, 3),
+        Err(LinalgError::IndexOutOfBounds { index: 3, len: 3 })
+    );
+    assert_eq!(
+        V::try_from_fn(3, |i| i as f64),
+        Ok(V::from_slice(&[0.0, 1.0, 2.0]))
+    );
+
+    // Finiteness.
+    assert_eq!(x.check_finite(), Ok(()));
+    assert_eq!(
+        V::from_slice(&[1.0, f64::NAN, 3.0]).check_finite(),
+        Err(LinalgError::NonFinite)
+    );
+    assert_eq!(
+        V::from_slice(&[1.0, 2.0, f64::NEG_INFINITY]).check_finite(),
+        Err(LinalgError::NonFinite)
+    );
+}
+
+/// Check that the fallible operations report length mismatches for dynamically-sized vectors.
+fn try_length_mismatch_test_helper<V: Vector<f64>>() {
+    let x = V::from_slice(X);
+    let z = V::from_slice(&[1.0, 2.0]);
+    let error = LinalgError::LengthMismatch {
+        expected: 3,
+        actual: 2,
+    };
+    assert_eq!(x.try_add(&z), Err(error));
+    assert_eq!(x.try_sub(&z), Err(error));
+    assert_eq!(x.try_dot(&z), Err(error));
+    assert_eq!(x.try_hadamard(&z), Err(error));
+    assert_eq!(x.try_zip_map(&z, |a, b| a + b), Err(error));
+    assert_eq!(x.try_hadamard_div(&z), Err(error));
+    assert_eq!(x.try_norm_wrms(&z, 1.0, 0.0), Err(error));
+
+    // The in-place and preallocated operations leave their outputs unchanged on error.
+    let mut w = x.clone();
+    assert_eq!(w.try_add_assign(&z), Err(error));
+    assert_eq!(w.try_sub_assign(&z), Err(error));
+    assert_eq!(w.try_axpy(2.0, &z), Err(error));
+    assert_eq!(w.try_axpby(2.0, &z, 3.0), Err(error));
+    assert_eq!(w.try_zip_apply(&z, |a, b| *a += b), Err(error));
+    assert_eq!(
+        w.try_linear_combination(&[(1.0, &x), (1.0, &z)]),
+        Err(error)
+    );
+    assert_eq!(w.try_copy_from(&[1.0, 2.0]), Err(error));
+    assert_eq!(w, x);
+    let mut u = z.clone();
+    assert_eq!(x.try_add_to(&x, &mut u), Err(error));
+    assert_eq!(x.try_sub_to(&z, &mut u), Err(error));
+    assert_eq!(x.try_mul_to(2.0, &mut u), Err(error));
+    assert_eq!(x.try_div_to(2.0, &mut u), Err(error));
+    assert_eq!(u, z);
+}
+
+#[test]
+fn test_vec() {
+    try_test_helper::<Vec<f64>>();
+    try_length_mismatch_test_helper::<Vec<f64>>();
+    assert_eq!(Vec::<f64>::static_length(), None);
+}
+
+#[test]
+fn test_array() {
+    assert_eq!(<[f64; 3]>::static_length(), Some(3));
+    assert_eq!(<[f64; 3]>::try_from_slice(X), Ok(*X));
+    assert_eq!(
+        <[f64; 3]>::try_new_with_length(4),
+        Err(LinalgError::LengthMismatch {
+            expected: 3,
+            actual: 4
+        })
+    );
+    assert_eq!(
+        <[f64; 3]>::try_from_slice(&[1.0, 2.0]),
+        Err(LinalgError::LengthMismatch {
+            expected: 3,
+            actual: 2
+        })
+    );
+    assert_eq!(
+        <[f64; 3]>::try_from_fn(4, |i| i as f64),
+        Err(LinalgError::LengthMismatch {
+            expected: 3,
+            actual: 4
+        })
+    );
+    assert_eq!(
+        <[f64; 3]>::try_basis(2, 0),
+        Err(LinalgError::LengthMismatch {
+            expected: 3,
+            actual: 2
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_dvector() {
+    assert_eq!(DVector::<f64>::static_length(), None);
+    let x = DVector::<f64>::try_new_with_length(0).unwrap();
+    assert_eq!(DVector::try_from_slice(&[]), Ok(x.clone()));
+    assert_eq!(x.try_add(&x), Ok(x.clone()));
+    assert_eq!(x.try_dot(&x), Ok(0.0));
+    assert_eq!(
+        x.try_add(&DVector::from_slice(X)),
+        Err(LinalgError::LengthMismatch {
+            expected: 0,
+            actual: 3
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn test_nalgebra_svector() {
+    assert_eq!(SVector::<f64, 3>::static_length(), Some(3));
+    assert_eq!(
+        <SVector<f64, 3> as Vector<f64>>::try_from_slice(X),
+        Ok(SVector::from_column_slice(X))
+    );
+    assert_eq!(
+        SVector::<f64, 3>::try_new_with_length(4),
+        Err(LinalgError::LengthMismatch {
+            expected: 3,
+            actual: 4
+        })
+    );
+    assert_eq!(
+        SVector::<f64, 3>::try_from_slice(&[1.0, 2.0]),
+        Err(LinalgError::LengthMismatch {
+            expected: 3,
+            actual: 2
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_ndarray_array1() {
+    assert_eq!(Array1::<f64>::static_length(), None);
+    let x = Array1::<f64>::try_new_with_length(0).unwrap();
+    assert_eq!(Array1::try_from_slice(&[]), Ok(x.clone()));
+    assert_eq!(x.try_add(&x), Ok(x.clone()));
+    assert_eq!(x.try_dot(&x), Ok(0.0));
+    assert_eq!(
+        x.try_add(&Array1::from_slice(X)),
+        Err(LinalgError::LengthMismatch {
+            expected: 0,
+            actual: 3
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "faer")]
+fn test_faer_col() {
+    assert_eq!(Col::<f64>::static_length(), None);
+    let x = Col::<f64>::try_new_with_length(0).unwrap();
+    assert_eq!(Col::try_from_slice(&[]), Ok(x.clone()));
+    assert_eq!(x.try_add(&x), Ok(x.clone()));
+    assert_eq!(x.try_dot(&x), Ok(0.0));
+    assert_eq!(
+        x.try_add(&Col::from_slice(X)),
+        Err(LinalgError::LengthMismatch {
+            expected: 0,
+            actual: 3
+        })
+    );
+}